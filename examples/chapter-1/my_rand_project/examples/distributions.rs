@@ -0,0 +1,74 @@
+use rand::distributions::{Distribution, Uniform, WeightedIndex};
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom; // Adds `shuffle` and `choose` to slices
+use rand::{Rng, SeedableRng};
+use rand_distr::Normal; // Distributions beyond the basics live in `rand_distr`
+
+// Taking `&mut impl Rng` instead of creating the generator inside the
+// function lets the caller decide: entropy in production, a fixed seed in tests.
+fn roll_dice(rng: &mut impl Rng, count: usize) -> Vec<u8> {
+    // `Uniform` precomputes the range once, which is faster than
+    // calling `gen_range` in a loop.
+    let die = Uniform::new_inclusive(1, 6);
+    die.sample_iter(rng).take(count).collect()
+}
+
+fn main() {
+    // The same seed always produces the same sequence of numbers
+    let mut rng = SmallRng::seed_from_u64(42);
+
+    println!("Dice: {:?}", roll_dice(&mut rng, 10));
+
+    // Normal distribution: mean 170 cm, standard deviation 10 cm
+    let height = Normal::new(170.0, 10.0).unwrap();
+    let heights: Vec<f64> = height.sample_iter(&mut rng).take(1000).collect();
+    let mean = heights.iter().sum::<f64>() / heights.len() as f64;
+    println!("Average of 1000 heights: {:.1} cm", mean);
+
+    // Weighted choice: "common" is 7 times more likely than "rare"
+    let items = ["common", "uncommon", "rare"];
+    let weights = [70, 20, 10];
+    let loot = WeightedIndex::new(weights).unwrap();
+    let mut counts = [0; 3];
+    for _ in 0..1000 {
+        counts[loot.sample(&mut rng)] += 1;
+    }
+    for (item, count) in items.iter().zip(counts) {
+        println!("{item}: {count}");
+    }
+
+    // Shuffling a Vec in place and picking a random element
+    let mut cards: Vec<u32> = (1..=10).collect();
+    cards.shuffle(&mut rng);
+    println!("Shuffled: {:?}", cards);
+    println!("Random pick: {:?}", cards.choose(&mut rng));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_rolls() {
+        let mut first = SmallRng::seed_from_u64(7);
+        let mut second = SmallRng::seed_from_u64(7);
+        assert_eq!(roll_dice(&mut first, 20), roll_dice(&mut second, 20));
+    }
+
+    #[test]
+    fn rolls_stay_in_range() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        assert!(roll_dice(&mut rng, 1000)
+            .iter()
+            .all(|roll| (1..=6).contains(roll)));
+    }
+
+    #[test]
+    fn shuffle_keeps_all_elements() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        let mut cards: Vec<u32> = (1..=10).collect();
+        cards.shuffle(&mut rng);
+        cards.sort();
+        assert_eq!(cards, (1..=10).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,62 @@
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use rayon::prelude::*;
+use std::time::Instant;
+
+// Throw `samples` darts at a 1x1 square and count how many land inside
+// the quarter circle of radius 1. The ratio approaches π/4.
+fn count_hits(rng: &mut impl Rng, samples: u64) -> u64 {
+    (0..samples)
+        .filter(|_| {
+            let x: f64 = rng.gen();
+            let y: f64 = rng.gen();
+            x * x + y * y <= 1.0
+        })
+        .count() as u64
+}
+
+fn estimate_pi(seed: u64, samples: u64) -> f64 {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    4.0 * count_hits(&mut rng, samples) as f64 / samples as f64
+}
+
+// Split the work into chunks and give every chunk its own generator.
+// Seeding each chunk from its index keeps the result reproducible no matter
+// which thread runs which chunk.
+fn estimate_pi_parallel(seed: u64, samples: u64) -> f64 {
+    const CHUNKS: u64 = 64;
+    let per_chunk = samples / CHUNKS;
+    let hits: u64 = (0..CHUNKS)
+        .into_par_iter()
+        .map(|chunk| {
+            let mut rng = SmallRng::seed_from_u64(seed.wrapping_add(chunk));
+            count_hits(&mut rng, per_chunk)
+        })
+        .sum();
+    4.0 * hits as f64 / (per_chunk * CHUNKS) as f64
+}
+
+fn main() {
+    println!("{:>12} {:>10} {:>10}", "samples", "estimate", "error");
+    for power in 2..=7 {
+        let samples = 10u64.pow(power);
+        let pi = estimate_pi(42, samples);
+        let error = (pi - std::f64::consts::PI).abs();
+        println!("{:>12} {:>10.6} {:>10.6}", samples, pi, error);
+    }
+
+    let samples = 50_000_000;
+
+    let start = Instant::now();
+    let pi = estimate_pi(42, samples);
+    println!("Sequential: {:.6} in {:?}", pi, start.elapsed());
+
+    let start = Instant::now();
+    let pi = estimate_pi_parallel(42, samples);
+    println!(
+        "Parallel:   {:.6} in {:?} (threads: {})",
+        pi,
+        start.elapsed(),
+        rayon::current_num_threads()
+    );
+}
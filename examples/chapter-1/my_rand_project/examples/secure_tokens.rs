@@ -0,0 +1,59 @@
+use rand::distributions::{Alphanumeric, DistString};
+use rand::rngs::{OsRng, SmallRng, StdRng};
+use rand::{Rng, RngCore, SeedableRng};
+
+// Formats bytes as a lowercase hex string, e.g. [0xab, 0x01] -> "ab01"
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+// An API token must be impossible to guess, so the bytes come straight
+// from the operating system's secure random source.
+fn generate_api_token() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    to_hex(&bytes)
+}
+
+// A salt doesn't have to be secret, but it must be unique for every password.
+// 16 random bytes from a secure generator make collisions practically impossible.
+fn generate_salt(rng: &mut impl RngCore) -> [u8; 16] {
+    let mut salt = [0u8; 16];
+    rng.fill_bytes(&mut salt);
+    salt
+}
+
+fn main() {
+    // OsRng asks the operating system for every value. It is secure but
+    // slower, so it fits values you generate rarely: keys, tokens, seeds.
+    println!("API token:      {}", generate_api_token());
+
+    // StdRng is a cryptographically secure generator (ChaCha). Seeded from
+    // the OS once, it quickly produces many secure values.
+    let mut secure_rng = StdRng::from_entropy();
+    println!("Salt:           {}", to_hex(&generate_salt(&mut secure_rng)));
+    println!(
+        "Session id:     {}",
+        Alphanumeric.sample_string(&mut secure_rng, 24)
+    );
+
+    // `rand::thread_rng()` is also cryptographically secure and is
+    // the easiest choice when you don't need a specific generator.
+    let reset_code: u32 = rand::thread_rng().gen_range(100_000..1_000_000);
+    println!("Reset code:     {}", reset_code);
+
+    // SmallRng is fast but NOT cryptographically secure. Anyone who learns
+    // its seed, or observes enough of its output, can predict every value
+    // it will produce. Use it for simulations, games, and tests, never for
+    // passwords, tokens, salts, keys, or anything an attacker could exploit.
+    let mut first = SmallRng::seed_from_u64(2024);
+    let mut second = SmallRng::seed_from_u64(2024);
+    println!(
+        "SmallRng token: {}",
+        Alphanumeric.sample_string(&mut first, 24)
+    );
+    println!(
+        "Predicted:      {}",
+        Alphanumeric.sample_string(&mut second, 24)
+    );
+}
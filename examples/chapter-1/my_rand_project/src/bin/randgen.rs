@@ -0,0 +1,143 @@
+use clap::{Parser, Subcommand, ValueEnum};
+use rand::distributions::{Alphanumeric, DistString};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+use std::error::Error;
+use std::io;
+use uuid::Uuid;
+
+#[derive(Parser)]
+#[command(name = "randgen", version = "1.0", about = "Generates random test data")]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+
+    /// Number of records to generate
+    #[arg(short, long, default_value_t = 10, global = true)]
+    count: usize,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Format::Json, global = true)]
+    format: Format,
+
+    /// Seed for reproducible output
+    #[arg(short, long, global = true)]
+    seed: Option<u64>,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Generates users with a name, email, and age
+    Users,
+    /// Generates random alphanumeric passwords
+    Passwords {
+        /// Password length
+        #[arg(short, long, default_value_t = 16)]
+        length: usize,
+    },
+    /// Generates random version 4 UUIDs
+    Uuids,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Json,
+    Csv,
+}
+
+#[derive(Serialize)]
+struct User {
+    id: usize,
+    name: String,
+    email: String,
+    age: u8,
+}
+
+#[derive(Serialize)]
+struct Password {
+    password: String,
+}
+
+#[derive(Serialize)]
+struct Id {
+    uuid: Uuid,
+}
+
+const FIRST_NAMES: [&str; 6] = ["Alice", "Bob", "Carol", "Dave", "Eve", "Frank"];
+const LAST_NAMES: [&str; 5] = ["Smith", "Jones", "Brown", "Taylor", "Wilson"];
+
+fn generate_user(rng: &mut impl Rng, id: usize) -> User {
+    let first = FIRST_NAMES.choose(rng).unwrap();
+    let last = LAST_NAMES.choose(rng).unwrap();
+    User {
+        id,
+        name: format!("{first} {last}"),
+        email: format!("{}.{}{}@example.com", first, last, id).to_lowercase(),
+        age: rng.gen_range(18..=80),
+    }
+}
+
+fn generate_password(rng: &mut impl Rng, length: usize) -> Password {
+    Password {
+        password: Alphanumeric.sample_string(rng, length),
+    }
+}
+
+fn generate_uuid(rng: &mut impl Rng) -> Id {
+    // Build the UUID from our own generator so that `--seed` applies to it too
+    Id {
+        uuid: uuid::Builder::from_random_bytes(rng.gen()).into_uuid(),
+    }
+}
+
+// Any `Serialize` type can be written as JSON or CSV,
+// so each subcommand only has to produce its records.
+fn write_records<T: Serialize>(records: &[T], format: Format) -> Result<(), Box<dyn Error>> {
+    let stdout = io::stdout().lock();
+    match format {
+        Format::Json => {
+            serde_json::to_writer_pretty(stdout, records)?;
+            println!();
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(stdout);
+            for record in records {
+                writer.serialize(record)?;
+            }
+            writer.flush()?;
+        }
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let cli = Cli::parse();
+
+    // StdRng is a cryptographically secure generator, but with `--seed` its
+    // output becomes predictable: never use seeded passwords for real accounts.
+    let mut rng = match cli.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    match cli.command {
+        Commands::Users => {
+            let users: Vec<User> = (1..=cli.count)
+                .map(|id| generate_user(&mut rng, id))
+                .collect();
+            write_records(&users, cli.format)
+        }
+        Commands::Passwords { length } => {
+            let passwords: Vec<Password> = (0..cli.count)
+                .map(|_| generate_password(&mut rng, length))
+                .collect();
+            write_records(&passwords, cli.format)
+        }
+        Commands::Uuids => {
+            let ids: Vec<Id> = (0..cli.count).map(|_| generate_uuid(&mut rng)).collect();
+            write_records(&ids, cli.format)
+        }
+    }
+}
@@ -6,6 +6,7 @@ use rand::SeedableRng; // Trait that allows creating a random number generator f
 fn main() {
     // Create an instance of `SmallRng`, seeded from the system's entropy source.
     // `from_entropy()` provides a convenient way to initialize the RNG with randomness from the system.
+    // `SmallRng` is not cryptographically secure: fine for a dice roll, never for passwords or tokens.
     let mut rng = SmallRng::from_entropy();
 
     // Generate a random number of type `u8` in the range 1 to 100 (inclusive of 1 and exclusive of 101).
@@ -27,20 +27,33 @@ impl User {
     }
 }
 
+#[cfg(test)]
 mod tests {
     use super::User;
 
-    #[test]
-    fn test_user_struct() {
-        let user = User {
+    fn alice() -> User {
+        User {
             name: "Alice".to_string(),
             age: 30,
-        };
+        }
+    }
 
-        #[cfg(feature = "serde_json_support")]
-        assert_eq!(user.to_json(), r#"{"name":"Alice","age":30}"#);
+    #[test]
+    fn test_user_struct() {
+        let user = alice();
+        assert_eq!(user.name, "Alice");
+        assert_eq!(user.age, 30);
+    }
 
-        #[cfg(feature = "bincode_support")]
-        assert_eq!(user.to_bincode(), vec![5, 65, 108, 105, 99, 101, 30]);
+    #[cfg(feature = "serde_json_support")]
+    #[test]
+    fn test_to_json() {
+        assert_eq!(alice().to_json(), r#"{"name":"Alice","age":30}"#);
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "bincode_support")]
+    #[test]
+    fn test_to_bincode() {
+        assert_eq!(alice().to_bincode(), vec![5, 65, 108, 105, 99, 101, 30]);
+    }
+}
@@ -0,0 +1,75 @@
+//! Builds and tests the library with every combination of its features,
+//! with warnings treated as errors. It starts one `cargo test` per
+//! combination, so it's slow and ignored by default:
+//!
+//! ```text
+//! cargo test --test feature_matrix -- --ignored --nocapture
+//! ```
+
+use std::process::Command;
+
+/// Every feature in `Cargo.toml`, except `default`.
+const FEATURES: [&str; 2] = ["serde_json_support", "bincode_support"];
+
+/// All subsets of `FEATURES`, from none to all: bit `i` of the counter
+/// says whether `FEATURES[i]` is in the subset.
+fn combinations() -> Vec<Vec<&'static str>> {
+    (0..1u32 << FEATURES.len())
+        .map(|mask| {
+            FEATURES
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, feature)| *feature)
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+#[ignore]
+fn every_feature_combination_builds_and_passes() {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut failed = Vec::new();
+
+    for features in combinations() {
+        let list = features.join(",");
+        let output = Command::new(&cargo)
+            .args(["test", "--no-default-features", "--features", &list])
+            .args([
+                "--manifest-path",
+                concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"),
+            ])
+            // Its own directory, so it doesn't wait for the lock on the
+            // `target` directory of the `cargo test` that runs this test
+            .args([
+                "--target-dir",
+                concat!(env!("CARGO_TARGET_TMPDIR"), "/feature-matrix"),
+            ])
+            .args(["--quiet", "--lib"])
+            .env("RUSTFLAGS", "-D warnings")
+            .output()
+            .expect("failed to run cargo");
+
+        let name = if list.is_empty() { "(none)" } else { &list };
+        if output.status.success() {
+            println!("{name:<40} ok");
+        } else {
+            println!("{name:<40} FAILED");
+            println!("{}", String::from_utf8_lossy(&output.stderr));
+            failed.push(name.to_string());
+        }
+    }
+
+    assert!(failed.is_empty(), "failing combinations: {failed:?}");
+}
+
+#[test]
+fn combinations_cover_every_subset() {
+    let all = combinations();
+    assert_eq!(all.len(), 4);
+    assert!(all.contains(&vec![]));
+    assert!(all.contains(&vec!["serde_json_support"]));
+    assert!(all.contains(&vec!["bincode_support"]));
+    assert!(all.contains(&vec!["serde_json_support", "bincode_support"]));
+}
@@ -1,6 +1,20 @@
-use core_lib::add;
+use core_lib::{add, find_user, to_json, users_with_role};
+use domain_types::{Role, User, UserId};
 
 fn main() {
     let result = add(3, 5);
     println!("3 + 5 = {}", result);
+
+    let users = vec![
+        User::new(1, "Alice", Role::Admin),
+        User::new(2, "Bob", Role::Member),
+        User::new(3, "Carol", Role::Admin),
+    ];
+
+    if let Some(user) = find_user(&users, UserId(2)) {
+        println!("Found {} {}", user.id, user.name);
+    }
+
+    println!("Admins:");
+    println!("{}", to_json(&users_with_role(&users, Role::Admin)));
 }
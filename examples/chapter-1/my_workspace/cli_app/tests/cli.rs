@@ -0,0 +1,42 @@
+// Integration tests live in the `tests` directory and see the crates
+// only from the outside, exactly like any other user of the workspace.
+use core_lib::{find_user, users_with_role, Role, User, UserId};
+use std::process::Command;
+
+fn sample_users() -> Vec<User> {
+    vec![
+        User::new(1, "Alice", Role::Admin),
+        User::new(2, "Bob", Role::Member),
+    ]
+}
+
+#[test]
+fn re_exported_types_work_with_core_lib() {
+    // `core_lib::User` and `domain_types::User` are the same type
+    let user: domain_types::User = User::new(7, "Dave", Role::Member);
+    let users = vec![user];
+    assert_eq!(find_user(&users, UserId(7)).unwrap().name, "Dave");
+}
+
+#[test]
+fn filters_users_by_role() {
+    let users = sample_users();
+    let admins = users_with_role(&users, Role::Admin);
+    assert_eq!(admins.len(), 1);
+    assert_eq!(admins[0].name, "Alice");
+}
+
+#[test]
+fn binary_prints_admins_as_json() {
+    // Cargo builds the binary before running integration tests
+    // and tells us where it is
+    let output = Command::new(env!("CARGO_BIN_EXE_cli_app"))
+        .output()
+        .expect("failed to run cli_app");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert!(stdout.contains("Found #2 Bob"));
+    assert!(stdout.contains("\"role\": \"admin\""));
+    assert!(!stdout.contains("\"role\": \"member\""));
+}
@@ -0,0 +1,34 @@
+use core_lib::{dedup_hashset, dedup_naive};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+// Builds `size` words where every word repeats about four times
+fn make_words(size: usize) -> Vec<String> {
+    (0..size)
+        .map(|i| format!("word{}", i % (size / 4)))
+        .collect()
+}
+
+fn bench_dedup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dedup");
+
+    for size in [100, 1_000, 10_000] {
+        let owned = make_words(size);
+        let words: Vec<&str> = owned.iter().map(String::as_str).collect();
+
+        // Report results as words per second in addition to time per run
+        group.throughput(Throughput::Elements(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("naive", size), &words, |b, words| {
+            // `black_box` keeps the compiler from optimizing the work away
+            b.iter(|| dedup_naive(black_box(words)))
+        });
+        group.bench_with_input(BenchmarkId::new("hashset", size), &words, |b, words| {
+            b.iter(|| dedup_hashset(black_box(words)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_dedup);
+criterion_main!(benches);
@@ -0,0 +1,37 @@
+use std::collections::HashSet;
+
+// Removes repeated words and keeps the first occurrence of each one.
+// `Vec::contains` scans the whole result for every word, so the running
+// time grows with the square of the input size.
+pub fn dedup_naive<'a>(words: &[&'a str]) -> Vec<&'a str> {
+    let mut unique = Vec::new();
+    for &word in words {
+        if !unique.contains(&word) {
+            unique.push(word);
+        }
+    }
+    unique
+}
+
+// Same result, but a `HashSet` remembers the words already seen,
+// so every check takes constant time on average.
+pub fn dedup_hashset<'a>(words: &[&'a str]) -> Vec<&'a str> {
+    let mut seen = HashSet::with_capacity(words.len());
+    words
+        .iter()
+        .copied()
+        .filter(|word| seen.insert(*word))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn both_versions_keep_first_occurrences_in_order() {
+        let words = ["b", "a", "b", "c", "a"];
+        assert_eq!(dedup_naive(&words), ["b", "a", "c"]);
+        assert_eq!(dedup_hashset(&words), ["b", "a", "c"]);
+    }
+}
@@ -5,9 +5,43 @@ pub fn add(left: usize, right: usize) -> usize {
     left + right
 }
 
+// Re-export the shared types, so users of core_lib can write
+// `core_lib::User` without adding domain_types to their dependencies.
+pub use domain_types::{Role, User, UserId};
+
+use serde::Serialize;
+
+mod dedup;
+mod parse;
+pub use dedup::{dedup_hashset, dedup_naive};
+pub use parse::{parse_users, ParseUserError};
+
+// Returns the users with the given role
+pub fn users_with_role(users: &[User], role: Role) -> Vec<&User> {
+    users.iter().filter(|user| user.role == role).collect()
+}
+
+// Looks up a user by id
+pub fn find_user(users: &[User], id: UserId) -> Option<&User> {
+    users.iter().find(|user| user.id == id)
+}
+
+// Splits users into teams of `team_size`; the last team may be smaller.
+// Panics if `team_size` is zero, just like `slice::chunks`.
+pub fn split_into_teams(users: &[User], team_size: usize) -> Vec<&[User]> {
+    assert!(team_size > 0, "team size must be greater than zero");
+    users.chunks(team_size).collect()
+}
+
+// Converts any serializable value into pretty-printed JSON
+pub fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string_pretty(value).expect("serializing to a string can't fail")
+}
+
 // This module contains unit tests for the `add` function.
-#[cfg(test)] // This annotation ensures that the test module is only 
+// The `#[cfg(test)]` annotation ensures that the test module is only
 // included when running tests.
+#[cfg(test)]
 mod tests {
     // Bring the `add` function from the parent scope into this module.
     use super::*;
@@ -20,4 +54,4 @@ mod tests {
         // Assert that the result is equal to 4. If it is not, the test will fail.
         assert_eq!(result, 4);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,56 @@
+use domain_types::{Role, User};
+use std::error::Error;
+use std::fmt;
+
+// Describes what is wrong with a line and where it is
+#[derive(Debug, PartialEq)]
+pub enum ParseUserError {
+    MissingField { line: usize },
+    InvalidId { line: usize, value: String },
+    UnknownRole { line: usize, value: String },
+}
+
+impl fmt::Display for ParseUserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseUserError::MissingField { line } => write!(f, "line {}: missing field", line),
+            ParseUserError::InvalidId { line, value } => {
+                write!(f, "line {}: invalid id '{}'", line, value)
+            }
+            ParseUserError::UnknownRole { line, value } => {
+                write!(f, "line {}: unknown role '{}'", line, value)
+            }
+        }
+    }
+}
+
+impl Error for ParseUserError {}
+
+// Parses users from lines in the form `id,name,role`.
+// Empty lines and lines starting with `#` are skipped.
+pub fn parse_users(input: &str) -> Result<Vec<User>, ParseUserError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line, text)| parse_line(line, text))
+        .collect()
+}
+
+fn parse_line(line: usize, text: &str) -> Result<User, ParseUserError> {
+    let fields: Vec<&str> = text.split(',').map(str::trim).collect();
+    let [id, name, role] = fields[..] else {
+        return Err(ParseUserError::MissingField { line });
+    };
+
+    let id: u64 = id.parse().map_err(|_| ParseUserError::InvalidId {
+        line,
+        value: id.to_string(),
+    })?;
+    let role = Role::from_name(role).ok_or_else(|| ParseUserError::UnknownRole {
+        line,
+        value: role.to_string(),
+    })?;
+    Ok(User::new(id, name, role))
+}
@@ -0,0 +1,16 @@
+// Helpers shared by all integration tests.
+// Files in `tests/common/` are not compiled as separate test crates,
+// so this module is included with `mod common;` where it's needed.
+use std::fs;
+use std::path::PathBuf;
+
+// Reads a file from `tests/fixtures`. `CARGO_MANIFEST_DIR` points to the
+// crate root, so the path works no matter where `cargo test` is started.
+pub fn read_fixture(name: &str) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name);
+    fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("can't read fixture {}: {}", path.display(), error))
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use common::read_fixture;
+use core_lib::{find_user, parse_users, split_into_teams, users_with_role};
+use core_lib::{ParseUserError, Role, UserId};
+use std::error::Error;
+
+#[test]
+fn parses_all_users_from_fixture() {
+    let users = parse_users(&read_fixture("users.txt")).unwrap();
+    assert_eq!(users.len(), 5);
+    assert_eq!(users[0].name, "Alice");
+}
+
+// A test can return `Result`: `?` fails the test with the error message
+// instead of panicking on `unwrap`.
+#[test]
+fn finds_admins_in_fixture() -> Result<(), Box<dyn Error>> {
+    let users = parse_users(&read_fixture("users.txt"))?;
+    let admins = users_with_role(&users, Role::Admin);
+
+    let names: Vec<&str> = admins.iter().map(|user| user.name.as_str()).collect();
+    assert_eq!(names, ["Alice", "Carol"]);
+
+    let bob = find_user(&users, UserId(2)).ok_or("Bob is missing")?;
+    assert_eq!(bob.role, Role::Member);
+    Ok(())
+}
+
+#[test]
+fn reports_the_line_of_an_unknown_role() {
+    let error = parse_users(&read_fixture("invalid_role.txt")).unwrap_err();
+    assert_eq!(
+        error,
+        ParseUserError::UnknownRole {
+            line: 2,
+            value: "owner".to_string()
+        }
+    );
+    assert_eq!(error.to_string(), "line 2: unknown role 'owner'");
+}
+
+#[test]
+fn rejects_lines_with_missing_fields() {
+    let error = parse_users("1,Alice").unwrap_err();
+    assert_eq!(error, ParseUserError::MissingField { line: 1 });
+}
+
+#[test]
+fn splits_users_into_teams() -> Result<(), ParseUserError> {
+    let users = parse_users(&read_fixture("users.txt"))?;
+    let teams = split_into_teams(&users, 2);
+    let sizes: Vec<usize> = teams.iter().map(|team| team.len()).collect();
+    assert_eq!(sizes, [2, 2, 1]);
+    Ok(())
+}
+
+// `expected` checks that the panic message contains this text,
+// so the test doesn't pass because of an unrelated panic.
+#[test]
+#[should_panic(expected = "team size must be greater than zero")]
+fn panics_on_zero_team_size() {
+    let users = parse_users(&read_fixture("users.txt")).unwrap();
+    split_into_teams(&users, 0);
+}
@@ -0,0 +1,36 @@
+// Types shared by every crate in the workspace.
+// Keeping them in a small crate without logic lets both the library
+// and the application use them without depending on each other.
+use serde::Serialize;
+use std::fmt;
+
+// A newtype around `u64`, so a user id can't be mixed up with other numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct UserId(pub u64);
+
+impl fmt::Display for UserId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+// Generated from `roles.txt` by `cargo xtask codegen`
+mod role;
+pub use role::Role;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct User {
+    pub id: UserId,
+    pub name: String,
+    pub role: Role,
+}
+
+impl User {
+    pub fn new(id: u64, name: &str, role: Role) -> Self {
+        User {
+            id: UserId(id),
+            name: name.to_string(),
+            role,
+        }
+    }
+}
@@ -0,0 +1,239 @@
+// Project automation for the workspace, run with `cargo xtask <task>`.
+// Each task is a plain Rust function, so it works the same on Linux, macOS,
+// and Windows, without shell scripts or a Makefile.
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+
+type DynError = Box<dyn Error>;
+
+const HELP: &str = "\
+Usage: cargo xtask <task>
+
+Tasks:
+  dist              Build the release binary and package it in target/dist
+  codegen           Generate domain_types/src/role.rs from domain_types/roles.txt
+  codegen --check   Fail if the generated code is out of date
+  check-all         Run formatting, generated code, clippy, and test checks
+";
+
+fn main() {
+    if let Err(e) = try_main() {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn try_main() -> Result<(), DynError> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match args[..] {
+        ["dist"] => dist(),
+        ["codegen"] => codegen(false),
+        ["codegen", "--check"] => codegen(true),
+        ["check-all"] => check_all(),
+        _ => {
+            print!("{}", HELP);
+            Err("unknown or missing task".into())
+        }
+    }
+}
+
+// The workspace root, one level above this crate. Tasks work from any
+// directory, because Cargo finds the alias from anywhere in the workspace.
+fn project_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask is inside the workspace")
+        .to_path_buf()
+}
+
+// Runs cargo with the given arguments in the workspace root. `CARGO` is the
+// cargo binary that started this task, so the same toolchain is used.
+fn cargo(args: &[&str]) -> Result<(), DynError> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    println!("$ cargo {}", args.join(" "));
+    let status = Command::new(cargo)
+        .args(args)
+        .current_dir(project_root())
+        .status()?;
+    if !status.success() {
+        return Err(format!("`cargo {}` failed", args.join(" ")).into());
+    }
+    Ok(())
+}
+
+fn dist() -> Result<(), DynError> {
+    cargo(&["build", "--release", "--package", "cli_app"])?;
+
+    let root = project_root();
+    // xtask and cli_app both inherit the workspace version
+    let name = format!("cli_app-{}", env!("CARGO_PKG_VERSION"));
+    let dist_dir = root.join("target").join("dist").join(&name);
+    // Start from an empty directory, so no file from an older build is left
+    let _ = fs::remove_dir_all(&dist_dir);
+    fs::create_dir_all(&dist_dir)?;
+
+    let binary = format!("cli_app{}", env::consts::EXE_SUFFIX);
+    fs::copy(
+        root.join("target").join("release").join(&binary),
+        dist_dir.join(&binary),
+    )?;
+    fs::write(
+        dist_dir.join("README.txt"),
+        format!(
+            "{}\nBuilt for {}-{}\n\nRun `{}` to print the admins as JSON.\n",
+            name,
+            env::consts::ARCH,
+            env::consts::OS,
+            binary
+        ),
+    )?;
+
+    println!("Packaged {}:", dist_dir.strip_prefix(&root)?.display());
+    let mut entries: Vec<_> = fs::read_dir(&dist_dir)?.collect::<Result<_, _>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let size = entry.metadata()?.len();
+        println!(
+            "  {:<12} {:>9} bytes",
+            entry.file_name().to_string_lossy(),
+            size
+        );
+    }
+    Ok(())
+}
+
+const ROLES_FILE: &str = "domain_types/roles.txt";
+const ROLE_MODULE: &str = "domain_types/src/role.rs";
+
+fn codegen(check: bool) -> Result<(), DynError> {
+    let root = project_root();
+    let roles = fs::read_to_string(root.join(ROLES_FILE))?;
+    let code = generate_role_enum(&roles)?;
+    let target = root.join(ROLE_MODULE);
+    let current = fs::read_to_string(&target).unwrap_or_default();
+
+    if current == code {
+        println!("{} is up to date", ROLE_MODULE);
+    } else if check {
+        return Err(format!("{} is out of date, run `cargo xtask codegen`", ROLE_MODULE).into());
+    } else {
+        fs::write(&target, code)?;
+        println!("Updated {}", ROLE_MODULE);
+    }
+    Ok(())
+}
+
+// Turns the role names into an enum with a lookup by name
+fn generate_role_enum(roles: &str) -> Result<String, DynError> {
+    let names: Vec<&str> = roles
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+    if names.is_empty() {
+        return Err(format!("{} contains no roles", ROLES_FILE).into());
+    }
+    for name in &names {
+        if !name.chars().all(|c| c.is_ascii_lowercase()) {
+            return Err(format!("role '{}' must be lowercase letters only", name).into());
+        }
+    }
+    let variants: Vec<String> = names
+        .iter()
+        .map(|name| name[..1].to_uppercase() + &name[1..])
+        .collect();
+
+    let mut code = String::from(
+        "// @generated by `cargo xtask codegen` from roles.txt. Don't edit it by hand.\n\
+         use serde::Serialize;\n\
+         \n\
+         #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]\n\
+         #[serde(rename_all = \"lowercase\")]\n\
+         pub enum Role {\n",
+    );
+    for variant in &variants {
+        code += &format!("{},\n", variant);
+    }
+    code += "}\n\nimpl Role {\n";
+    code += &format!("pub const ALL: [Role; {}] = [\n", variants.len());
+    for variant in &variants {
+        code += &format!("Role::{},\n", variant);
+    }
+    code += "];\n\n";
+    code += "// The name used in files and in JSON\n";
+    code += "pub fn name(self) -> &'static str {\nmatch self {\n";
+    for (variant, name) in variants.iter().zip(&names) {
+        code += &format!("Role::{} => \"{}\",\n", variant, name);
+    }
+    code += "}\n}\n\n";
+    code += "pub fn from_name(name: &str) -> Option<Role> {\n";
+    code += "Role::ALL.into_iter().find(|role| role.name() == name)\n";
+    code += "}\n}\n";
+    rustfmt(&code)
+}
+
+// Formats generated code with rustfmt, so that the generator doesn't have
+// to care about indentation, and `cargo fmt --check` accepts the result
+fn rustfmt(code: &str) -> Result<String, DynError> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2021", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(code.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err("rustfmt failed on the generated code".into());
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+fn check_all() -> Result<(), DynError> {
+    cargo(&["fmt", "--all", "--check"])?;
+    codegen(true)?;
+    cargo(&[
+        "clippy",
+        "--workspace",
+        "--all-targets",
+        "--",
+        "-D",
+        "warnings",
+    ])?;
+    cargo(&["test", "--workspace"])?;
+    println!("All checks passed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_one_variant_per_role() {
+        let code = generate_role_enum("# comment\nadmin\n\nguest\n").unwrap();
+        assert!(code.contains("    Admin,\n    Guest,\n}"));
+        assert!(code.contains("pub const ALL: [Role; 2] = [Role::Admin, Role::Guest];"));
+        assert!(code.contains("Role::Guest => \"guest\","));
+    }
+
+    #[test]
+    fn rejects_invalid_role_files() {
+        assert!(generate_role_enum("# no roles\n").is_err());
+        assert!(generate_role_enum("Admin\n").is_err());
+        assert!(generate_role_enum("super-user\n").is_err());
+    }
+
+    #[test]
+    fn checked_in_code_is_up_to_date() {
+        codegen(true).unwrap();
+    }
+}
@@ -0,0 +1,124 @@
+use rusqlite::{params, Connection, OptionalExtension, Result};
+
+#[derive(Debug)]
+struct User {
+    id: i64,
+    name: String,
+    email: String,
+    age: u32,
+}
+
+fn create_schema(conn: &Connection) -> Result<()> {
+    // `IF NOT EXISTS` makes the call safe to repeat on every start
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS users (
+            id    INTEGER PRIMARY KEY AUTOINCREMENT,
+            name  TEXT NOT NULL,
+            email TEXT NOT NULL UNIQUE,
+            age   INTEGER NOT NULL
+        );",
+    )
+}
+
+fn insert_user(conn: &Connection, name: &str, email: &str, age: u32) -> Result<i64> {
+    // Values are passed as parameters (?1, ?2, ...), never formatted into
+    // the SQL string, which protects against SQL injection
+    conn.execute(
+        "INSERT INTO users (name, email, age) VALUES (?1, ?2, ?3)",
+        params![name, email, age],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+// Maps a row to our struct; the column order matches the SELECT below
+fn row_to_user(row: &rusqlite::Row) -> Result<User> {
+    Ok(User {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        email: row.get(2)?,
+        age: row.get(3)?,
+    })
+}
+
+fn find_user_by_email(conn: &Connection, email: &str) -> Result<Option<User>> {
+    conn.query_row(
+        "SELECT id, name, email, age FROM users WHERE email = ?1",
+        [email],
+        row_to_user,
+    )
+    // Turns the "no rows" error into `None`
+    .optional()
+}
+
+fn users_older_than(conn: &Connection, age: u32) -> Result<Vec<User>> {
+    // A prepared statement is parsed once and can be executed many times.
+    // `prepare_cached` also keeps it in the connection's cache for reuse.
+    let mut statement =
+        conn.prepare_cached("SELECT id, name, email, age FROM users WHERE age > ?1 ORDER BY age")?;
+    let users = statement.query_map([age], row_to_user)?;
+    users.collect()
+}
+
+fn import_users(conn: &mut Connection, users: &[(&str, &str, u32)]) -> Result<()> {
+    // Either every user is inserted or none of them is
+    let tx = conn.transaction()?;
+    {
+        let mut statement =
+            tx.prepare("INSERT INTO users (name, email, age) VALUES (?1, ?2, ?3)")?;
+        for (name, email, age) in users {
+            statement.execute(params![name, email, age])?;
+        }
+    }
+    // Without `commit`, dropping `tx` rolls the transaction back
+    tx.commit()
+}
+
+fn main() -> Result<()> {
+    // `Connection::open("users.db")` would create a file;
+    // an in-memory database keeps the example repeatable
+    let mut conn = Connection::open_in_memory()?;
+    create_schema(&conn)?;
+
+    let id = insert_user(&conn, "Alice", "alice@example.com", 30)?;
+    println!("Inserted Alice with id {}", id);
+
+    import_users(
+        &mut conn,
+        &[
+            ("Bob", "bob@example.com", 25),
+            ("Carol", "carol@example.com", 41),
+        ],
+    )?;
+    println!("Imported 2 users in a transaction");
+
+    // The second user has the same email as Alice, so the UNIQUE constraint
+    // fails and the whole transaction is rolled back, including Dave
+    let result = import_users(
+        &mut conn,
+        &[
+            ("Dave", "dave@example.com", 35),
+            ("Alice Clone", "alice@example.com", 30),
+        ],
+    );
+    println!("Failed import: {}", result.unwrap_err());
+    let dave = find_user_by_email(&conn, "dave@example.com")?;
+    println!("Dave after rollback: {:?}", dave.map(|user| user.name));
+
+    let updated = conn.execute("UPDATE users SET age = age + 1 WHERE name = ?1", ["Bob"])?;
+    println!("Updated {} row(s)", updated);
+
+    println!("Users older than 25:");
+    for user in users_older_than(&conn, 25)? {
+        println!(
+            "  #{} {} <{}>, {} years",
+            user.id, user.name, user.email, user.age
+        );
+    }
+
+    let deleted = conn.execute("DELETE FROM users WHERE id = ?1", [id])?;
+    println!("Deleted {} row(s)", deleted);
+
+    let count: i64 = conn.query_row("SELECT COUNT(*) FROM users", [], |row| row.get(0))?;
+    println!("Users left: {}", count);
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use sqlx::migrate::Migrator;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePool};
+
+// `migrate!` embeds every file from `./migrations` into the binary at compile
+// time, so the application doesn't need the SQL files at runtime
+static MIGRATOR: Migrator = sqlx::migrate!();
+
+async fn connect(path: &std::path::Path) -> Result<SqlitePool, sqlx::Error> {
+    let options = SqliteConnectOptions::new()
+        .filename(path)
+        .create_if_missing(true);
+    SqlitePool::connect_with(options).await
+}
+
+async fn print_applied(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    // sqlx records every applied migration in the `_sqlx_migrations` table
+    let applied: Vec<(i64, String)> =
+        sqlx::query_as("SELECT version, description FROM _sqlx_migrations ORDER BY version")
+            .fetch_all(pool)
+            .await?;
+    for (version, description) in applied {
+        println!("  applied {} {}", version, description);
+    }
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::temp_dir().join("sqlx-migrations-example.db");
+    let _ = std::fs::remove_file(&path);
+
+    println!("Migrations embedded in the binary:");
+    for migration in MIGRATOR.iter() {
+        println!("  {} {}", migration.version, migration.description);
+    }
+
+    // Typical startup: bring the schema up to date before serving requests
+    let pool = connect(&path).await?;
+    MIGRATOR.run(&pool).await?;
+    println!("First start:");
+    print_applied(&pool).await?;
+
+    sqlx::query("INSERT INTO users (name, email, age) VALUES ('Alice', 'alice@example.com', 30)")
+        .execute(&pool)
+        .await?;
+    pool.close().await;
+
+    // On the next start, already applied migrations are skipped
+    let pool = connect(&path).await?;
+    MIGRATOR.run(&pool).await?;
+    println!("Second start: nothing to do, data is kept");
+    let (name, active): (String, bool) = sqlx::query_as("SELECT name, active FROM users")
+        .fetch_one(&pool)
+        .await?;
+    println!("  {} active={}", name, active);
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions};
+use sqlx::SqlitePool;
+use std::path::Path;
+use std::time::Duration;
+use tokio::task::JoinSet;
+use tokio::time::{sleep, Instant};
+
+async fn create_pool(
+    path: &Path,
+    max_connections: u32,
+    acquire_timeout: Duration,
+) -> Result<SqlitePool, sqlx::Error> {
+    let options = SqliteConnectOptions::new()
+        .filename(path)
+        .create_if_missing(true)
+        // Write-ahead logging lets readers work while another connection writes
+        .journal_mode(SqliteJournalMode::Wal);
+
+    let pool = SqlitePoolOptions::new()
+        // Upper limit of open connections. Tasks beyond it wait in a queue.
+        .max_connections(max_connections)
+        // Connections opened at startup and kept open even when idle
+        .min_connections(1)
+        // How long a task may wait for a free connection before giving up
+        .acquire_timeout(acquire_timeout)
+        .connect_with(options)
+        .await?;
+
+    sqlx::raw_sql(include_str!("../schema.sql"))
+        .execute(&pool)
+        .await?;
+    Ok(pool)
+}
+
+// Simulates a request handler that keeps its connection busy for a while
+async fn handle_request(pool: SqlitePool, id: i64, work: Duration) -> Result<(), sqlx::Error> {
+    // `acquire` waits until a connection is free or the timeout expires
+    let mut conn = pool.acquire().await?;
+    let email = format!("user{}@example.com", id);
+    sqlx::query!(
+        "INSERT INTO users (name, email, age) VALUES (?, ?, ?)",
+        "User",
+        email,
+        30
+    )
+    .execute(&mut *conn)
+    .await?;
+    sleep(work).await;
+    // The connection returns to the pool when `conn` is dropped
+    Ok(())
+}
+
+// Starts `requests` concurrent tasks and reports how many failed
+async fn generate_load(pool: &SqlitePool, requests: i64, work: Duration) -> usize {
+    let mut tasks = JoinSet::new();
+    for id in 0..requests {
+        tasks.spawn(handle_request(pool.clone(), id, work));
+    }
+
+    let mut failures = 0;
+    while let Some(result) = tasks.join_next().await {
+        match result.unwrap() {
+            Ok(()) => {}
+            // The pool was exhausted for longer than `acquire_timeout`
+            Err(sqlx::Error::PoolTimedOut) => failures += 1,
+            Err(error) => panic!("unexpected error: {}", error),
+        }
+    }
+    failures
+}
+
+async fn count_users(pool: &SqlitePool) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar!(r#"SELECT COUNT(*) as "count!" FROM users"#)
+        .fetch_one(pool)
+        .await
+}
+
+#[tokio::main]
+async fn main() -> Result<(), sqlx::Error> {
+    let path = std::env::temp_dir().join("sqlx-pool-example.db");
+    for suffix in ["", "-wal", "-shm"] {
+        let _ = std::fs::remove_file(format!("{}{}", path.display(), suffix));
+    }
+
+    // 10 requests of 100 ms each share 3 connections
+    let pool = create_pool(&path, 3, Duration::from_secs(2)).await?;
+    println!("Pool size at startup: {}", pool.size());
+
+    let start = Instant::now();
+    let failures = generate_load(&pool, 10, Duration::from_millis(100)).await;
+    println!(
+        "10 requests with 3 connections: {} failed, took {} ms",
+        failures,
+        start.elapsed().as_millis()
+    );
+    println!(
+        "Pool size: {}, idle connections: {}",
+        pool.size(),
+        pool.num_idle()
+    );
+    println!("Users in the table: {}", count_users(&pool).await?);
+    pool.close().await;
+
+    // Long requests and a short timeout: some requests can't get a connection
+    let pool = create_pool(&path, 2, Duration::from_millis(250)).await?;
+    sqlx::query!("DELETE FROM users").execute(&pool).await?;
+    let failures = generate_load(&pool, 6, Duration::from_millis(200)).await;
+    println!("6 slow requests with 2 connections: {} timed out", failures);
+    pool.close().await;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn pool_handles_concurrent_load() {
+        let path = std::env::temp_dir().join(format!("sqlx-pool-test-{}.db", std::process::id()));
+        let pool = create_pool(&path, 4, Duration::from_secs(5)).await.unwrap();
+
+        // 50 concurrent requests with only 4 connections must all succeed
+        let failures = generate_load(&pool, 50, Duration::from_millis(5)).await;
+
+        assert_eq!(failures, 0);
+        assert_eq!(count_users(&pool).await.unwrap(), 50);
+        assert!(pool.size() <= 4);
+
+        pool.close().await;
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{}", path.display(), suffix));
+        }
+    }
+}
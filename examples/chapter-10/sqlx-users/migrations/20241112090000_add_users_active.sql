@@ -0,0 +1,4 @@
+-- Existing rows get the default value
+ALTER TABLE users ADD COLUMN active INTEGER NOT NULL DEFAULT 1;
+
+CREATE INDEX idx_users_age ON users (age);
@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS users (
+    id    INTEGER PRIMARY KEY NOT NULL,
+    name  TEXT NOT NULL,
+    email TEXT NOT NULL UNIQUE,
+    age   INTEGER NOT NULL
+);
@@ -0,0 +1,140 @@
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+
+#[derive(Debug)]
+struct User {
+    id: i64,
+    name: String,
+    email: String,
+    age: i64,
+}
+
+async fn create_schema(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    // The same SQL file is used to prepare the database for compile-time checks
+    sqlx::raw_sql(include_str!("../schema.sql"))
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+async fn insert_user(
+    pool: &SqlitePool,
+    name: &str,
+    email: &str,
+    age: i64,
+) -> Result<i64, sqlx::Error> {
+    // `query!` checks the SQL against the database schema at compile time:
+    // a typo in a column name or a wrong parameter type is a build error
+    let result = sqlx::query!(
+        "INSERT INTO users (name, email, age) VALUES (?, ?, ?)",
+        name,
+        email,
+        age
+    )
+    .execute(pool)
+    .await?;
+    Ok(result.last_insert_rowid())
+}
+
+async fn find_user_by_email(pool: &SqlitePool, email: &str) -> Result<Option<User>, sqlx::Error> {
+    // `query_as!` maps the columns to the struct fields by name
+    sqlx::query_as!(
+        User,
+        "SELECT id, name, email, age FROM users WHERE email = ?",
+        email
+    )
+    .fetch_optional(pool)
+    .await
+}
+
+async fn users_older_than(pool: &SqlitePool, age: i64) -> Result<Vec<User>, sqlx::Error> {
+    sqlx::query_as!(
+        User,
+        "SELECT id, name, email, age FROM users WHERE age > ? ORDER BY age",
+        age
+    )
+    .fetch_all(pool)
+    .await
+}
+
+async fn import_users(pool: &SqlitePool, users: &[(&str, &str, i64)]) -> Result<(), sqlx::Error> {
+    let mut tx: Transaction<'_, Sqlite> = pool.begin().await?;
+    for (name, email, age) in users {
+        sqlx::query!(
+            "INSERT INTO users (name, email, age) VALUES (?, ?, ?)",
+            name,
+            email,
+            age
+        )
+        // `&mut *tx` runs the query inside the transaction
+        .execute(&mut *tx)
+        .await?;
+    }
+    // If we return early with `?`, `tx` is dropped and rolled back
+    tx.commit().await
+}
+
+#[tokio::main]
+async fn main() -> Result<(), sqlx::Error> {
+    // Statements are prepared and cached per connection automatically.
+    // A single connection is required for an in-memory database,
+    // because every new connection would open a separate, empty database.
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await?;
+    create_schema(&pool).await?;
+
+    let id = insert_user(&pool, "Alice", "alice@example.com", 30).await?;
+    println!("Inserted Alice with id {}", id);
+
+    import_users(
+        &pool,
+        &[
+            ("Bob", "bob@example.com", 25),
+            ("Carol", "carol@example.com", 41),
+        ],
+    )
+    .await?;
+    println!("Imported 2 users in a transaction");
+
+    let result = import_users(
+        &pool,
+        &[
+            ("Dave", "dave@example.com", 35),
+            ("Alice Clone", "alice@example.com", 30),
+        ],
+    )
+    .await;
+    println!("Failed import: {}", result.unwrap_err());
+    let dave = find_user_by_email(&pool, "dave@example.com").await?;
+    println!("Dave after rollback: {:?}", dave.map(|user| user.name));
+
+    let updated = sqlx::query!("UPDATE users SET age = age + 1 WHERE name = ?", "Bob")
+        .execute(&pool)
+        .await?
+        .rows_affected();
+    println!("Updated {} row(s)", updated);
+
+    println!("Users older than 25:");
+    for user in users_older_than(&pool, 25).await? {
+        println!(
+            "  #{} {} <{}>, {} years",
+            user.id, user.name, user.email, user.age
+        );
+    }
+
+    let deleted = sqlx::query!("DELETE FROM users WHERE id = ?", id)
+        .execute(&pool)
+        .await?
+        .rows_affected();
+    println!("Deleted {} row(s)", deleted);
+
+    // `query_scalar!` returns a single value; `as "count!"` tells sqlx
+    // that the column is never NULL
+    let count = sqlx::query_scalar!(r#"SELECT COUNT(*) as "count!" FROM users"#)
+        .fetch_one(&pool)
+        .await?;
+    println!("Users left: {}", count);
+    Ok(())
+}
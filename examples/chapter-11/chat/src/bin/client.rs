@@ -0,0 +1,34 @@
+use futures::{SinkExt, StreamExt};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let name = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "guest".to_string());
+    let url = format!("ws://127.0.0.1:3000/ws?name={}", name);
+
+    let (socket, _response) = connect_async(url).await?;
+    let (mut write, mut read) = socket.split();
+
+    // Print incoming messages while the main task reads the keyboard
+    let printer = tokio::spawn(async move {
+        while let Some(Ok(message)) = read.next().await {
+            if let Message::Text(text) = message {
+                println!("{}", text);
+            }
+        }
+    });
+
+    // Send every line typed on stdin; Ctrl+D ends the session
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    while let Some(line) = lines.next_line().await? {
+        write.send(Message::Text(line)).await?;
+    }
+
+    write.send(Message::Close(None)).await?;
+    printer.await?;
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Query, State};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{info, warn};
+
+#[derive(Clone)]
+pub struct AppState {
+    // Every message sent here is delivered to all subscribed connections
+    tx: broadcast::Sender<String>,
+}
+
+impl AppState {
+    pub fn new() -> Self {
+        // Slow clients may fall up to 100 messages behind
+        let (tx, _rx) = broadcast::channel(100);
+        AppState { tx }
+    }
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Deserialize)]
+struct JoinParams {
+    name: String,
+}
+
+pub fn app(state: AppState) -> Router {
+    Router::new()
+        .route("/ws", get(ws_handler))
+        .with_state(state)
+}
+
+// A WebSocket connection starts as a normal HTTP request that asks
+// the server to "upgrade" the connection
+async fn ws_handler(
+    ws: WebSocketUpgrade,
+    Query(params): Query<JoinParams>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    ws.on_upgrade(move |socket| handle_socket(socket, state, params.name))
+}
+
+async fn handle_socket(socket: WebSocket, state: AppState, name: String) {
+    // Split the socket so that reading and writing can run concurrently
+    let (mut sender, mut receiver) = socket.split();
+
+    // Subscribe before announcing ourselves, so we see our own join message
+    let mut rx = state.tx.subscribe();
+    info!(%name, "Client connected");
+    let _ = state.tx.send(format!("* {} joined", name));
+
+    // Forward every broadcast message to this client
+    let mut send_task = tokio::spawn(async move {
+        loop {
+            match rx.recv().await {
+                Ok(text) => {
+                    if sender.send(Message::Text(text)).await.is_err() {
+                        break; // The client disconnected
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!(skipped, "Client is too slow, messages dropped");
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+
+    // Broadcast every message this client sends
+    let tx = state.tx.clone();
+    let author = name.clone();
+    let mut recv_task = tokio::spawn(async move {
+        while let Some(Ok(message)) = receiver.next().await {
+            match message {
+                Message::Text(text) => {
+                    let _ = tx.send(format!("{}: {}", author, text));
+                }
+                Message::Close(_) => break,
+                // Pings are answered automatically; binary messages are ignored
+                _ => {}
+            }
+        }
+    });
+
+    // When one direction ends, stop the other one too
+    tokio::select! {
+        _ = &mut send_task => recv_task.abort(),
+        _ = &mut recv_task => send_task.abort(),
+    }
+
+    info!(%name, "Client disconnected");
+    let _ = state.tx.send(format!("* {} left", name));
+}
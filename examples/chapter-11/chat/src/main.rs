@@ -0,0 +1,16 @@
+use chat::{app, AppState};
+use shutdown::shutdown_signal;
+use tokio::net::TcpListener;
+use tracing::info;
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt().with_target(false).init();
+
+    let listener = TcpListener::bind("127.0.0.1:3000").await.unwrap();
+    info!("Chat server on ws://{}/ws", listener.local_addr().unwrap());
+    axum::serve(listener, app(AppState::new()))
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .unwrap();
+}
@@ -0,0 +1,50 @@
+use chat::{app, AppState};
+use futures::{SinkExt, StreamExt};
+use std::net::SocketAddr;
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+type Client = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+// Starts the server on a random free port
+async fn start_server() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app(AppState::new())).await.unwrap();
+    });
+    addr
+}
+
+async fn join(addr: SocketAddr, name: &str) -> Client {
+    let url = format!("ws://{}/ws?name={}", addr, name);
+    let (client, _) = connect_async(url).await.unwrap();
+    client
+}
+
+async fn next_text(client: &mut Client) -> String {
+    match client.next().await {
+        Some(Ok(Message::Text(text))) => text,
+        other => panic!("expected a text message, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn messages_are_broadcast_to_all_clients() {
+    let addr = start_server().await;
+
+    let mut alice = join(addr, "alice").await;
+    assert_eq!(next_text(&mut alice).await, "* alice joined");
+
+    let mut bob = join(addr, "bob").await;
+    assert_eq!(next_text(&mut bob).await, "* bob joined");
+    assert_eq!(next_text(&mut alice).await, "* bob joined");
+
+    alice.send(Message::Text("hi bob".into())).await.unwrap();
+    assert_eq!(next_text(&mut alice).await, "alice: hi bob");
+    assert_eq!(next_text(&mut bob).await, "alice: hi bob");
+
+    alice.close(None).await.unwrap();
+    assert_eq!(next_text(&mut bob).await, "* alice left");
+}
@@ -0,0 +1,54 @@
+use axum::extract::Path;
+use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE, ETAG, IF_NONE_MATCH};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use rust_embed::RustEmbed;
+use std::fmt::Write;
+
+// A single file is simply a byte array in the binary
+pub const FAVICON: &[u8] = include_bytes!("../assets/favicon.ico");
+
+/// Every file below `static/`. Release builds contain the files, while
+/// debug builds read them from the disk, so edits show up without a rebuild.
+#[derive(RustEmbed)]
+#[folder = "static/"]
+pub struct Static;
+
+pub async fn favicon() -> impl IntoResponse {
+    ([(CONTENT_TYPE, "image/x-icon")], FAVICON)
+}
+
+pub async fn static_file(Path(path): Path<String>, headers: HeaderMap) -> Response {
+    let Some(file) = Static::get(&path) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    // The hash changes whenever the content does, which makes it a good ETag
+    let etag = etag(&file.metadata.sha256_hash());
+    let cached = headers
+        .get(IF_NONE_MATCH)
+        .is_some_and(|value| value.as_bytes() == etag.as_bytes());
+    if cached {
+        return (StatusCode::NOT_MODIFIED, [(ETAG, etag)]).into_response();
+    }
+
+    (
+        [
+            (CONTENT_TYPE, file.metadata.mimetype().to_string()),
+            (ETAG, etag),
+            // Browsers may keep the file but must ask whether it changed
+            (CACHE_CONTROL, "no-cache".to_string()),
+        ],
+        file.data,
+    )
+        .into_response()
+}
+
+fn etag(hash: &[u8]) -> String {
+    let mut etag = String::from("\"");
+    for byte in &hash[..8] {
+        write!(etag, "{:02x}", byte).unwrap();
+    }
+    etag.push('"');
+    etag
+}
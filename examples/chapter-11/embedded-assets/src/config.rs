@@ -0,0 +1,44 @@
+use serde::Deserialize;
+use std::net::SocketAddr;
+
+// The file is read by the compiler, so a missing file is a build error
+const DEFAULT_CONFIG: &str = include_str!("../config/default.toml");
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub address: SocketAddr,
+    pub title: String,
+    pub greeting: String,
+}
+
+impl Config {
+    pub fn from_toml(text: &str) -> Result<Config, toml::de::Error> {
+        toml::from_str(text)
+    }
+
+    /// The configuration compiled into the binary
+    pub fn embedded() -> Config {
+        // Only a broken default file can fail here, and a test catches that
+        Config::from_toml(DEFAULT_CONFIG).expect("config/default.toml is invalid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedded_config_is_valid() {
+        let config = Config::embedded();
+        assert_eq!(config.address.port(), 3000);
+        assert_eq!(config.title, "Embedded Assets");
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        let text = format!("{}\ncolour = \"red\"\n", DEFAULT_CONFIG);
+        let error = Config::from_toml(&text).unwrap_err();
+        assert!(error.to_string().contains("unknown field `colour`"));
+    }
+}
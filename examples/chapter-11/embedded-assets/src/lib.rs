@@ -0,0 +1,48 @@
+mod assets;
+mod config;
+
+pub use assets::{Static, FAVICON};
+pub use config::Config;
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::response::Html;
+use axum::routing::get;
+use axum::Router;
+
+const INDEX_TEMPLATE: &str = include_str!("../templates/index.html");
+
+#[derive(Clone)]
+pub struct AppState {
+    // The page never changes, so it's rendered once at startup.
+    // Cloning `Bytes` only increments a reference count.
+    index: Bytes,
+}
+
+impl AppState {
+    pub fn new(config: &Config) -> Self {
+        AppState {
+            index: render_index(config).into(),
+        }
+    }
+}
+
+// The values come from the operator's config file, not from users
+fn render_index(config: &Config) -> String {
+    INDEX_TEMPLATE
+        .replace("{{title}}", &config.title)
+        .replace("{{greeting}}", &config.greeting)
+        .replace("{{version}}", env!("CARGO_PKG_VERSION"))
+}
+
+pub fn app(state: AppState) -> Router {
+    Router::new()
+        .route("/", get(index))
+        .route("/favicon.ico", get(assets::favicon))
+        .route("/static/*path", get(assets::static_file))
+        .with_state(state)
+}
+
+async fn index(State(state): State<AppState>) -> Html<Bytes> {
+    Html(state.index)
+}
@@ -0,0 +1,37 @@
+use embedded_assets::{app, AppState, Config, Static};
+use shutdown::shutdown_signal;
+use std::error::Error;
+use tokio::net::TcpListener;
+use tracing::info;
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt().with_target(false).init();
+
+    // An optional file overrides the configuration built into the binary
+    let config = match std::env::args().nth(1) {
+        Some(path) => load_config(&path).unwrap_or_else(|error| {
+            eprintln!("{}: {}", path, error);
+            std::process::exit(1);
+        }),
+        None => Config::embedded(),
+    };
+
+    let files: Vec<_> = Static::iter().collect();
+    info!("Embedded files: {}", files.join(", "));
+
+    let listener = TcpListener::bind(config.address).await.unwrap();
+    info!(
+        "Open http://{} in a browser",
+        listener.local_addr().unwrap()
+    );
+    axum::serve(listener, app(AppState::new(&config)))
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .unwrap();
+}
+
+fn load_config(path: &str) -> Result<Config, Box<dyn Error>> {
+    let text = std::fs::read_to_string(path)?;
+    Ok(Config::from_toml(&text)?)
+}
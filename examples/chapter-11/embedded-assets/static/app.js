@@ -0,0 +1,8 @@
+// Shows that scripts from the binary run like any other script
+function tick() {
+    document.getElementById("clock").textContent =
+        "Your clock says " + new Date().toLocaleTimeString() + ".";
+}
+
+tick();
+setInterval(tick, 1000);
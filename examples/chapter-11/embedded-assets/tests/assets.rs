@@ -0,0 +1,92 @@
+use axum::body::{Body, Bytes};
+use axum::http::{header, Request, StatusCode};
+use axum::response::Response;
+use embedded_assets::{app, AppState, Config, Static, FAVICON};
+use http_body_util::BodyExt;
+use tower::ServiceExt;
+
+async fn send(request: Request<Body>) -> Response {
+    let state = AppState::new(&Config::embedded());
+    app(state).oneshot(request).await.unwrap()
+}
+
+async fn get(uri: &str) -> Response {
+    send(Request::builder().uri(uri).body(Body::empty()).unwrap()).await
+}
+
+async fn body(response: Response) -> Bytes {
+    response.into_body().collect().await.unwrap().to_bytes()
+}
+
+#[tokio::test]
+async fn index_is_rendered_from_the_template() {
+    let response = get("/").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/html; charset=utf-8"
+    );
+
+    let page = String::from_utf8(body(response).await.to_vec()).unwrap();
+    assert!(page.contains("<title>Embedded Assets</title>"));
+    assert!(page.contains("Server version 0.1.0."));
+    assert!(!page.contains("{{"), "unreplaced placeholder in {}", page);
+}
+
+#[tokio::test]
+async fn favicon_is_served_from_the_binary() {
+    let response = get("/favicon.ico").await;
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "image/x-icon");
+    assert_eq!(body(response).await, FAVICON);
+    // Every ICO file starts with these four bytes
+    assert_eq!(FAVICON[..4], [0, 0, 1, 0]);
+}
+
+#[tokio::test]
+async fn static_files_have_a_content_type_and_an_etag() {
+    let response = get("/static/style.css").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "text/css");
+    assert_eq!(response.headers()[header::CACHE_CONTROL], "no-cache");
+    assert_eq!(response.headers()[header::ETAG].len(), 18);
+
+    let expected = Static::get("style.css").unwrap().data;
+    assert_eq!(body(response).await, expected.as_ref());
+
+    let response = get("/static/logo.svg").await;
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "image/svg+xml");
+}
+
+#[tokio::test]
+async fn unchanged_files_are_not_sent_again() {
+    let response = get("/static/app.js").await;
+    let etag = response.headers()[header::ETAG].clone();
+
+    let request = Request::builder()
+        .uri("/static/app.js")
+        .header(header::IF_NONE_MATCH, etag)
+        .body(Body::empty())
+        .unwrap();
+    let response = send(request).await;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert!(body(response).await.is_empty());
+}
+
+#[tokio::test]
+async fn missing_files_are_not_found() {
+    assert_eq!(
+        get("/static/missing.css").await.status(),
+        StatusCode::NOT_FOUND
+    );
+    // Only files below `static/` are embedded, even in debug builds,
+    // which read them from the disk
+    let response = get("/static/..%2FCargo.toml").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[test]
+fn every_static_file_is_embedded() {
+    let mut files: Vec<_> = Static::iter().collect();
+    files.sort();
+    assert_eq!(files, ["app.js", "logo.svg", "style.css"]);
+}
@@ -0,0 +1,49 @@
+use futures::future::join_all;
+use futures::stream::{self, StreamExt};
+use http_client::base_url;
+use reqwest::Client;
+use std::time::Instant;
+
+async fn fetch(client: &Client, id: u32) -> reqwest::Result<u32> {
+    // Every request takes about one second on the server side
+    let url = format!("{}/delay/1?id={}", base_url(), id);
+    client.get(url).send().await?.error_for_status()?;
+    Ok(id)
+}
+
+#[tokio::main]
+async fn main() {
+    let client = Client::new();
+
+    let start = Instant::now();
+    for id in 1..=3 {
+        fetch(&client, id).await.unwrap();
+    }
+    println!("3 requests one by one: {:.1?}", start.elapsed());
+
+    // `join_all` runs all futures concurrently and returns their results in order
+    let start = Instant::now();
+    let requests = (1..=5).map(|id| fetch(&client, id));
+    let results = join_all(requests).await;
+    let ok = results.iter().filter(|result| result.is_ok()).count();
+    println!(
+        "5 requests with join_all: {} ok in {:.1?}",
+        ok,
+        start.elapsed()
+    );
+
+    // `buffer_unordered` limits how many requests run at the same time,
+    // which is kinder to the server than starting thousands at once
+    let start = Instant::now();
+    let finished: Vec<u32> = stream::iter(1..=6)
+        .map(|id| fetch(&client, id))
+        .buffer_unordered(3)
+        .filter_map(|result| async move { result.ok() })
+        .collect()
+        .await;
+    println!(
+        "6 requests, at most 3 at a time: {} ok in {:.1?}",
+        finished.len(),
+        start.elapsed()
+    );
+}
@@ -0,0 +1,35 @@
+use http_client::base_url;
+use indicatif::{ProgressBar, ProgressStyle};
+use std::error::Error;
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // httpbin returns the requested number of random bytes
+    let url = format!("{}/bytes/102400", base_url());
+    let mut response = reqwest::get(&url).await?.error_for_status()?;
+
+    // The size is known only if the server sends a `Content-Length` header
+    let total = response.content_length().unwrap_or(0);
+    let progress = ProgressBar::new(total);
+    progress.set_style(
+        ProgressStyle::with_template("{bar:40.cyan/blue} {bytes}/{total_bytes} ({bytes_per_sec})")?
+            .progress_chars("=> "),
+    );
+
+    let path = std::env::temp_dir().join("download.bin");
+    let mut file = File::create(&path).await?;
+
+    // `chunk()` returns the body piece by piece as it arrives, so even
+    // a multi-gigabyte download never has to fit into memory
+    while let Some(chunk) = response.chunk().await? {
+        file.write_all(&chunk).await?;
+        progress.inc(chunk.len() as u64);
+    }
+    file.flush().await?;
+    progress.finish();
+
+    println!("Saved {} bytes to {}", progress.position(), path.display());
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use http_client::base_url;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// Only the fields we need; serde ignores the rest of the response
+#[derive(Debug, Deserialize)]
+struct EchoResponse {
+    args: HashMap<String, String>,
+    url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Slideshow {
+    title: String,
+    author: String,
+    slides: Vec<Slide>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Slide {
+    title: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlideshowResponse {
+    slideshow: Slideshow,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), reqwest::Error> {
+    // A `Client` keeps a pool of connections; create it once and reuse it
+    let client = reqwest::Client::new();
+
+    // `json()` deserializes the body into any type that implements `Deserialize`
+    let response: SlideshowResponse = client
+        .get(format!("{}/json", base_url()))
+        .send()
+        .await?
+        .json()
+        .await?;
+    let slideshow = response.slideshow;
+    println!("'{}' by {}", slideshow.title, slideshow.author);
+    for slide in &slideshow.slides {
+        println!("  - {}", slide.title);
+    }
+
+    // `query` encodes the parameters and appends them to the URL
+    let echo: EchoResponse = client
+        .get(format!("{}/get", base_url()))
+        .query(&[("search", "rust books"), ("page", "2")])
+        .send()
+        .await?
+        .json()
+        .await?;
+    println!("Requested URL: {}", echo.url);
+    println!("Server saw search={:?}", echo.args["search"]);
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use http_client::base_url;
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT};
+use serde_json::Value;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Default headers are sent with every request made by this client
+    let mut headers = HeaderMap::new();
+    headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+    headers.insert("X-Api-Version", HeaderValue::from_static("2024-11-01"));
+
+    let client = reqwest::Client::builder()
+        .user_agent("rust-recipes/0.1")
+        .default_headers(headers)
+        .build()?;
+
+    // Headers can also be added to a single request
+    let echo: Value = client
+        .get(format!("{}/headers", base_url()))
+        .header("X-Request-Id", "42")
+        .send()
+        .await?
+        .json()
+        .await?;
+    let sent = &echo["headers"];
+    println!("User-Agent:    {}", sent["User-Agent"]);
+    println!("X-Api-Version: {}", sent["X-Api-Version"]);
+    println!("X-Request-Id:  {}", sent["X-Request-Id"]);
+
+    // Bearer token authentication, used by most APIs.
+    // Read real tokens from the environment, never hard-code them.
+    let token = std::env::var("API_TOKEN").unwrap_or_else(|_| "secret-token".to_string());
+    let response = client
+        .get(format!("{}/bearer", base_url()))
+        .bearer_auth(&token)
+        .send()
+        .await?;
+    println!("Bearer auth: {}", response.status());
+
+    // HTTP basic authentication with a user name and password
+    let response = client
+        .get(format!("{}/basic-auth/alice/wonderland", base_url()))
+        .basic_auth("alice", Some("wonderland"))
+        .send()
+        .await?;
+    println!("Basic auth:  {}", response.status());
+
+    let response = client
+        .get(format!("{}/basic-auth/alice/wonderland", base_url()))
+        .basic_auth("alice", Some("wrong"))
+        .send()
+        .await?;
+    println!("Wrong password: {}", response.status());
+    Ok(())
+}
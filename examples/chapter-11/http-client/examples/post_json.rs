@@ -0,0 +1,50 @@
+use http_client::base_url;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NewUser {
+    name: String,
+    email: String,
+    age: u32,
+}
+
+// httpbin returns the JSON it received in the `json` field
+#[derive(Debug, Deserialize)]
+struct PostResponse {
+    json: NewUser,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), reqwest::Error> {
+    let client = reqwest::Client::new();
+    let user = NewUser {
+        name: "Alice".to_string(),
+        email: "alice@example.com".to_string(),
+        age: 30,
+    };
+
+    // `json()` serializes the body and sets `Content-Type: application/json`
+    let response = client
+        .post(format!("{}/post", base_url()))
+        .json(&user)
+        .send()
+        .await?;
+    println!("Status: {}", response.status());
+
+    // A 4xx or 5xx status is not an error for reqwest by default;
+    // `error_for_status` turns it into one
+    let echoed: PostResponse = response.error_for_status()?.json().await?;
+    println!("Server received: {:?}", echoed.json);
+
+    let result = client
+        .post(format!("{}/status/422", base_url()))
+        .json(&user)
+        .send()
+        .await?
+        .error_for_status();
+    if let Err(error) = result {
+        println!("Error: {}", error);
+        println!("Status code: {:?}", error.status());
+    }
+    Ok(())
+}
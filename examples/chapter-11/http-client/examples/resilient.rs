@@ -0,0 +1,208 @@
+use governor::clock::DefaultClock;
+use governor::state::{InMemoryState, NotKeyed};
+use governor::{Quota, RateLimiter};
+use http_client::base_url;
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Client, Response, StatusCode};
+use std::num::NonZeroU32;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+// A client that never sends more than the allowed number of requests
+// per second and retries temporary failures on its own
+struct ResilientClient {
+    client: Client,
+    limiter: RateLimiter<NotKeyed, InMemoryState, DefaultClock>,
+    max_retries: u32,
+    base_delay: Duration,
+}
+
+impl ResilientClient {
+    fn new(requests_per_second: u32, max_retries: u32) -> Self {
+        // Token bucket: the bucket holds `requests_per_second` tokens and is
+        // refilled at that rate. Every request takes one token.
+        let quota = Quota::per_second(NonZeroU32::new(requests_per_second).unwrap());
+        ResilientClient {
+            client: Client::builder()
+                .timeout(Duration::from_secs(5))
+                .build()
+                .unwrap(),
+            limiter: RateLimiter::direct(quota),
+            max_retries,
+            base_delay: Duration::from_millis(100),
+        }
+    }
+
+    async fn get(&self, url: &str) -> reqwest::Result<Response> {
+        let mut attempt = 0;
+        loop {
+            // Waits until a token is available; retries count against the limit too
+            self.limiter.until_ready().await;
+
+            let result = self.client.get(url).send().await;
+            let delay = match &result {
+                Ok(response) if should_retry(response.status()) => {
+                    retry_after(response).unwrap_or(self.base_delay * 2u32.pow(attempt))
+                }
+                Err(error) if error.is_timeout() || error.is_connect() => {
+                    self.base_delay * 2u32.pow(attempt)
+                }
+                // Success, or an error that retrying won't fix
+                _ => return result,
+            };
+
+            if attempt == self.max_retries {
+                return result;
+            }
+            attempt += 1;
+            sleep(delay).await;
+        }
+    }
+}
+
+fn should_retry(status: StatusCode) -> bool {
+    // 429 Too Many Requests means the server is rate limiting us
+    status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+}
+
+// Servers often say how long to wait in the `Retry-After` header (in seconds)
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    value.parse().ok().map(Duration::from_secs)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), reqwest::Error> {
+    let client = ResilientClient::new(2, 3);
+
+    let start = Instant::now();
+    for i in 1..=5 {
+        let response = client
+            .get(&format!("{}/get?request={}", base_url(), i))
+            .await?;
+        println!(
+            "request {} -> {} after {:.1?}",
+            i,
+            response.status(),
+            start.elapsed()
+        );
+    }
+
+    let response = client.get(&format!("{}/status/503", base_url())).await?;
+    println!("Still failing after retries: {}", response.status());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn retries_until_the_server_recovers() {
+        // A local HTTP server that lives as long as the test
+        let server = MockServer::start().await;
+
+        // The first two requests fail...
+        Mock::given(method("GET"))
+            .and(path("/data"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        // ...and every later one succeeds
+        Mock::given(method("GET"))
+            .and(path("/data"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .with_priority(2)
+            .mount(&server)
+            .await;
+
+        let client = ResilientClient::new(100, 3);
+        let response = client.get(&format!("{}/data", server.uri())).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_retries() {
+        let server = MockServer::start().await;
+        Mock::given(path("/down"))
+            .respond_with(ResponseTemplate::new(500))
+            // Verified when the server is dropped: 1 try + 2 retries
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = ResilientClient::new(100, 2);
+        let response = client.get(&format!("{}/down", server.uri())).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_client_errors() {
+        let server = MockServer::start().await;
+        Mock::given(path("/missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = ResilientClient::new(100, 3);
+        let response = client
+            .get(&format!("{}/missing", server.uri()))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn honors_retry_after() {
+        let server = MockServer::start().await;
+        Mock::given(path("/busy"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(path("/busy"))
+            .respond_with(ResponseTemplate::new(200))
+            .with_priority(2)
+            .mount(&server)
+            .await;
+
+        let client = ResilientClient::new(100, 3);
+        let start = Instant::now();
+        let response = client.get(&format!("{}/busy", server.uri())).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(start.elapsed() >= Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn rate_limiter_spaces_out_requests() {
+        let server = MockServer::start().await;
+        Mock::given(path("/limited"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        // 5 requests per second: the first 5 use the full bucket,
+        // the next 5 have to wait for tokens to be refilled
+        let client = ResilientClient::new(5, 0);
+        let start = Instant::now();
+        for _ in 0..10 {
+            client
+                .get(&format!("{}/limited", server.uri()))
+                .await
+                .unwrap();
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(900));
+    }
+}
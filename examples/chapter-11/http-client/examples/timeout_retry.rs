@@ -0,0 +1,80 @@
+use http_client::base_url;
+use reqwest::{Client, Response, StatusCode};
+use std::time::Duration;
+use tokio::time::sleep;
+
+// Retries on timeouts, connection errors, and 5xx responses.
+// 4xx responses mean the request itself is wrong, so they are not retried.
+async fn get_with_retry(
+    client: &Client,
+    url: &str,
+    max_attempts: u32,
+) -> reqwest::Result<Response> {
+    let mut delay = Duration::from_millis(200);
+    let mut attempt = 1;
+    loop {
+        let result = client.get(url).send().await;
+        let retryable = match &result {
+            Ok(response) => response.status().is_server_error(),
+            Err(error) => error.is_timeout() || error.is_connect(),
+        };
+        if !retryable || attempt == max_attempts {
+            return result;
+        }
+
+        match &result {
+            Ok(response) => println!(
+                "  attempt {}: {}, retrying in {:?}",
+                attempt,
+                response.status(),
+                delay
+            ),
+            Err(error) => println!("  attempt {}: {}, retrying in {:?}", attempt, error, delay),
+        }
+        sleep(delay).await;
+        // Exponential backoff: wait twice as long before each new attempt
+        delay *= 2;
+        attempt += 1;
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), reqwest::Error> {
+    let client = Client::builder()
+        // Limit for the whole request, from connecting to reading the body
+        .timeout(Duration::from_secs(2))
+        // Limit for establishing the connection only
+        .connect_timeout(Duration::from_secs(1))
+        .build()?;
+
+    // The server waits 5 seconds before answering
+    match client.get(format!("{}/delay/5", base_url())).send().await {
+        Ok(response) => println!("Unexpected response: {}", response.status()),
+        Err(error) if error.is_timeout() => println!("Timed out: {}", error),
+        Err(error) => println!("Other error: {}", error),
+    }
+
+    // A single request can override the client's timeout
+    let response = client
+        .get(format!("{}/delay/3", base_url()))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await?;
+    println!("Slow endpoint with a longer timeout: {}", response.status());
+
+    println!("Server error:");
+    let response = get_with_retry(&client, &format!("{}/status/503", base_url()), 3).await?;
+    println!("  gave up with {}", response.status());
+
+    println!("Client error:");
+    let response = get_with_retry(&client, &format!("{}/status/404", base_url()), 3).await?;
+    println!("  not retried: {}", response.status());
+
+    println!("Timeouts:");
+    let result = get_with_retry(&client, &format!("{}/delay/5", base_url()), 2).await;
+    println!("  gave up: {}", result.unwrap_err().is_timeout());
+
+    let response = get_with_retry(&client, &format!("{}/get", base_url()), 3).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    Ok(())
+}
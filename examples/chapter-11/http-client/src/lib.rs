@@ -0,0 +1,9 @@
+pub mod limited;
+
+// All examples talk to httpbin.org, a service that echoes requests back.
+// To run them without internet access, start a local copy with
+// `docker run -p 8080:80 kennethreitz/httpbin` and set
+// `HTTPBIN_URL=http://localhost:8080`.
+pub fn base_url() -> String {
+    std::env::var("HTTPBIN_URL").unwrap_or_else(|_| "https://httpbin.org".to_string())
+}
@@ -0,0 +1,68 @@
+//! A `reqwest` client that keeps a rate limit itself, with the token
+//! bucket from Chapter 27
+
+use async_trait::async_trait;
+use http::Extensions;
+use rate_limit::{until_ready, TokenBucket};
+use reqwest::{Client, Request, Response};
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, Middleware, Next};
+use std::sync::Mutex;
+
+/// Client middleware: every request waits for a token before it is sent.
+///
+/// `reqwest-middleware` wraps a `reqwest::Client` and runs a chain of
+/// middleware around each request, like the layers of an axum router.
+struct RateLimit {
+    bucket: Mutex<TokenBucket>,
+}
+
+#[async_trait]
+impl Middleware for RateLimit {
+    async fn handle(
+        &self,
+        request: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        until_ready(&self.bucket).await;
+        next.run(request, extensions).await
+    }
+}
+
+/// A client that sends at most `burst` requests at once and
+/// `per_second` requests per second on average
+pub fn limited_client(burst: u32, per_second: f64) -> ClientWithMiddleware {
+    ClientBuilder::new(Client::new())
+        .with(RateLimit {
+            bucket: Mutex::new(TokenBucket::new(burst, per_second)),
+        })
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn requests_are_spaced_out() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(6)
+            .mount(&server)
+            .await;
+
+        // A burst of 2, then one request every 100 ms
+        let client = limited_client(2, 10.0);
+        let start = Instant::now();
+        for _ in 0..6 {
+            client.get(server.uri()).send().await.unwrap();
+        }
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(390), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_secs(2), "{:?}", elapsed);
+    }
+}
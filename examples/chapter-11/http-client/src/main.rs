@@ -0,0 +1,12 @@
+use http_client::base_url;
+
+#[tokio::main]
+async fn main() -> Result<(), reqwest::Error> {
+    // The shortest possible request: GET a URL and read the body as text
+    let body = reqwest::get(format!("{}/get", base_url()))
+        .await?
+        .text()
+        .await?;
+    println!("{}", body);
+    Ok(())
+}
@@ -0,0 +1,70 @@
+mod progress;
+
+pub use progress::ProgressStream;
+
+use axum::extract::State;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::Html;
+use axum::routing::get;
+use axum::Router;
+use futures::Stream;
+use std::convert::Infallible;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::StreamExt;
+
+#[derive(Clone)]
+pub struct AppState {
+    logs: broadcast::Sender<String>,
+}
+
+impl AppState {
+    pub fn new() -> Self {
+        let (logs, _rx) = broadcast::channel(100);
+        AppState { logs }
+    }
+
+    pub fn log(&self, line: impl Into<String>) {
+        // Sending fails when nobody is listening, which is fine for logs
+        let _ = self.logs.send(line.into());
+    }
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn app(state: AppState) -> Router {
+    Router::new()
+        .route("/", get(index))
+        .route("/progress", get(progress))
+        .route("/logs", get(logs))
+        .with_state(state)
+}
+
+async fn index() -> Html<&'static str> {
+    Html(include_str!("index.html"))
+}
+
+async fn progress() -> Sse<ProgressStream> {
+    Sse::new(ProgressStream::new(Duration::from_millis(500)))
+}
+
+async fn logs(State(state): State<AppState>) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    // Every client gets its own receiver, turned into a `Stream`
+    let stream = BroadcastStream::new(state.logs.subscribe())
+        // A client that falls behind skips the lost lines instead of disconnecting
+        .filter_map(|line| line.ok())
+        .map(|line| Ok(Event::default().event("log").data(line)));
+
+    // Logs may be quiet for a long time. A comment sent every few seconds
+    // keeps proxies and load balancers from closing the idle connection.
+    Sse::new(stream).keep_alive(
+        KeepAlive::new()
+            .interval(Duration::from_secs(2))
+            .text("keep-alive"),
+    )
+}
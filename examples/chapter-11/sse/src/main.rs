@@ -0,0 +1,42 @@
+use futures::FutureExt;
+use shutdown::shutdown_signal;
+use sse::{app, AppState};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tracing::info;
+
+// Simulates an application that writes a log line every few seconds
+async fn produce_logs(state: AppState) {
+    let requests = ["GET /orders 200", "POST /orders 201", "GET /orders/7 404"];
+    for request in requests.iter().cycle() {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        state.log(*request);
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt().with_target(false).init();
+
+    let state = AppState::new();
+    tokio::spawn(produce_logs(state.clone()));
+
+    let listener = TcpListener::bind("127.0.0.1:3000").await.unwrap();
+    info!(
+        "Open http://{} in a browser",
+        listener.local_addr().unwrap()
+    );
+
+    // Event streams never end on their own, so a graceful shutdown would
+    // wait for every open browser tab. Give them a few seconds, then exit.
+    let signal = shutdown_signal().shared();
+    let server = axum::serve(listener, app(state)).with_graceful_shutdown(signal.clone());
+    let deadline = async {
+        signal.await;
+        tokio::time::sleep(Duration::from_secs(3)).await;
+    };
+    tokio::select! {
+        result = server => result.unwrap(),
+        _ = deadline => info!("Closing the remaining event streams"),
+    }
+}
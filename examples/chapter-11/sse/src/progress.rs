@@ -0,0 +1,62 @@
+use axum::response::sse::Event;
+use futures::Stream;
+use serde::Serialize;
+use std::convert::Infallible;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
+use tokio::time::{interval, Interval};
+
+#[derive(Serialize)]
+struct Progress {
+    percent: u32,
+}
+
+// Reports the progress of a simulated job: one event every `step`,
+// from 0% to 100%, followed by a final `done` event
+pub struct ProgressStream {
+    interval: Interval,
+    percent: u32,
+    finished: bool,
+}
+
+impl ProgressStream {
+    pub fn new(step: Duration) -> Self {
+        ProgressStream {
+            interval: interval(step),
+            percent: 0,
+            finished: false,
+        }
+    }
+}
+
+impl Stream for ProgressStream {
+    type Item = Result<Event, Infallible>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.finished {
+            // `None` ends the stream, and axum closes the response
+            return Poll::Ready(None);
+        }
+
+        // Not time yet: `poll_tick` registers the waker and returns `Pending`,
+        // so the runtime polls us again when the timer fires
+        ready!(self.interval.poll_tick(cx));
+
+        if self.percent > 100 {
+            self.finished = true;
+            let event = Event::default().event("done").data("Job finished");
+            return Poll::Ready(Some(Ok(event)));
+        }
+
+        let event = Event::default()
+            .event("progress")
+            .id(self.percent.to_string())
+            .json_data(Progress {
+                percent: self.percent,
+            })
+            .unwrap();
+        self.percent += 10;
+        Poll::Ready(Some(Ok(event)))
+    }
+}
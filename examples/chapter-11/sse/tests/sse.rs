@@ -0,0 +1,51 @@
+use axum::body::Body;
+use axum::http::{header, Request};
+use http_body_util::BodyExt;
+use sse::{app, AppState};
+use tower::ServiceExt;
+
+fn get(uri: &str) -> Request<Body> {
+    Request::builder().uri(uri).body(Body::empty()).unwrap()
+}
+
+// With the clock paused, Tokio skips ahead whenever all tasks are waiting
+// for a timer, so the five-second job finishes instantly
+#[tokio::test(start_paused = true)]
+async fn progress_is_streamed_until_done() {
+    let response = app(AppState::new())
+        .oneshot(get("/progress"))
+        .await
+        .unwrap();
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/event-stream"
+    );
+
+    // The stream ends after the `done` event, so the body can be collected
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+
+    assert!(body.starts_with("event: progress\nid: 0\ndata: {\"percent\":0}\n\n"));
+    assert!(body.contains("data: {\"percent\":100}"));
+    assert!(body.ends_with("event: done\ndata: Job finished\n\n"));
+    assert_eq!(body.matches("event: progress").count(), 11);
+}
+
+#[tokio::test(start_paused = true)]
+async fn logs_are_forwarded_with_keep_alive() {
+    let state = AppState::new();
+    let response = app(state.clone()).oneshot(get("/logs")).await.unwrap();
+    let mut body = response.into_body();
+
+    // The log stream never ends, so we read it one frame at a time
+    state.log("GET /orders 200");
+    let frame = body.frame().await.unwrap().unwrap();
+    assert_eq!(
+        frame.data_ref().unwrap(),
+        "event: log\ndata: GET /orders 200\n\n"
+    );
+
+    // Nothing happens for a while, so a keep-alive comment is sent
+    let frame = body.frame().await.unwrap().unwrap();
+    assert_eq!(frame.data_ref().unwrap(), ": keep-alive\n\n");
+}
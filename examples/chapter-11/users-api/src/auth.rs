@@ -0,0 +1,304 @@
+use crate::error::ApiError;
+use crate::AppState;
+use axum::extract::{Request, State};
+use axum::http::{header, HeaderMap, Method};
+use axum::middleware::Next;
+use axum::response::Response;
+use jsonwebtoken::errors::{Error, ErrorKind};
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Tokens are only accepted if they were issued by and for this service
+const ISSUER: &str = "users-api";
+const AUDIENCE: &str = "users-api";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    User,
+    Admin,
+}
+
+/// The payload of a token. `exp`, `iat`, `iss`, and `aud` are registered
+/// claim names from RFC 7519; `role` is our own.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    pub role: Role,
+    pub iss: String,
+    pub aud: String,
+    // Seconds since the Unix epoch
+    pub iat: u64,
+    pub exp: u64,
+}
+
+impl Claims {
+    pub fn new(subject: &str, role: Role, valid_for: Duration) -> Self {
+        let now = jsonwebtoken::get_current_timestamp();
+        Claims {
+            sub: subject.to_string(),
+            role,
+            iss: ISSUER.to_string(),
+            aud: AUDIENCE.to_string(),
+            iat: now,
+            exp: now + valid_for.as_secs(),
+        }
+    }
+}
+
+/// Keys for one signing algorithm.
+/// HS256 uses one shared secret to sign and verify. RS256 signs with a
+/// private key and verifies with the public key, so services that only
+/// check tokens never see the key that creates them.
+pub struct JwtKeys {
+    algorithm: Algorithm,
+    encoding: Option<EncodingKey>,
+    decoding: DecodingKey,
+}
+
+impl JwtKeys {
+    pub fn hs256(secret: &[u8]) -> Self {
+        JwtKeys {
+            algorithm: Algorithm::HS256,
+            encoding: Some(EncodingKey::from_secret(secret)),
+            decoding: DecodingKey::from_secret(secret),
+        }
+    }
+
+    pub fn rs256(private_pem: &[u8], public_pem: &[u8]) -> Result<Self, Error> {
+        Ok(JwtKeys {
+            algorithm: Algorithm::RS256,
+            encoding: Some(EncodingKey::from_rsa_pem(private_pem)?),
+            decoding: DecodingKey::from_rsa_pem(public_pem)?,
+        })
+    }
+
+    /// For services that verify tokens issued somewhere else
+    pub fn rs256_verify_only(public_pem: &[u8]) -> Result<Self, Error> {
+        Ok(JwtKeys {
+            algorithm: Algorithm::RS256,
+            encoding: None,
+            decoding: DecodingKey::from_rsa_pem(public_pem)?,
+        })
+    }
+
+    pub fn issue(&self, claims: &Claims) -> Result<String, Error> {
+        let key = self
+            .encoding
+            .as_ref()
+            .ok_or_else(|| Error::from(ErrorKind::InvalidKeyFormat))?;
+        encode(&Header::new(self.algorithm), claims, key)
+    }
+
+    /// Checks the signature, the expiration time, the issuer, and the audience
+    pub fn verify(&self, token: &str) -> Result<Claims, Error> {
+        // Only the configured algorithm is accepted. Trusting the `alg` field
+        // of the token would let an attacker pick a weaker one.
+        let mut validation = Validation::new(self.algorithm);
+        validation.set_issuer(&[ISSUER]);
+        validation.set_audience(&[AUDIENCE]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+        Ok(decode::<Claims>(token, &self.decoding, &validation)?.claims)
+    }
+}
+
+/// Chooses the keys from the environment: RS256 if `JWT_PRIVATE_KEY` and
+/// `JWT_PUBLIC_KEY` point to PEM files, HS256 with `JWT_SECRET` otherwise.
+/// Without any of them, this is an error: a default secret would be in the
+/// source code, where anyone can read it and sign their own tokens.
+pub fn keys_from_env() -> Result<JwtKeys, Box<dyn std::error::Error>> {
+    keys_from(|name| std::env::var(name).ok())
+}
+
+fn keys_from(var: impl Fn(&str) -> Option<String>) -> Result<JwtKeys, Box<dyn std::error::Error>> {
+    match (var("JWT_PRIVATE_KEY"), var("JWT_PUBLIC_KEY")) {
+        (Some(private), Some(public)) => {
+            return Ok(JwtKeys::rs256(
+                &std::fs::read(private)?,
+                &std::fs::read(public)?,
+            )?);
+        }
+        (None, None) => {}
+        // Half a key pair is a mistake, not a reason to switch to HS256
+        _ => return Err("set both JWT_PRIVATE_KEY and JWT_PUBLIC_KEY, or neither".into()),
+    }
+    match var("JWT_SECRET") {
+        Some(secret) if !secret.is_empty() => Ok(JwtKeys::hs256(secret.as_bytes())),
+        _ => Err("set JWT_SECRET, or JWT_PRIVATE_KEY and JWT_PUBLIC_KEY".into()),
+    }
+}
+
+/// Middleware: reading is public, everything else needs a valid token.
+/// The claims are stored in the request for the handlers.
+pub async fn require_auth(
+    State(state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    if matches!(*request.method(), Method::GET | Method::HEAD) {
+        return Ok(next.run(request).await);
+    }
+
+    let token = bearer_token(request.headers())
+        .ok_or_else(|| ApiError::Unauthorized("missing bearer token".into()))?;
+    let claims = state.keys.verify(token).map_err(|e| {
+        let reason = match e.kind() {
+            ErrorKind::ExpiredSignature => "token expired".to_string(),
+            ErrorKind::InvalidSignature => "invalid signature".to_string(),
+            ErrorKind::InvalidAlgorithm => "unexpected algorithm".to_string(),
+            _ => format!("invalid token: {}", e),
+        };
+        ApiError::Unauthorized(reason)
+    })?;
+
+    tracing::debug!(sub = %claims.sub, role = ?claims.role, "Authenticated");
+    request.extensions_mut().insert(claims);
+    Ok(next.run(request).await)
+}
+
+fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRIVATE_PEM: &[u8] = include_bytes!("../tests/keys/private.pem");
+    const PUBLIC_PEM: &[u8] = include_bytes!("../tests/keys/public.pem");
+
+    fn claims() -> Claims {
+        Claims::new("alice", Role::Admin, Duration::from_secs(60))
+    }
+
+    #[test]
+    fn hs256_roundtrip() {
+        let keys = JwtKeys::hs256(b"secret");
+        let token = keys.issue(&claims()).unwrap();
+        // header.payload.signature, each part base64url-encoded
+        assert_eq!(token.split('.').count(), 3);
+        assert_eq!(keys.verify(&token).unwrap(), claims());
+    }
+
+    #[test]
+    fn hs256_rejects_other_secrets() {
+        let token = JwtKeys::hs256(b"secret").issue(&claims()).unwrap();
+        let err = JwtKeys::hs256(b"other").verify(&token).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::InvalidSignature);
+    }
+
+    #[test]
+    fn rs256_signs_with_private_and_verifies_with_public_key() {
+        let signer = JwtKeys::rs256(PRIVATE_PEM, PUBLIC_PEM).unwrap();
+        let verifier = JwtKeys::rs256_verify_only(PUBLIC_PEM).unwrap();
+        let token = signer.issue(&claims()).unwrap();
+        assert_eq!(verifier.verify(&token).unwrap().sub, "alice");
+        assert!(verifier.issue(&claims()).is_err());
+    }
+
+    // `keys_from_env` with the given variables instead of the real ones
+    fn keys_with(vars: &[(&str, &str)]) -> Result<JwtKeys, String> {
+        keys_from(|name| {
+            let value = vars.iter().find(|(key, _)| *key == name);
+            value.map(|(_, value)| value.to_string())
+        })
+        .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn keys_are_required() {
+        assert!(keys_with(&[("JWT_SECRET", "secret")]).is_ok());
+        let pair = [
+            ("JWT_PRIVATE_KEY", "tests/keys/private.pem"),
+            ("JWT_PUBLIC_KEY", "tests/keys/public.pem"),
+        ];
+        assert!(keys_with(&pair).is_ok());
+
+        let missing = "set JWT_SECRET, or JWT_PRIVATE_KEY and JWT_PUBLIC_KEY";
+        assert_eq!(keys_with(&[]).err().unwrap(), missing);
+        assert_eq!(keys_with(&[("JWT_SECRET", "")]).err().unwrap(), missing);
+        // A forgotten public key doesn't silently switch to the secret
+        assert_eq!(
+            keys_with(&[pair[0], ("JWT_SECRET", "secret")])
+                .err()
+                .unwrap(),
+            "set both JWT_PRIVATE_KEY and JWT_PUBLIC_KEY, or neither"
+        );
+    }
+
+    #[test]
+    fn rejects_expired_tokens() {
+        let keys = JwtKeys::hs256(b"secret");
+        let mut expired = claims();
+        // The default leeway of 60 seconds allows for clock differences
+        expired.exp = jsonwebtoken::get_current_timestamp() - 120;
+        let token = keys.issue(&expired).unwrap();
+        assert_eq!(
+            keys.verify(&token).unwrap_err().kind(),
+            &ErrorKind::ExpiredSignature
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_issuer_and_audience() {
+        let keys = JwtKeys::hs256(b"secret");
+        let mut other = claims();
+        other.iss = "another-service".into();
+        let token = keys.issue(&other).unwrap();
+        assert_eq!(
+            keys.verify(&token).unwrap_err().kind(),
+            &ErrorKind::InvalidIssuer
+        );
+
+        let mut other = claims();
+        other.aud = "billing".into();
+        let token = keys.issue(&other).unwrap();
+        assert_eq!(
+            keys.verify(&token).unwrap_err().kind(),
+            &ErrorKind::InvalidAudience
+        );
+    }
+
+    #[test]
+    fn rejects_algorithm_confusion() {
+        // An attacker signs an HS256 token with the public key as the secret.
+        // A verifier that trusted the token's `alg` field would accept it.
+        let forged = encode(
+            &Header::new(Algorithm::HS256),
+            &claims(),
+            &EncodingKey::from_secret(PUBLIC_PEM),
+        )
+        .unwrap();
+        let verifier = JwtKeys::rs256_verify_only(PUBLIC_PEM).unwrap();
+        assert_eq!(
+            verifier.verify(&forged).unwrap_err().kind(),
+            &ErrorKind::InvalidAlgorithm
+        );
+    }
+
+    #[test]
+    fn rejects_modified_payloads() {
+        let keys = JwtKeys::hs256(b"secret");
+        let token = keys
+            .issue(&Claims::new("bob", Role::User, Duration::from_secs(60)))
+            .unwrap();
+        let parts: Vec<&str> = token.split('.').collect();
+        let admin = keys.issue(&claims()).unwrap();
+        // Bob's signature with the admin payload
+        let forged = format!(
+            "{}.{}.{}",
+            parts[0],
+            admin.split('.').nth(1).unwrap(),
+            parts[2]
+        );
+        assert_eq!(
+            keys.verify(&forged).unwrap_err().kind(),
+            &ErrorKind::InvalidSignature
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use std::time::Duration;
+use users_api::{keys_from_env, Claims, Role};
+
+// Prints a token for trying out the API, signed with the same keys as the
+// server. A real service would issue tokens after checking a password.
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let subject = args.next().unwrap_or_else(|| "alice".to_string());
+    let role = match args.next().as_deref() {
+        Some("admin") => Role::Admin,
+        Some("user") | None => Role::User,
+        Some(other) => {
+            eprintln!("Unknown role '{}', expected 'user' or 'admin'", other);
+            std::process::exit(1);
+        }
+    };
+
+    let keys = keys_from_env().unwrap_or_else(|e| {
+        eprintln!("Failed to load the JWT keys: {}", e);
+        std::process::exit(1);
+    });
+    let claims = Claims::new(&subject, role, Duration::from_secs(60 * 60));
+    println!("{}", keys.issue(&claims).expect("Failed to sign the token"));
+}
@@ -0,0 +1,44 @@
+use axum::extract::rejection::{JsonRejection, QueryRejection};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde_json::json;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ApiError {
+    #[error("User {0} not found")]
+    NotFound(u64),
+    #[error("Email '{0}' is already taken")]
+    EmailTaken(String),
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+    #[error("Invalid input: {0}")]
+    Validation(String),
+    // Malformed bodies and query strings are reported in the same format.
+    // `body_text()` includes the details, such as the missing field.
+    #[error("{}", .0.body_text())]
+    Json(#[from] JsonRejection),
+    #[error("{}", .0.body_text())]
+    Query(#[from] QueryRejection),
+}
+
+// Handlers return `Result<_, ApiError>`, and axum calls this to turn
+// the error into an HTTP response
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            ApiError::NotFound(_) => StatusCode::NOT_FOUND,
+            ApiError::EmailTaken(_) => StatusCode::CONFLICT,
+            ApiError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            ApiError::Forbidden(_) => StatusCode::FORBIDDEN,
+            ApiError::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::Json(rejection) => rejection.status(),
+            ApiError::Query(rejection) => rejection.status(),
+        };
+        tracing::warn!(%status, error = %self, "Request failed");
+        (status, Json(json!({ "error": self.to_string() }))).into_response()
+    }
+}
@@ -0,0 +1,132 @@
+use crate::auth::{Claims, Role};
+use crate::error::ApiError;
+use crate::models::{CreateUser, ListParams, UpdateUser, User};
+use crate::AppState;
+use axum::extract::rejection::{JsonRejection, QueryRejection};
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Extension;
+use axum::Json;
+use tracing::info;
+
+pub async fn list_users(
+    State(state): State<AppState>,
+    params: Result<Query<ListParams>, QueryRejection>,
+) -> Result<Json<Vec<User>>, ApiError> {
+    let Query(params) = params?;
+    if params.limit > 100 {
+        return Err(ApiError::Validation("limit must be at most 100".into()));
+    }
+
+    let db = state.db.read().unwrap();
+    let users = db
+        .users
+        .values()
+        .filter(|user| match &params.name {
+            Some(name) => user.name.to_lowercase().contains(&name.to_lowercase()),
+            None => true,
+        })
+        .skip(params.offset)
+        .take(params.limit)
+        .cloned()
+        .collect();
+    Ok(Json(users))
+}
+
+pub async fn get_user(
+    State(state): State<AppState>,
+    Path(id): Path<u64>,
+) -> Result<Json<User>, ApiError> {
+    let db = state.db.read().unwrap();
+    let user = db.users.get(&id).cloned().ok_or(ApiError::NotFound(id))?;
+    Ok(Json(user))
+}
+
+pub async fn create_user(
+    State(state): State<AppState>,
+    payload: Result<Json<CreateUser>, JsonRejection>,
+) -> Result<(StatusCode, Json<User>), ApiError> {
+    let Json(input) = payload?;
+    validate_name(&input.name)?;
+    validate_email(&input.email)?;
+
+    let mut db = state.db.write().unwrap();
+    if db.users.values().any(|user| user.email == input.email) {
+        return Err(ApiError::EmailTaken(input.email));
+    }
+    db.next_id += 1;
+    let user = User {
+        id: db.next_id,
+        name: input.name,
+        email: input.email,
+    };
+    db.users.insert(user.id, user.clone());
+
+    info!(id = user.id, "User created");
+    Ok((StatusCode::CREATED, Json(user)))
+}
+
+pub async fn update_user(
+    State(state): State<AppState>,
+    Path(id): Path<u64>,
+    payload: Result<Json<UpdateUser>, JsonRejection>,
+) -> Result<Json<User>, ApiError> {
+    let Json(input) = payload?;
+    if let Some(name) = &input.name {
+        validate_name(name)?;
+    }
+    if let Some(email) = &input.email {
+        validate_email(email)?;
+    }
+
+    let mut db = state.db.write().unwrap();
+    if let Some(email) = &input.email {
+        if db
+            .users
+            .values()
+            .any(|user| user.id != id && &user.email == email)
+        {
+            return Err(ApiError::EmailTaken(email.clone()));
+        }
+    }
+    let user = db.users.get_mut(&id).ok_or(ApiError::NotFound(id))?;
+    if let Some(name) = input.name {
+        user.name = name;
+    }
+    if let Some(email) = input.email {
+        user.email = email;
+    }
+
+    info!(id, "User updated");
+    Ok(Json(user.clone()))
+}
+
+// `Extension<Claims>` is set by the `require_auth` middleware
+pub async fn delete_user(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<u64>,
+) -> Result<StatusCode, ApiError> {
+    if claims.role != Role::Admin {
+        return Err(ApiError::Forbidden("only admins can delete users".into()));
+    }
+    let mut db = state.db.write().unwrap();
+    db.users.remove(&id).ok_or(ApiError::NotFound(id))?;
+
+    info!(id, by = %claims.sub, "User deleted");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn validate_name(name: &str) -> Result<(), ApiError> {
+    if name.trim().is_empty() {
+        return Err(ApiError::Validation("name must not be empty".into()));
+    }
+    Ok(())
+}
+
+fn validate_email(email: &str) -> Result<(), ApiError> {
+    if !email.contains('@') {
+        return Err(ApiError::Validation(format!("'{}' is not an email", email)));
+    }
+    Ok(())
+}
@@ -0,0 +1,99 @@
+pub mod auth;
+mod error;
+mod handlers;
+mod models;
+
+pub use auth::{keys_from_env, Claims, JwtKeys, Role};
+pub use error::ApiError;
+pub use models::{CreateUser, ListParams, UpdateUser, User};
+
+use axum::middleware;
+use axum::routing::get;
+use axum::Router;
+use rate_limit::middleware::rate_limit;
+use rate_limit::{KeyedLimiter, TokenBucket};
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tower_http::trace::{DefaultMakeSpan, DefaultOnResponse, TraceLayer};
+use tracing::Level;
+
+// In-memory storage. A real service would keep a database pool here.
+#[derive(Default)]
+struct Db {
+    users: BTreeMap<u64, User>,
+    next_id: u64,
+}
+
+// Shared state is cloned for every request, so it only holds `Arc`s
+#[derive(Clone)]
+pub struct AppState {
+    db: Arc<RwLock<Db>>,
+    keys: Arc<JwtKeys>,
+    limiter: Option<Arc<KeyedLimiter<IpAddr, TokenBucket>>>,
+}
+
+impl AppState {
+    /// State without a rate limit; see `with_rate_limit`
+    pub fn new(keys: JwtKeys) -> Self {
+        AppState {
+            db: Arc::default(),
+            keys: Arc::new(keys),
+            limiter: None,
+        }
+    }
+
+    /// Allows each client IP address a burst of `burst` requests and
+    /// `per_second` requests per second after that
+    pub fn with_rate_limit(self, burst: u32, per_second: f64) -> Self {
+        AppState {
+            limiter: Some(Arc::new(per_ip_limiter(burst, per_second))),
+            ..self
+        }
+    }
+}
+
+// The token bucket from Chapter 27, one per client IP address
+fn per_ip_limiter(burst: u32, per_second: f64) -> KeyedLimiter<IpAddr, TokenBucket> {
+    KeyedLimiter::new(Duration::from_secs(60), move || {
+        TokenBucket::new(burst, per_second)
+    })
+}
+
+pub fn app(state: AppState) -> Router {
+    let router = Router::new()
+        .route(
+            "/users",
+            get(handlers::list_users).post(handlers::create_user),
+        )
+        .route(
+            "/users/:id",
+            get(handlers::get_user)
+                .patch(handlers::update_user)
+                .delete(handlers::delete_user),
+        )
+        // Checks the token before any handler runs
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth::require_auth,
+        ));
+    // Runs before the authentication, so floods of requests with invalid
+    // tokens are limited too
+    let router = match state.limiter.clone() {
+        Some(limiter) => router.layer(middleware::from_fn_with_state(
+            limiter,
+            rate_limit::<TokenBucket>,
+        )),
+        None => router,
+    };
+    router
+        // Logs every request and response and puts the handler's events
+        // into a span with the method and path
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(DefaultMakeSpan::new().level(Level::INFO))
+                .on_response(DefaultOnResponse::new().level(Level::INFO)),
+        )
+        .with_state(state)
+}
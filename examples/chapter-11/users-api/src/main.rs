@@ -0,0 +1,34 @@
+use shutdown::shutdown_signal;
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+use users_api::{app, keys_from_env, AppState};
+
+#[tokio::main]
+async fn main() {
+    // RUST_LOG overrides the default, e.g. RUST_LOG=tower_http=debug
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+            EnvFilter::new("users_api=info,tower_http=info,shutdown=info,rate_limit=info")
+        }))
+        .init();
+
+    let keys = keys_from_env().unwrap_or_else(|e| {
+        eprintln!("Failed to load the JWT keys: {}", e);
+        std::process::exit(1);
+    });
+
+    let listener = TcpListener::bind("127.0.0.1:3000").await.unwrap();
+    info!("Listening on http://{}", listener.local_addr().unwrap());
+    // A burst of 20 requests per client, then 10 per second
+    let state = AppState::new(keys).with_rate_limit(20, 10.0);
+    // `ConnectInfo` gives the rate limiter the address of each client
+    let service = app(state).into_make_service_with_connect_info::<SocketAddr>();
+    // Stops accepting connections on Ctrl-C or SIGTERM and waits for
+    // the requests in progress to finish
+    axum::serve(listener, service)
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .unwrap();
+}
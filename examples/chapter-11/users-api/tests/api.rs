@@ -0,0 +1,272 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use axum::Router;
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use std::time::Duration;
+use tower::ServiceExt;
+use users_api::{app, AppState, Claims, JwtKeys, Role, User};
+
+const SECRET: &[u8] = b"test secret";
+
+fn test_app() -> Router {
+    app(AppState::new(JwtKeys::hs256(SECRET)))
+}
+
+fn token(subject: &str, role: Role) -> String {
+    let claims = Claims::new(subject, role, Duration::from_secs(60));
+    JwtKeys::hs256(SECRET).issue(&claims).unwrap()
+}
+
+// Sends one request as an admin
+async fn send(app: &Router, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+    let token = token("admin", Role::Admin);
+    send_with_token(app, method, uri, body, Some(&token)).await
+}
+
+// Sends one request to the router without starting a server
+async fn send_with_token(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+    token: Option<&str>,
+) -> (StatusCode, Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Bearer {}", token));
+    }
+    let request = match body {
+        Some(body) => request
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => request.body(Body::empty()),
+    }
+    .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let json = if bytes.is_empty() {
+        Value::Null
+    } else {
+        serde_json::from_slice(&bytes).unwrap()
+    };
+    (status, json)
+}
+
+async fn create(app: &Router, name: &str, email: &str) -> User {
+    let body = json!({ "name": name, "email": email });
+    let (status, json) = send(app, "POST", "/users", Some(body)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    serde_json::from_value(json).unwrap()
+}
+
+#[tokio::test]
+async fn create_and_get_user() {
+    let app = test_app();
+    let user = create(&app, "Alice", "alice@example.com").await;
+    assert_eq!(user.id, 1);
+
+    let (status, json) = send(&app, "GET", "/users/1", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(serde_json::from_value::<User>(json).unwrap(), user);
+}
+
+#[tokio::test]
+async fn unknown_user_is_not_found() {
+    let app = test_app();
+    let (status, json) = send(&app, "GET", "/users/42", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(json["error"], "User 42 not found");
+}
+
+#[tokio::test]
+async fn invalid_input_is_rejected() {
+    let app = test_app();
+
+    let body = json!({ "name": "Bob", "email": "not-an-email" });
+    let (status, _) = send(&app, "POST", "/users", Some(body)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // A missing field is reported by the JSON extractor in the same format
+    let body = json!({ "name": "Bob" });
+    let (status, json) = send(&app, "POST", "/users", Some(body)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(json["error"]
+        .as_str()
+        .unwrap()
+        .contains("missing field `email`"));
+}
+
+#[tokio::test]
+async fn duplicate_email_is_a_conflict() {
+    let app = test_app();
+    create(&app, "Alice", "alice@example.com").await;
+
+    let body = json!({ "name": "Alice 2", "email": "alice@example.com" });
+    let (status, _) = send(&app, "POST", "/users", Some(body)).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn list_filters_and_paginates() {
+    let app = test_app();
+    for name in ["Alice", "Bob", "Alicia", "Carol"] {
+        let email = format!("{}@example.com", name.to_lowercase());
+        create(&app, name, &email).await;
+    }
+
+    let (_, json) = send(&app, "GET", "/users?name=ali", None).await;
+    let names: Vec<&str> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|user| user["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["Alice", "Alicia"]);
+
+    let (_, json) = send(&app, "GET", "/users?offset=1&limit=2", None).await;
+    let ids: Vec<u64> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|user| user["id"].as_u64().unwrap())
+        .collect();
+    assert_eq!(ids, [2, 3]);
+
+    let (status, _) = send(&app, "GET", "/users?limit=abc", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn update_and_delete_user() {
+    let app = test_app();
+    create(&app, "Alice", "alice@example.com").await;
+
+    let body = json!({ "name": "Alice Smith" });
+    let (status, json) = send(&app, "PATCH", "/users/1", Some(body)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["name"], "Alice Smith");
+    assert_eq!(json["email"], "alice@example.com");
+
+    let (status, _) = send(&app, "DELETE", "/users/1", None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send(&app, "DELETE", "/users/1", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn reading_needs_no_token() {
+    let app = test_app();
+    let (status, _) = send_with_token(&app, "GET", "/users", None, None).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn writing_needs_a_valid_token() {
+    let app = test_app();
+    let body = json!({ "name": "Alice", "email": "alice@example.com" });
+
+    let (status, json) = send_with_token(&app, "POST", "/users", Some(body.clone()), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(json["error"], "Unauthorized: missing bearer token");
+
+    let (status, json) = send_with_token(
+        &app,
+        "POST",
+        "/users",
+        Some(body.clone()),
+        Some("not.a.jwt"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert!(json["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("Unauthorized: invalid token"));
+
+    let other = JwtKeys::hs256(b"other secret")
+        .issue(&Claims::new(
+            "mallory",
+            Role::Admin,
+            Duration::from_secs(60),
+        ))
+        .unwrap();
+    let (status, json) =
+        send_with_token(&app, "POST", "/users", Some(body.clone()), Some(&other)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(json["error"], "Unauthorized: invalid signature");
+
+    let user = token("bob", Role::User);
+    let (status, _) = send_with_token(&app, "POST", "/users", Some(body), Some(&user)).await;
+    assert_eq!(status, StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn expired_tokens_are_rejected() {
+    let app = test_app();
+    let mut claims = Claims::new("alice", Role::Admin, Duration::ZERO);
+    claims.exp -= 3600;
+    let expired = JwtKeys::hs256(SECRET).issue(&claims).unwrap();
+
+    let (status, json) = send_with_token(&app, "DELETE", "/users/1", None, Some(&expired)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(json["error"], "Unauthorized: token expired");
+}
+
+#[tokio::test]
+async fn only_admins_can_delete() {
+    let app = test_app();
+    create(&app, "Alice", "alice@example.com").await;
+
+    let user = token("bob", Role::User);
+    let (status, json) = send_with_token(&app, "DELETE", "/users/1", None, Some(&user)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(json["error"], "Forbidden: only admins can delete users");
+
+    let admin = token("carol", Role::Admin);
+    let (status, _) = send_with_token(&app, "DELETE", "/users/1", None, Some(&admin)).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn rs256_tokens_from_an_auth_server() {
+    // The API only has the public key; the auth server signs with the private key
+    let public = include_bytes!("keys/public.pem");
+    let private = include_bytes!("keys/private.pem");
+    let app = app(AppState::new(JwtKeys::rs256_verify_only(public).unwrap()));
+    let auth_server = JwtKeys::rs256(private, public).unwrap();
+    let token = auth_server
+        .issue(&Claims::new("alice", Role::User, Duration::from_secs(60)))
+        .unwrap();
+
+    let body = json!({ "name": "Alice", "email": "alice@example.com" });
+    let (status, _) = send_with_token(&app, "POST", "/users", Some(body), Some(&token)).await;
+    assert_eq!(status, StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn clients_over_the_rate_limit_get_429() {
+    let app = app(AppState::new(JwtKeys::hs256(SECRET)).with_rate_limit(3, 1.0));
+    for _ in 0..3 {
+        let (status, _) = send(&app, "GET", "/users", None).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+    // A POST without a token gets 429, not 401: the limit applies
+    // before the authentication
+    let body = json!({ "name": "Mallory", "email": "mallory@example.com" });
+    let (status, json) = send_with_token(&app, "POST", "/users", Some(body), None).await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(json["error"], "Too many requests, retry in 1 s");
+}
+
+#[tokio::test]
+async fn the_rate_limit_is_opt_in() {
+    let app = app(AppState::new(JwtKeys::hs256(SECRET)));
+    for _ in 0..50 {
+        let (status, _) = send(&app, "GET", "/users", None).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+}
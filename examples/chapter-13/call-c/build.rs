@@ -0,0 +1,11 @@
+fn main() {
+    // Compiles the C file into a static library `libtextstats.a` in the
+    // build directory and tells Cargo to link it into our crate
+    cc::Build::new()
+        .file("c/textstats.c")
+        .warnings(true)
+        .compile("textstats");
+
+    // Rebuild only when the C code changes
+    println!("cargo:rerun-if-changed=c/textstats.c");
+}
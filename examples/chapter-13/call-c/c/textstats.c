@@ -0,0 +1,40 @@
+#include <ctype.h>
+#include <stddef.h>
+#include <stdio.h>
+
+/* Returns the average of `len` numbers, or 0 if there are none. */
+double ts_mean(const double *values, size_t len) {
+    if (len == 0) {
+        return 0.0;
+    }
+    double sum = 0.0;
+    for (size_t i = 0; i < len; i++) {
+        sum += values[i];
+    }
+    return sum / (double)len;
+}
+
+/* Counts words separated by whitespace in a NUL-terminated string. */
+size_t ts_word_count(const char *text) {
+    size_t count = 0;
+    int in_word = 0;
+    for (const char *p = text; *p != '\0'; p++) {
+        if (isspace((unsigned char)*p)) {
+            in_word = 0;
+        } else if (!in_word) {
+            in_word = 1;
+            count++;
+        }
+    }
+    return count;
+}
+
+/*
+ * Writes a summary into the caller's buffer, like snprintf. Returns the
+ * length of the full summary, which may be larger than `out_len`: then
+ * the output was truncated.
+ */
+int ts_summary(const char *text, char *out, size_t out_len) {
+    size_t count = ts_word_count(text);
+    return snprintf(out, out_len, "%zu word%s", count, count == 1 ? "" : "s");
+}
@@ -0,0 +1,95 @@
+use std::ffi::{c_char, c_double, c_int, CStr, CString, NulError};
+
+// The C functions as Rust sees them. The compiler can't check these
+// declarations against the C code: if a type here is wrong, the program
+// compiles and misbehaves at runtime. That's why calling them is `unsafe`.
+extern "C" {
+    fn ts_mean(values: *const c_double, len: usize) -> c_double;
+    fn ts_word_count(text: *const c_char) -> usize;
+    fn ts_summary(text: *const c_char, out: *mut c_char, out_len: usize) -> c_int;
+}
+
+// Safe wrappers: each one upholds the C function's requirements, so the
+// rest of the program never needs `unsafe`.
+
+fn mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    // SAFETY: the pointer and length come from a valid slice, and C only
+    // reads `len` elements
+    Some(unsafe { ts_mean(values.as_ptr(), values.len()) })
+}
+
+// Rust strings aren't NUL-terminated and may contain NUL bytes; C strings
+// are and can't. `CString::new` adds the terminator and rejects inner NULs.
+fn word_count(text: &str) -> Result<usize, NulError> {
+    let text = CString::new(text)?;
+    // SAFETY: `text` is a valid NUL-terminated string that lives until
+    // the end of this function, and C doesn't keep the pointer
+    Ok(unsafe { ts_word_count(text.as_ptr()) })
+}
+
+fn summary(text: &str) -> Result<String, NulError> {
+    let text = CString::new(text)?;
+    let mut buffer = vec![0u8; 16];
+    loop {
+        // SAFETY: the buffer is writable for `buffer.len()` bytes, and
+        // snprintf never writes more than that
+        let needed = unsafe {
+            ts_summary(
+                text.as_ptr(),
+                buffer.as_mut_ptr().cast::<c_char>(),
+                buffer.len(),
+            )
+        };
+        let needed = usize::try_from(needed).expect("snprintf failed");
+        if needed < buffer.len() {
+            break;
+        }
+        // Too small: grow to the reported size plus the NUL terminator
+        buffer.resize(needed + 1, 0);
+    }
+    let summary = CStr::from_bytes_until_nul(&buffer).expect("C wrote a NUL terminator");
+    Ok(summary.to_string_lossy().into_owned())
+}
+
+fn main() {
+    let temperatures = [21.5, 23.0, 19.5, 22.0];
+    println!("Mean: {:?}", mean(&temperatures));
+    println!("Mean of nothing: {:?}", mean(&[]));
+
+    let text = "Rust can call C functions directly";
+    println!("Words: {:?}", word_count(text));
+    println!("Summary: {:?}", summary(text));
+
+    match word_count("embedded\0NUL") {
+        Ok(n) => println!("Words: {}", n),
+        Err(e) => println!("Can't pass to C: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calls_c_with_slices() {
+        assert_eq!(mean(&[1.0, 2.0, 6.0]), Some(3.0));
+        assert_eq!(mean(&[]), None);
+    }
+
+    #[test]
+    fn calls_c_with_strings() {
+        assert_eq!(word_count("  hello   world \n"), Ok(2));
+        assert_eq!(word_count(""), Ok(0));
+        assert!(word_count("a\0b").is_err());
+    }
+
+    #[test]
+    fn grows_the_buffer_when_c_needs_more_space() {
+        let long = "word ".repeat(123_456);
+        assert_eq!(summary(&long).unwrap(), "123456 words");
+        assert_eq!(summary("one").unwrap(), "1 word");
+    }
+}
@@ -0,0 +1,219 @@
+//! The interface between `plugin-host` and plugins compiled as separate
+//! shared libraries.
+//!
+//! Rust has no stable ABI: two crates compiled by different compiler
+//! versions, or with different settings, may disagree on the layout of a
+//! `String` or a trait object. Only C types cross the boundary, so the host
+//! and a plugin only need to agree on the C ABI and on `ABI_VERSION`.
+
+use std::ffi::c_char;
+use std::mem::ManuallyDrop;
+use std::panic::{self, AssertUnwindSafe};
+
+/// The version of everything below. It changes whenever
+/// `PluginDeclaration` or `Buffer` changes, and a host only uses plugins
+/// built for its own version.
+pub const ABI_VERSION: u32 = 1;
+
+/// The name of a `AbiVersionFn`. This symbol and its signature never
+/// change, so any host can ask any plugin for its version.
+pub const ABI_VERSION_SYMBOL: &[u8] = b"plugin_abi_version\0";
+
+/// The name of a `DeclarationFn`. Only called after the version matched.
+pub const DECLARATION_SYMBOL: &[u8] = b"plugin_declaration\0";
+
+pub type AbiVersionFn = unsafe extern "C" fn() -> u32;
+pub type DeclarationFn = unsafe extern "C" fn() -> *const PluginDeclaration;
+
+/// `process` succeeded, the buffer contains the output
+pub const STATUS_OK: i32 = 0;
+/// `process` failed, the buffer contains the error message
+pub const STATUS_ERROR: i32 = 1;
+/// The plugin panicked, the buffer contains a message
+pub const STATUS_PANIC: i32 = 2;
+
+/// UTF-8 text allocated by the plugin. Only the plugin's `free_buffer`
+/// may free it: the host and the plugin may use different allocators.
+#[repr(C)]
+pub struct Buffer {
+    pub ptr: *mut u8,
+    pub len: usize,
+    pub capacity: usize,
+}
+
+impl Buffer {
+    pub fn empty() -> Self {
+        Self::from_string(String::new())
+    }
+
+    pub fn from_string(text: String) -> Self {
+        // The buffer owns the memory now, `into_string` gives it back
+        let mut bytes = ManuallyDrop::new(text.into_bytes());
+        Buffer {
+            ptr: bytes.as_mut_ptr(),
+            len: bytes.len(),
+            capacity: bytes.capacity(),
+        }
+    }
+
+    /// The text, borrowed
+    ///
+    /// # Safety
+    ///
+    /// The buffer must have been created by `from_string`, in any library,
+    /// and not freed yet.
+    pub unsafe fn as_str(&self) -> &str {
+        let bytes = std::slice::from_raw_parts(self.ptr, self.len);
+        std::str::from_utf8_unchecked(bytes)
+    }
+
+    /// # Safety
+    ///
+    /// The buffer must have been created by `from_string` in the same
+    /// library, and not freed yet.
+    pub unsafe fn into_string(self) -> String {
+        String::from_raw_parts(self.ptr, self.len, self.capacity)
+    }
+}
+
+/// What a plugin exports, as a table of C function pointers.
+#[repr(C)]
+pub struct PluginDeclaration {
+    /// A NUL-terminated UTF-8 name, valid while the library is loaded
+    pub name: *const c_char,
+    /// A NUL-terminated UTF-8 description, valid while the library is loaded
+    pub description: *const c_char,
+    /// Processes `len` bytes of UTF-8 text at `input`. Writes the output or
+    /// the error message to `output` and returns one of the `STATUS_`
+    /// constants. The host frees `output` with `free_buffer`.
+    pub process: unsafe extern "C" fn(input: *const u8, len: usize, output: *mut Buffer) -> i32,
+    pub free_buffer: unsafe extern "C" fn(buffer: Buffer),
+}
+
+// The pointers point to static strings and functions, which are safe to
+// share. Without this, the declaration couldn't be a `static`.
+unsafe impl Sync for PluginDeclaration {}
+
+/// The safe function a plugin implements
+pub type ProcessFn = fn(&str) -> Result<String, String>;
+
+/// Runs `process` for the `process` function of a plugin, see
+/// `export_plugin!`. Invalid arguments and panics become error statuses;
+/// a panic must not unwind into the host.
+///
+/// # Safety
+///
+/// `input` must point to `len` readable bytes, and `output` must point to
+/// a writable `Buffer`.
+#[doc(hidden)]
+pub unsafe fn call_plugin(
+    process: ProcessFn,
+    input: *const u8,
+    len: usize,
+    output: *mut Buffer,
+) -> i32 {
+    if output.is_null() {
+        return STATUS_ERROR;
+    }
+    let bytes = if len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(input, len)
+    };
+    let (status, text) = match std::str::from_utf8(bytes) {
+        Err(e) => (STATUS_ERROR, format!("the input is not UTF-8: {}", e)),
+        Ok(input) => match panic::catch_unwind(AssertUnwindSafe(|| process(input))) {
+            Ok(Ok(text)) => (STATUS_OK, text),
+            Ok(Err(message)) => (STATUS_ERROR, message),
+            Err(_) => (STATUS_PANIC, "the plugin panicked".to_string()),
+        },
+    };
+    output.write(Buffer::from_string(text));
+    status
+}
+
+/// Exports a plugin from a `cdylib` crate:
+///
+/// ```ignore
+/// plugin_api::export_plugin! {
+///     name: "rot13",
+///     description: "Rotates letters by 13 places",
+///     process: rot13,
+/// }
+/// ```
+///
+/// `process` is a `fn(&str) -> Result<String, String>`. The macro writes
+/// the two exported symbols and all the `unsafe` code around it.
+#[macro_export]
+macro_rules! export_plugin {
+    (name: $name:literal, description: $description:literal, process: $process:path $(,)?) => {
+        #[no_mangle]
+        pub extern "C" fn plugin_abi_version() -> u32 {
+            $crate::ABI_VERSION
+        }
+
+        #[no_mangle]
+        pub extern "C" fn plugin_declaration() -> *const $crate::PluginDeclaration {
+            unsafe extern "C" fn process(
+                input: *const u8,
+                len: usize,
+                output: *mut $crate::Buffer,
+            ) -> i32 {
+                $crate::call_plugin($process, input, len, output)
+            }
+
+            unsafe extern "C" fn free_buffer(buffer: $crate::Buffer) {
+                drop(buffer.into_string());
+            }
+
+            static DECLARATION: $crate::PluginDeclaration = $crate::PluginDeclaration {
+                name: concat!($name, "\0").as_ptr().cast(),
+                description: concat!($description, "\0").as_ptr().cast(),
+                process,
+                free_buffer,
+            };
+            &DECLARATION
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(process: ProcessFn, input: &[u8]) -> (i32, String) {
+        let mut output = Buffer::empty();
+        unsafe {
+            let status = call_plugin(process, input.as_ptr(), input.len(), &mut output);
+            (status, output.into_string())
+        }
+    }
+
+    fn shout(text: &str) -> Result<String, String> {
+        match text {
+            "" => Err("nothing to shout".to_string()),
+            "boom" => panic!("boom"),
+            text => Ok(text.to_uppercase() + "!"),
+        }
+    }
+
+    #[test]
+    fn passes_results_and_errors_through() {
+        assert_eq!(call(shout, b"hello"), (STATUS_OK, "HELLO!".to_string()));
+        assert_eq!(
+            call(shout, b""),
+            (STATUS_ERROR, "nothing to shout".to_string())
+        );
+    }
+
+    #[test]
+    fn turns_panics_and_invalid_input_into_errors() {
+        assert_eq!(
+            call(shout, b"boom"),
+            (STATUS_PANIC, "the plugin panicked".to_string())
+        );
+        let (status, message) = call(shout, &[0xff]);
+        assert_eq!(status, STATUS_ERROR);
+        assert!(message.starts_with("the input is not UTF-8"));
+    }
+}
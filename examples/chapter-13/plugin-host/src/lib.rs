@@ -0,0 +1,163 @@
+//! Loads plugins from shared libraries at runtime and wraps them in a safe
+//! API. All `unsafe` code of the host is in this file.
+
+use libloading::Library;
+use plugin_api::{
+    AbiVersionFn, Buffer, DeclarationFn, PluginDeclaration, ABI_VERSION, ABI_VERSION_SYMBOL,
+    DECLARATION_SYMBOL, STATUS_OK,
+};
+use std::ffi::{c_char, CStr};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum LoadError {
+    #[error("failed to load {path}: {source}")]
+    Open {
+        path: PathBuf,
+        source: libloading::Error,
+    },
+    #[error("{path} is not a plugin: {source}")]
+    NotAPlugin {
+        path: PathBuf,
+        source: libloading::Error,
+    },
+    #[error(
+        "{path} was built for plugin ABI version {found}, this program supports version {expected}"
+    )]
+    VersionMismatch {
+        path: PathBuf,
+        expected: u32,
+        found: u32,
+    },
+    #[error("{path} has an invalid plugin declaration")]
+    InvalidDeclaration { path: PathBuf },
+}
+
+#[derive(Debug, Error, PartialEq)]
+#[error("{plugin}: {message}")]
+pub struct PluginError {
+    pub plugin: String,
+    pub message: String,
+}
+
+/// A plugin loaded from a shared library
+pub struct Plugin {
+    name: String,
+    description: String,
+    // Points into the library, so it's only valid while `library` is loaded.
+    // `Plugin` never hands it out, and `library` can't be unloaded before
+    // `Plugin` is dropped.
+    declaration: *const PluginDeclaration,
+    library: Library,
+}
+
+impl Plugin {
+    pub fn load(path: impl AsRef<Path>) -> Result<Plugin, LoadError> {
+        let path = path.as_ref();
+
+        // SAFETY: loading a library runs its initialization code, which
+        // can do anything. We can only load libraries we trust.
+        let library = unsafe { Library::new(path) }.map_err(|source| LoadError::Open {
+            path: path.to_owned(),
+            source,
+        })?;
+
+        // SAFETY: every version of the interface exports this symbol with
+        // this signature, so it's the one thing we can call before we know
+        // the version
+        let found = unsafe {
+            let abi_version =
+                library
+                    .get::<AbiVersionFn>(ABI_VERSION_SYMBOL)
+                    .map_err(|source| LoadError::NotAPlugin {
+                        path: path.to_owned(),
+                        source,
+                    })?;
+            abi_version()
+        };
+        if found != ABI_VERSION {
+            return Err(LoadError::VersionMismatch {
+                path: path.to_owned(),
+                expected: ABI_VERSION,
+                found,
+            });
+        }
+
+        // SAFETY: the versions match, so the plugin was built against the
+        // same `plugin-api` types as the host
+        let declaration = unsafe {
+            let declare = library
+                .get::<DeclarationFn>(DECLARATION_SYMBOL)
+                .map_err(|source| LoadError::NotAPlugin {
+                    path: path.to_owned(),
+                    source,
+                })?;
+            declare()
+        };
+        let invalid = || LoadError::InvalidDeclaration {
+            path: path.to_owned(),
+        };
+        if declaration.is_null() {
+            return Err(invalid());
+        }
+        // SAFETY: the declaration is valid while the library is loaded, and
+        // its strings are NUL-terminated, as `plugin-api` requires
+        let (name, description) = unsafe {
+            (
+                read_str((*declaration).name).ok_or_else(invalid)?,
+                read_str((*declaration).description).ok_or_else(invalid)?,
+            )
+        };
+
+        Ok(Plugin {
+            name,
+            description,
+            declaration,
+            library,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn process(&self, input: &str) -> Result<String, PluginError> {
+        let mut output = Buffer::empty();
+        // SAFETY: `library` is loaded, so the declaration and its function
+        // pointers are valid. `output` is only read after the plugin wrote
+        // it, and freed by the plugin that allocated it.
+        let (status, text) = unsafe {
+            let declaration = &*self.declaration;
+            let status = (declaration.process)(input.as_ptr(), input.len(), &mut output);
+            let text = output.as_str().to_string();
+            (declaration.free_buffer)(output);
+            (status, text)
+        };
+
+        if status == STATUS_OK {
+            Ok(text)
+        } else {
+            Err(PluginError {
+                plugin: self.name.clone(),
+                message: text,
+            })
+        }
+    }
+
+    /// Unloads the library. Dropping the plugin does the same.
+    pub fn unload(self) -> Result<(), libloading::Error> {
+        self.library.close()
+    }
+}
+
+unsafe fn read_str(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        return None;
+    }
+    CStr::from_ptr(ptr).to_str().ok().map(str::to_string)
+}
@@ -0,0 +1,30 @@
+use plugin_host::Plugin;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let Some((text, paths)) = args.split_first() else {
+        eprintln!("Usage: plugin-host <text> <library>...");
+        return ExitCode::FAILURE;
+    };
+
+    // A plugin that fails to load is skipped, the others still run
+    let mut plugins = Vec::new();
+    for path in paths {
+        match Plugin::load(path) {
+            Ok(plugin) => {
+                println!("Loaded {}: {}", plugin.name(), plugin.description());
+                plugins.push(plugin);
+            }
+            Err(e) => eprintln!("Skipped: {}", e),
+        }
+    }
+
+    for plugin in &plugins {
+        match plugin.process(text) {
+            Ok(output) => println!("{}: {}", plugin.name(), output),
+            Err(e) => eprintln!("Error: {}", e),
+        }
+    }
+    ExitCode::SUCCESS
+}
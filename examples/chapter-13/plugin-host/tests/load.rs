@@ -0,0 +1,57 @@
+use plugin_host::{LoadError, Plugin};
+use std::path::PathBuf;
+use std::process::Command;
+
+// Cargo doesn't build the plugins for the host's tests: the host doesn't
+// depend on them. Each test builds the one it needs, into a directory of
+// its own so it doesn't wait for the lock on the workspace's target
+// directory.
+fn build_plugin(package: &str) -> PathBuf {
+    let target_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(package);
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--quiet", "--package", package, "--target-dir"])
+        .arg(&target_dir)
+        .status()
+        .expect("failed to run cargo");
+    assert!(status.success(), "failed to build {}", package);
+
+    let file_name = libloading::library_filename(package.replace('-', "_"));
+    target_dir.join("debug").join(file_name)
+}
+
+#[test]
+fn loads_and_runs_a_plugin() {
+    let plugin = Plugin::load(build_plugin("plugin-rot13")).unwrap();
+    assert_eq!(plugin.name(), "rot13");
+    assert_eq!(plugin.process("Hello, World!").unwrap(), "Uryyb, Jbeyq!");
+
+    let error = plugin.process("123").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "rot13: the text contains no letters to rotate"
+    );
+    plugin.unload().unwrap();
+}
+
+#[test]
+fn rejects_plugins_for_another_version() {
+    let error = Plugin::load(build_plugin("plugin-legacy")).err().unwrap();
+    assert!(
+        matches!(
+            error,
+            LoadError::VersionMismatch {
+                expected: 1,
+                found: 0,
+                ..
+            }
+        ),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn reports_missing_libraries() {
+    let error = Plugin::load("no-such-plugin.so").err().unwrap();
+    assert!(matches!(error, LoadError::Open { .. }), "{}", error);
+}
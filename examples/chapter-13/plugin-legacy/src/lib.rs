@@ -0,0 +1,23 @@
+//! A plugin written for an older version of the interface, where a plugin
+//! exported a single function that took and returned C strings. It doesn't
+//! use `plugin-api`, because that crate no longer describes its interface.
+//!
+//! A host that called `plugin_declaration` and read the result as today's
+//! `PluginDeclaration` would jump to a random address. The version check
+//! prevents that.
+
+use std::ffi::{c_char, CStr, CString};
+
+#[no_mangle]
+pub extern "C" fn plugin_abi_version() -> u32 {
+    0
+}
+
+/// # Safety
+///
+/// `text` must point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn plugin_declaration(text: *const c_char) -> *mut c_char {
+    let text = CStr::from_ptr(text).to_string_lossy();
+    CString::new(text.to_uppercase()).unwrap().into_raw()
+}
@@ -0,0 +1,20 @@
+// An ordinary Rust function. `export_plugin!` takes care of the C ABI.
+fn rot13(text: &str) -> Result<String, String> {
+    if !text.chars().any(|c| c.is_ascii_alphabetic()) {
+        return Err("the text contains no letters to rotate".to_string());
+    }
+    Ok(text
+        .chars()
+        .map(|c| match c {
+            'a'..='z' => (((c as u8 - b'a') + 13) % 26 + b'a') as char,
+            'A'..='Z' => (((c as u8 - b'A') + 13) % 26 + b'A') as char,
+            _ => c,
+        })
+        .collect())
+}
+
+plugin_api::export_plugin! {
+    name: "rot13",
+    description: "Rotates each letter by 13 places",
+    process: rot13,
+}
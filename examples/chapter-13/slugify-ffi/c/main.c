@@ -0,0 +1,25 @@
+#include <stdio.h>
+
+#include "slugify.h"
+
+int main(void) {
+    const char *titles[] = {
+        "Hello, World!",
+        "Rust & C: Calling Rust from C",
+        "  Spaces   everywhere  ",
+    };
+
+    for (size_t i = 0; i < sizeof(titles) / sizeof(titles[0]); i++) {
+        char *slug = slug_new(titles[i]);
+        if (slug == NULL) {
+            fprintf(stderr, "could not slugify '%s'\n", titles[i]);
+            continue;
+        }
+        printf("%-32s -> %s (%d words)\n", titles[i], slug, slug_word_count(titles[i]));
+        slug_free(slug);
+    }
+
+    /* Invalid UTF-8 is reported instead of crashing */
+    printf("Invalid input: %d\n", slug_word_count("\xff\xfe"));
+    return 0;
+}
@@ -0,0 +1,18 @@
+#ifndef SLUGIFY_H
+#define SLUGIFY_H
+
+#include <stdint.h>
+
+/* Counts the words in a UTF-8 string. Returns -1 for NULL or invalid UTF-8. */
+int32_t slug_word_count(const char *text);
+
+/*
+ * Returns a newly allocated slug, or NULL for NULL or invalid UTF-8.
+ * Free the result with slug_free, not with free.
+ */
+char *slug_new(const char *text);
+
+/* Frees a string returned by slug_new. Does nothing for NULL. */
+void slug_free(char *slug);
+
+#endif
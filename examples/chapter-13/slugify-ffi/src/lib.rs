@@ -0,0 +1,103 @@
+use std::ffi::{c_char, CStr, CString};
+
+// Turns "Hello, World!" into "hello-world"
+fn slugify(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+// `#[no_mangle]` keeps the symbol name as written, so C can find it, and
+// `extern "C"` uses the C calling convention. Only C-compatible types
+// cross the boundary: integers, raw pointers, and `#[repr(C)]` types.
+
+/// Counts the words in a NUL-terminated UTF-8 string.
+/// Returns -1 if `text` is NULL or not valid UTF-8.
+///
+/// # Safety
+///
+/// `text` must be NULL or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn slug_word_count(text: *const c_char) -> i32 {
+    if text.is_null() {
+        return -1;
+    }
+    match CStr::from_ptr(text).to_str() {
+        Ok(text) => text.split_whitespace().count() as i32,
+        Err(_) => -1,
+    }
+}
+
+/// Returns a newly allocated slug for `text`, or NULL if `text` is NULL
+/// or not valid UTF-8. The result must be freed with `slug_free`.
+///
+/// # Safety
+///
+/// `text` must be NULL or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn slug_new(text: *const c_char) -> *mut c_char {
+    if text.is_null() {
+        return std::ptr::null_mut();
+    }
+    let Ok(text) = CStr::from_ptr(text).to_str() else {
+        return std::ptr::null_mut();
+    };
+    // A slug contains no NUL bytes, so this can't fail
+    let slug = CString::new(slugify(text)).unwrap();
+    // Hands the memory over to C. Rust won't free it until it comes back
+    // through `slug_free`.
+    slug.into_raw()
+}
+
+/// Frees a string returned by `slug_new`. Does nothing for NULL.
+///
+/// # Safety
+///
+/// `slug` must be NULL or a pointer returned by `slug_new` that
+/// hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn slug_free(slug: *mut c_char) {
+    if !slug.is_null() {
+        // Takes ownership back, and the `CString` is dropped here. Calling
+        // C's `free` instead would be undefined behavior: the memory came
+        // from Rust's allocator.
+        drop(CString::from_raw(slug));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugifies_text() {
+        assert_eq!(slugify("Hello, World!"), "hello-world");
+        assert_eq!(slugify("  Rust & C: FFI 101 "), "rust-c-ffi-101");
+        assert_eq!(slugify("!!!"), "");
+    }
+
+    #[test]
+    fn exported_functions_round_trip() {
+        let input = CString::new("Calling Rust from C").unwrap();
+        unsafe {
+            assert_eq!(slug_word_count(input.as_ptr()), 4);
+
+            let slug = slug_new(input.as_ptr());
+            assert_eq!(CStr::from_ptr(slug).to_str(), Ok("calling-rust-from-c"));
+            slug_free(slug);
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_reported_not_a_crash() {
+        let invalid = [0xff, 0xfe, 0];
+        unsafe {
+            assert_eq!(slug_word_count(std::ptr::null()), -1);
+            assert_eq!(slug_word_count(invalid.as_ptr().cast()), -1);
+            assert!(slug_new(std::ptr::null()).is_null());
+            slug_free(std::ptr::null_mut());
+        }
+    }
+}
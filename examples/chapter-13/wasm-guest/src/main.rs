@@ -0,0 +1,32 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+
+// An ordinary command-line program. Built with
+// `cargo build --target wasm32-wasip1`, it becomes a WASI module that any
+// WASI runtime can run. It can only see the arguments, environment
+// variables, and directories that the runtime chooses to give it.
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let greeting = env::var("GREETING").unwrap_or_else(|_| "Hello".to_string());
+    println!("{} from WebAssembly! Arguments: {:?}", greeting, args);
+
+    let text = match args.first() {
+        Some(path) => match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) => {
+                eprintln!("Cannot read {}: {}", path, e);
+                std::process::exit(1);
+            }
+        },
+        None => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text).unwrap();
+            text
+        }
+    };
+
+    let lines = text.lines().count();
+    let words = text.split_whitespace().count();
+    println!("{} lines, {} words", lines, words);
+}
@@ -0,0 +1,16 @@
+;; A minimal WASI program in the WebAssembly text format. It writes
+;; "Hello from WAT!" to stdout and exits with status 3.
+(module
+    (import "wasi_snapshot_preview1" "fd_write"
+        (func $fd_write (param i32 i32 i32 i32) (result i32)))
+    (import "wasi_snapshot_preview1" "proc_exit"
+        (func $proc_exit (param i32)))
+    (memory (export "memory") 1)
+    ;; An iovec at offset 0: the text starts at offset 16 and is 16 bytes long
+    (data (i32.const 0) "\10\00\00\00\10\00\00\00")
+    (data (i32.const 16) "Hello from WAT!\n")
+    (func (export "_start")
+        ;; fd_write(stdout, iovecs, iovecs_len, bytes_written_ptr)
+        (drop (call $fd_write (i32.const 1) (i32.const 0) (i32.const 1) (i32.const 8)))
+        (call $proc_exit (i32.const 3)))
+)
@@ -0,0 +1,105 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+use wasmtime::{Engine, Linker, Module, Store};
+use wasmtime_wasi::pipe::{MemoryInputPipe, MemoryOutputPipe};
+use wasmtime_wasi::preview1::{self, WasiP1Ctx};
+use wasmtime_wasi::{DirPerms, FilePerms, I32Exit, WasiCtxBuilder};
+
+#[derive(Debug, PartialEq)]
+pub struct Output {
+    pub status: i32,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+// What the guest is allowed to see. Everything else, like the host's
+// files, network, and environment, simply doesn't exist for it.
+#[derive(Default)]
+pub struct Sandbox<'a> {
+    pub args: Vec<String>,
+    pub env: Vec<(String, String)>,
+    pub stdin: String,
+    // A host directory, visible to the guest as `.`
+    pub dir: Option<&'a Path>,
+}
+
+const OUTPUT_LIMIT: usize = 1024 * 1024;
+
+// Runs a WASI command module, given as binary `.wasm` or as text `.wat`,
+// and captures what it prints
+pub fn run(module: &[u8], sandbox: Sandbox) -> Result<Output> {
+    let engine = Engine::default();
+    // Compiles the WebAssembly code to native machine code
+    let module = Module::new(&engine, module).context("invalid WebAssembly module")?;
+
+    let stdout = MemoryOutputPipe::new(OUTPUT_LIMIT);
+    let stderr = MemoryOutputPipe::new(OUTPUT_LIMIT);
+    let mut wasi = WasiCtxBuilder::new();
+    wasi.stdin(MemoryInputPipe::new(sandbox.stdin))
+        .stdout(stdout.clone())
+        .stderr(stderr.clone())
+        // By convention, the first argument is the program name
+        .arg("guest")
+        .args(&sandbox.args)
+        .envs(&sandbox.env);
+    if let Some(dir) = sandbox.dir {
+        wasi.preopened_dir(dir, ".", DirPerms::READ, FilePerms::READ)?;
+    }
+
+    // The store holds the state of one running instance, here the WASI
+    // context. The linker provides the WASI functions the module imports.
+    let mut store: Store<WasiP1Ctx> = Store::new(&engine, wasi.build_p1());
+    let mut linker = Linker::new(&engine);
+    preview1::add_to_linker_sync(&mut linker, |ctx| ctx)?;
+
+    let instance = linker.instantiate(&mut store, &module)?;
+    let start = instance.get_typed_func::<(), ()>(&mut store, "_start")?;
+
+    // A guest calling `exit` ends up here as an `I32Exit` error
+    let status = match start.call(&mut store, ()) {
+        Ok(()) => 0,
+        Err(e) => match e.downcast_ref::<I32Exit>() {
+            Some(exit) => exit.0,
+            None => return Err(e.context("the module crashed")),
+        },
+    };
+
+    // The pipes given to WASI share their buffers with our clones
+    Ok(Output {
+        status,
+        stdout: String::from_utf8_lossy(&stdout.contents()).into_owned(),
+        stderr: String::from_utf8_lossy(&stderr.contents()).into_owned(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HELLO: &str = include_str!("../hello.wat");
+
+    #[test]
+    fn captures_output_and_exit_status() {
+        let output = run(HELLO.as_bytes(), Sandbox::default()).unwrap();
+        assert_eq!(
+            output,
+            Output {
+                status: 3,
+                stdout: "Hello from WAT!\n".to_string(),
+                stderr: String::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn traps_are_reported_as_errors() {
+        let crash = r#"(module (func (export "_start") unreachable))"#;
+        let err = run(crash.as_bytes(), Sandbox::default()).unwrap_err();
+        assert!(format!("{:#}", err).contains("the module crashed"));
+    }
+
+    #[test]
+    fn rejects_invalid_modules() {
+        assert!(run(b"not wasm", Sandbox::default()).is_err());
+    }
+}
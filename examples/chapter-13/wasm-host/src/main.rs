@@ -0,0 +1,35 @@
+use anyhow::{bail, Context, Result};
+use std::io::{IsTerminal, Read};
+use std::path::Path;
+use wasm_host::{run, Sandbox};
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let Some(path) = args.next() else {
+        bail!("usage: wasm-host <module.wasm> [args...]");
+    };
+    let module = std::fs::read(&path).with_context(|| format!("cannot read {}", path))?;
+
+    // Pass on piped input, but don't wait for the keyboard
+    let mut stdin = String::new();
+    if !std::io::stdin().is_terminal() {
+        std::io::stdin().read_to_string(&mut stdin)?;
+    }
+
+    // The guest gets its arguments, one environment variable, our stdin,
+    // and read-only access to the current directory. Nothing else.
+    let output = run(
+        &module,
+        Sandbox {
+            args: args.collect(),
+            env: vec![("GREETING".to_string(), "Hi".to_string())],
+            stdin,
+            dir: Some(Path::new(".")),
+        },
+    )?;
+
+    print!("{}", output.stdout);
+    eprint!("{}", output.stderr);
+    println!("[guest exited with status {}]", output.status);
+    Ok(())
+}
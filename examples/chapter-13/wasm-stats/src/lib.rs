@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+// A `#[wasm_bindgen]` struct becomes a JavaScript class. Public fields of
+// `Copy` types get JavaScript getters and setters.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+}
+
+// Parses numbers from text, one or more per line, and skips anything that
+// isn't a number. JavaScript strings arrive as `&str`, and the result
+// arrives in JavaScript as a `Stats` object, or `undefined` for `None`.
+#[wasm_bindgen]
+pub fn summarize(input: &str) -> Option<Stats> {
+    let values: Vec<f64> = input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter_map(|token| token.parse().ok())
+        .filter(|value: &f64| value.is_finite())
+        .collect();
+
+    let count = values.len();
+    if count == 0 {
+        return None;
+    }
+    let (min, max, sum) = values.iter().fold(
+        (f64::INFINITY, f64::NEG_INFINITY, 0.0),
+        |(min, max, sum), &value| (min.min(value), max.max(value), sum + value),
+    );
+    Some(Stats {
+        count,
+        min,
+        max,
+        mean: sum / count as f64,
+    })
+}
+
+// `Vec<String>` arrives in JavaScript as an array of strings
+#[wasm_bindgen]
+pub fn top_words(text: &str, n: usize) -> Vec<String> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for word in text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.len() > 2)
+    {
+        *counts.entry(word.to_lowercase()).or_default() += 1;
+    }
+
+    let mut sorted: Vec<(String, usize)> = counts.into_iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    sorted
+        .into_iter()
+        .take(n)
+        .map(|(word, count)| format!("{} ({})", word, count))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_numbers_and_skips_the_rest() {
+        let stats = summarize("3, 1\nfoo 8\n\n-2 NaN").unwrap();
+        assert_eq!(
+            stats,
+            Stats {
+                count: 4,
+                min: -2.0,
+                max: 8.0,
+                mean: 2.5,
+            }
+        );
+        assert_eq!(summarize("no numbers here"), None);
+    }
+
+    #[test]
+    fn counts_the_most_common_words() {
+        let words = top_words("The cat and the hat. THE END, the cat!", 2);
+        assert_eq!(words, vec!["the (4)", "cat (2)"]);
+    }
+}
@@ -0,0 +1,28 @@
+// `wasm-pack build --target web --out-dir www/pkg` generates this module.
+// The default export downloads and compiles the .wasm file.
+import init, { summarize, top_words } from "./pkg/wasm_stats.js";
+
+const input = document.getElementById("input");
+const output = document.getElementById("output");
+
+function update() {
+    // Calls into Rust: the string is copied into WebAssembly memory,
+    // and the results are converted back into JavaScript values
+    const stats = summarize(input.value);
+    const words = top_words(input.value, 3);
+
+    const lines = [];
+    if (stats === undefined) {
+        lines.push("No numbers found");
+    } else {
+        lines.push(`Numbers: ${stats.count}, min ${stats.min}, max ${stats.max}, mean ${stats.mean.toFixed(2)}`);
+        // Objects created by Rust live in WebAssembly memory until freed
+        stats.free();
+    }
+    lines.push(`Top words: ${words.join(", ")}`);
+    output.textContent = lines.join("\n");
+}
+
+await init();
+input.addEventListener("input", update);
+update();
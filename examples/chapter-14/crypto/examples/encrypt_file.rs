@@ -0,0 +1,175 @@
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::Argon2;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+// Encrypted file layout: salt | nonce | ciphertext with the 16-byte tag.
+// The salt and nonce aren't secret; they're needed to decrypt.
+
+/// Derives a 256-bit key from a password. A password is too short and
+/// predictable to be a key itself; Argon2 makes guessing it expensive.
+fn derive_key(password: &str, salt: &[u8]) -> Key<Aes256Gcm> {
+    let mut key = Key::<Aes256Gcm>::default();
+    Argon2::default()
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .expect("the salt and output lengths are valid");
+    key
+}
+
+/// Encrypts with an explicit key and nonce. A nonce must never be used
+/// twice with the same key, so only tests should pick one by hand.
+fn encrypt_with(
+    key: &Key<Aes256Gcm>,
+    nonce: &Nonce<aes_gcm::aead::consts::U12>,
+    plaintext: &[u8],
+) -> Vec<u8> {
+    Aes256Gcm::new(key)
+        .encrypt(nonce, plaintext)
+        .expect("encryption only fails for inputs larger than 64 GB")
+}
+
+fn encrypt(password: &str, plaintext: &[u8]) -> Vec<u8> {
+    // A new random salt and nonce for every file
+    let mut salt = [0u8; SALT_LEN];
+    aes_gcm::aead::rand_core::RngCore::fill_bytes(&mut OsRng, &mut salt);
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+
+    let key = derive_key(password, &salt);
+    let mut output = Vec::with_capacity(SALT_LEN + NONCE_LEN + plaintext.len() + 16);
+    output.extend_from_slice(&salt);
+    output.extend_from_slice(&nonce);
+    output.extend_from_slice(&encrypt_with(&key, &nonce, plaintext));
+    output
+}
+
+#[derive(Debug, PartialEq)]
+enum DecryptError {
+    TooShort,
+    // A wrong password and modified data look the same: the tag doesn't match
+    WrongPasswordOrCorrupted,
+}
+
+fn decrypt(password: &str, data: &[u8]) -> Result<Vec<u8>, DecryptError> {
+    if data.len() < SALT_LEN + NONCE_LEN {
+        return Err(DecryptError::TooShort);
+    }
+    let (salt, rest) = data.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let key = derive_key(password, salt);
+    Aes256Gcm::new(&key)
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| DecryptError::WrongPasswordOrCorrupted)
+}
+
+fn encrypt_file(password: &str, input: &Path, output: &Path) -> io::Result<()> {
+    let plaintext = fs::read(input)?;
+    fs::write(output, encrypt(password, &plaintext))
+}
+
+fn decrypt_file(password: &str, input: &Path, output: &Path) -> io::Result<()> {
+    let data = fs::read(input)?;
+    let plaintext = decrypt(password, &data)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{:?}", e)))?;
+    fs::write(output, plaintext)
+}
+
+fn main() -> io::Result<()> {
+    let dir = std::env::temp_dir().join(format!("encrypt-file-{}", std::process::id()));
+    fs::create_dir_all(&dir)?;
+    let (plain, encrypted, decrypted) = (
+        dir.join("notes.txt"),
+        dir.join("notes.enc"),
+        dir.join("notes.dec.txt"),
+    );
+    fs::write(&plain, "Meeting moved to Friday, 10:00.")?;
+
+    encrypt_file("s3cret pass", &plain, &encrypted)?;
+    let data = fs::read(&encrypted)?;
+    println!("Plaintext:  {} bytes", fs::metadata(&plain)?.len());
+    println!(
+        "Encrypted:  {} bytes (16 salt + 12 nonce + 31 data + 16 tag)",
+        data.len()
+    );
+    println!("Salt:       {}", hex::encode(&data[..SALT_LEN]));
+    println!(
+        "Nonce:      {}",
+        hex::encode(&data[SALT_LEN..SALT_LEN + NONCE_LEN])
+    );
+
+    decrypt_file("s3cret pass", &encrypted, &decrypted)?;
+    println!("Decrypted:  {}", fs::read_to_string(&decrypted)?);
+
+    println!(
+        "Wrong password: {:?}",
+        decrypt_file("guess", &encrypted, &decrypted).map_err(|e| e.to_string())
+    );
+
+    let mut tampered = data.clone();
+    *tampered.last_mut().unwrap() ^= 1;
+    println!(
+        "Tampered:       {:?}",
+        decrypt("s3cret pass", &tampered).map(|_| ())
+    );
+
+    fs::remove_dir_all(&dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test cases 13 and 14 from "The Galois/Counter Mode of Operation (GCM)"
+    // by McGrew and Viega: an all-zero 256-bit key and an all-zero nonce
+    #[test]
+    fn known_vectors() {
+        let key = Key::<Aes256Gcm>::from_slice(&[0u8; 32]);
+        let nonce = Nonce::from_slice(&[0u8; 12]);
+
+        let tag_only = encrypt_with(key, nonce, b"");
+        assert_eq!(hex::encode(tag_only), "530f8afbc74536b9a963b4f1c4cb738b");
+
+        let output = encrypt_with(key, nonce, &[0u8; 16]);
+        assert_eq!(
+            hex::encode(output),
+            "cea7403d4d606b6e074ec5d3baf39d18d0d1c8a799996bf0265b98b5d48ab919"
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let data = encrypt("pw", b"hello");
+        assert_eq!(decrypt("pw", &data).unwrap(), b"hello");
+    }
+
+    #[test]
+    fn same_input_encrypts_differently() {
+        // Different salts and nonces, so equal files can't be recognized
+        assert_ne!(encrypt("pw", b"same"), encrypt("pw", b"same"));
+    }
+
+    #[test]
+    fn detects_wrong_password_and_tampering() {
+        let data = encrypt("pw", b"hello");
+        assert_eq!(
+            decrypt("wrong", &data),
+            Err(DecryptError::WrongPasswordOrCorrupted)
+        );
+
+        for i in [0, SALT_LEN, SALT_LEN + NONCE_LEN, data.len() - 1] {
+            let mut tampered = data.clone();
+            tampered[i] ^= 0x80;
+            assert_eq!(
+                decrypt("pw", &tampered),
+                Err(DecryptError::WrongPasswordOrCorrupted),
+                "byte {}",
+                i
+            );
+        }
+        assert_eq!(decrypt("pw", &data[..10]), Err(DecryptError::TooShort));
+    }
+}
@@ -0,0 +1,95 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+// Signs a message with a secret key. Anyone with the key can check that
+// the message came from someone with the key and wasn't changed.
+fn sign(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message);
+    mac.finalize().into_bytes().to_vec()
+}
+
+// `verify_slice` compares in constant time. Comparing with `==` can stop
+// at the first wrong byte, and the timing tells an attacker how many
+// bytes of a forged signature are correct.
+fn verify(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message);
+    mac.verify_slice(signature).is_ok()
+}
+
+// A signed token for a URL or cookie: "<payload>.<signature in hex>"
+fn signed_token(key: &[u8], payload: &str) -> String {
+    format!("{}.{}", payload, hex::encode(sign(key, payload.as_bytes())))
+}
+
+fn check_token<'a>(key: &[u8], token: &'a str) -> Option<&'a str> {
+    let (payload, signature) = token.rsplit_once('.')?;
+    let signature = hex::decode(signature).ok()?;
+    verify(key, payload.as_bytes(), &signature).then_some(payload)
+}
+
+fn main() {
+    let key = b"server-side secret key";
+
+    let signature = sign(key, b"amount=100&to=alice");
+    println!("Signature: {}", hex::encode(&signature));
+    println!(
+        "Valid:     {}",
+        verify(key, b"amount=100&to=alice", &signature)
+    );
+    println!(
+        "Changed:   {}",
+        verify(key, b"amount=900&to=alice", &signature)
+    );
+    println!(
+        "Other key: {}",
+        verify(b"another key", b"amount=100&to=alice", &signature)
+    );
+
+    let token = signed_token(key, "user=42;role=viewer");
+    println!("Token:     {}", token);
+    println!("Checked:   {:?}", check_token(key, &token));
+    let forged = token.replace("viewer", "admin");
+    println!("Forged:    {:?}", check_token(key, &forged));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test cases 1 and 2 for HMAC-SHA-256 from RFC 4231
+    #[test]
+    fn known_vectors() {
+        assert_eq!(
+            hex::encode(sign(&[0x0b; 20], b"Hi There")),
+            "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7"
+        );
+        assert_eq!(
+            hex::encode(sign(b"Jefe", b"what do ya want for nothing?")),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn rejects_changed_messages_and_signatures() {
+        let signature = sign(b"key", b"message");
+        assert!(verify(b"key", b"message", &signature));
+        assert!(!verify(b"key", b"messagE", &signature));
+        assert!(!verify(b"key", b"message", &signature[..31]));
+        let mut flipped = signature.clone();
+        flipped[0] ^= 1;
+        assert!(!verify(b"key", b"message", &flipped));
+    }
+
+    #[test]
+    fn tokens() {
+        let token = signed_token(b"k", "a.b.c");
+        assert_eq!(check_token(b"k", &token), Some("a.b.c"));
+        assert_eq!(check_token(b"other", &token), None);
+        assert_eq!(check_token(b"k", "no-signature"), None);
+        assert_eq!(check_token(b"k", "payload.zz"), None);
+    }
+}
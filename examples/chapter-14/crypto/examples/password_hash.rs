@@ -0,0 +1,109 @@
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+
+// Hashes a password for storage. The result is a PHC string that contains
+// the algorithm, the parameters, the salt, and the hash, so it's all that
+// needs to be stored.
+fn hash_password(password: &str) -> Result<String, argon2::password_hash::Error> {
+    // A new random salt for every password
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::default().hash_password(password.as_bytes(), &salt)?;
+    Ok(hash.to_string())
+}
+
+// Verifies with the parameters stored in the hash, not the current defaults,
+// so old hashes keep working after the parameters are raised
+fn verify_password(password: &str, stored: &str) -> bool {
+    match PasswordHash::new(stored) {
+        Ok(hash) => Argon2::default()
+            .verify_password(password.as_bytes(), &hash)
+            .is_ok(),
+        Err(_) => false,
+    }
+}
+
+// Explicit parameters: memory in KiB, number of passes, and parallelism.
+// More memory makes attacks with GPUs and special hardware more expensive.
+fn hasher(memory_kib: u32, passes: u32) -> Argon2<'static> {
+    let params = Params::new(memory_kib, passes, 1, None).expect("valid parameters");
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+}
+
+fn main() -> Result<(), argon2::password_hash::Error> {
+    let first = hash_password("correct horse battery staple")?;
+    let second = hash_password("correct horse battery staple")?;
+    println!("First hash:  {}", first);
+    println!("Second hash: {}", second);
+    println!("Same hash:   {}", first == second);
+
+    println!(
+        "Correct password: {}",
+        verify_password("correct horse battery staple", &first)
+    );
+    println!(
+        "Wrong password:   {}",
+        verify_password("Correct horse battery staple", &first)
+    );
+
+    // A fixed salt, only to show the parameters in the PHC string
+    let salt = SaltString::from_b64("c29tZXNhbHQxMjM0NTY3OA")?;
+    let stronger = hasher(64 * 1024, 3).hash_password(b"secret", &salt)?;
+    println!("Stronger:    {}", stronger);
+    println!(
+        "Verifies with defaults: {}",
+        verify_password("secret", &stronger.to_string())
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use argon2::{AssociatedData, ParamsBuilder};
+
+    #[test]
+    fn roundtrip_and_unique_salts() {
+        let a = hash_password("hunter2").unwrap();
+        let b = hash_password("hunter2").unwrap();
+        assert_ne!(a, b);
+        assert!(verify_password("hunter2", &a));
+        assert!(verify_password("hunter2", &b));
+        assert!(!verify_password("hunter3", &a));
+        assert!(!verify_password("hunter2", "not a hash"));
+    }
+
+    #[test]
+    fn same_salt_gives_same_hash() {
+        let salt = SaltString::from_b64("c29tZXNhbHQxMjM0NTY3OA").unwrap();
+        let argon2 = hasher(8 * 1024, 1);
+        let a = argon2.hash_password(b"pw", &salt).unwrap().to_string();
+        let b = argon2.hash_password(b"pw", &salt).unwrap().to_string();
+        assert_eq!(a, b);
+        assert!(a.starts_with("$argon2id$v=19$m=8192,t=1,p=1$c29tZXNhbHQxMjM0NTY3OA$"));
+    }
+
+    // Test vector for Argon2id from RFC 9106, section 5.3
+    #[test]
+    fn rfc_9106_vector() {
+        let params = ParamsBuilder::new()
+            .m_cost(32)
+            .t_cost(3)
+            .p_cost(4)
+            .output_len(32)
+            .data(AssociatedData::new(&[0x04; 12]).unwrap())
+            .build()
+            .unwrap();
+        let argon2 =
+            Argon2::new_with_secret(&[0x03; 8], Algorithm::Argon2id, Version::V0x13, params)
+                .unwrap();
+        let mut output = [0u8; 32];
+        argon2
+            .hash_password_into(&[0x01; 32], &[0x02; 16], &mut output)
+            .unwrap();
+        assert_eq!(
+            hex::encode(output),
+            "0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659"
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+// Hashes a file in chunks, so large files don't have to fit in memory
+fn sha256_file(path: &Path) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 8192];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+fn main() -> io::Result<()> {
+    println!("sha256(\"abc\")   = {}", sha256_hex(b"abc"));
+    println!("sha256(\"abd\")   = {}", sha256_hex(b"abd"));
+
+    // Feeding the data in parts gives the same hash as all at once
+    let mut hasher = Sha256::new();
+    hasher.update(b"a");
+    hasher.update(b"bc");
+    println!("in two parts    = {}", hex::encode(hasher.finalize()));
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    println!("Cargo.toml      = {}", sha256_file(&path)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from FIPS 180-2, the SHA-256 standard
+    #[test]
+    fn known_vectors() {
+        assert_eq!(
+            sha256_hex(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            sha256_hex(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+    }
+
+    #[test]
+    fn file_hash_matches_memory_hash() {
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        let path = std::env::temp_dir().join(format!("sha256-test-{}", std::process::id()));
+        std::fs::write(&path, &data).unwrap();
+        let from_file = sha256_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(from_file, sha256_hex(&data));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    println!("Run one of the examples, for example: cargo run --example sha256");
+}
@@ -0,0 +1,14 @@
+#![no_main]
+
+use fuzzing::{format_duration, parse_duration};
+use libfuzzer_sys::fuzz_target;
+
+// libFuzzer calls this with millions of generated inputs. Any panic,
+// including a failed assertion, is reported as a crash.
+fuzz_target!(|input: &str| {
+    if let Ok(duration) = parse_duration(input) {
+        // Every valid duration must survive a round trip
+        let formatted = format_duration(duration);
+        assert_eq!(parse_duration(&formatted), Ok(duration), "{}", formatted);
+    }
+});
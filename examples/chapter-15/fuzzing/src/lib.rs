@@ -0,0 +1,130 @@
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum ParseError {
+    #[error("empty duration")]
+    Empty,
+    #[error("unknown unit '{0}', use h, m, or s")]
+    UnknownUnit(char),
+    #[error("unit '{0}' must follow a number")]
+    MissingNumber(char),
+    #[error("number without a unit at the end")]
+    MissingUnit,
+    #[error("duration is too large")]
+    TooLarge,
+}
+
+// Parses durations such as "90s", "15m", or "1h30m" into a `Duration`
+pub fn parse_duration(input: &str) -> Result<Duration, ParseError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let mut total: u64 = 0;
+    let mut number = String::new();
+    for c in input.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let seconds_per_unit = match c {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return Err(ParseError::UnknownUnit(c)),
+        };
+        if number.is_empty() {
+            return Err(ParseError::MissingNumber(c));
+        }
+        let value: u64 = number.parse().map_err(|_| ParseError::TooLarge)?;
+        total = add_seconds(total, value, seconds_per_unit)?;
+        number.clear();
+    }
+    if !number.is_empty() {
+        return Err(ParseError::MissingUnit);
+    }
+    Ok(Duration::from_secs(total))
+}
+
+// The version with the bug that the fuzzer finds: a large number of hours
+// overflows. Debug builds panic with "attempt to multiply with overflow",
+// release builds silently return a wrong duration.
+#[cfg(feature = "overflow-bug")]
+fn add_seconds(total: u64, value: u64, seconds_per_unit: u64) -> Result<u64, ParseError> {
+    Ok(total + value * seconds_per_unit)
+}
+
+// The fix: checked arithmetic returns `None` instead of overflowing
+#[cfg(not(feature = "overflow-bug"))]
+fn add_seconds(total: u64, value: u64, seconds_per_unit: u64) -> Result<u64, ParseError> {
+    value
+        .checked_mul(seconds_per_unit)
+        .and_then(|seconds| total.checked_add(seconds))
+        .ok_or(ParseError::TooLarge)
+}
+
+// Formats a duration the way `parse_duration` reads it: 5400s -> "1h30m"
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (h, m, s) = (secs / 3600, secs % 3600 / 60, secs % 60);
+    let mut out = String::new();
+    if h > 0 {
+        out += &format!("{}h", h);
+    }
+    if m > 0 {
+        out += &format!("{}m", m);
+    }
+    if s > 0 || out.is_empty() {
+        out += &format!("{}s", s);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_units_and_combinations() {
+        assert_eq!(parse_duration("90s"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("15m"), Ok(Duration::from_secs(900)));
+        assert_eq!(parse_duration(" 1h30m "), Ok(Duration::from_secs(5400)));
+        assert_eq!(parse_duration("1m1m"), Ok(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert_eq!(parse_duration(""), Err(ParseError::Empty));
+        assert_eq!(parse_duration("10d"), Err(ParseError::UnknownUnit('d')));
+        assert_eq!(parse_duration("h"), Err(ParseError::MissingNumber('h')));
+        assert_eq!(parse_duration("1h30"), Err(ParseError::MissingUnit));
+        assert_eq!(
+            parse_duration("99999999999999999999s"),
+            Err(ParseError::TooLarge)
+        );
+    }
+
+    // The input found by the fuzzer, kept as a regression test
+    #[cfg(not(feature = "overflow-bug"))]
+    #[test]
+    fn huge_values_are_too_large() {
+        assert_eq!(
+            parse_duration("5511551119155111h"),
+            Err(ParseError::TooLarge)
+        );
+        assert_eq!(
+            parse_duration("5124095576030431h1h"),
+            Err(ParseError::TooLarge)
+        );
+    }
+
+    #[test]
+    fn format_round_trips() {
+        for secs in [0, 1, 59, 60, 3599, 3600, 5400, 86_461] {
+            let duration = Duration::from_secs(secs);
+            assert_eq!(parse_duration(&format_duration(duration)), Ok(duration));
+        }
+    }
+}
@@ -0,0 +1,259 @@
+//! A tiny library for semantic version numbers like `1.4.2`.
+//!
+//! Versions can be parsed, compared, and bumped:
+//!
+//! ```
+//! use semver_lite::Version;
+//!
+//! let current = Version::parse("1.4.2")?;
+//! let next = current.bump_minor();
+//!
+//! assert_eq!(next.to_string(), "1.5.0");
+//! assert!(next > current);
+//! # Ok::<(), semver_lite::ParseError>(())
+//! ```
+//!
+//! Every example in this documentation is compiled and run by `cargo test`.
+
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// An error returned when a version string can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    /// The version doesn't have exactly three parts separated by dots.
+    #[error("expected MAJOR.MINOR.PATCH, found {0} part(s)")]
+    WrongNumberOfParts(usize),
+    /// One of the parts is not a non-negative integer.
+    #[error("'{0}' is not a valid version number")]
+    InvalidNumber(String),
+}
+
+/// A semantic version: `MAJOR.MINOR.PATCH`.
+///
+/// Versions are ordered by major, then minor, then patch number:
+///
+/// ```
+/// use semver_lite::Version;
+///
+/// let mut versions = vec![
+///     Version::new(1, 10, 0),
+///     Version::new(1, 2, 3),
+///     Version::new(0, 9, 9),
+/// ];
+/// versions.sort();
+///
+/// assert_eq!(versions[0], Version::new(0, 9, 9));
+/// assert_eq!(versions[2], Version::new(1, 10, 0));
+/// ```
+///
+/// The fields are private, so a `Version` is always created with
+/// [`Version::new`] or [`Version::parse`]:
+///
+/// ```compile_fail
+/// let version = semver_lite::Version { major: 1, minor: 0, patch: 0 };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    major: u64,
+    minor: u64,
+    patch: u64,
+}
+
+impl Version {
+    /// Creates a version from its three parts.
+    ///
+    /// ```
+    /// let version = semver_lite::Version::new(2, 0, 1);
+    /// assert_eq!(version.to_string(), "2.0.1");
+    /// ```
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Version {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parses a version such as `"1.4.2"`. Surrounding whitespace and a
+    /// leading `v` are ignored.
+    ///
+    /// ```
+    /// use semver_lite::Version;
+    ///
+    /// assert_eq!(Version::parse("1.4.2"), Ok(Version::new(1, 4, 2)));
+    /// assert_eq!(Version::parse(" v0.3.0\n"), Ok(Version::new(0, 3, 0)));
+    /// ```
+    ///
+    /// `Version` also implements [`FromStr`], so `str::parse` works too:
+    ///
+    /// ```
+    /// # use semver_lite::Version;
+    /// let version: Version = "3.1.0".parse()?;
+    /// assert_eq!(version.major(), 3);
+    /// # Ok::<(), semver_lite::ParseError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::WrongNumberOfParts`] if the input doesn't have
+    /// three parts, and [`ParseError::InvalidNumber`] if a part isn't a
+    /// number:
+    ///
+    /// ```
+    /// use semver_lite::{ParseError, Version};
+    ///
+    /// assert_eq!(Version::parse("1.2"), Err(ParseError::WrongNumberOfParts(2)));
+    /// assert_eq!(
+    ///     Version::parse("1.x.0"),
+    ///     Err(ParseError::InvalidNumber("x".to_string()))
+    /// );
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let input = input.trim();
+        let input = input.strip_prefix('v').unwrap_or(input);
+
+        let parts: Vec<&str> = input.split('.').collect();
+        if parts.len() != 3 {
+            return Err(ParseError::WrongNumberOfParts(parts.len()));
+        }
+        let number = |part: &str| {
+            part.parse::<u64>()
+                .map_err(|_| ParseError::InvalidNumber(part.to_string()))
+        };
+        Ok(Version::new(
+            number(parts[0])?,
+            number(parts[1])?,
+            number(parts[2])?,
+        ))
+    }
+
+    /// The major version, incremented for incompatible changes.
+    pub fn major(&self) -> u64 {
+        self.major
+    }
+
+    /// The minor version, incremented for new features.
+    pub fn minor(&self) -> u64 {
+        self.minor
+    }
+
+    /// The patch version, incremented for bug fixes.
+    pub fn patch(&self) -> u64 {
+        self.patch
+    }
+
+    /// Returns the next major version. Minor and patch are reset to zero.
+    ///
+    /// ```
+    /// # use semver_lite::Version;
+    /// assert_eq!(Version::new(1, 4, 2).bump_major(), Version::new(2, 0, 0));
+    /// ```
+    pub fn bump_major(&self) -> Self {
+        Version::new(self.major + 1, 0, 0)
+    }
+
+    /// Returns the next minor version. The patch is reset to zero.
+    ///
+    /// ```
+    /// # use semver_lite::Version;
+    /// assert_eq!(Version::new(1, 4, 2).bump_minor(), Version::new(1, 5, 0));
+    /// ```
+    pub fn bump_minor(&self) -> Self {
+        Version::new(self.major, self.minor + 1, 0)
+    }
+
+    /// Returns the next patch version.
+    ///
+    /// ```
+    /// # use semver_lite::Version;
+    /// assert_eq!(Version::new(1, 4, 2).bump_patch(), Version::new(1, 4, 3));
+    /// ```
+    pub fn bump_patch(&self) -> Self {
+        Version::new(self.major, self.minor, self.patch + 1)
+    }
+
+    /// Checks whether this version can be used where `required` is expected,
+    /// following the same rules as Cargo's default (caret) requirements: the
+    /// version must not be older, and must have the same major version. Below
+    /// `1.0.0`, the minor version counts as the major one, and below `0.1.0`,
+    /// every release may break something, so only the exact version matches.
+    ///
+    /// ```
+    /// # use semver_lite::{ParseError, Version};
+    /// # fn main() -> Result<(), ParseError> {
+    /// let required = Version::parse("1.2.0")?;
+    ///
+    /// assert!(Version::parse("1.2.0")?.is_compatible_with(&required));
+    /// assert!(Version::parse("1.9.3")?.is_compatible_with(&required));
+    /// assert!(!Version::parse("1.1.9")?.is_compatible_with(&required));
+    /// assert!(!Version::parse("2.0.0")?.is_compatible_with(&required));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ```
+    /// # use semver_lite::Version;
+    /// let required = Version::new(0, 3, 1);
+    /// assert!(Version::new(0, 3, 7).is_compatible_with(&required));
+    /// assert!(!Version::new(0, 4, 0).is_compatible_with(&required));
+    ///
+    /// let required = Version::new(0, 0, 3);
+    /// assert!(Version::new(0, 0, 3).is_compatible_with(&required));
+    /// assert!(!Version::new(0, 0, 4).is_compatible_with(&required));
+    /// ```
+    pub fn is_compatible_with(&self, required: &Version) -> bool {
+        match (required.major, required.minor) {
+            (0, 0) => self == required,
+            (0, minor) => self.major == 0 && self.minor == minor && self >= required,
+            (major, _) => self.major == major && self >= required,
+        }
+    }
+}
+
+/// Returns the newest version from `available` that is compatible with
+/// `required`, or `None` if there isn't one.
+///
+/// This is how a package manager picks a dependency version:
+///
+/// ```
+/// use semver_lite::{latest_compatible, Version};
+///
+/// # fn main() -> Result<(), semver_lite::ParseError> {
+/// let available = ["1.0.0", "1.2.0", "1.4.1", "2.0.0"]
+///     .iter()
+///     .map(|v| v.parse())
+///     .collect::<Result<Vec<Version>, _>>()?;
+///
+/// let required = Version::new(1, 1, 0);
+/// assert_eq!(
+///     latest_compatible(&available, &required),
+///     Some(&Version::new(1, 4, 1))
+/// );
+///
+/// let required = Version::new(3, 0, 0);
+/// assert_eq!(latest_compatible(&available, &required), None);
+/// # Ok(())
+/// # }
+/// ```
+pub fn latest_compatible<'a>(available: &'a [Version], required: &Version) -> Option<&'a Version> {
+    available
+        .iter()
+        .filter(|version| version.is_compatible_with(required))
+        .max()
+}
+
+impl FromStr for Version {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Version::parse(s)
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
@@ -0,0 +1,130 @@
+use crate::pricing::{apply_discount, DiscountError};
+use std::fmt::Write;
+
+#[derive(Debug, Clone)]
+pub struct Line {
+    pub description: String,
+    pub quantity: u32,
+    pub unit_price_cents: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Invoice {
+    pub number: u32,
+    pub customer: String,
+    pub lines: Vec<Line>,
+    pub discount_code: String,
+}
+
+impl Invoice {
+    pub fn subtotal_cents(&self) -> u64 {
+        self.lines
+            .iter()
+            .map(|line| line.quantity as u64 * line.unit_price_cents)
+            .sum()
+    }
+
+    pub fn total_cents(&self) -> Result<u64, DiscountError> {
+        apply_discount(self.subtotal_cents(), &self.discount_code)
+    }
+}
+
+fn money(cents: u64) -> String {
+    format!("{}.{:02}", cents / 100, cents % 100)
+}
+
+// Renders the invoice as plain text, as it would be printed or e-mailed
+pub fn render(invoice: &Invoice) -> Result<String, DiscountError> {
+    let total = invoice.total_cents()?;
+    let subtotal = invoice.subtotal_cents();
+
+    let mut out = String::new();
+    writeln!(out, "INVOICE #{:06}", invoice.number).unwrap();
+    writeln!(out, "Customer: {}", invoice.customer).unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "{:<24}{:>5}{:>10}{:>10}",
+        "Item", "Qty", "Price", "Amount"
+    )
+    .unwrap();
+    writeln!(out, "{}", "-".repeat(49)).unwrap();
+    for line in &invoice.lines {
+        writeln!(
+            out,
+            "{:<24}{:>5}{:>10}{:>10}",
+            line.description,
+            line.quantity,
+            money(line.unit_price_cents),
+            money(line.quantity as u64 * line.unit_price_cents)
+        )
+        .unwrap();
+    }
+    writeln!(out, "{}", "-".repeat(49)).unwrap();
+    writeln!(out, "{:<39}{:>10}", "Subtotal", money(subtotal)).unwrap();
+    if total != subtotal {
+        let label = format!("Discount ({})", invoice.discount_code);
+        let amount = format!("-{}", money(subtotal - total));
+        writeln!(out, "{:<39}{:>10}", label, amount).unwrap();
+    }
+    writeln!(out, "{:<39}{:>10}", "Total", money(total)).unwrap();
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::{fixture, rstest};
+
+    // A fixture defines the test data in one place. Each test that
+    // names `invoice` as a parameter gets a fresh copy.
+    #[fixture]
+    fn invoice() -> Invoice {
+        Invoice {
+            number: 42,
+            customer: "Alice Smith".to_string(),
+            lines: vec![
+                Line {
+                    description: "Rust Recipes (paperback)".to_string(),
+                    quantity: 2,
+                    unit_price_cents: 3_990,
+                },
+                Line {
+                    description: "Ferris plush".to_string(),
+                    quantity: 1,
+                    unit_price_cents: 1_500,
+                },
+            ],
+            discount_code: String::new(),
+        }
+    }
+
+    #[rstest]
+    fn subtotal_adds_all_lines(invoice: Invoice) {
+        assert_eq!(invoice.subtotal_cents(), 9_480);
+    }
+
+    // The expected output lives in src/snapshots/ and is reviewed like code
+    #[rstest]
+    fn renders_invoice(invoice: Invoice) {
+        insta::assert_snapshot!(render(&invoice).unwrap());
+    }
+
+    #[rstest]
+    fn renders_discount(mut invoice: Invoice) {
+        invoice.discount_code = "SAVE10".to_string();
+        insta::assert_snapshot!(render(&invoice).unwrap());
+    }
+
+    #[rstest]
+    fn invalid_code_is_an_error(mut invoice: Invoice) {
+        invoice.discount_code = "BOGUS".to_string();
+        insta::assert_debug_snapshot!(render(&invoice), @r###"
+        Err(
+            UnknownCode(
+                "BOGUS",
+            ),
+        )
+        "###);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod invoice;
+pub mod pricing;
+pub mod reminders;
@@ -0,0 +1,65 @@
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum DiscountError {
+    #[error("unknown discount code '{0}'")]
+    UnknownCode(String),
+    #[error("code '{code}' requires an order of at least {minimum_cents} cents")]
+    OrderTooSmall { code: String, minimum_cents: u64 },
+}
+
+// Returns the total after applying a discount code. Amounts are in cents,
+// so there are no rounding surprises with floating point numbers.
+pub fn apply_discount(total_cents: u64, code: &str) -> Result<u64, DiscountError> {
+    match code.trim().to_uppercase().as_str() {
+        "" => Ok(total_cents),
+        "SAVE10" => Ok(total_cents - total_cents / 10),
+        "HALF" if total_cents < 5_000 => Err(DiscountError::OrderTooSmall {
+            code: "HALF".to_string(),
+            minimum_cents: 5_000,
+        }),
+        "HALF" => Ok(total_cents / 2),
+        // A fixed amount never makes the total negative
+        "MINUS5" => Ok(total_cents.saturating_sub(500)),
+        _ => Err(DiscountError::UnknownCode(code.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // Every `case` becomes a separate test with its own name in the output
+    #[rstest]
+    #[case::no_code(10_000, "", 10_000)]
+    #[case::ten_percent(10_000, "SAVE10", 9_000)]
+    #[case::lowercase(10_000, "save10", 9_000)]
+    #[case::whitespace(10_000, " SAVE10 ", 9_000)]
+    #[case::half(10_000, "HALF", 5_000)]
+    #[case::half_at_minimum(5_000, "HALF", 2_500)]
+    #[case::fixed_amount(1_200, "MINUS5", 700)]
+    #[case::fixed_amount_above_total(300, "MINUS5", 0)]
+    fn applies_discount(#[case] total: u64, #[case] code: &str, #[case] expected: u64) {
+        assert_eq!(apply_discount(total, code), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("BOGUS")]
+    #[case("SAVE 10")]
+    fn rejects_unknown_codes(#[case] code: &str) {
+        assert_eq!(
+            apply_discount(10_000, code),
+            Err(DiscountError::UnknownCode(code.to_string()))
+        );
+    }
+
+    // `values` runs the test for every combination: 3 totals x 3 codes
+    #[rstest]
+    fn discount_never_increases_total(
+        #[values(0, 4_999, 123_456)] total: u64,
+        #[values("", "SAVE10", "MINUS5")] code: &str,
+    ) {
+        assert!(apply_discount(total, code).unwrap() <= total);
+    }
+}
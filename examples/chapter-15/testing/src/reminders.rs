@@ -0,0 +1,149 @@
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Customer {
+    pub name: String,
+    pub email: String,
+    pub days_left: u32,
+}
+
+#[derive(Debug, Error)]
+#[error("mail server rejected the message: {0}")]
+pub struct MailError(pub String);
+
+// The service only depends on these traits. In tests, mockall generates
+// `MockCustomers` and `MockMailer` implementations of them.
+#[cfg_attr(test, mockall::automock)]
+pub trait Customers {
+    fn expiring_within(&self, days: u32) -> Vec<Customer>;
+}
+
+#[cfg_attr(test, mockall::automock)]
+pub trait Mailer {
+    fn send(&self, to: &str, subject: &str, body: &str) -> Result<(), MailError>;
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Summary {
+    pub sent: usize,
+    pub failed: Vec<String>,
+}
+
+pub struct Reminders<C, M> {
+    customers: C,
+    mailer: M,
+}
+
+impl<C: Customers, M: Mailer> Reminders<C, M> {
+    pub fn new(customers: C, mailer: M) -> Self {
+        Reminders { customers, mailer }
+    }
+
+    // E-mails every customer whose subscription expires within `days`.
+    // A failed e-mail doesn't stop the others.
+    pub fn send(&self, days: u32) -> Summary {
+        let mut summary = Summary::default();
+        for customer in self.customers.expiring_within(days) {
+            let subject = match customer.days_left {
+                0 => "Your subscription expires today".to_string(),
+                1 => "Your subscription expires tomorrow".to_string(),
+                n => format!("Your subscription expires in {} days", n),
+            };
+            let body = format!("Hi {}, renew now to keep your plan.", customer.name);
+
+            match self.mailer.send(&customer.email, &subject, &body) {
+                Ok(()) => summary.sent += 1,
+                Err(_) => summary.failed.push(customer.email),
+            }
+        }
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::{always, eq};
+
+    fn customer(name: &str, days_left: u32) -> Customer {
+        Customer {
+            name: name.to_string(),
+            email: format!("{}@example.com", name.to_lowercase()),
+            days_left,
+        }
+    }
+
+    #[test]
+    fn sends_one_email_per_expiring_customer() {
+        let mut customers = MockCustomers::new();
+        customers
+            .expect_expiring_within()
+            .with(eq(7))
+            .times(1)
+            .returning(|_| vec![customer("Alice", 1), customer("Bob", 5)]);
+
+        let mut mailer = MockMailer::new();
+        mailer
+            .expect_send()
+            .with(
+                eq("alice@example.com"),
+                eq("Your subscription expires tomorrow"),
+                always(),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        mailer
+            .expect_send()
+            .withf(|to, subject, body| {
+                to == "bob@example.com" && subject.ends_with("in 5 days") && body.contains("Hi Bob")
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let summary = Reminders::new(customers, mailer).send(7);
+        assert_eq!(summary.sent, 2);
+        // The mocks check on drop that every expectation was met
+    }
+
+    #[test]
+    fn failed_emails_are_reported_and_others_still_sent() {
+        let mut customers = MockCustomers::new();
+        customers
+            .expect_expiring_within()
+            .returning(|_| vec![customer("Alice", 0), customer("Bob", 2)]);
+
+        let mut mailer = MockMailer::new();
+        mailer
+            .expect_send()
+            .with(eq("alice@example.com"), always(), always())
+            .returning(|_, _, _| Err(MailError("mailbox full".to_string())));
+        mailer
+            .expect_send()
+            .with(eq("bob@example.com"), always(), always())
+            .returning(|_, _, _| Ok(()));
+
+        let summary = Reminders::new(customers, mailer).send(7);
+        assert_eq!(
+            summary,
+            Summary {
+                sent: 1,
+                failed: vec!["alice@example.com".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn nothing_is_sent_without_expiring_customers() {
+        let mut customers = MockCustomers::new();
+        customers.expect_expiring_within().returning(|_| vec![]);
+
+        let mut mailer = MockMailer::new();
+        // Any call to `send` would fail the test
+        mailer.expect_send().never();
+
+        assert_eq!(
+            Reminders::new(customers, mailer).send(7),
+            Summary::default()
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use std::fmt::Debug;
+
+// A blanket implementation implements a trait for every type that meets
+// a bound. The standard library does this too: every `T: Display` gets
+// `ToString` for free.
+trait Inspect {
+    fn inspect(&self) -> String;
+}
+
+impl<T: Debug> Inspect for T {
+    fn inspect(&self) -> String {
+        format!("{}: {:?}", std::any::type_name::<T>(), self)
+    }
+}
+
+// An extension trait adds methods to types from other crates. Here, every
+// iterator over `f64` gets `mean` and `variance`.
+trait Stats: Iterator<Item = f64> + Sized {
+    fn mean(self) -> Option<f64> {
+        let (sum, count) = self.fold((0.0, 0), |(sum, count), x| (sum + x, count + 1));
+        (count > 0).then(|| sum / count as f64)
+    }
+
+    fn variance(self) -> Option<f64> {
+        let values: Vec<f64> = self.collect();
+        let mean = values.iter().copied().mean()?;
+        values.into_iter().map(|x| (x - mean).powi(2)).mean()
+    }
+}
+
+impl<I: Iterator<Item = f64>> Stats for I {}
+
+fn main() {
+    println!("{}", 42.inspect());
+    println!("{}", "hello".inspect());
+    println!("{}", std::time::Duration::from_millis(1500).inspect());
+    println!("{}", vec![Some(1), None].inspect());
+
+    let temperatures = [21.5, 23.0, 19.5, 22.0];
+    println!("Mean: {:?}", temperatures.iter().copied().mean());
+    println!("Variance: {:?}", temperatures.iter().copied().variance());
+    println!("Mean of nothing: {:?}", std::iter::empty().mean());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_debug_type_can_be_inspected() {
+        assert_eq!(42u8.inspect(), "u8: 42");
+        assert_eq!((1, "a").inspect(), "(i32, &str): (1, \"a\")");
+    }
+
+    #[test]
+    fn stats_work_on_any_f64_iterator() {
+        assert_eq!([1.0, 2.0, 3.0].into_iter().mean(), Some(2.0));
+        assert_eq!((1..=3).map(f64::from).variance(), Some(2.0 / 3.0));
+        assert_eq!(Vec::<f64>::new().into_iter().mean(), None);
+    }
+}
@@ -0,0 +1,95 @@
+// Implementors only have to write `check`. Everything else is built on top
+// of it, the same way `Iterator` provides dozens of methods for `next`.
+trait Rule {
+    fn check(&self, password: &str) -> Result<(), String>;
+
+    fn is_satisfied(&self, password: &str) -> bool {
+        self.check(password).is_ok()
+    }
+
+    // Returns the error message for every password that fails the rule
+    fn failures<'a>(&self, passwords: &[&'a str]) -> Vec<(&'a str, String)> {
+        passwords
+            .iter()
+            .filter_map(|password| self.check(password).err().map(|e| (*password, e)))
+            .collect()
+    }
+}
+
+struct MinLength(usize);
+
+struct ContainsDigit;
+
+// A rule that doesn't check anything, but overrides a default method
+// with a faster version
+struct AllowAll;
+
+impl Rule for MinLength {
+    fn check(&self, password: &str) -> Result<(), String> {
+        if password.chars().count() >= self.0 {
+            Ok(())
+        } else {
+            Err(format!("must be at least {} characters long", self.0))
+        }
+    }
+}
+
+impl Rule for ContainsDigit {
+    fn check(&self, password: &str) -> Result<(), String> {
+        if password.chars().any(|c| c.is_ascii_digit()) {
+            Ok(())
+        } else {
+            Err("must contain a digit".to_string())
+        }
+    }
+}
+
+impl Rule for AllowAll {
+    fn check(&self, _password: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn failures<'a>(&self, _passwords: &[&'a str]) -> Vec<(&'a str, String)> {
+        Vec::new()
+    }
+}
+
+fn main() {
+    let passwords = ["hunter2", "correct horse", "abc"];
+    let rules: [&dyn Rule; 3] = [&MinLength(8), &ContainsDigit, &AllowAll];
+
+    for rule in rules {
+        for (password, error) in rule.failures(&passwords) {
+            println!("'{}' {}", password, error);
+        }
+    }
+    println!(
+        "'hunter2' is 8+ characters: {}",
+        MinLength(8).is_satisfied("hunter2")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_methods_use_check() {
+        let rule = MinLength(4);
+        assert!(rule.is_satisfied("abcd"));
+        assert!(!rule.is_satisfied("abc"));
+        assert_eq!(
+            rule.failures(&["abc", "abcdef", "x"]),
+            vec![
+                ("abc", "must be at least 4 characters long".to_string()),
+                ("x", "must be at least 4 characters long".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn overridden_method_is_used() {
+        assert!(AllowAll.failures(&["", "x"]).is_empty());
+        assert!(AllowAll.is_satisfied(""));
+    }
+}
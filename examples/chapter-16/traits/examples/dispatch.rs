@@ -0,0 +1,143 @@
+use std::f64::consts::PI;
+use std::mem::size_of;
+
+trait Shape {
+    fn area(&self) -> f64;
+    fn name(&self) -> &'static str;
+}
+
+struct Circle {
+    radius: f64,
+}
+
+struct Rectangle {
+    width: f64,
+    height: f64,
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    fn name(&self) -> &'static str {
+        "circle"
+    }
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn name(&self) -> &'static str {
+        "rectangle"
+    }
+}
+
+// Static dispatch: the compiler generates a separate copy of this function
+// for every shape type it's called with, and can inline `area`
+fn total_area<S: Shape>(shapes: &[S]) -> f64 {
+    shapes.iter().map(|shape| shape.area()).sum()
+}
+
+// `impl Trait` in argument position is a shorter way to write a generic
+fn describe(shape: &impl Shape) -> String {
+    format!("{} with area {:.2}", shape.name(), shape.area())
+}
+
+// Dynamic dispatch: one function for all shapes. Each call to `area`
+// looks up the right method in a table (vtable) at runtime.
+fn total_area_dyn(shapes: &[Box<dyn Shape>]) -> f64 {
+    shapes.iter().map(|shape| shape.area()).sum()
+}
+
+// `impl Trait` in return position hides the concrete type from the caller,
+// but the function must always return the same type
+fn unit_square() -> impl Shape {
+    Rectangle {
+        width: 1.0,
+        height: 1.0,
+    }
+}
+
+// Returning different types depending on the input requires `dyn`
+fn parse_shape(input: &str) -> Option<Box<dyn Shape>> {
+    let mut parts = input.split_whitespace();
+    match (parts.next()?, parts.next(), parts.next()) {
+        ("circle", Some(r), None) => Some(Box::new(Circle {
+            radius: r.parse().ok()?,
+        })),
+        ("rect", Some(w), Some(h)) => Some(Box::new(Rectangle {
+            width: w.parse().ok()?,
+            height: h.parse().ok()?,
+        })),
+        _ => None,
+    }
+}
+
+fn main() {
+    // A slice of one concrete type works with the generic function
+    let circles = [Circle { radius: 1.0 }, Circle { radius: 2.0 }];
+    println!("Circles: {:.2}", total_area(&circles));
+
+    // Mixing types in one collection needs trait objects
+    let shapes: Vec<Box<dyn Shape>> = ["circle 1", "rect 2 3", "circle 0.5"]
+        .iter()
+        .filter_map(|line| parse_shape(line))
+        .collect();
+    for shape in &shapes {
+        println!("- {} with area {:.2}", shape.name(), shape.area());
+    }
+    println!("All shapes: {:.2}", total_area_dyn(&shapes));
+    println!("{}", describe(&unit_square()));
+
+    // A reference to a trait object is a "fat" pointer: data + vtable
+    println!("&Circle:    {} bytes", size_of::<&Circle>());
+    println!("&dyn Shape: {} bytes", size_of::<&dyn Shape>());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generic_and_dyn_versions_agree() {
+        let rects = [
+            Rectangle {
+                width: 2.0,
+                height: 3.0,
+            },
+            Rectangle {
+                width: 1.0,
+                height: 1.0,
+            },
+        ];
+        let boxed: Vec<Box<dyn Shape>> = vec![
+            Box::new(Rectangle {
+                width: 2.0,
+                height: 3.0,
+            }),
+            Box::new(Rectangle {
+                width: 1.0,
+                height: 1.0,
+            }),
+        ];
+        assert_eq!(total_area(&rects), 7.0);
+        assert_eq!(total_area_dyn(&boxed), 7.0);
+    }
+
+    #[test]
+    fn parses_shapes_of_different_types() {
+        assert_eq!(parse_shape("circle 1").unwrap().name(), "circle");
+        assert_eq!(parse_shape("rect 2 3").unwrap().area(), 6.0);
+        assert!(parse_shape("triangle 1 2 3").is_none());
+        assert!(parse_shape("rect 2").is_none());
+    }
+
+    #[test]
+    fn impl_trait_arguments_accept_any_shape() {
+        assert_eq!(describe(&unit_square()), "rectangle with area 1.00");
+        assert_eq!(describe(&Circle { radius: 1.0 }), "circle with area 3.14");
+    }
+}
@@ -0,0 +1,246 @@
+use std::fmt;
+
+// A matrix of `f64`, stored row by row in one `Vec`
+#[derive(Clone, PartialEq, Default)]
+struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    fn identity(n: usize) -> Self {
+        let mut data = vec![0.0; n * n];
+        for i in 0..n {
+            data[i * n + i] = 1.0;
+        }
+        Matrix {
+            rows: n,
+            cols: n,
+            data,
+        }
+    }
+
+    fn transpose(&self) -> Matrix {
+        let mut data = Vec::with_capacity(self.data.len());
+        for c in 0..self.cols {
+            data.extend(self.rows().map(|row| row[c]));
+        }
+        Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            data,
+        }
+    }
+
+    fn rows(&self) -> Rows<'_> {
+        Rows {
+            matrix: self,
+            next: 0,
+        }
+    }
+}
+
+// An iterator over the rows of a matrix, as slices. Implementing `next`
+// is all it takes to get `map`, `filter`, `sum`, `zip`, `for` loops, and
+// every other iterator method.
+struct Rows<'a> {
+    matrix: &'a Matrix,
+    next: usize,
+}
+
+impl<'a> Iterator for Rows<'a> {
+    type Item = &'a [f64];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.matrix.rows {
+            return None;
+        }
+        let start = self.next * self.matrix.cols;
+        self.next += 1;
+        Some(&self.matrix.data[start..start + self.matrix.cols])
+    }
+}
+
+// `for row in &matrix`
+impl<'a> IntoIterator for &'a Matrix {
+    type Item = &'a [f64];
+    type IntoIter = Rows<'a>;
+
+    fn into_iter(self) -> Rows<'a> {
+        self.rows()
+    }
+}
+
+// `Matrix::from([[1.0, 2.0], [3.0, 4.0]])`. Arrays always have rows of
+// the same length, so this can't fail.
+impl<const R: usize, const C: usize> From<[[f64; C]; R]> for Matrix {
+    fn from(rows: [[f64; C]; R]) -> Self {
+        Matrix {
+            rows: R,
+            cols: C,
+            data: rows.into_iter().flatten().collect(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct RaggedRowError {
+    row: usize,
+    expected: usize,
+    found: usize,
+}
+
+impl fmt::Display for RaggedRowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "row {} has length {}, expected {}",
+            self.row, self.found, self.expected
+        )
+    }
+}
+
+impl std::error::Error for RaggedRowError {}
+
+// Nested `Vec`s, for example from a parsed file, may have rows of
+// different lengths
+impl TryFrom<Vec<Vec<f64>>> for Matrix {
+    type Error = RaggedRowError;
+
+    fn try_from(rows: Vec<Vec<f64>>) -> Result<Self, Self::Error> {
+        let cols = rows.first().map_or(0, Vec::len);
+        let mut data = Vec::with_capacity(rows.len() * cols);
+        for (i, row) in rows.iter().enumerate() {
+            if row.len() != cols {
+                return Err(RaggedRowError {
+                    row: i,
+                    expected: cols,
+                    found: row.len(),
+                });
+            }
+            data.extend(row);
+        }
+        Ok(Matrix {
+            rows: rows.len(),
+            cols,
+            data,
+        })
+    }
+}
+
+// `{}` prints an aligned grid. The formatter carries the options from the
+// format string, so `{:.2}` prints every value with two decimals.
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells: Vec<String> = self
+            .data
+            .iter()
+            .map(|x| match f.precision() {
+                Some(p) => format!("{:.*}", p, x),
+                None => x.to_string(),
+            })
+            .collect();
+        let width = cells.iter().map(String::len).max().unwrap_or(0);
+        for row in cells.chunks(self.cols.max(1)) {
+            write!(f, "[")?;
+            for cell in row {
+                write!(f, " {:>width$}", cell, width = width)?;
+            }
+            writeln!(f, " ]")?;
+        }
+        Ok(())
+    }
+}
+
+// `{:?}` shows the size and the rows instead of one long `data` vector.
+// `debug_list` also supports pretty-printing with `{:#?}`.
+impl fmt::Debug for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Matrix {}x{} ", self.rows, self.cols)?;
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
+
+fn main() {
+    let m = Matrix::from([[1.0, 2.5, -3.0], [4.0, 50.0, 6.0]]);
+
+    println!("{}", m);
+    println!("{:.2}", m.transpose());
+    println!("{:?}", m);
+    println!("{:#?}", Matrix::identity(2));
+
+    for (i, row) in m.rows().enumerate() {
+        println!("Row {} sums to {}", i, row.iter().sum::<f64>());
+    }
+
+    let parsed = Matrix::try_from(vec![vec![1.0, 2.0], vec![3.0]]);
+    if let Err(e) = parsed {
+        println!("Error: {}", e);
+    }
+
+    println!("Default: {:?}", Matrix::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_honors_precision() {
+        let m = Matrix::from([[1.0, 20.0], [-3.5, 4.0]]);
+        assert_eq!(m.to_string(), "[    1   20 ]\n[ -3.5    4 ]\n");
+        assert_eq!(format!("{:.1}", m), "[  1.0 20.0 ]\n[ -3.5  4.0 ]\n");
+    }
+
+    #[test]
+    fn debug_shows_rows() {
+        let m = Matrix::identity(2);
+        assert_eq!(format!("{:?}", m), "Matrix 2x2 [[1.0, 0.0], [0.0, 1.0]]");
+        assert!(format!("{:#?}", m).contains("[\n    [\n        1.0,"));
+    }
+
+    #[test]
+    fn partial_eq_compares_contents() {
+        let m = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        assert_eq!(m.transpose().transpose(), m);
+        assert_ne!(m.transpose(), m);
+        // Same values, different shape
+        assert_ne!(Matrix::from([[1.0, 2.0]]), Matrix::from([[1.0], [2.0]]));
+    }
+
+    #[test]
+    fn iterator_unlocks_adapters_and_for_loops() {
+        let m = Matrix::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let sums: Vec<f64> = m.rows().map(|row| row.iter().sum()).collect();
+        assert_eq!(sums, [3.0, 7.0, 11.0]);
+        assert_eq!(m.rows().count(), 3);
+        assert_eq!(m.rows().last(), Some(&[5.0, 6.0][..]));
+
+        let mut firsts = Vec::new();
+        for row in &m {
+            firsts.push(row[0]);
+        }
+        assert_eq!(firsts, [1.0, 3.0, 5.0]);
+    }
+
+    #[test]
+    fn conversions() {
+        let from_vecs = Matrix::try_from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        assert_eq!(from_vecs, Ok(Matrix::from([[1.0, 2.0], [3.0, 4.0]])));
+
+        let ragged = Matrix::try_from(vec![vec![1.0], vec![2.0, 3.0]]);
+        assert_eq!(
+            ragged.unwrap_err().to_string(),
+            "row 1 has length 2, expected 1"
+        );
+    }
+
+    #[test]
+    fn default_is_empty() {
+        let m = Matrix::default();
+        assert_eq!(m.rows().count(), 0);
+        assert_eq!(m.to_string(), "");
+        assert_eq!(Matrix::try_from(Vec::new()), Ok(m));
+    }
+}
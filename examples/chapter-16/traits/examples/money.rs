@@ -0,0 +1,263 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Currency {
+    Usd,
+    Eur,
+}
+
+// Amounts are stored in cents to avoid floating point rounding errors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Money {
+    cents: i64,
+    currency: Currency,
+}
+
+impl Money {
+    fn usd(cents: i64) -> Self {
+        Money {
+            cents,
+            currency: Currency::Usd,
+        }
+    }
+
+    fn eur(cents: i64) -> Self {
+        Money {
+            cents,
+            currency: Currency::Eur,
+        }
+    }
+
+    // For values that come from users, return an error instead of panicking
+    fn checked_add(self, other: Money) -> Result<Money, MoneyError> {
+        if self.currency != other.currency {
+            return Err(MoneyError::CurrencyMismatch(
+                Operation::Add,
+                self.currency,
+                other.currency,
+            ));
+        }
+        // `+` on `i64` panics on overflow in debug builds, but silently wraps
+        // around in release builds. `checked_add` returns `None` in both.
+        let cents = self
+            .cents
+            .checked_add(other.cents)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money { cents, ..self })
+    }
+
+    fn checked_sub(self, other: Money) -> Result<Money, MoneyError> {
+        if self.currency != other.currency {
+            return Err(MoneyError::CurrencyMismatch(
+                Operation::Sub,
+                self.currency,
+                other.currency,
+            ));
+        }
+        let cents = self
+            .cents
+            .checked_sub(other.cents)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money { cents, ..self })
+    }
+
+    fn checked_mul(self, quantity: i64) -> Result<Money, MoneyError> {
+        let cents = self
+            .cents
+            .checked_mul(quantity)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money { cents, ..self })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Operation {
+    Add,
+    Sub,
+}
+
+#[derive(Debug, PartialEq)]
+enum MoneyError {
+    CurrencyMismatch(Operation, Currency, Currency),
+    Overflow,
+}
+
+impl fmt::Display for MoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoneyError::CurrencyMismatch(Operation::Add, left, right) => {
+                write!(f, "cannot add {:?} to {:?}", right, left)
+            }
+            MoneyError::CurrencyMismatch(Operation::Sub, left, right) => {
+                write!(f, "cannot subtract {:?} from {:?}", right, left)
+            }
+            MoneyError::Overflow => write!(f, "amount is too large"),
+        }
+    }
+}
+
+impl std::error::Error for MoneyError {}
+
+// Operators can't return a `Result`, so they panic with the error, in
+// debug and release builds alike
+fn or_panic(result: Result<Money, MoneyError>) -> Money {
+    result.unwrap_or_else(|e| panic!("{}", e))
+}
+
+// `a + b`. Adding different currencies is a bug in the program, like an
+// integer overflow, so it panics.
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        or_panic(self.checked_add(other))
+    }
+}
+
+// `a - b`
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, other: Money) -> Money {
+        or_panic(self.checked_sub(other))
+    }
+}
+
+// `-a`
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        let cents = self.cents.checked_neg().expect("amount is too large");
+        Money { cents, ..self }
+    }
+}
+
+// `a += b`
+impl AddAssign for Money {
+    fn add_assign(&mut self, other: Money) {
+        *self = *self + other;
+    }
+}
+
+// `price * quantity`: the right-hand side doesn't have to be `Money`
+impl Mul<i64> for Money {
+    type Output = Money;
+
+    fn mul(self, quantity: i64) -> Money {
+        or_panic(self.checked_mul(quantity))
+    }
+}
+
+// `iter.sum()`. An empty iterator has no currency, so this implementation
+// requires at least one element.
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(mut iter: I) -> Money {
+        let first = iter.next().expect("cannot sum an empty list of Money");
+        iter.fold(first, |total, m| total + m)
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self.currency {
+            Currency::Usd => "$",
+            Currency::Eur => "€",
+        };
+        let sign = if self.cents < 0 { "-" } else { "" };
+        // `unsigned_abs` because `i64::MIN.abs()` overflows
+        let cents = self.cents.unsigned_abs();
+        write!(f, "{}{}{}.{:02}", sign, symbol, cents / 100, cents % 100)
+    }
+}
+
+fn main() {
+    let book = Money::usd(3990);
+    let pen = Money::usd(150);
+
+    let mut total = book * 2 + pen;
+    println!("Subtotal: {}", total);
+
+    total += Money::usd(500);
+    println!("With shipping: {}", total);
+    println!("After a $10 coupon: {}", total - Money::usd(1000));
+
+    let payments = [Money::eur(1200), Money::eur(-250), Money::eur(99)];
+    println!("Balance: {}", payments.iter().copied().sum::<Money>());
+
+    match Money::usd(100).checked_add(Money::eur(100)) {
+        Ok(sum) => println!("Sum: {}", sum),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    let salary = Money::usd(i64::MAX / 2);
+    match salary.checked_mul(3) {
+        Ok(total) => println!("Total: {}", total),
+        Err(e) => println!("Error: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_operators() {
+        assert_eq!(Money::usd(100) + Money::usd(250), Money::usd(350));
+        assert_eq!(Money::usd(100) - Money::usd(250), Money::usd(-150));
+        assert_eq!(Money::eur(199) * 3, Money::eur(597));
+
+        let mut wallet = Money::eur(0);
+        wallet += Money::eur(50);
+        assert_eq!(wallet, Money::eur(50));
+    }
+
+    #[test]
+    fn sum_and_display() {
+        let total: Money = [Money::usd(1), Money::usd(1999)].into_iter().sum();
+        assert_eq!(total.to_string(), "$20.00");
+        assert_eq!(Money::eur(-5).to_string(), "-€0.05");
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add Eur to Usd")]
+    fn mixing_currencies_panics() {
+        let _ = Money::usd(100) + Money::eur(100);
+    }
+
+    #[test]
+    fn checked_operations_report_mismatch() {
+        let error = Money::usd(1).checked_add(Money::eur(1)).unwrap_err();
+        assert_eq!(error.to_string(), "cannot add Eur to Usd");
+        let error = Money::usd(1).checked_sub(Money::eur(1)).unwrap_err();
+        assert_eq!(error.to_string(), "cannot subtract Eur from Usd");
+    }
+
+    #[test]
+    fn checked_operations_report_overflow() {
+        let max = Money::usd(i64::MAX);
+        assert_eq!(max.checked_add(Money::usd(1)), Err(MoneyError::Overflow));
+        assert_eq!(
+            Money::usd(i64::MIN).checked_sub(Money::usd(1)),
+            Err(MoneyError::Overflow)
+        );
+        assert_eq!(max.checked_mul(2), Err(MoneyError::Overflow));
+        assert_eq!(Money::usd(-5).checked_mul(3), Ok(Money::usd(-15)));
+        assert_eq!(max.checked_sub(max), Ok(Money::usd(0)));
+        // `i64::MIN` can't be negated, but subtracting it from -1 fits
+        assert_eq!(
+            Money::usd(-1).checked_sub(Money::usd(i64::MIN)),
+            Ok(Money::usd(i64::MAX))
+        );
+    }
+
+    // Without the checks, this would wrap around to a negative amount in a
+    // release build
+    #[test]
+    #[should_panic(expected = "amount is too large")]
+    fn operators_panic_on_overflow_in_every_build() {
+        let _ = Money::usd(i64::MAX) + Money::usd(1);
+    }
+}
@@ -0,0 +1,150 @@
+use num_traits::{CheckedAdd, Float, Num, NumCast, ToPrimitive};
+use std::fmt;
+
+// `Num` covers `+`, `-`, `*`, `/`, `%`, `zero()`, and `one()` for every
+// integer and float type. Inside a generic function, `0` is an `i32`, not a
+// `T`, so the starting value has to be `T::zero()`.
+fn sum<T: Num + Copy>(xs: &[T]) -> T {
+    xs.iter().fold(T::zero(), |total, &x| total + x)
+}
+
+// The first attempt at a generic mean. It compiles and works for floats,
+// but for integers `/` rounds toward zero, and `sum` can overflow long
+// before the mean does.
+fn naive_mean<T: Num + NumCast + Copy>(xs: &[T]) -> Option<T> {
+    if xs.is_empty() {
+        return None;
+    }
+    let count = T::from(xs.len())?;
+    Some(sum(xs) / count)
+}
+
+// The mean of any number type as `f64`: exact for floats, and for integers
+// up to 2^53. Summing in `f64` can't overflow.
+fn mean<T: ToPrimitive>(xs: &[T]) -> Option<f64> {
+    if xs.is_empty() {
+        return None;
+    }
+    let total = xs.iter().map(|x| x.to_f64()).sum::<Option<f64>>()?;
+    Some(total / xs.len() as f64)
+}
+
+// For floats, the mean can stay in the input type: `f32` in, `f32` out
+fn float_mean<T: Float>(xs: &[T]) -> Option<T> {
+    if xs.is_empty() {
+        return None;
+    }
+    let total = xs.iter().fold(T::zero(), |total, &x| total + x);
+    Some(total / T::from(xs.len())?)
+}
+
+#[derive(Debug, PartialEq)]
+enum StatsError {
+    Empty,
+    Overflow,
+}
+
+impl fmt::Display for StatsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatsError::Empty => write!(f, "cannot average an empty list"),
+            StatsError::Overflow => write!(f, "the sum does not fit in the number type"),
+        }
+    }
+}
+
+impl std::error::Error for StatsError {}
+
+// The integer version, in the input type, with overflow reported instead
+// of panicking (debug builds) or wrapping around (release builds). The
+// result is rounded toward zero, like `/` on integers.
+fn checked_mean<T: Num + CheckedAdd + NumCast + Copy>(xs: &[T]) -> Result<T, StatsError> {
+    if xs.is_empty() {
+        return Err(StatsError::Empty);
+    }
+    let total = xs
+        .iter()
+        .try_fold(T::zero(), |total, x| total.checked_add(x))
+        .ok_or(StatsError::Overflow)?;
+    // A `u8` can't count to 300
+    let count = T::from(xs.len()).ok_or(StatsError::Overflow)?;
+    Ok(total / count)
+}
+
+fn main() {
+    let scores = [3, 4, 4, 5];
+    let prices = [2.5, 3.0, 4.25];
+
+    println!("Sum of scores: {}", sum(&scores));
+    println!("Sum of prices: {}", sum(&prices));
+
+    println!("Naive mean of scores: {:?}", naive_mean(&scores));
+    println!("Naive mean of prices: {:?}", naive_mean(&prices));
+
+    println!("Mean of scores: {:?}", mean(&scores));
+    println!("Mean of u64 IDs: {:?}", mean(&[u64::MAX, u64::MAX]));
+    println!("Float mean of f32: {:?}", float_mean(&[0.5f32, 1.0]));
+
+    let bytes: [u8; 3] = [200, 100, 150];
+    println!("Checked mean of bytes: {:?}", checked_mean(&bytes));
+    println!("Checked mean of i32: {:?}", checked_mean(&[10, 20, 31]));
+    match checked_mean::<i32>(&[]) {
+        Ok(m) => println!("Mean: {}", m),
+        Err(e) => println!("Error: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_works_for_every_number_type() {
+        assert_eq!(sum(&[1u8, 2, 3]), 6);
+        assert_eq!(sum(&[-1i64, 1]), 0);
+        assert_eq!(sum(&[0.5f32, 0.25]), 0.75);
+        assert_eq!(sum::<u32>(&[]), 0);
+    }
+
+    #[test]
+    fn naive_mean_truncates_integers() {
+        assert_eq!(naive_mean(&[1, 2]), Some(1));
+        assert_eq!(naive_mean(&[-1, -2]), Some(-1));
+        assert_eq!(naive_mean(&[1.0, 2.0]), Some(1.5));
+        assert_eq!(naive_mean::<i32>(&[]), None);
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "overflow only panics in debug builds")]
+    #[should_panic(expected = "overflow")]
+    fn naive_mean_overflows_in_the_sum() {
+        // The mean, 200, fits in a `u8`; the sum, 400, doesn't
+        naive_mean(&[200u8, 200]);
+    }
+
+    #[test]
+    fn mean_as_f64_handles_any_type() {
+        assert_eq!(mean(&[1, 2]), Some(1.5));
+        assert_eq!(mean(&[200u8, 200]), Some(200.0));
+        assert_eq!(mean(&[i64::MAX, i64::MAX]), Some(i64::MAX as f64));
+        assert_eq!(mean(&[0.5f32, 1.0]), Some(0.75));
+        assert_eq!(mean::<u16>(&[]), None);
+    }
+
+    #[test]
+    fn float_mean_keeps_the_type() {
+        let m: f32 = float_mean(&[1.0f32, 2.0, 4.0]).unwrap();
+        assert!((m - 7.0 / 3.0).abs() < f32::EPSILON);
+        assert_eq!(float_mean::<f64>(&[]), None);
+    }
+
+    #[test]
+    fn checked_mean_reports_overflow() {
+        assert_eq!(checked_mean(&[10, 20, 31]), Ok(20));
+        // The sum, 400, doesn't fit in a `u8`
+        assert_eq!(checked_mean(&[200u8, 200]), Err(StatsError::Overflow));
+        // The sum is 0, so only the count overflows
+        assert_eq!(checked_mean(&[0u8; 300]), Err(StatsError::Overflow));
+        assert_eq!(checked_mean::<i8>(&[]), Err(StatsError::Empty));
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Each implementation decides what it stores and what its keys look like.
+// Associated types fix them once per implementation, so callers never have
+// to name them.
+trait Repository {
+    type Item;
+    type Id: Copy + Eq + Hash;
+
+    fn insert(&mut self, item: Self::Item) -> Self::Id;
+    fn get(&self, id: Self::Id) -> Option<&Self::Item>;
+    fn remove(&mut self, id: Self::Id) -> Option<Self::Item>;
+    fn len(&self) -> usize;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct User {
+    name: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Product {
+    sku: String,
+    price_cents: u64,
+}
+
+// Users get increasing numeric IDs
+#[derive(Default)]
+struct UserRepository {
+    next_id: u32,
+    users: HashMap<u32, User>,
+}
+
+impl Repository for UserRepository {
+    type Item = User;
+    type Id = u32;
+
+    fn insert(&mut self, user: User) -> u32 {
+        self.next_id += 1;
+        self.users.insert(self.next_id, user);
+        self.next_id
+    }
+
+    fn get(&self, id: u32) -> Option<&User> {
+        self.users.get(&id)
+    }
+
+    fn remove(&mut self, id: u32) -> Option<User> {
+        self.users.remove(&id)
+    }
+
+    fn len(&self) -> usize {
+        self.users.len()
+    }
+}
+
+// Products are keyed by a hash of their SKU
+#[derive(Default)]
+struct ProductRepository {
+    products: HashMap<u64, Product>,
+}
+
+fn sku_key(sku: &str) -> u64 {
+    sku.bytes().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+impl Repository for ProductRepository {
+    type Item = Product;
+    type Id = u64;
+
+    fn insert(&mut self, product: Product) -> u64 {
+        let id = sku_key(&product.sku);
+        self.products.insert(id, product);
+        id
+    }
+
+    fn get(&self, id: u64) -> Option<&Product> {
+        self.products.get(&id)
+    }
+
+    fn remove(&mut self, id: u64) -> Option<Product> {
+        self.products.remove(&id)
+    }
+
+    fn len(&self) -> usize {
+        self.products.len()
+    }
+}
+
+// Works with any repository. `R::Item` and `R::Id` are whatever
+// the implementation chose.
+fn insert_all<R: Repository>(repo: &mut R, items: Vec<R::Item>) -> Vec<R::Id> {
+    items.into_iter().map(|item| repo.insert(item)).collect()
+}
+
+// Bounds on associated types use the `Trait<Name = Type>` syntax
+fn user_names<R: Repository<Item = User>>(repo: &R, ids: &[R::Id]) -> Vec<String> {
+    ids.iter()
+        .filter_map(|id| repo.get(*id))
+        .map(|user| user.name.clone())
+        .collect()
+}
+
+fn main() {
+    let mut users = UserRepository::default();
+    let ids = insert_all(
+        &mut users,
+        vec![
+            User {
+                name: "Alice".to_string(),
+            },
+            User {
+                name: "Bob".to_string(),
+            },
+        ],
+    );
+    println!("User IDs: {:?}, names: {:?}", ids, user_names(&users, &ids));
+
+    let mut products = ProductRepository::default();
+    let ids = insert_all(
+        &mut products,
+        vec![Product {
+            sku: "BOOK-1".to_string(),
+            price_cents: 3990,
+        }],
+    );
+    println!("Product IDs: {:?}", ids);
+    println!("Removed: {:?}", products.remove(ids[0]));
+    println!("Products left: {}", products.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // One test body for every implementation
+    fn round_trip<R: Repository>(mut repo: R, item: R::Item)
+    where
+        R::Item: Clone + PartialEq + std::fmt::Debug,
+    {
+        let id = repo.insert(item.clone());
+        assert_eq!(repo.get(id), Some(&item));
+        assert_eq!(repo.len(), 1);
+        assert_eq!(repo.remove(id), Some(item));
+        assert_eq!(repo.get(id), None);
+    }
+
+    #[test]
+    fn user_repository_round_trip() {
+        round_trip(
+            UserRepository::default(),
+            User {
+                name: "Alice".to_string(),
+            },
+        );
+    }
+
+    #[test]
+    fn product_repository_round_trip() {
+        round_trip(
+            ProductRepository::default(),
+            Product {
+                sku: "PEN".to_string(),
+                price_cents: 150,
+            },
+        );
+    }
+
+    #[test]
+    fn user_ids_increase() {
+        let mut users = UserRepository::default();
+        let names = ["a", "b", "c"].map(|name| User {
+            name: name.to_string(),
+        });
+        assert_eq!(insert_all(&mut users, names.to_vec()), vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,73 @@
+use std::fmt;
+
+// Every `Report` must also implement `Display`. In exchange, the trait's
+// own methods can use `self` with `{}`.
+trait Report: fmt::Display {
+    fn title(&self) -> String;
+
+    fn render(&self) -> String {
+        let line = "=".repeat(self.title().len());
+        format!("{}\n{}\n{}", self.title(), line, self)
+    }
+}
+
+// A supertrait can also be another trait of your own
+trait Exportable: Report {
+    fn file_name(&self) -> String {
+        format!("{}.txt", self.title().to_lowercase().replace(' ', "_"))
+    }
+}
+
+struct SalesReport {
+    month: String,
+    total_cents: u64,
+}
+
+impl fmt::Display for SalesReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Total sales: {}.{:02}",
+            self.total_cents / 100,
+            self.total_cents % 100
+        )
+    }
+}
+
+impl Report for SalesReport {
+    fn title(&self) -> String {
+        format!("Sales {}", self.month)
+    }
+}
+
+// All methods have defaults, so the implementation is empty
+impl Exportable for SalesReport {}
+
+// A generic function can rely on all traits in the chain
+fn export(report: &impl Exportable) -> (String, String) {
+    (report.file_name(), report.render())
+}
+
+fn main() {
+    let report = SalesReport {
+        month: "November".to_string(),
+        total_cents: 1_234_550,
+    };
+    let (file_name, content) = export(&report);
+    println!("{}:\n{}", file_name, content);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_uses_display_and_title() {
+        let report = SalesReport {
+            month: "May".to_string(),
+            total_cents: 905,
+        };
+        assert_eq!(report.render(), "Sales May\n=========\nTotal sales: 9.05");
+        assert_eq!(report.file_name(), "sales_may.txt");
+    }
+}
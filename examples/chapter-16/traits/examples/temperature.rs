@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+use std::fmt;
+
+// Stored in tenths of a degree Celsius. Whole numbers compare and hash
+// exactly, which lets `Temperature` implement `Eq`, `Ord`, and `Hash`;
+// `f64` implements none of them.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+struct Temperature {
+    tenths: i32,
+}
+
+const ABSOLUTE_ZERO: f64 = -273.15;
+
+impl Temperature {
+    fn degrees(self) -> f64 {
+        self.tenths as f64 / 10.0
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum TemperatureError {
+    NotANumber,
+    BelowAbsoluteZero(f64),
+    TooHot(f64),
+}
+
+impl fmt::Display for TemperatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemperatureError::NotANumber => write!(f, "temperature is not a number"),
+            TemperatureError::BelowAbsoluteZero(d) => {
+                write!(f, "{}°C is below absolute zero", d)
+            }
+            TemperatureError::TooHot(d) => write!(f, "{}°C is too hot to measure", d),
+        }
+    }
+}
+
+impl std::error::Error for TemperatureError {}
+
+// `Temperature::try_from(21.5)` and `21.5.try_into()`. Not every `f64` is a
+// temperature, so the conversion can fail.
+impl TryFrom<f64> for Temperature {
+    type Error = TemperatureError;
+
+    fn try_from(degrees: f64) -> Result<Self, Self::Error> {
+        if degrees.is_nan() {
+            return Err(TemperatureError::NotANumber);
+        }
+        if degrees < ABSOLUTE_ZERO {
+            return Err(TemperatureError::BelowAbsoluteZero(degrees));
+        }
+        if degrees > 10_000.0 {
+            return Err(TemperatureError::TooHot(degrees));
+        }
+        // -273.15 itself rounds to -273.2, which is colder than absolute
+        // zero, so the rounded value is kept at -273.1 or above
+        let tenths = (degrees * 10.0).round().max(-2731.0);
+        Ok(Temperature {
+            tenths: tenths as i32,
+        })
+    }
+}
+
+// `f64::from(t)` and `t.into()`. Every temperature is an `f64`, so this
+// one can't fail.
+impl From<Temperature> for f64 {
+    fn from(t: Temperature) -> f64 {
+        t.degrees()
+    }
+}
+
+// `{}`: for users
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1}°C", self.degrees())
+    }
+}
+
+// `{:?}`: for developers. The derived version would print
+// `Temperature { tenths: 215 }`, which hides the unit.
+impl fmt::Debug for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Temperature({}°C)", self.degrees())
+    }
+}
+
+// A thermostat whose target can be raised for a while. `Drop` puts the
+// old target back when the boost ends, even on an early return or a panic.
+struct Thermostat {
+    target: Temperature,
+}
+
+impl Default for Thermostat {
+    fn default() -> Self {
+        Thermostat {
+            target: Temperature { tenths: 200 },
+        }
+    }
+}
+
+impl Thermostat {
+    fn boost(&mut self, target: Temperature) -> Boost<'_> {
+        let previous = std::mem::replace(&mut self.target, target);
+        Boost {
+            thermostat: self,
+            previous,
+        }
+    }
+}
+
+struct Boost<'a> {
+    thermostat: &'a mut Thermostat,
+    previous: Temperature,
+}
+
+impl Drop for Boost<'_> {
+    fn drop(&mut self) {
+        println!(
+            "Boost ended, target back from {} to {}",
+            self.thermostat.target, self.previous
+        );
+        self.thermostat.target = self.previous;
+    }
+}
+
+fn parse_readings(values: &[f64]) -> Result<Vec<Temperature>, TemperatureError> {
+    values.iter().map(|&d| Temperature::try_from(d)).collect()
+}
+
+fn main() -> Result<(), TemperatureError> {
+    let mut readings = parse_readings(&[21.5, 19.0, 23.25, 19.0, 21.5, 19.0])?;
+
+    // `Display` and `Debug`
+    println!("First reading: {} ({:?})", readings[0], readings[0]);
+
+    // `Ord`
+    readings.sort();
+    let (min, max) = (readings[0], readings[readings.len() - 1]);
+    println!("Sorted: {:?}", readings);
+    println!("Range: {} to {}", min, max);
+
+    // `Hash` and `Eq`
+    let mut counts: HashMap<Temperature, usize> = HashMap::new();
+    for &t in &readings {
+        *counts.entry(t).or_default() += 1;
+    }
+    let (most_common, count) = counts.iter().max_by_key(|(_, &n)| n).unwrap();
+    println!("Most common: {} ({} times)", most_common, count);
+
+    // `From`
+    let total: f64 = readings.iter().map(|&t| f64::from(t)).sum();
+    println!("Average: {:.2}°C", total / readings.len() as f64);
+
+    // `TryFrom`
+    if let Err(e) = Temperature::try_from(-300.0) {
+        println!("Error: {}", e);
+    }
+
+    // `Default` and `Drop`
+    let mut thermostat = Thermostat::default();
+    {
+        let _boost = thermostat.boost(Temperature::try_from(24.0)?);
+        println!("Heating up...");
+    }
+    println!("Target: {}", thermostat.target);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn t(degrees: f64) -> Temperature {
+        Temperature::try_from(degrees).unwrap()
+    }
+
+    #[test]
+    fn display_and_debug() {
+        assert_eq!(t(21.5).to_string(), "21.5°C");
+        assert_eq!(format!("{:>8}|", t(-3.0).to_string()), "  -3.0°C|");
+        assert_eq!(format!("{:?}", t(21.5)), "Temperature(21.5°C)");
+        assert_eq!(format!("{:?}", Some(t(0.0))), "Some(Temperature(0°C))");
+    }
+
+    #[test]
+    fn ord_enables_sorting_and_min_max() {
+        let mut readings = vec![t(5.0), t(-2.5), t(30.0)];
+        readings.sort();
+        assert_eq!(readings, [t(-2.5), t(5.0), t(30.0)]);
+        assert_eq!(readings.iter().max(), Some(&t(30.0)));
+        assert!(t(0.1) > t(0.0));
+    }
+
+    #[test]
+    fn hash_and_eq_enable_hashmap_keys_and_sets() {
+        let mut counts = HashMap::new();
+        for reading in [t(20.0), t(21.0), t(20.0)] {
+            *counts.entry(reading).or_insert(0) += 1;
+        }
+        assert_eq!(counts[&t(20.0)], 2);
+
+        // `Ord` keeps a `BTreeSet` sorted
+        let unique: BTreeSet<_> = [t(3.0), t(1.0), t(3.0)].into_iter().collect();
+        assert_eq!(unique.into_iter().collect::<Vec<_>>(), [t(1.0), t(3.0)]);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(f64::from(t(36.6)), 36.6);
+        let degrees: f64 = t(-40.0).into();
+        assert_eq!(degrees, -40.0);
+
+        // Rounded to tenths, so nearly equal readings are equal
+        assert_eq!(t(20.04), t(20.0));
+
+        assert_eq!(
+            Temperature::try_from(-300.0),
+            Err(TemperatureError::BelowAbsoluteZero(-300.0))
+        );
+        assert_eq!(
+            Temperature::try_from(f64::NAN),
+            Err(TemperatureError::NotANumber)
+        );
+        let result: Result<Temperature, _> = 20_000.0.try_into();
+        assert!(result.is_err());
+        assert!(parse_readings(&[1.0, f64::NAN]).is_err());
+    }
+
+    #[test]
+    fn rounded_values_stay_above_absolute_zero() {
+        assert_eq!(t(-273.1).tenths, -2731);
+        assert_eq!(t(-273.149).tenths, -2731);
+        assert_eq!(Temperature::try_from(-273.15).map(|c| c.tenths), Ok(-2731));
+        assert_eq!(
+            Temperature::try_from(-273.16),
+            Err(TemperatureError::BelowAbsoluteZero(-273.16))
+        );
+        assert_eq!(
+            Temperature::try_from(10_000.04),
+            Err(TemperatureError::TooHot(10_000.04))
+        );
+    }
+
+    #[test]
+    fn defaults() {
+        assert_eq!(Temperature::default(), t(0.0));
+        assert_eq!(Thermostat::default().target, t(20.0));
+    }
+
+    #[test]
+    fn drop_restores_the_target() {
+        let mut thermostat = Thermostat::default();
+        let boost = thermostat.boost(t(25.0));
+        assert_eq!(boost.thermostat.target, t(25.0));
+        drop(boost);
+        assert_eq!(thermostat.target, t(20.0));
+    }
+}
@@ -0,0 +1,239 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub};
+
+// A 2D vector, for positions and velocities in a game or a simulation
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct Vector2 {
+    x: f64,
+    y: f64,
+}
+
+impl Vector2 {
+    const ZERO: Vector2 = Vector2 { x: 0.0, y: 0.0 };
+
+    fn new(x: f64, y: f64) -> Self {
+        Vector2 { x, y }
+    }
+
+    fn length(self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    // Floats don't panic or wrap on overflow: they become infinity, and
+    // `inf - inf` becomes `NaN`, which then spreads through every later
+    // calculation. The checked versions stop at the first bad result.
+    fn checked(self) -> Result<Vector2, VectorError> {
+        if self.x.is_finite() && self.y.is_finite() {
+            Ok(self)
+        } else {
+            Err(VectorError::NotFinite(self))
+        }
+    }
+
+    fn checked_add(self, other: Vector2) -> Result<Vector2, VectorError> {
+        (self + other).checked()
+    }
+
+    fn checked_div(self, divisor: f64) -> Result<Vector2, VectorError> {
+        Vector2::new(self.x / divisor, self.y / divisor).checked()
+    }
+
+    fn normalized(self) -> Result<Vector2, VectorError> {
+        let length = self.length();
+        if length == 0.0 {
+            return Err(VectorError::ZeroLength);
+        }
+        // Dividing by the length, not multiplying by `1.0 / length`, which
+        // is infinite for tiny vectors
+        self.checked_div(length)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum VectorError {
+    NotFinite(Vector2),
+    ZeroLength,
+}
+
+impl fmt::Display for VectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VectorError::NotFinite(v) => write!(f, "result {} is not finite", v),
+            VectorError::ZeroLength => write!(f, "a zero vector has no direction"),
+        }
+    }
+}
+
+impl std::error::Error for VectorError {}
+
+// `a + b`
+impl Add for Vector2 {
+    type Output = Vector2;
+
+    fn add(self, other: Vector2) -> Vector2 {
+        Vector2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+// `a - b`
+impl Sub for Vector2 {
+    type Output = Vector2;
+
+    fn sub(self, other: Vector2) -> Vector2 {
+        Vector2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+// `-a`
+impl Neg for Vector2 {
+    type Output = Vector2;
+
+    fn neg(self) -> Vector2 {
+        Vector2::new(-self.x, -self.y)
+    }
+}
+
+// `a += b`
+impl AddAssign for Vector2 {
+    fn add_assign(&mut self, other: Vector2) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+// `v * 2.0`
+impl Mul<f64> for Vector2 {
+    type Output = Vector2;
+
+    fn mul(self, factor: f64) -> Vector2 {
+        Vector2::new(self.x * factor, self.y * factor)
+    }
+}
+
+// `2.0 * v`: the left-hand side is `f64`, so this is an implementation
+// for `f64`. It's allowed because `Vector2` is our own type.
+impl Mul<Vector2> for f64 {
+    type Output = Vector2;
+
+    fn mul(self, v: Vector2) -> Vector2 {
+        v * self
+    }
+}
+
+// `v[0]` and `v[1]`. `Index` returns a reference, and panics on an
+// invalid index, like indexing a slice.
+impl Index<usize> for Vector2 {
+    type Output = f64;
+
+    fn index(&self, axis: usize) -> &f64 {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("axis {} is out of range for Vector2", axis),
+        }
+    }
+}
+
+// `v[0] = 1.0`
+impl IndexMut<usize> for Vector2 {
+    fn index_mut(&mut self, axis: usize) -> &mut f64 {
+        match axis {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("axis {} is out of range for Vector2", axis),
+        }
+    }
+}
+
+impl fmt::Display for Vector2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+fn main() {
+    let mut position = Vector2::ZERO;
+    let velocity = Vector2::new(3.0, 4.0);
+    let gravity = Vector2::new(0.0, -9.8);
+
+    for _ in 0..3 {
+        position += velocity * 0.5 + 0.5 * gravity * 0.1;
+    }
+    println!("Position: {}", position);
+    println!("Speed: {}", velocity.length());
+    println!("Reversed: {}", -velocity);
+    println!(
+        "Distance to target: {}",
+        (Vector2::new(6.0, 8.0) - velocity).length()
+    );
+    println!("x = {}, y = {}", velocity[0], velocity[1]);
+
+    match velocity.normalized() {
+        Ok(direction) => println!("Direction: {}", direction),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    let huge = Vector2::new(f64::MAX, 1.0);
+    println!("Unchecked: {}", huge + huge);
+    match huge.checked_add(huge) {
+        Ok(v) => println!("Checked: {}", v),
+        Err(e) => println!("Error: {}", e),
+    }
+    match Vector2::ZERO.normalized() {
+        Ok(v) => println!("Direction: {}", v),
+        Err(e) => println!("Error: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_operators() {
+        let a = Vector2::new(1.0, 2.0);
+        let b = Vector2::new(3.0, -1.0);
+        assert_eq!(a + b, Vector2::new(4.0, 1.0));
+        assert_eq!(a - b, Vector2::new(-2.0, 3.0));
+        assert_eq!(-a, Vector2::new(-1.0, -2.0));
+        assert_eq!(a * 3.0, 3.0 * a);
+
+        let mut c = a;
+        c += b;
+        c += b;
+        assert_eq!(c, Vector2::new(7.0, 0.0));
+    }
+
+    #[test]
+    fn indexing() {
+        let mut v = Vector2::new(1.5, -2.0);
+        assert_eq!((v[0], v[1]), (1.5, -2.0));
+        v[1] = 10.0;
+        assert_eq!(v.y, 10.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "axis 2 is out of range")]
+    fn indexing_past_y_panics() {
+        let _ = Vector2::ZERO[2];
+    }
+
+    #[test]
+    fn checked_operations_catch_infinity_and_nan() {
+        let huge = Vector2::new(f64::MAX, 0.0);
+        assert!((huge * 2.0).x.is_infinite());
+        assert!(huge.checked_div(0.5).is_err());
+        assert!(huge.checked_add(huge).is_err());
+        assert_eq!(huge.checked_add(-huge), Ok(Vector2::ZERO));
+        assert_eq!(Vector2::ZERO.normalized(), Err(VectorError::ZeroLength));
+        assert_eq!(
+            Vector2::new(0.0, 5.0).normalized(),
+            Ok(Vector2::new(0.0, 1.0))
+        );
+        // The smallest positive `f64`, far below `1.0 / f64::MAX`
+        assert_eq!(
+            Vector2::new(5e-324, 0.0).normalized(),
+            Ok(Vector2::new(1.0, 0.0))
+        );
+    }
+}
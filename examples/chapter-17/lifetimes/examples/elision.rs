@@ -0,0 +1,102 @@
+// Most functions with references don't need lifetime annotations, because
+// the compiler fills them in using three elision rules. Each function below
+// is shown with the signature the compiler actually sees.
+
+// Rule 1: every reference in the parameters gets its own lifetime.
+// Rule 2: if there's exactly one input lifetime, the output gets it.
+//
+// fn trim_comment<'a>(line: &'a str) -> &'a str
+fn trim_comment(line: &str) -> &str {
+    line.split('#').next().unwrap_or("").trim_end()
+}
+
+// Rule 1 only: no output reference, so nothing to infer. The nested
+// `&str` inside the slice gets a lifetime of its own, too.
+//
+// fn starts_with_any<'a, 'b, 'c>(text: &'a str, prefixes: &'b [&'c str]) -> bool
+fn starts_with_any(text: &str, prefixes: &[&str]) -> bool {
+    prefixes.iter().any(|prefix| text.starts_with(prefix))
+}
+
+struct Config {
+    values: Vec<(String, String)>,
+}
+
+impl Config {
+    // Rule 3: in a method, if one of the parameters is `&self` or
+    // `&mut self`, the output gets the lifetime of `self`.
+    //
+    // fn get<'s, 'k>(&'s self, key: &'k str) -> Option<&'s str>
+    fn get(&self, key: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    // Rule 3 would tie the result to `self`, but here it comes from
+    // `text`. Writing the lifetimes out overrides the rule, and the result
+    // can outlive the config.
+    fn first_known_key<'t>(&self, text: &'t str) -> Option<&'t str> {
+        text.split_whitespace()
+            .find(|word| self.get(word).is_some())
+    }
+}
+
+// Two input references and a reference output: none of the rules apply,
+// and the compiler asks for an annotation. This doesn't compile:
+//
+// fn pick(a: &str, b: &str) -> &str { a }
+//
+// error[E0106]: missing lifetime specifier
+fn pick<'a>(a: &'a str, _b: &str) -> &'a str {
+    a
+}
+
+fn main() {
+    println!("{:?}", trim_comment("port = 8080 # default"));
+    println!(
+        "{}",
+        starts_with_any("https://example.com", &["http://", "https://"])
+    );
+
+    let config = Config {
+        values: vec![("port".to_string(), "8080".to_string())],
+    };
+    println!("port = {:?}", config.get("port"));
+    println!("{:?}", config.first_known_key("please change the port"));
+    println!("{}", pick("left", "right"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elided_signatures_work_like_explicit_ones() {
+        assert_eq!(trim_comment("a = 1 # note"), "a = 1");
+        assert_eq!(trim_comment("# only a comment"), "");
+        assert!(starts_with_any("v1.2", &["v", "V"]));
+    }
+
+    #[test]
+    fn method_results_borrow_from_self() {
+        let config = Config {
+            values: vec![("mode".to_string(), "fast".to_string())],
+        };
+        assert_eq!(config.get("mode"), Some("fast"));
+        assert_eq!(config.get("speed"), None);
+    }
+
+    #[test]
+    fn annotated_result_outlives_self() {
+        let text = String::from("set mode to slow");
+        let key = {
+            let config = Config {
+                values: vec![("mode".to_string(), "fast".to_string())],
+            };
+            config.first_known_key(&text)
+        };
+        assert_eq!(key, Some("mode"));
+    }
+}
@@ -0,0 +1,103 @@
+use std::ops::Range;
+
+// A document that owns its text and knows where each word starts and ends.
+//
+// The obvious version doesn't compile:
+//
+// struct Document<'a> {
+//     text: String,
+//     words: Vec<&'a str>, // borrowed from `text`
+// }
+//
+// The struct definition compiles, but no value of it can be created: the
+// slices would borrow `text` while `text` is being moved into the struct.
+// Moving a finished `Document` would be a problem too, because references
+// to its own fields would have to follow it. Storing positions instead of
+// references avoids both problems: a `Range<usize>` doesn't borrow anything.
+struct Document {
+    text: String,
+    words: Vec<Range<usize>>,
+}
+
+impl Document {
+    fn new(text: String) -> Self {
+        let mut words = Vec::new();
+        let mut start = None;
+        for (i, c) in text.char_indices() {
+            match (c.is_alphanumeric(), start) {
+                (true, None) => start = Some(i),
+                (false, Some(s)) => {
+                    words.push(s..i);
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(s) = start {
+            words.push(s..text.len());
+        }
+        Document { text, words }
+    }
+
+    // The references are created on demand and borrow from `self`,
+    // so the compiler checks them as usual
+    fn word(&self, index: usize) -> Option<&str> {
+        self.words.get(index).map(|range| &self.text[range.clone()])
+    }
+
+    fn words(&self) -> impl Iterator<Item = &str> + '_ {
+        self.words.iter().map(|range| &self.text[range.clone()])
+    }
+
+    // Changing the text invalidates the positions, so they're recomputed.
+    // With references, the borrow checker wouldn't even allow this method.
+    fn append(&mut self, more: &str) {
+        let mut text = std::mem::take(&mut self.text);
+        text.push_str(more);
+        *self = Document::new(text);
+    }
+}
+
+fn main() {
+    let doc = Document::new("Rust has no garbage collector.".to_string());
+    println!("Words: {:?}", doc.words().collect::<Vec<_>>());
+    println!("Third word: {:?}", doc.word(2));
+
+    // Moving the document is fine: the positions don't depend on
+    // where the `Document` lives
+    let mut moved = Box::new(doc);
+    moved.append(" It has ownership.");
+    println!(
+        "Now {} words, last: {:?}",
+        moved.words.len(),
+        moved.words().last()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_words_by_position() {
+        let doc = Document::new("  héllo, wörld!  ".to_string());
+        assert_eq!(doc.words().collect::<Vec<_>>(), vec!["héllo", "wörld"]);
+        assert_eq!(doc.word(1), Some("wörld"));
+        assert_eq!(doc.word(2), None);
+    }
+
+    #[test]
+    fn positions_survive_moves() {
+        let doc = Document::new("one two".to_string());
+        let moved = Box::new(doc);
+        assert_eq!(moved.word(1), Some("two"));
+    }
+
+    #[test]
+    fn appending_updates_the_words() {
+        let mut doc = Document::new("one".to_string());
+        doc.append(" two three");
+        assert_eq!(doc.words().count(), 3);
+        assert_eq!(doc.word(2), Some("three"));
+    }
+}
@@ -0,0 +1,71 @@
+// The classic example. The result is one of the two arguments, and the
+// compiler can't know which, so we tell it: the result is valid as long
+// as both inputs are.
+fn longest<'a>(a: &'a str, b: &'a str) -> &'a str {
+    if a.len() >= b.len() {
+        a
+    } else {
+        b
+    }
+}
+
+// When the result can only come from one argument, only that argument
+// needs to share its lifetime. The other one may be shorter-lived.
+fn first_part<'a>(text: &'a str, separator: &str) -> &'a str {
+    text.split(separator).next().unwrap_or(text)
+}
+
+// Returns the longest word across several lines, borrowed from the lines
+fn longest_word<'a>(lines: &[&'a str]) -> Option<&'a str> {
+    lines
+        .iter()
+        .flat_map(|line| line.split_whitespace())
+        .reduce(longest)
+}
+
+fn main() {
+    let city = String::from("Amsterdam, Netherlands");
+    let result;
+    {
+        let other = String::from("Rome");
+        // The result may point to `other`, so it must not be used after
+        // `other` is dropped at the end of this block
+        println!("Longest: {}", longest(&city, &other));
+
+        let separator = String::from(", ");
+        result = first_part(&city, &separator);
+    }
+    // OK: `first_part` only borrows from `city`
+    println!("First part: {}", result);
+
+    let lines = ["borrowing is checked", "at compile time by the compiler"];
+    println!("Longest word: {:?}", longest_word(&lines));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_the_longer_slice() {
+        assert_eq!(longest("ab", "abc"), "abc");
+        // Ties go to the first argument
+        assert_eq!(longest("xy", "ab"), "xy");
+    }
+
+    #[test]
+    fn result_may_outlive_the_unrelated_argument() {
+        let text = String::from("key=value");
+        let key = {
+            let separator = String::from("=");
+            first_part(&text, &separator)
+        };
+        assert_eq!(key, "key");
+    }
+
+    #[test]
+    fn finds_the_longest_word() {
+        assert_eq!(longest_word(&["a bb", "ccc d"]), Some("ccc"));
+        assert_eq!(longest_word(&[]), None);
+    }
+}
@@ -0,0 +1,93 @@
+// A parsed log line that borrows its fields from the original text instead
+// of copying them into new `String`s. The `'a` says: a `LogLine` can't
+// outlive the text it was parsed from.
+#[derive(Debug, PartialEq)]
+struct LogLine<'a> {
+    level: &'a str,
+    target: &'a str,
+    message: &'a str,
+}
+
+// The returned `LogLine` borrows from `line`, so they share the lifetime
+fn parse(line: &str) -> Option<LogLine<'_>> {
+    // Format: "LEVEL [target] message"
+    let (level, rest) = line.split_once(' ')?;
+    let rest = rest.strip_prefix('[')?;
+    let (target, message) = rest.split_once("] ")?;
+    Some(LogLine {
+        level,
+        target,
+        message,
+    })
+}
+
+// Methods on a borrowing struct. The impl names the lifetime `'a` so that
+// `module` can return `&'a str`: the slice lives as long as the original
+// text, not as long as the `&self` borrow. `impl LogLine<'_>` would be
+// enough if no method needed to mention the lifetime.
+impl<'a> LogLine<'a> {
+    fn module(&self) -> &'a str {
+        self.target.rsplit("::").next().unwrap_or(self.target)
+    }
+}
+
+// Parsed lines can be collected as long as the text is still around
+fn errors(log: &str) -> Vec<LogLine<'_>> {
+    log.lines()
+        .filter_map(parse)
+        .filter(|line| line.level == "ERROR")
+        .collect()
+}
+
+fn main() {
+    let log = String::from(
+        "INFO [app::server] listening on port 8080\n\
+         ERROR [app::db::pool] connection refused\n\
+         WARN [app::cache] cache is 90% full\n\
+         ERROR [app::server] request timed out",
+    );
+
+    let module = {
+        let errors = errors(&log);
+        for line in &errors {
+            println!("{:<8} {}", line.module(), line.message);
+        }
+        // `module` returns `&'a str`, borrowed from `log`, not from `errors`,
+        // so it can outlive the vector
+        errors[0].module()
+    };
+
+    // Uncommenting the next line is a compile error: `log` can't be
+    // dropped while `module` still borrows from it
+    // drop(log);
+    println!("First error came from: {}", module);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_fields_without_copying() {
+        let text = "DEBUG [net::tcp] sent 42 bytes";
+        let line = parse(text).unwrap();
+        assert_eq!(
+            line,
+            LogLine {
+                level: "DEBUG",
+                target: "net::tcp",
+                message: "sent 42 bytes",
+            }
+        );
+        // The message points into the original text
+        assert_eq!(line.message.as_ptr(), text[17..].as_ptr());
+        assert_eq!(line.module(), "tcp");
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        assert_eq!(parse("no brackets here"), None);
+        assert_eq!(parse("INFO [unterminated"), None);
+        assert_eq!(parse(""), None);
+    }
+}
@@ -0,0 +1,125 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Expr, Fields, Lit, Meta, Type};
+
+// The entry point. The compiler hands us the tokens of the struct, and we
+// return the tokens of a new `impl Describe for ...` block. The derive
+// also registers `describe` as an attribute, so it can be used on fields.
+#[proc_macro_derive(Describe, attributes(describe))]
+pub fn derive_describe(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    // Errors become `compile_error!` invocations that point at the
+    // offending code, instead of a panic inside the macro
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "Describe can only be derived for structs with named fields",
+                ))
+            }
+        },
+        Data::Enum(data) => {
+            return Err(syn::Error::new_spanned(
+                data.enum_token,
+                "Describe can only be derived for structs, not enums",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "Describe can only be derived for structs, not unions",
+            ))
+        }
+    };
+
+    let mut field_infos = Vec::new();
+    for field in fields {
+        if is_skipped(&field.attrs)? {
+            continue;
+        }
+        let name = field.ident.as_ref().unwrap().to_string();
+        let ty = type_name(&field.ty);
+        let doc = doc_comment(&field.attrs);
+        field_infos.push(quote! {
+            ::macros::FieldInfo {
+                name: #name,
+                ty: #ty,
+                doc: #doc,
+            }
+        });
+    }
+
+    let ident = &input.ident;
+    let name = ident.to_string();
+    let doc = doc_comment(&input.attrs);
+    // Keeps `<T: Clone>` and `where` clauses of generic structs
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::macros::Describe for #ident #ty_generics #where_clause {
+            const NAME: &'static str = #name;
+            const DOC: &'static str = #doc;
+
+            fn fields() -> &'static [::macros::FieldInfo] {
+                &[#(#field_infos),*]
+            }
+        }
+    })
+}
+
+// Turning tokens back into a string puts spaces between all of them,
+// as in `Vec < String >`. This removes the ones nobody would write.
+fn type_name(ty: &Type) -> String {
+    quote!(#ty)
+        .to_string()
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace(" :: ", "::")
+        .replace("& ", "&")
+}
+
+// Doc comments reach the macro as `#[doc = "..."]` attributes, one per line
+fn doc_comment(attrs: &[Attribute]) -> String {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(nv) => match &nv.value {
+                Expr::Lit(lit) => match &lit.lit {
+                    Lit::Str(s) => Some(s.value().trim().to_string()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Handles `#[describe(skip)]` and rejects anything else
+fn is_skipped(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut skip = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("describe")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown describe attribute, expected `skip`"))
+            }
+        })?;
+    }
+    Ok(skip)
+}
@@ -0,0 +1,125 @@
+use std::fmt::Write;
+
+// The derive macro lives in its own crate, because a `proc-macro` crate
+// can export nothing but macros. Re-exporting it here lets users write
+// `use macros::Describe` and get both the trait and the derive.
+pub use describe_derive::Describe;
+
+pub struct FieldInfo {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub doc: &'static str,
+}
+
+// Implemented by `#[derive(Describe)]` from the struct's definition
+pub trait Describe {
+    const NAME: &'static str;
+    const DOC: &'static str;
+
+    fn fields() -> &'static [FieldInfo];
+
+    fn describe() -> String {
+        let mut out = format!("{}: {}\n", Self::NAME, Self::DOC);
+        for field in Self::fields() {
+            let _ = writeln!(out, "  {}: {} - {}", field.name, field.ty, field.doc);
+        }
+        out
+    }
+}
+
+/// Creates a `HashMap` from `key => value` pairs.
+#[macro_export]
+macro_rules! hashmap {
+    // Internal rule: turns any expression into `()`, so the pairs can be
+    // counted with `<[()]>::len` at compile time
+    (@unit $_key:expr) => { () };
+
+    () => { ::std::collections::HashMap::new() };
+
+    // One or more pairs, separated by commas, with an optional trailing comma
+    ($($key:expr => $value:expr),+ $(,)?) => {{
+        let capacity = <[()]>::len(&[$($crate::hashmap!(@unit $key)),+]);
+        let mut map = ::std::collections::HashMap::with_capacity(capacity);
+        $(
+            map.insert($key, $value);
+        )+
+        map
+    }};
+}
+
+/// Evaluates an expression that returns a `Result` until it succeeds or
+/// the attempts run out, with an optional delay between attempts.
+#[macro_export]
+macro_rules! retry {
+    ($attempts:expr, $body:expr) => {
+        $crate::retry!($attempts, ::std::time::Duration::ZERO, $body)
+    };
+    ($attempts:expr, $delay:expr, $body:expr) => {{
+        let attempts: u32 = $attempts;
+        let mut attempt = 1;
+        loop {
+            // `$body` is pasted here, so it's evaluated again on every attempt
+            match $body {
+                Ok(value) => break Ok(value),
+                Err(e) if attempt < attempts => {
+                    // `stringify!` turns the code itself into a string
+                    eprintln!(
+                        "`{}` failed (attempt {}/{}): {}",
+                        stringify!($body),
+                        attempt,
+                        attempts,
+                        e
+                    );
+                    attempt += 1;
+                    ::std::thread::sleep($delay);
+                }
+                Err(e) => break Err(e),
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    #[test]
+    fn hashmap_literal() {
+        let map = hashmap! {
+            "one" => 1,
+            "two" => 2,
+        };
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["two"], 2);
+        assert!(map.capacity() >= 2);
+
+        let empty: HashMap<String, u8> = hashmap!();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn retry_until_success() {
+        let mut calls = 0;
+        let result: Result<u32, String> = retry!(5, {
+            calls += 1;
+            if calls < 3 {
+                Err(format!("call {} failed", calls))
+            } else {
+                Ok(calls)
+            }
+        });
+        assert_eq!(result, Ok(3));
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn retry_returns_the_last_error() {
+        let mut calls = 0;
+        let result: Result<(), String> = retry!(2, {
+            calls += 1;
+            Err(format!("call {} failed", calls))
+        });
+        assert_eq!(result, Err("call 2 failed".to_string()));
+        assert_eq!(calls, 2);
+    }
+}
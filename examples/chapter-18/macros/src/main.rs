@@ -0,0 +1,55 @@
+use macros::{hashmap, retry, Describe};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// A registered user of the service
+#[derive(Describe)]
+#[allow(dead_code)]
+struct User {
+    /// Unique login name
+    name: String,
+    /// Age in full years
+    age: u32,
+    /// Roles granted to the user
+    roles: Vec<String>,
+    #[describe(skip)]
+    password_hash: String,
+}
+
+/// A page of results
+#[derive(Describe)]
+#[allow(dead_code)]
+struct Page<T> {
+    /// Items on this page
+    items: Vec<T>,
+    /// Token for the next page, if any
+    next: Option<String>,
+}
+
+// Fails twice before succeeding, like a flaky network call
+fn connect(attempts: &mut u32) -> Result<&'static str, String> {
+    *attempts += 1;
+    if *attempts < 3 {
+        Err("connection refused".to_string())
+    } else {
+        Ok("connected")
+    }
+}
+
+fn main() {
+    let ports: HashMap<&str, u16> = hashmap! {
+        "http" => 80,
+        "https" => 443,
+        "ssh" => 22,
+    };
+    let mut names: Vec<_> = ports.iter().collect();
+    names.sort();
+    println!("Ports: {:?}", names);
+
+    let mut attempts = 0;
+    let result = retry!(5, Duration::from_millis(50), connect(&mut attempts));
+    println!("Result: {:?} after {} attempts", result, attempts);
+
+    print!("{}", User::describe());
+    print!("{}", Page::<User>::describe());
+}
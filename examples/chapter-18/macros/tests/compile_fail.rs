@@ -0,0 +1,8 @@
+// Each file in `tests/ui` must fail to compile, with exactly the error
+// message stored next to it in a `.stderr` file. Run with
+// `TRYBUILD=overwrite cargo test` to create or update the messages.
+#[test]
+fn describe_errors() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,9 @@
+use macros::Describe;
+
+#[derive(Describe)]
+enum Status {
+    Active,
+    Banned,
+}
+
+fn main() {}
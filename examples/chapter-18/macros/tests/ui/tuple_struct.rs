@@ -0,0 +1,6 @@
+use macros::Describe;
+
+#[derive(Describe)]
+struct Meters(f64);
+
+fn main() {}
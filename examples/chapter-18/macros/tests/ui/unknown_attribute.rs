@@ -0,0 +1,10 @@
+use macros::Describe;
+
+#[derive(Describe)]
+struct User {
+    name: String,
+    #[describe(hide)]
+    password: String,
+}
+
+fn main() {}
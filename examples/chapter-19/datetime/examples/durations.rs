@@ -0,0 +1,89 @@
+use chrono::{Datelike, Months, NaiveDate, TimeDelta, Weekday};
+
+// `TimeDelta` (also available as `chrono::Duration`) is an exact amount of
+// time. Months and years aren't: they have different lengths, so chrono
+// has a separate `Months` type for calendar arithmetic.
+fn add_months(date: NaiveDate, months: u32) -> NaiveDate {
+    // 31 January + 1 month is the last day of February, not 3 March
+    date.checked_add_months(Months::new(months))
+        .expect("date out of range")
+}
+
+// Counts Monday to Friday between two dates, excluding the end date
+fn business_days(start: NaiveDate, end: NaiveDate) -> usize {
+    start
+        .iter_days()
+        .take_while(|day| *day < end)
+        .filter(|day| !matches!(day.weekday(), Weekday::Sat | Weekday::Sun))
+        .count()
+}
+
+fn human(delta: TimeDelta) -> String {
+    let days = delta.num_days();
+    let hours = delta.num_hours() % 24;
+    let minutes = delta.num_minutes() % 60;
+    format!("{}d {}h {}m", days, hours, minutes)
+}
+
+fn main() {
+    let start = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    for months in 1..=3 {
+        println!(
+            "{} + {} month(s) = {}",
+            start,
+            months,
+            add_months(start, months)
+        );
+    }
+
+    let release = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap();
+    let today = NaiveDate::from_ymd_opt(2024, 11, 20).unwrap();
+    println!("Days until release: {}", (release - today).num_days());
+    println!("Business days:      {}", business_days(today, release));
+
+    let uptime = TimeDelta::seconds(200_000);
+    println!("Uptime: {}", human(uptime));
+    println!(
+        "Timeout in ms: {}",
+        TimeDelta::minutes(5).num_milliseconds()
+    );
+
+    // Arithmetic that could leave the supported range returns `Option`
+    println!(
+        "Overflow: {:?}",
+        NaiveDate::MAX.checked_add_signed(TimeDelta::days(1))
+    );
+
+    // The `time` crate has its own `Duration` with similar methods
+    let d = time::Duration::hours(36);
+    println!("time::Duration: {} ({} whole days)", d, d.whole_days());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn month_arithmetic_clamps_to_the_end_of_the_month() {
+        assert_eq!(add_months(date(2024, 1, 31), 1), date(2024, 2, 29));
+        assert_eq!(add_months(date(2023, 1, 31), 1), date(2023, 2, 28));
+        assert_eq!(add_months(date(2024, 1, 15), 12), date(2025, 1, 15));
+    }
+
+    #[test]
+    fn counts_business_days() {
+        // Monday to the next Monday
+        assert_eq!(business_days(date(2024, 11, 18), date(2024, 11, 25)), 5);
+        assert_eq!(business_days(date(2024, 11, 23), date(2024, 11, 25)), 0);
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(human(TimeDelta::minutes(1501)), "1d 1h 1m");
+        assert_eq!(date(2024, 3, 1) - date(2024, 2, 1), TimeDelta::days(29));
+    }
+}
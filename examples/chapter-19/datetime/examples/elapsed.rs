@@ -0,0 +1,72 @@
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// `Instant` is a monotonic clock: it never goes backwards, even if the
+// system clock is changed. Use it to measure how long something takes.
+fn timed<T>(label: &str, f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    println!("{} took {:?}", label, elapsed);
+    (result, elapsed)
+}
+
+// A deadline is an `Instant` in the future. Checking it in a loop works
+// no matter how long each step takes.
+fn work_until(deadline: Instant, step: Duration) -> u32 {
+    let mut steps = 0;
+    while Instant::now() < deadline {
+        thread::sleep(step);
+        steps += 1;
+    }
+    steps
+}
+
+fn main() {
+    let (sum, _) = timed("Summing 10 million numbers", || {
+        (0..10_000_000u64).sum::<u64>()
+    });
+    println!("Sum: {}", sum);
+
+    let deadline = Instant::now() + Duration::from_millis(100);
+    let steps = work_until(deadline, Duration::from_millis(30));
+    println!("Steps before the deadline: {}", steps);
+
+    // `SystemTime` is the wall clock. It's what you store and show, but
+    // it can jump, so `duration_since` returns a `Result`.
+    let now = SystemTime::now();
+    let since_epoch = now.duration_since(UNIX_EPOCH).expect("clock before 1970");
+    println!("Unix time: {}", since_epoch.as_secs());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_at_least_the_sleep_time() {
+        let (value, elapsed) = timed("sleep", || {
+            thread::sleep(Duration::from_millis(20));
+            42
+        });
+        assert_eq!(value, 42);
+        assert!(elapsed >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn stops_at_the_deadline() {
+        let start = Instant::now();
+        let steps = work_until(start + Duration::from_millis(50), Duration::from_millis(10));
+        assert!(steps >= 1);
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+
+    #[test]
+    fn instants_are_ordered() {
+        let a = Instant::now();
+        let b = Instant::now();
+        assert!(b >= a);
+        // Subtracting a later instant saturates to zero instead of panicking
+        assert_eq!(a.saturating_duration_since(b), Duration::ZERO);
+    }
+}
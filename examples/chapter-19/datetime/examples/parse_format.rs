@@ -0,0 +1,76 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+use time::format_description::well_known::Rfc3339;
+use time::macros::format_description;
+use time::OffsetDateTime;
+
+// chrono: `parse_from_rfc3339` for the standard format, `parse_from_str`
+// with strftime-style patterns for everything else
+fn parse_with_chrono(input: &str) -> Result<DateTime<FixedOffset>, chrono::ParseError> {
+    DateTime::parse_from_rfc3339(input)
+}
+
+// A European date and time without a time zone: a "naive" value
+fn parse_european(input: &str) -> Result<NaiveDateTime, chrono::ParseError> {
+    NaiveDateTime::parse_from_str(input, "%d.%m.%Y %H:%M")
+}
+
+// time: formats are described with a macro that's checked at compile time
+fn format_with_time(dt: OffsetDateTime) -> String {
+    let format = format_description!(
+        "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute] [offset_hour sign:mandatory]:[offset_minute]"
+    );
+    dt.format(&format).unwrap()
+}
+
+fn main() {
+    let dt = parse_with_chrono("2024-11-20T14:30:00+01:00").unwrap();
+    println!("Parsed:        {}", dt);
+    println!("In UTC:        {}", dt.with_timezone(&Utc));
+    println!("RFC 2822:      {}", dt.to_rfc2822());
+    println!("Custom:        {}", dt.format("%A, %e %B %Y at %H:%M"));
+    println!("ISO week:      {}", dt.format("%G-W%V"));
+
+    let meeting = parse_european("05.12.2024 09:15").unwrap();
+    println!("Naive:         {}", meeting);
+
+    let date = NaiveDate::parse_from_str("2024-02-29", "%Y-%m-%d").unwrap();
+    println!("Day of year:   {}", date.format("%j"));
+    println!(
+        "Invalid date:  {:?}",
+        NaiveDate::parse_from_str("2023-02-29", "%Y-%m-%d")
+    );
+
+    let dt = OffsetDateTime::parse("2024-11-20T14:30:00+01:00", &Rfc3339).unwrap();
+    println!("time crate:    {}", format_with_time(dt));
+    println!("Back to RFC:   {}", dt.format(&Rfc3339).unwrap());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, Timelike};
+
+    #[test]
+    fn parses_rfc3339_with_offset() {
+        let dt = parse_with_chrono("2024-11-20T14:30:00+01:00").unwrap();
+        assert_eq!(dt.offset().local_minus_utc(), 3600);
+        assert_eq!(dt.with_timezone(&Utc).hour(), 13);
+        assert!(parse_with_chrono("2024-11-20 14:30").is_err());
+    }
+
+    #[test]
+    fn parses_custom_formats() {
+        let dt = parse_european("31.12.2024 23:59").unwrap();
+        assert_eq!((dt.year(), dt.month(), dt.day()), (2024, 12, 31));
+        assert!(parse_european("32.12.2024 23:59").is_err());
+    }
+
+    #[test]
+    fn chrono_and_time_agree() {
+        let input = "2024-06-01T08:00:00Z";
+        let chrono = DateTime::parse_from_rfc3339(input).unwrap();
+        let time = OffsetDateTime::parse(input, &Rfc3339).unwrap();
+        assert_eq!(chrono.timestamp(), time.unix_timestamp());
+        assert_eq!(format_with_time(time), "Sat, 01 Jun 2024 08:00 +00:00");
+    }
+}
@@ -0,0 +1,104 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+// chrono's `DateTime<Utc>` is written as an ISO-8601 (RFC 3339) string by
+// default. The `chrono::serde` modules switch a field to Unix timestamps.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Event {
+    name: String,
+    // "2024-11-20T14:30:00Z"
+    starts_at: DateTime<Utc>,
+    // 1732113000
+    #[serde(with = "chrono::serde::ts_seconds")]
+    created: DateTime<Utc>,
+    // 1732113000123, or null
+    #[serde(with = "chrono::serde::ts_milliseconds_option")]
+    cancelled: Option<DateTime<Utc>>,
+}
+
+// The `time` crate has no default format for `OffsetDateTime`; each
+// field chooses one explicitly
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct LogEntry {
+    #[serde(with = "time::serde::rfc3339")]
+    at: OffsetDateTime,
+    #[serde(with = "time::serde::timestamp")]
+    received: OffsetDateTime,
+    message: String,
+}
+
+fn sample_event() -> Event {
+    let at = Utc.with_ymd_and_hms(2024, 11, 20, 14, 30, 0).unwrap();
+    Event {
+        name: "Release party".to_string(),
+        starts_at: at,
+        created: at - chrono::TimeDelta::days(7),
+        cancelled: None,
+    }
+}
+
+fn main() {
+    let event = sample_event();
+    let json = serde_json::to_string_pretty(&event).unwrap();
+    println!("{}", json);
+
+    let entry = LogEntry {
+        at: time::macros::datetime!(2024-11-20 14:30:00.250 +01:00),
+        received: time::macros::datetime!(2024-11-20 13:30:01 UTC),
+        message: "disk almost full".to_string(),
+    };
+    println!("{}", serde_json::to_string(&entry).unwrap());
+
+    // Timestamps with an offset other than UTC are converted when parsed
+    let parsed: Event = serde_json::from_str(
+        r#"{"name":"Call","starts_at":"2024-11-20T16:30:00+02:00","created":0,"cancelled":null}"#,
+    )
+    .unwrap();
+    println!("Parsed start in UTC: {}", parsed.starts_at);
+    println!("Created: {}", parsed.created);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn chrono_fields_use_the_chosen_formats() {
+        let mut event = sample_event();
+        event.cancelled = Some(Utc.timestamp_millis_opt(1_732_113_000_123).unwrap());
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            json!({
+                "name": "Release party",
+                "starts_at": "2024-11-20T14:30:00Z",
+                "created": 1_731_508_200,
+                "cancelled": 1_732_113_000_123u64,
+            })
+        );
+        let back: Event = serde_json::from_value(serde_json::to_value(&event).unwrap()).unwrap();
+        assert_eq!(back, event);
+    }
+
+    #[test]
+    fn time_fields_round_trip() {
+        let entry = LogEntry {
+            at: time::macros::datetime!(2024-03-31 02:59:59.5 +01:00),
+            received: time::macros::datetime!(1970-01-01 0:00:10 UTC),
+            message: String::new(),
+        };
+        let json = serde_json::to_string(&entry).unwrap();
+        assert!(json.contains(r#""at":"2024-03-31T02:59:59.5+01:00""#));
+        assert!(json.contains(r#""received":10"#));
+        assert_eq!(serde_json::from_str::<LogEntry>(&json).unwrap(), entry);
+    }
+
+    #[test]
+    fn invalid_timestamps_are_rejected() {
+        let result = serde_json::from_str::<Event>(
+            r#"{"name":"x","starts_at":"yesterday","created":0,"cancelled":null}"#,
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,138 @@
+use chrono::{DateTime, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeDelta, TimeZone, Utc};
+use chrono_tz::America::New_York;
+use chrono_tz::Asia::Tokyo;
+use chrono_tz::Europe::Berlin;
+use chrono_tz::Tz;
+
+// Store and compute in UTC; convert to a time zone only to show a time
+// to a person, or to read one from a person
+fn show_in(utc: DateTime<Utc>, zones: &[Tz]) -> Vec<String> {
+    zones
+        .iter()
+        .map(|tz| {
+            let local = utc.with_timezone(tz);
+            format!("{:<16} {}", tz.name(), local.format("%Y-%m-%d %H:%M %Z"))
+        })
+        .collect()
+}
+
+// A local time entered by a user doesn't always exist exactly once.
+// When clocks jump forward, some times never happen; when they jump back,
+// some times happen twice. This picks a sensible answer for each case.
+fn resolve_local(tz: Tz, local: NaiveDateTime) -> DateTime<Tz> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(dt) => dt,
+        // 02:30 happens twice: take the first one
+        LocalResult::Ambiguous(earliest, _latest) => earliest,
+        // 02:30 never happens: move forward by the size of the gap, by
+        // reading the time with the UTC offset from before the gap
+        LocalResult::None => {
+            // Most gaps are an hour, but Samoa skipped all of 30 December
+            // 2011, so search back until the local time exists again
+            let mut before = local;
+            let offset = loop {
+                before -= TimeDelta::hours(1);
+                if let Some(dt) = tz.from_local_datetime(&before).latest() {
+                    break dt.offset().fix();
+                }
+            };
+            Utc.from_utc_datetime(&(local - offset)).with_timezone(&tz)
+        }
+    }
+}
+
+fn berlin(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d)
+        .unwrap()
+        .and_hms_opt(h, min, 0)
+        .unwrap()
+}
+
+fn main() {
+    let meeting = Utc.with_ymd_and_hms(2024, 11, 20, 15, 0, 0).unwrap();
+    for line in show_in(meeting, &[Berlin, New_York, Tokyo]) {
+        println!("{}", line);
+    }
+
+    // On 31 March 2024, clocks in Berlin jumped from 02:00 to 03:00
+    let missing = berlin(2024, 3, 31, 2, 30);
+    println!(
+        "{} in Berlin: {:?}",
+        missing,
+        Berlin.from_local_datetime(&missing)
+    );
+    println!("Resolved: {}", resolve_local(Berlin, missing));
+
+    // On 27 October 2024, they went back from 03:00 to 02:00
+    let twice = berlin(2024, 10, 27, 2, 30);
+    match Berlin.from_local_datetime(&twice) {
+        LocalResult::Ambiguous(a, b) => println!("{} happened twice: {} and {}", twice, a, b),
+        other => println!("{:?}", other),
+    }
+
+    // "Tomorrow at the same time" and "in 24 hours" differ across DST
+    let evening = Berlin.with_ymd_and_hms(2024, 10, 26, 18, 0, 0).unwrap();
+    println!("In 24 hours:  {}", evening + TimeDelta::hours(24));
+    let next_day = resolve_local(Berlin, evening.naive_local() + TimeDelta::days(1));
+    println!("Next day:     {}", next_day);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono_tz::Pacific::Apia;
+
+    #[test]
+    fn converts_between_zones() {
+        let utc = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        assert_eq!(
+            show_in(utc, &[Berlin, New_York]),
+            vec![
+                "Europe/Berlin    2024-07-01 14:00 CEST",
+                "America/New_York 2024-07-01 08:00 EDT",
+            ]
+        );
+    }
+
+    #[test]
+    fn spring_forward_gap_does_not_exist() {
+        let missing = berlin(2024, 3, 31, 2, 30);
+        assert_eq!(Berlin.from_local_datetime(&missing), LocalResult::None);
+        assert_eq!(
+            resolve_local(Berlin, missing).to_rfc3339(),
+            "2024-03-31T03:30:00+02:00"
+        );
+    }
+
+    #[test]
+    fn a_skipped_day_does_not_exist() {
+        let missing = NaiveDate::from_ymd_opt(2011, 12, 30)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        assert_eq!(Apia.from_local_datetime(&missing), LocalResult::None);
+        assert_eq!(
+            resolve_local(Apia, missing).to_rfc3339(),
+            "2011-12-31T12:00:00+14:00"
+        );
+    }
+
+    #[test]
+    fn fall_back_hour_is_ambiguous() {
+        let twice = berlin(2024, 10, 27, 2, 30);
+        let LocalResult::Ambiguous(first, second) = Berlin.from_local_datetime(&twice) else {
+            panic!("expected two matches");
+        };
+        assert_eq!(first.to_rfc3339(), "2024-10-27T02:30:00+02:00");
+        assert_eq!(second.to_rfc3339(), "2024-10-27T02:30:00+01:00");
+        assert_eq!(second - first, TimeDelta::hours(1));
+        assert_eq!(resolve_local(Berlin, twice), first);
+    }
+
+    #[test]
+    fn a_day_is_not_always_24_hours() {
+        let before = Berlin.with_ymd_and_hms(2024, 10, 26, 18, 0, 0).unwrap();
+        let after = Berlin.with_ymd_and_hms(2024, 10, 27, 18, 0, 0).unwrap();
+        assert_eq!(after - before, TimeDelta::hours(25));
+    }
+}
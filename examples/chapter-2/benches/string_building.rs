@@ -0,0 +1,24 @@
+use chapter_2::string_building::{with_capacity, with_format, with_push_str, with_write};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn bench_string_building(c: &mut Criterion) {
+    let mut group = c.benchmark_group("build_string");
+    for n in [100, 1_000, 10_000] {
+        group.bench_with_input(BenchmarkId::new("format", n), &n, |b, &n| {
+            b.iter(|| with_format(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("push_str", n), &n, |b, &n| {
+            b.iter(|| with_push_str(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("write", n), &n, |b, &n| {
+            b.iter(|| with_write(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("with_capacity", n), &n, |b, &n| {
+            b.iter(|| with_capacity(black_box(n)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_string_building);
+criterion_main!(benches);
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+// Enough for identifiers, commands, HTTP headers, and file extensions.
+// No allocation, but only ASCII letters are folded.
+fn is_yes(answer: &str) -> bool {
+    answer.trim().eq_ignore_ascii_case("yes")
+}
+
+// For text in any language: compare the lowercase forms.
+// Allocates two new strings for every comparison.
+fn eq_ignore_case(a: &str, b: &str) -> bool {
+    a.to_lowercase() == b.to_lowercase()
+}
+
+fn contains_ignore_case(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(&needle.to_lowercase())
+}
+
+fn main() {
+    for answer in ["YES", " yes\n", "Yes!"] {
+        println!("{:?} is yes: {}", answer, is_yes(answer));
+    }
+
+    // ASCII-only comparison misses non-ASCII letters
+    println!("{}", "ÉCOLE".eq_ignore_ascii_case("école"));
+    println!("{}", eq_ignore_case("ÉCOLE", "école"));
+
+    // Case mapping can change the length: "ß" becomes "SS" in uppercase,
+    // so the result depends on which direction you compare in
+    println!("{} {}", "straße".to_uppercase(), "STRASSE".to_lowercase());
+    println!(
+        "lowercase equal: {}, uppercase equal: {}",
+        eq_ignore_case("straße", "STRASSE"),
+        "straße".to_uppercase() == "STRASSE".to_uppercase()
+    );
+
+    println!("{}", contains_ignore_case("Error: Disk FULL", "disk full"));
+
+    // Sorting: `sort_by_cached_key` lowercases each name once,
+    // instead of twice per comparison with `sort_by`
+    let mut names = vec!["bob", "Alice", "carol", "Bob", "alice"];
+    names.sort_by_cached_key(|name| name.to_lowercase());
+    println!("{:?}", names);
+
+    // A map with case-insensitive keys: normalize the key on the way in
+    let mut headers: HashMap<String, &str> = HashMap::new();
+    for (name, value) in [
+        ("Content-Type", "text/html"),
+        ("content-type", "application/json"),
+    ] {
+        headers.insert(name.to_ascii_lowercase(), value);
+    }
+    println!("{:?}", headers);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_comparison() {
+        assert!(is_yes("  yEs "));
+        assert!(!is_yes("yes please"));
+        assert!(!"Ä".eq_ignore_ascii_case("ä"));
+    }
+
+    #[test]
+    fn unicode_comparison() {
+        assert!(eq_ignore_case("Ä", "ä"));
+        assert!(eq_ignore_case("ΣΊΣΥΦΟΣ", "σίσυφος"));
+        assert!(!eq_ignore_case("straße", "STRASSE"));
+        assert!(contains_ignore_case("Hello WORLD", "o w"));
+    }
+}
@@ -0,0 +1,37 @@
+use itertools::Itertools;
+
+struct LogLine {
+    date: &'static str,
+    level: &'static str,
+    message: &'static str,
+}
+
+fn main() {
+    // Log lines are already ordered by date
+    let lines = [
+        LogLine { date: "2024-10-26", level: "INFO", message: "Server started" },
+        LogLine { date: "2024-10-26", level: "WARN", message: "Slow query" },
+        LogLine { date: "2024-10-27", level: "ERROR", message: "Disk full" },
+        LogLine { date: "2024-10-27", level: "INFO", message: "Disk cleaned" },
+        LogLine { date: "2024-10-27", level: "INFO", message: "Backup done" },
+        LogLine { date: "2024-10-28", level: "INFO", message: "Server stopped" },
+    ];
+
+    // Group consecutive lines that share the same date
+    for (date, group) in &lines.iter().chunk_by(|line| line.date) {
+        let group: Vec<&LogLine> = group.collect();
+        let errors = group.iter().filter(|line| line.level == "ERROR").count();
+        println!("{}: {} lines, {} errors", date, group.len(), errors);
+        for line in group {
+            println!("    [{}] {}", line.level, line.message);
+        }
+    }
+
+    // The standard library offers the same for slices
+    let levels: Vec<&str> = lines.iter().map(|line| line.level).collect();
+    let runs: Vec<(&str, usize)> = levels
+        .chunk_by(|a, b| a == b)
+        .map(|run| (run[0], run.len()))
+        .collect();
+    println!("{:?}", runs); // Output: [("INFO", 1), ("WARN", 1), ("ERROR", 1), ("INFO", 3)]
+}
@@ -0,0 +1,16 @@
+fn main() {
+    let readings = [12, 15, 11, 18, 20, 17, 16];
+
+    // Split the data into batches of 3; the last batch may be shorter
+    for (batch, chunk) in readings.chunks(3).enumerate() {
+        println!("Batch {}: {:?}", batch, chunk);
+    }
+
+    // Average of every batch
+    let averages: Vec<f64> = readings
+        .chunks(3)
+        .map(|chunk| chunk.iter().sum::<i32>() as f64 / chunk.len() as f64)
+        .collect();
+
+    println!("Averages: {:?}", averages); // Output: [12.666666666666666, 18.333333333333332, 16.0]
+}
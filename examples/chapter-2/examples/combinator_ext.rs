@@ -0,0 +1,57 @@
+use log::{error, warn};
+use std::fmt::Display;
+
+// New combinators for every Option
+trait OptionExt<T> {
+    // Runs `f` if the value is missing, then returns the Option unchanged
+    fn tap_none<F: FnOnce()>(self, f: F) -> Option<T>;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    fn tap_none<F: FnOnce()>(self, f: F) -> Option<T> {
+        if self.is_none() {
+            f();
+        }
+        self
+    }
+}
+
+// New combinators for every Result whose error can be displayed
+trait ResultExt<T, E> {
+    // Logs the error with some context, then returns the Result unchanged
+    fn log_err(self, context: &str) -> Result<T, E>;
+}
+
+impl<T, E: Display> ResultExt<T, E> for Result<T, E> {
+    fn log_err(self, context: &str) -> Result<T, E> {
+        if let Err(e) = &self {
+            error!("{}: {}", context, e);
+        }
+        self
+    }
+}
+
+fn find_port(config: &[(&str, &str)]) -> Option<u16> {
+    config
+        .iter()
+        .find(|(key, _)| *key == "port")
+        .tap_none(|| warn!("No port configured, using the default"))
+        .and_then(|(_, value)| value.parse().log_err("Invalid port").ok())
+}
+
+fn main() {
+    env_logger::Builder::new()
+        .filter_level(log::LevelFilter::Info)
+        .init();
+
+    let configs = [
+        vec![("host", "localhost"), ("port", "8080")],
+        vec![("host", "localhost")],
+        vec![("port", "eighty")],
+    ];
+
+    for config in &configs {
+        let port = find_port(config).unwrap_or(80);
+        println!("Using port {}", port);
+    }
+}
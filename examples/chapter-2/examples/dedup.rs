@@ -0,0 +1,35 @@
+#[derive(Debug)]
+struct Event {
+    user: String,
+    action: String,
+}
+
+fn main() {
+    // `dedup` removes consecutive duplicates only
+    let mut statuses = vec!["ok", "ok", "error", "error", "ok", "ok", "ok"];
+    statuses.dedup();
+    println!("{:?}", statuses); // Output: ["ok", "error", "ok"]
+
+    // Sort first to remove all duplicates
+    let mut tags = vec!["rust", "web", "rust", "cli", "web"];
+    tags.sort();
+    tags.dedup();
+    println!("{:?}", tags); // Output: ["cli", "rust", "web"]
+
+    // `dedup_by_key` compares a part of each element
+    let mut events = vec![
+        Event { user: "alice".to_string(), action: "click".to_string() },
+        Event { user: "alice".to_string(), action: "scroll".to_string() },
+        Event { user: "bob".to_string(), action: "click".to_string() },
+        Event { user: "alice".to_string(), action: "click".to_string() },
+    ];
+    events.dedup_by_key(|event| event.user.clone());
+
+    for event in &events {
+        println!("{} {}", event.user, event.action);
+    }
+    // Output:
+    // alice click
+    // bob click
+    // alice click
+}
@@ -0,0 +1,45 @@
+// `None` marks a number that no longer fits in u64
+struct Fibonacci {
+    current: Option<u64>,
+    next: Option<u64>,
+}
+
+impl Fibonacci {
+    fn new() -> Self {
+        Fibonacci {
+            current: Some(0),
+            next: Some(1),
+        }
+    }
+}
+
+impl Iterator for Fibonacci {
+    type Item = u64;
+
+    // Called every time the consumer asks for another value
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.current?;
+        self.current = self.next;
+        // Computes the number after the next one. `checked_add` returns
+        // `None` instead of panicking when the sum doesn't fit, and the
+        // two numbers before it are still returned.
+        self.next = self.next.and_then(|next| value.checked_add(next));
+        Some(value)
+    }
+}
+
+fn main() {
+    // The sequence is infinite, so `take` decides how much we want
+    let first_ten: Vec<u64> = Fibonacci::new().take(10).collect();
+    println!("{:?}", first_ten); // Output: [0, 1, 1, 2, 3, 5, 8, 13, 21, 34]
+
+    // Every iterator adapter works on our type too
+    let even_sum: u64 = Fibonacci::new()
+        .take_while(|&n| n < 4_000_000)
+        .filter(|n| n % 2 == 0)
+        .sum();
+    println!("Sum of even numbers below 4 million: {}", even_sum); // Output: 4613732
+
+    println!("Numbers that fit in u64: {}", Fibonacci::new().count()); // Output: 94
+    println!("The largest: {:?}", Fibonacci::new().last()); // Output: Some(12200160415121876738)
+}
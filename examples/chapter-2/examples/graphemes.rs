@@ -0,0 +1,74 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+// What a reader sees as one character can be several `char`s:
+// "é" written as "e" + a combining accent, a family emoji joined with
+// zero-width joiners, or a flag made of two regional indicators
+const SAMPLES: [&str; 4] = ["cafe", "cafe\u{301}", "👨‍👩‍👧", "🇩🇪🇫🇷"];
+
+fn reverse_chars(s: &str) -> String {
+    s.chars().rev().collect()
+}
+
+fn reverse_graphemes(s: &str) -> String {
+    s.graphemes(true).rev().collect()
+}
+
+// Cuts after `max` visible characters, never in the middle of one
+fn truncate(s: &str, max: usize) -> &str {
+    match s.grapheme_indices(true).nth(max) {
+        Some((index, _)) => &s[..index],
+        None => s,
+    }
+}
+
+fn main() {
+    for s in SAMPLES {
+        println!(
+            "{:<10} bytes: {:>2}  chars: {:>2}  graphemes: {}",
+            s,
+            s.len(),
+            s.chars().count(),
+            s.graphemes(true).count()
+        );
+    }
+
+    let word = "cafe\u{301}!";
+    println!("Reversed by chars:     {}", reverse_chars(word));
+    println!("Reversed by graphemes: {}", reverse_graphemes(word));
+    println!("Truncated: {}", truncate("👨‍👩‍👧 family", 3));
+
+    // Slicing uses byte offsets; slicing inside a character panics.
+    // `get` returns `None` instead.
+    let s = "naïve";
+    println!("{:?} {:?}", s.get(..2), s.get(..3));
+    println!("Boundary at 3: {}", s.is_char_boundary(3));
+
+    // `unicode_words` splits on word boundaries and drops punctuation
+    let words: Vec<&str> = "Hello, wörld! It's 3.14 o'clock.".unicode_words().collect();
+    println!("{:?}", words);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_visible_characters() {
+        let counts: Vec<usize> = SAMPLES.iter().map(|s| s.graphemes(true).count()).collect();
+        assert_eq!(counts, vec![4, 4, 1, 2]);
+    }
+
+    #[test]
+    fn reversing_chars_moves_the_accent() {
+        // The combining accent now follows "!" instead of "e"
+        assert_eq!(reverse_chars("e\u{301}!"), "!\u{301}e");
+        assert_eq!(reverse_graphemes("e\u{301}!"), "!e\u{301}");
+    }
+
+    #[test]
+    fn truncates_at_grapheme_boundaries() {
+        assert_eq!(truncate("🇩🇪🇫🇷", 1), "🇩🇪");
+        assert_eq!(truncate("abc", 10), "abc");
+        assert_eq!(truncate("", 1), "");
+    }
+}
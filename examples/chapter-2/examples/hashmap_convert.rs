@@ -0,0 +1,31 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+
+fn main() {
+    // Vec of pairs -> HashMap
+    let pairs = vec![("banana", 3), ("apple", 5), ("cherry", 7)];
+    let prices: HashMap<&str, i32> = pairs.into_iter().collect();
+
+    // HashMap -> BTreeMap: same data, sorted by key
+    let sorted: BTreeMap<&str, i32> = prices.clone().into_iter().collect();
+    println!("{:?}", sorted); // Output: {"apple": 5, "banana": 3, "cherry": 7}
+
+    // Map -> Vec of pairs, sorted by value in descending order
+    let mut by_price: Vec<(&str, i32)> = prices.into_iter().collect();
+    by_price.sort_by_key(|&(_, price)| Reverse(price));
+    println!("{:?}", by_price); // Output: [("cherry", 7), ("apple", 5), ("banana", 3)]
+
+    // Swap keys and values
+    let by_value: BTreeMap<i32, &str> = sorted.iter().map(|(k, v)| (*v, *k)).collect();
+    println!("{:?}", by_value); // Output: {3: "banana", 5: "apple", 7: "cherry"}
+
+    // Keys and values on their own
+    let names: Vec<&str> = sorted.keys().copied().collect();
+    let total: i32 = sorted.values().sum();
+    println!("{:?} cost {} in total", names, total);
+
+    // BTreeMap supports range queries over its sorted keys
+    for (name, price) in sorted.range("b".."c") {
+        println!("Starts with b: {} = {}", name, price);
+    }
+}
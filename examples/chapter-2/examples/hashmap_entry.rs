@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+// Pretend this is slow, e.g. a database query
+fn load_profile(customer: &str) -> String {
+    println!("  (loading profile of {})", customer);
+    customer.to_uppercase()
+}
+
+fn main() {
+    let orders = [
+        ("alice", "book"),
+        ("bob", "pen"),
+        ("alice", "laptop"),
+        ("carol", "book"),
+        ("bob", "book"),
+    ];
+
+    // Frequency counting: insert 0 the first time, then increment
+    let mut counts: HashMap<&str, u32> = HashMap::new();
+    for (_, product) in &orders {
+        *counts.entry(product).or_insert(0) += 1;
+    }
+    println!("Product counts: {:?}", counts);
+
+    // Grouping: `or_default` inserts an empty Vec for a new key
+    let mut by_customer: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (customer, product) in &orders {
+        by_customer.entry(customer).or_default().push(product);
+    }
+    println!("Alice ordered: {:?}", by_customer["alice"]);
+
+    // Caching: `or_insert_with` calls the closure only when the key is missing
+    let mut profiles: HashMap<&str, String> = HashMap::new();
+    for (customer, _) in &orders {
+        let profile = profiles
+            .entry(customer)
+            .or_insert_with(|| load_profile(customer));
+        println!("Order from {}", profile);
+    }
+
+    // `and_modify` changes an existing value, `or_insert` handles a new key
+    let mut stock: HashMap<&str, i32> = HashMap::from([("book", 10), ("pen", 5)]);
+    for product in ["book", "pen", "laptop"] {
+        stock
+            .entry(product)
+            .and_modify(|quantity| *quantity -= 1)
+            .or_insert(-1); // Sold something we don't have: backorder
+    }
+    println!("Stock: book={} pen={} laptop={}", stock["book"], stock["pen"], stock["laptop"]);
+
+    // `retain` keeps only the entries for which the closure returns true
+    stock.retain(|_, quantity| *quantity > 0);
+    println!("In stock: {} products", stock.len()); // Output: 2
+}
@@ -0,0 +1,77 @@
+fn main() {
+    let numbers = vec![1, 2, 3];
+
+    // Building the chain runs nothing: no "inspect" lines are printed here
+    let chain = numbers
+        .iter()
+        .inspect(|x| println!("  inspect: {}", x))
+        .map(|x| x * 10);
+    println!("Chain built, nothing has run yet");
+
+    // `collect` pulls the items through the chain one by one
+    let result: Vec<i32> = chain.collect();
+    println!("Collected: {:?}", result);
+
+    // Items flow through the whole chain one at a time, not stage by stage
+    numbers
+        .iter()
+        .inspect(|x| println!("  before filter: {}", x))
+        .filter(|&&x| x != 2)
+        .for_each(|x| println!("  consumed:      {}", x));
+
+    // Pitfall: a chain without a consumer is a silent no-op.
+    // The compiler warns about this line (the warnings are silenced here on purpose):
+    //   warning: unused `Map` that must be used
+    //   = note: iterators are lazy and do nothing unless consumed
+    //   warning: `Iterator::map` call that discard the iterator's values
+    //   help: you might have meant to use `Iterator::for_each`
+    #[allow(unused_must_use, map_unit_fn)]
+    numbers.iter().map(|x| println!("  never printed: {}", x));
+
+    // Fix: use a `for` loop (or `for_each`) when you only want side effects
+    for x in &numbers {
+        println!("  printed: {}", x);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    #[test]
+    fn adapters_do_not_run_until_consumed() {
+        let calls = Cell::new(0);
+        let chain = [1, 2, 3].iter().map(|x| {
+            calls.set(calls.get() + 1);
+            x * 2
+        });
+
+        assert_eq!(calls.get(), 0); // Nothing has happened yet
+
+        let doubled: Vec<i32> = chain.collect();
+        assert_eq!(doubled, vec![2, 4, 6]);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn unconsumed_chain_is_a_no_op() {
+        let calls = Cell::new(0);
+
+        #[allow(unused_must_use, map_unit_fn)]
+        [1, 2, 3].iter().map(|_| calls.set(calls.get() + 1));
+
+        assert_eq!(calls.get(), 0);
+    }
+
+    #[test]
+    fn take_stops_pulling_items_early() {
+        let calls = Cell::new(0);
+        let first_two: Vec<u32> = (1..)
+            .inspect(|_| calls.set(calls.get() + 1))
+            .take(2)
+            .collect();
+
+        assert_eq!(first_two, vec![1, 2]);
+        assert_eq!(calls.get(), 2); // Only two items were produced from an infinite range
+    }
+}
@@ -0,0 +1,66 @@
+// Pretends to be a remote API that returns users one page at a time
+struct UserApi {
+    users: Vec<String>,
+    page_size: usize,
+}
+
+struct Page {
+    items: Vec<String>,
+    next_page: Option<usize>,
+}
+
+impl UserApi {
+    fn fetch_page(&self, page: usize) -> Page {
+        println!("  -> fetching page {}", page);
+        let start = page * self.page_size;
+        let end = (start + self.page_size).min(self.users.len());
+        Page {
+            items: self.users[start..end].to_vec(),
+            next_page: if end < self.users.len() { Some(page + 1) } else { None },
+        }
+    }
+
+    fn users(&self) -> UserIter<'_> {
+        UserIter { api: self, buffer: Vec::new(), next_page: Some(0) }
+    }
+}
+
+// Yields users one by one and fetches a new page only when the buffer is empty
+struct UserIter<'a> {
+    api: &'a UserApi,
+    buffer: Vec<String>,
+    next_page: Option<usize>,
+}
+
+impl Iterator for UserIter<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.is_empty() {
+            let page = self.api.fetch_page(self.next_page?);
+            self.next_page = page.next_page;
+            // Reverse so that `pop` returns the items in their original order
+            self.buffer = page.items.into_iter().rev().collect();
+        }
+        self.buffer.pop()
+    }
+}
+
+fn main() {
+    let api = UserApi {
+        users: (1..=7).map(|i| format!("user{}", i)).collect(),
+        page_size: 3,
+    };
+
+    println!("First two users:");
+    for user in api.users().take(2) {
+        println!("{}", user);
+    }
+
+    println!("All users with an odd number:");
+    let odd: Vec<String> = api
+        .users()
+        .filter(|name| name.ends_with(['1', '3', '5', '7']))
+        .collect();
+    println!("{:?}", odd);
+}
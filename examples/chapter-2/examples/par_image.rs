@@ -0,0 +1,54 @@
+use rayon::prelude::*;
+use std::time::Instant;
+
+const WIDTH: usize = 1920;
+const HEIGHT: usize = 1080;
+
+// An expensive per-pixel computation: iterations of the Mandelbrot formula
+fn pixel(x: usize, y: usize) -> u8 {
+    let cx = x as f64 / WIDTH as f64 * 3.5 - 2.5;
+    let cy = y as f64 / HEIGHT as f64 * 2.0 - 1.0;
+    let (mut zx, mut zy) = (0.0, 0.0);
+    let mut i = 0;
+    while zx * zx + zy * zy < 4.0 && i < 255 {
+        let tmp = zx * zx - zy * zy + cx;
+        zy = 2.0 * zx * zy + cy;
+        zx = tmp;
+        i += 1;
+    }
+    i as u8
+}
+
+fn main() {
+    let mut image = vec![0u8; WIDTH * HEIGHT];
+
+    let start = Instant::now();
+    image.chunks_mut(WIDTH).enumerate().for_each(|(y, row)| {
+        for (x, p) in row.iter_mut().enumerate() {
+            *p = pixel(x, y);
+        }
+    });
+    println!("Sequential: {:?}", start.elapsed());
+    let sequential = image.clone();
+
+    // Each row is independent, so rows can be rendered on different threads
+    let start = Instant::now();
+    image.par_chunks_mut(WIDTH).enumerate().for_each(|(y, row)| {
+        for (x, p) in row.iter_mut().enumerate() {
+            *p = pixel(x, y);
+        }
+    });
+    println!("Parallel:   {:?}", start.elapsed());
+    assert_eq!(sequential, image);
+
+    // Parallel `reduce` needs an identity value: every thread starts from it
+    let brightness: u64 = image
+        .par_iter()
+        .map(|&p| p as u64)
+        .reduce(|| 0, |a, b| a + b);
+    println!("Average brightness: {:.2}", brightness as f64 / image.len() as f64);
+
+    // Finding the maximum: the identity is the smallest possible value
+    let max = image.par_iter().copied().reduce(|| u8::MIN, u8::max);
+    println!("Max brightness: {}", max);
+}
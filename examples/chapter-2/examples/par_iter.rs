@@ -0,0 +1,25 @@
+use rayon::prelude::*;
+use std::time::Instant;
+
+fn is_prime(n: u64) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+}
+
+fn main() {
+    let numbers: Vec<u64> = (1..2_000_000).collect();
+
+    // Sequential pipeline from the filtering recipe
+    let start = Instant::now();
+    let sequential: Vec<u64> = numbers.iter().copied().filter(|&n| is_prime(n)).collect();
+    println!("iter:     {} primes in {:?}", sequential.len(), start.elapsed());
+
+    // The same pipeline on all CPU cores: only `iter` changed to `par_iter`
+    let start = Instant::now();
+    let parallel: Vec<u64> = numbers.par_iter().copied().filter(|&n| is_prime(n)).collect();
+    println!("par_iter: {} primes in {:?}", parallel.len(), start.elapsed());
+
+    // `collect` keeps the original order, so both results are identical
+    assert_eq!(sequential, parallel);
+
+    println!("Threads used by rayon: {}", rayon::current_num_threads());
+}
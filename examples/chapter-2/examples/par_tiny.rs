@@ -0,0 +1,18 @@
+use rayon::prelude::*;
+use std::time::Instant;
+
+fn main() {
+    let numbers: Vec<u64> = (1..=1_000).collect();
+
+    // Tiny workload: the work per item is a single multiplication
+    let start = Instant::now();
+    let sequential: u64 = numbers.iter().map(|n| n * n).sum();
+    println!("Sequential: {:?}", start.elapsed());
+
+    // Splitting the work and waking up threads costs more than the work itself
+    let start = Instant::now();
+    let parallel: u64 = numbers.par_iter().map(|n| n * n).sum();
+    println!("Parallel:   {:?}", start.elapsed());
+
+    assert_eq!(sequential, parallel);
+}
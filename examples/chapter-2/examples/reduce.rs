@@ -0,0 +1,30 @@
+fn main() {
+    let temperatures = [21.5, 19.0, 25.3, 17.8, 23.1];
+
+    // `reduce` uses the first element as the starting value
+    let max = temperatures.iter().copied().reduce(f64::max);
+    let min = temperatures.iter().copied().reduce(f64::min);
+    println!("Max: {:?}, Min: {:?}", max, min); // Output: Max: Some(25.3), Min: Some(17.8)
+
+    // An empty iterator has no first element, so the result is `None`
+    let empty: [f64; 0] = [];
+    println!("Max of nothing: {:?}", empty.iter().copied().reduce(f64::max)); // Output: None
+
+    // Min and max in a single pass with `fold`
+    let (low, high) = temperatures
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), &t| {
+            (low.min(t), high.max(t))
+        });
+    println!("Range: {} to {}", low, high); // Output: Range: 17.8 to 25.3
+
+    // Running maximum with `scan`
+    let records: Vec<f64> = temperatures
+        .iter()
+        .scan(f64::NEG_INFINITY, |best, &t| {
+            *best = best.max(t);
+            Some(*best)
+        })
+        .collect();
+    println!("Record so far: {:?}", records); // Output: [21.5, 21.5, 25.3, 25.3, 25.3]
+}
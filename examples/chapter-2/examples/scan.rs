@@ -0,0 +1,32 @@
+fn main() {
+    let deposits = [100, -30, 50, -70, 20];
+
+    // `scan` keeps state between items and yields every intermediate value
+    let balances: Vec<i32> = deposits
+        .iter()
+        .scan(0, |balance, &amount| {
+            *balance += amount;
+            Some(*balance)
+        })
+        .collect();
+    println!("Running balance: {:?}", balances); // Output: [100, 70, 120, 50, 70]
+
+    // Returning `None` from the closure stops the iteration early
+    let until_limit: Vec<i32> = deposits
+        .iter()
+        .scan(0, |total, &amount| {
+            *total += amount;
+            if *total > 110 { None } else { Some(*total) }
+        })
+        .collect();
+    println!("Until the balance exceeds 110: {:?}", until_limit); // Output: [100, 70]
+
+    // The same running balance with a for loop
+    let mut balance = 0;
+    let mut loop_balances = Vec::new();
+    for amount in deposits {
+        balance += amount;
+        loop_balances.push(balance);
+    }
+    assert_eq!(balances, loop_balances);
+}
@@ -0,0 +1,30 @@
+use std::time::Instant;
+
+// A simple pseudo-random generator, so the example needs no extra crates
+fn random_numbers(count: usize) -> Vec<u64> {
+    let mut state = 42u64;
+    (0..count)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % 1_000_000
+        })
+        .collect()
+}
+
+fn main() {
+    let data = random_numbers(1_000_000);
+
+    let mut stable = data.clone();
+    let start = Instant::now();
+    stable.sort();
+    println!("sort:          {:?}", start.elapsed());
+
+    let mut unstable = data.clone();
+    let start = Instant::now();
+    unstable.sort_unstable();
+    println!("sort_unstable: {:?}", start.elapsed());
+
+    assert_eq!(stable, unstable);
+}
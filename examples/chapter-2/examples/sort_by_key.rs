@@ -0,0 +1,31 @@
+use std::cmp::Reverse;
+
+#[derive(Debug)]
+struct Product {
+    name: String,
+    price: u32,
+}
+
+fn main() {
+    let mut products = [
+        Product { name: "Keyboard".to_string(), price: 45 },
+        Product { name: "mouse".to_string(), price: 20 },
+        Product { name: "Monitor".to_string(), price: 180 },
+    ];
+
+    // Sort by a key extracted from each element
+    products.sort_by_key(|p| p.price);
+    println!("{:?}", products.iter().map(|p| &p.name).collect::<Vec<_>>());
+    // Output: ["mouse", "Keyboard", "Monitor"]
+
+    // `Reverse` flips the order: most expensive first
+    products.sort_by_key(|p| Reverse(p.price));
+    println!("{:?}", products.iter().map(|p| &p.name).collect::<Vec<_>>());
+    // Output: ["Monitor", "Keyboard", "mouse"]
+
+    // Case-insensitive sort by name. The key is computed once per element
+    // with `sort_by_cached_key`, which matters when the key is expensive.
+    products.sort_by_cached_key(|p| p.name.to_lowercase());
+    println!("{:?}", products.iter().map(|p| &p.name).collect::<Vec<_>>());
+    // Output: ["Keyboard", "Monitor", "mouse"]
+}
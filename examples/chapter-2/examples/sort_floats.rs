@@ -0,0 +1,18 @@
+fn main() {
+    let mut temperatures: Vec<f64> = vec![21.5, -3.0, 17.25, 30.0, 0.0];
+
+    // temperatures.sort(); // error: the trait `Ord` is not implemented for `{float}`
+
+    // `total_cmp` defines a total order for floats, including NaN
+    temperatures.sort_by(|a, b| a.total_cmp(b));
+    println!("{:?}", temperatures); // Output: [-3.0, 0.0, 17.25, 21.5, 30.0]
+
+    // Descending order: swap the arguments
+    temperatures.sort_by(|a, b| b.total_cmp(a));
+    println!("{:?}", temperatures); // Output: [30.0, 21.5, 17.25, 0.0, -3.0]
+
+    // NaN values end up at the end instead of causing a panic
+    let mut readings = vec![2.0, f64::NAN, 1.0];
+    readings.sort_by(f64::total_cmp);
+    println!("{:?}", readings); // Output: [1.0, 2.0, NaN]
+}
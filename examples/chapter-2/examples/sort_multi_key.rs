@@ -0,0 +1,36 @@
+#[derive(Debug)]
+struct Employee {
+    department: &'static str,
+    name: &'static str,
+    salary: u32,
+}
+
+fn main() {
+    let mut employees = vec![
+        Employee { department: "Sales", name: "Dave", salary: 50_000 },
+        Employee { department: "Engineering", name: "Alice", salary: 90_000 },
+        Employee { department: "Sales", name: "Carol", salary: 65_000 },
+        Employee { department: "Engineering", name: "Bob", salary: 90_000 },
+    ];
+
+    // Department ascending, then salary descending, then name ascending
+    employees.sort_by(|a, b| {
+        a.department
+            .cmp(b.department)
+            .then_with(|| b.salary.cmp(&a.salary))
+            .then_with(|| a.name.cmp(b.name))
+    });
+
+    for e in &employees {
+        println!("{:<12} {:<6} {}", e.department, e.name, e.salary);
+    }
+
+    // The same with a tuple key: tuples compare element by element
+    employees.sort_by_key(|e| (e.department, std::cmp::Reverse(e.salary), e.name));
+
+    // `sort` is stable: equal elements keep their previous relative order.
+    // `sort_unstable` may reorder them, but is faster and uses no extra memory.
+    let mut ids = vec![42, 7, 19, 7, 3];
+    ids.sort_unstable();
+    println!("{:?}", ids); // Output: [3, 7, 7, 19, 42]
+}
@@ -0,0 +1,48 @@
+fn main() {
+    // `trim` removes whitespace at both ends; `split` and `map` handle the rest
+    let line = "  Alice , 30 ,  Berlin  ";
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    println!("{:?}", fields);
+
+    // `split_whitespace` splits on any amount of whitespace and skips empty parts
+    let words: Vec<&str> = "  the   quick\tbrown\nfox ".split_whitespace().collect();
+    println!("{:?}", words);
+
+    // `split` keeps empty parts, which matters for formats like CSV
+    let cells: Vec<&str> = "a,,c,".split(',').collect();
+    println!("{:?}", cells);
+
+    // `split_once` splits at the first match into exactly two parts
+    for pair in ["host=localhost", "url=http://x/?a=b", "broken"] {
+        match pair.split_once('=') {
+            Some((key, value)) => println!("{} -> {}", key, value),
+            None => println!("No '=' in {:?}", pair),
+        }
+    }
+
+    // `rsplit_once` splits at the last match: a file's extension
+    let file = "archive.tar.gz";
+    println!("{:?}", file.rsplit_once('.'));
+
+    // `lines` handles both "\n" and "\r\n" line endings
+    let text = "first\r\nsecond\n\nfourth";
+    for (number, line) in text.lines().enumerate().filter(|(_, l)| !l.is_empty()) {
+        println!("{}: {}", number + 1, line);
+    }
+
+    // `trim_matches`, `trim_start_matches`, and `strip_suffix` for specific characters
+    println!("{}", "--title--".trim_matches('-'));
+    println!("{}", "0042".trim_start_matches('0'));
+    println!("{:?}", "report.csv".strip_suffix(".csv"));
+
+    // `join` works on slices of strings; `collect::<String>` concatenates
+    let tags = ["rust", "strings", "iterators"];
+    println!("{}", tags.join(", "));
+    let path: Vec<String> = ["usr", "local", "bin"]
+        .iter()
+        .map(|part| part.to_uppercase())
+        .collect();
+    println!("/{}", path.join("/"));
+    let acronym: String = tags.iter().filter_map(|t| t.chars().next()).collect();
+    println!("{}", acronym);
+}
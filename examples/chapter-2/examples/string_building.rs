@@ -0,0 +1,25 @@
+use chapter_2::string_building::{
+    with_capacity, with_collect, with_format, with_push_str, with_write,
+};
+use std::time::Instant;
+
+type Builder = fn(usize) -> String;
+
+fn main() {
+    let n = 20_000;
+    let functions: [(&str, Builder); 5] = [
+        ("format! in a loop", with_format),
+        ("push_str", with_push_str),
+        ("write!", with_write),
+        ("with_capacity + write!", with_capacity),
+        ("collect", with_collect),
+    ];
+
+    let expected = with_write(n);
+    for (name, build) in functions {
+        let start = Instant::now();
+        let s = build(n);
+        println!("{:<24}{:?}", name, start.elapsed());
+        assert_eq!(s, expected);
+    }
+}
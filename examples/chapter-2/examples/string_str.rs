@@ -0,0 +1,68 @@
+use std::borrow::Cow;
+
+// Take `&str` when the function only reads the text: it accepts string
+// literals, `&String`, and slices of other strings
+fn initials(name: &str) -> String {
+    name.split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .collect()
+}
+
+// Take `impl Into<String>` when the function stores the text. Callers
+// with a `String` move it in without a copy; callers with `&str` still work.
+struct User {
+    name: String,
+}
+
+impl User {
+    fn new(name: impl Into<String>) -> Self {
+        User { name: name.into() }
+    }
+}
+
+// Return `Cow` when the result is usually the input unchanged:
+// it borrows in that case and allocates only when something changed
+fn strip_prefix_title(name: &str) -> Cow<'_, str> {
+    match name.strip_prefix("Dr. ") {
+        Some(rest) => Cow::Owned(format!("{} (Dr.)", rest)),
+        None => Cow::Borrowed(name),
+    }
+}
+
+fn main() {
+    let literal: &str = "Ada Lovelace"; // Points into the program binary
+    let owned: String = String::from("Grace Hopper"); // Owns a heap buffer
+
+    // &str -> String: all of these allocate and copy
+    let a: String = literal.to_string();
+    let b: String = literal.to_owned();
+    let c: String = String::from(literal);
+    let d: String = literal.into();
+    assert!(a == b && b == c && c == d);
+
+    // String -> &str: free, just a borrow
+    let slice: &str = &owned;
+    let also_slice: &str = owned.as_str();
+    let part: &str = &owned[..5];
+    println!("{} / {} / {}", slice, also_slice, part);
+
+    // `&String` converts to `&str` automatically (deref coercion)
+    println!("{} {}", initials(literal), initials(&owned));
+
+    let users = [User::new(literal), User::new(owned)];
+    let names: Vec<&str> = users.iter().map(|u| u.name.as_str()).collect();
+    println!("{:?}", names);
+
+    for name in ["Dr. Jane Doe", "John Roe"] {
+        match strip_prefix_title(name) {
+            Cow::Borrowed(s) => println!("Borrowed: {}", s),
+            Cow::Owned(s) => println!("Owned:    {}", s),
+        }
+    }
+
+    // String -> Box<str>: drops spare capacity, for text that won't grow
+    let mut buffer = String::with_capacity(64);
+    buffer.push_str("fixed");
+    let boxed: Box<str> = buffer.into_boxed_str();
+    println!("{} ({} bytes)", boxed, boxed.len());
+}
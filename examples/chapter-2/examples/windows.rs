@@ -0,0 +1,18 @@
+fn main() {
+    let prices = [100, 102, 101, 105, 110, 108];
+
+    // Every pair of neighbours: [100, 102], [102, 101], ...
+    let changes: Vec<i32> = prices.windows(2).map(|w| w[1] - w[0]).collect();
+    println!("Daily changes: {:?}", changes); // Output: [2, -1, 4, 5, -2]
+
+    // Moving average over 3 days
+    let moving_average: Vec<f64> = prices
+        .windows(3)
+        .map(|w| w.iter().sum::<i32>() as f64 / 3.0)
+        .collect();
+    println!("3-day moving average: {:?}", moving_average);
+
+    // Is the list sorted?
+    let rising = prices.windows(2).all(|w| w[0] <= w[1]);
+    println!("Always rising: {}", rising); // Output: false
+}
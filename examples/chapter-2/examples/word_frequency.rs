@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+fn main() {
+    let text = "the quick brown fox jumps over the lazy dog the end";
+
+    // Functional version: `fold` with a HashMap as the accumulator
+    let frequencies = text
+        .split_whitespace()
+        .fold(HashMap::new(), |mut counts, word| {
+            *counts.entry(word).or_insert(0) += 1;
+            counts
+        });
+
+    // Imperative version: the same logic with a for loop
+    let mut loop_frequencies = HashMap::new();
+    for word in text.split_whitespace() {
+        *loop_frequencies.entry(word).or_insert(0) += 1;
+    }
+    assert_eq!(frequencies, loop_frequencies);
+
+    // Sort by count (descending), then alphabetically
+    let mut sorted: Vec<(&str, i32)> = frequencies.into_iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+
+    for (word, count) in sorted.iter().take(3) {
+        println!("{}: {}", word, count);
+    }
+    // Output:
+    // the: 3
+    // brown: 1
+    // dog: 1
+}
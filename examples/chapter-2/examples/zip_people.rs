@@ -0,0 +1,54 @@
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, Clone)]
+struct Person {
+    name: String,
+    age: u32,
+}
+
+fn main() {
+    let names = ["Alice", "Bob", "Charlie", "Diana"];
+    let ages = [30, 17, 25, 12];
+
+    // zip: combine two collections into structs
+    let people: Vec<Person> = names
+        .iter()
+        .zip(ages.iter())
+        .map(|(name, &age)| Person {
+            name: name.to_string(),
+            age,
+        })
+        .collect();
+    println!("{:?}", people);
+
+    // unzip: split structs back into two collections
+    let (names_again, ages_again): (Vec<String>, Vec<u32>) = people
+        .iter()
+        .map(|person| (person.name.clone(), person.age))
+        .unzip();
+    println!("Names: {:?}", names_again);
+    println!("Ages: {:?}", ages_again);
+
+    // partition: adults and minors
+    let (adults, minors): (Vec<Person>, Vec<Person>) =
+        people.iter().cloned().partition(|person| person.age >= 18);
+    println!(
+        "Adults: {:?}",
+        adults.iter().map(|p| &p.name).collect::<Vec<_>>()
+    );
+    println!(
+        "Minors: {:?}",
+        minors.iter().map(|p| &p.name).collect::<Vec<_>>()
+    );
+
+    // HashMap: fast lookup by name, no particular order
+    let age_by_name: HashMap<&str, u32> = names.iter().copied().zip(ages.iter().copied()).collect();
+    println!("Bob is {}", age_by_name["Bob"]);
+
+    // BTreeMap: keys are kept sorted, so iteration goes from youngest to oldest
+    let name_by_age: BTreeMap<u32, &str> =
+        ages.iter().copied().zip(names.iter().copied()).collect();
+    for (age, name) in &name_by_age {
+        println!("{}: {}", age, name);
+    }
+}
@@ -0,0 +1,2 @@
+//! Code shared by the examples and the benchmarks of this chapter
+pub mod string_building;
@@ -0,0 +1,47 @@
+//! Five ways to build the string "0,1,2,...,n-1,"
+
+use std::fmt::Write;
+
+// Each `format!` allocates a new string and copies everything built so far,
+// so the total work grows with the square of the length
+pub fn with_format(n: usize) -> String {
+    let mut s = String::new();
+    for i in 0..n {
+        s = format!("{}{},", s, i);
+    }
+    s
+}
+
+// `push_str` appends in place; the buffer doubles when it's full
+pub fn with_push_str(n: usize) -> String {
+    let mut s = String::new();
+    for i in 0..n {
+        s.push_str(&i.to_string());
+        s.push(',');
+    }
+    s
+}
+
+// `write!` formats directly into the existing buffer, without the
+// temporary string that `to_string` creates
+pub fn with_write(n: usize) -> String {
+    let mut s = String::new();
+    for i in 0..n {
+        write!(s, "{},", i).unwrap();
+    }
+    s
+}
+
+// Reserving the space up front avoids the reallocations as well.
+// Up to 6 digits and a comma per number for n <= 1_000_000.
+pub fn with_capacity(n: usize) -> String {
+    let mut s = String::with_capacity(n * 7);
+    for i in 0..n {
+        write!(s, "{},", i).unwrap();
+    }
+    s
+}
+
+pub fn with_collect(n: usize) -> String {
+    (0..n).map(|i| format!("{},", i)).collect()
+}
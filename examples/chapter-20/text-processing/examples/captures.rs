@@ -0,0 +1,106 @@
+use regex::Regex;
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq)]
+struct Request {
+    method: String,
+    path: String,
+    status: u16,
+    millis: u32,
+}
+
+// Named groups `(?P<name>...)`, or the shorter `(?<name>...)`, make the
+// code that reads them independent of the group order
+fn parse_request(line: &str) -> Option<Request> {
+    let re = Regex::new(
+        r"^(?<method>GET|POST|PUT|DELETE) (?<path>/\S*) (?<status>\d{3}) (?<millis>\d+)ms$",
+    )
+    .unwrap();
+    let caps = re.captures(line)?;
+    Some(Request {
+        method: caps["method"].to_string(),
+        path: caps["path"].to_string(),
+        // The pattern guarantees digits, but `\d+` can still overflow
+        status: caps["status"].parse().ok()?,
+        millis: caps["millis"].parse().ok()?,
+    })
+}
+
+// `captures_iter` finds every match in the input, not just the first
+fn parse_pairs(input: &str) -> HashMap<&str, &str> {
+    let re = Regex::new(r#"(\w+)=("[^"]*"|\S+)"#).unwrap();
+    re.captures_iter(input)
+        .map(|caps| {
+            // `get` returns a `Match` with the position and the text.
+            // Groups borrow from the input, so no strings are copied.
+            let key = caps.get(1).unwrap().as_str();
+            let value = caps.get(2).unwrap().as_str().trim_matches('"');
+            (key, value)
+        })
+        .collect()
+}
+
+// `extract` destructures a match with a fixed number of groups into an array
+fn versions(input: &str) -> Vec<(u32, u32)> {
+    let re = Regex::new(r"v(\d+)\.(\d+)").unwrap();
+    re.captures_iter(input)
+        .map(|caps| {
+            let (_, [major, minor]) = caps.extract();
+            (major.parse().unwrap(), minor.parse().unwrap())
+        })
+        .collect()
+}
+
+fn main() {
+    let lines = [
+        "GET /api/users/42 200 15ms",
+        "POST /api/orders 201 120ms",
+        "PATCH /api/users/42 405 1ms",
+    ];
+    for line in lines {
+        match parse_request(line) {
+            Some(request) => println!("{:?}", request),
+            None => println!("Not a request: {}", line),
+        }
+    }
+
+    let pairs = parse_pairs(r#"user=alice role=admin note="has two words" retries=3"#);
+    let mut keys: Vec<_> = pairs.iter().collect();
+    keys.sort();
+    println!("{:?}", keys);
+
+    println!("{:?}", versions("Upgraded from v1.4 to v2.0, v2.1 is next"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captures_into_a_struct() {
+        assert_eq!(
+            parse_request("DELETE /items/7 204 3ms"),
+            Some(Request {
+                method: "DELETE".to_string(),
+                path: "/items/7".to_string(),
+                status: 204,
+                millis: 3,
+            })
+        );
+        assert_eq!(parse_request("GET /x 200 99999999999ms"), None);
+        assert_eq!(parse_request("GET /x 200"), None);
+    }
+
+    #[test]
+    fn finds_all_pairs() {
+        let pairs = parse_pairs(r#"a=1 b="x y" c=z"#);
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs["b"], "x y");
+    }
+
+    #[test]
+    fn extracts_versions() {
+        assert_eq!(versions("v0.9 and v10.11"), vec![(0, 9), (10, 11)]);
+        assert!(versions("no versions").is_empty());
+    }
+}
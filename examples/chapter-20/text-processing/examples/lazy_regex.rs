@@ -0,0 +1,73 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::sync::LazyLock;
+use std::time::Instant;
+
+// Compiling a regex is much slower than running it. In a function that's
+// called often, compile it once and reuse it.
+
+// `std::sync::LazyLock` (Rust 1.80+) runs the closure on first use, in a
+// thread-safe way, and keeps the result for the rest of the program
+static WORD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b\w+\b").unwrap());
+
+// `once_cell::sync::Lazy` is the same thing for older compilers, and
+// the crate that `LazyLock` was modeled after
+static NUMBER: Lazy<Regex> = Lazy::new(|| Regex::new(r"-?\d+").unwrap());
+
+fn count_words_slow(text: &str) -> usize {
+    // Compiled again on every call
+    Regex::new(r"\b\w+\b").unwrap().find_iter(text).count()
+}
+
+fn count_words(text: &str) -> usize {
+    WORD.find_iter(text).count()
+}
+
+fn sum_numbers(text: &str) -> i64 {
+    NUMBER
+        .find_iter(text)
+        .filter_map(|m| m.as_str().parse::<i64>().ok())
+        .sum()
+}
+
+fn main() {
+    let line = "the quick brown fox jumps over the lazy dog";
+    let runs = 200;
+
+    let start = Instant::now();
+    for _ in 0..runs {
+        count_words_slow(line);
+    }
+    let slow = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..runs {
+        count_words(line);
+    }
+    let fast = start.elapsed();
+
+    println!("Compiling every call: {:?}", slow);
+    println!("Compiled once:        {:?}", fast);
+    println!("Sum: {}", sum_numbers("3 apples, -1 pear, 10 plums"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn both_versions_agree() {
+        let text = "one, two; three";
+        assert_eq!(count_words(text), 3);
+        assert_eq!(count_words_slow(text), 3);
+    }
+
+    #[test]
+    fn statics_work_from_many_threads() {
+        let handles: Vec<_> = (0..4)
+            .map(|i| std::thread::spawn(move || sum_numbers(&format!("{} {}", i, i))))
+            .collect();
+        let sums: Vec<i64> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(sums, vec![0, 2, 4, 6]);
+    }
+}
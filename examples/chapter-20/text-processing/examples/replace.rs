@@ -0,0 +1,80 @@
+use regex::{Captures, Regex};
+use std::collections::HashMap;
+
+// `$name` in the replacement refers to a named group. `${name}` is needed
+// when the reference is followed by letters, digits, or underscores.
+fn us_dates_to_iso(text: &str) -> String {
+    let re = Regex::new(r"\b(?<m>\d{2})/(?<d>\d{2})/(?<y>\d{4})\b").unwrap();
+    re.replace_all(text, "$y-$m-$d").into_owned()
+}
+
+// A closure computes each replacement from the match
+fn mask_emails(text: &str) -> String {
+    let re = Regex::new(r"\b([\w.+-])[\w.+-]*@([\w-]+\.[\w.-]+)\b").unwrap();
+    re.replace_all(text, |caps: &Captures| {
+        format!("{}***@{}", &caps[1], &caps[2])
+    })
+    .into_owned()
+}
+
+// Fills `{name}` placeholders from a map and leaves unknown ones alone
+fn render(template: &str, values: &HashMap<&str, String>) -> String {
+    let re = Regex::new(r"\{(\w+)\}").unwrap();
+    re.replace_all(template, |caps: &Captures| match values.get(&caps[1]) {
+        Some(value) => value.clone(),
+        None => caps[0].to_string(),
+    })
+    .into_owned()
+}
+
+// Collapses runs of whitespace. `replace_all` returns a `Cow`: if nothing
+// matched, it borrows the input instead of allocating a copy.
+fn normalize_spaces(text: &str) -> std::borrow::Cow<'_, str> {
+    let re = Regex::new(r"\s{2,}").unwrap();
+    re.replace_all(text, " ")
+}
+
+fn main() {
+    println!("{}", us_dates_to_iso("Invoice 11/20/2024, due 12/20/2024"));
+    println!(
+        "{}",
+        mask_emails("Contact alice@example.com or bob.smith@mail.co.uk")
+    );
+
+    let values = HashMap::from([("name", "Alice".to_string()), ("count", 3.to_string())]);
+    println!(
+        "{}",
+        render("Hi {name}, you have {count} new {things}.", &values)
+    );
+    println!("{:?}", normalize_spaces("too    many \t spaces"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn rewrites_dates() {
+        assert_eq!(us_dates_to_iso("01/02/2003"), "2003-01-02");
+        // Not a date: the word boundaries don't match
+        assert_eq!(us_dates_to_iso("101/02/20033"), "101/02/20033");
+    }
+
+    #[test]
+    fn masks_emails() {
+        assert_eq!(mask_emails("mail x@y.io now"), "mail x***@y.io now");
+    }
+
+    #[test]
+    fn renders_templates() {
+        let values = HashMap::from([("a", "1".to_string())]);
+        assert_eq!(render("{a}{a}{b}", &values), "11{b}");
+    }
+
+    #[test]
+    fn avoids_copies_when_nothing_matches() {
+        assert!(matches!(normalize_spaces("one two"), Cow::Borrowed(_)));
+        assert!(matches!(normalize_spaces("one  two"), Cow::Owned(_)));
+    }
+}
@@ -0,0 +1,94 @@
+use regex::{Regex, RegexSet};
+
+// `^` and `$` anchor the pattern to the start and end of the input.
+// Without them, `is_match` succeeds if the pattern matches anywhere:
+// "abc#ff8800xyz" would be accepted as a color.
+fn is_hex_color(input: &str) -> bool {
+    let re = Regex::new(r"^#(?:[0-9a-fA-F]{3}){1,2}$").unwrap();
+    re.is_match(input)
+}
+
+// A simplified semantic version: 1.2.3, optionally with -alpha.1.
+// `x` mode allows whitespace and comments inside the pattern.
+fn is_version(input: &str) -> bool {
+    let re = Regex::new(
+        r"(?x)
+        ^
+        (0|[1-9]\d*) \. (0|[1-9]\d*) \. (0|[1-9]\d*)   # major.minor.patch, no leading zeros
+        (?: - [0-9A-Za-z.-]+ )?                         # optional pre-release
+        $",
+    )
+    .unwrap();
+    re.is_match(input)
+}
+
+// Good enough to catch typos in a form. Real e-mail validation means
+// sending an e-mail; no regex can tell whether an address exists.
+fn looks_like_email(input: &str) -> bool {
+    let re = Regex::new(r"^[^@\s]+@[^@\s]+\.[a-zA-Z]{2,}$").unwrap();
+    re.is_match(input)
+}
+
+// A `RegexSet` checks many patterns in a single pass and reports which
+// of them matched
+fn classify(input: &str) -> Vec<&'static str> {
+    let names = ["number", "date", "uuid", "ip"];
+    let set = RegexSet::new([
+        r"^-?\d+(\.\d+)?$",
+        r"^\d{4}-\d{2}-\d{2}$",
+        r"^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$",
+        r"^(\d{1,3}\.){3}\d{1,3}$",
+    ])
+    .unwrap();
+    set.matches(input).into_iter().map(|i| names[i]).collect()
+}
+
+fn main() {
+    for input in ["#ff8800", "#FFF", "ff8800", "#12345"] {
+        println!("{:<10} color: {}", input, is_hex_color(input));
+    }
+    for input in ["1.2.3", "1.02.3", "2.0.0-rc.1", "1.2"] {
+        println!("{:<10} version: {}", input, is_version(input));
+    }
+    for input in ["alice@example.com", "bob@localhost", "a b@c.de"] {
+        println!("{:<18} e-mail: {}", input, looks_like_email(input));
+    }
+    for input in ["42", "2024-11-20", "192.168.0.1", "hello"] {
+        println!("{:<12} is {:?}", input, classify(input));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_colors() {
+        assert!(is_hex_color("#abc"));
+        assert!(is_hex_color("#A0B1C2"));
+        assert!(!is_hex_color("#abcd"));
+        assert!(!is_hex_color("x#abc"));
+    }
+
+    #[test]
+    fn validates_versions() {
+        assert!(is_version("0.1.0"));
+        assert!(is_version("10.20.30-beta.2"));
+        assert!(!is_version("01.2.3"));
+        assert!(!is_version("1.2.3.4"));
+    }
+
+    #[test]
+    fn validates_emails() {
+        assert!(looks_like_email("first.last+tag@mail.example.org"));
+        assert!(!looks_like_email("no-at-sign.com"));
+        assert!(!looks_like_email("two@@example.com"));
+    }
+
+    #[test]
+    fn classifies_with_a_regex_set() {
+        assert_eq!(classify("-3.5"), vec!["number"]);
+        assert_eq!(classify("10.0.0.1"), vec!["ip"]);
+        assert!(classify("hello").is_empty());
+    }
+}
@@ -0,0 +1,318 @@
+//! Parses the text logs from Chapter 5 (`env_logger` and the `tracing`
+//! formatter) into events with the same shape as the JSON formatter
+
+use regex::Regex;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+// Terminal colors, which the `tracing` formatter writes even into a pipe
+static ANSI_COLOR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\x1b\[[0-9;]*m").unwrap());
+
+// [2024-10-25T05:47:13Z WARN  levels] This is a warning message.
+static ENV_LOGGER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\[(?<ts>\S+) (?<level>[A-Z]+)\s+(?<target>[^\]]+)\] (?<rest>.*)$").unwrap()
+});
+
+// 2024-10-26T06:04:16.105654Z  INFO main:compute{work_units=2}: span: Performing computation
+static TRACING: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?x)
+        ^(?<ts>\d{4}-\d{2}-\d{2}T\S+)
+        \s+(?<level>[A-Z]+)
+        \s(?:(?<spans>\w+(?:\{[^}]*\})?(?::\w+(?:\{[^}]*\})?)*):\s)?   # optional span list
+        (?<target>[\w:]+):\s
+        (?<rest>.*)$",
+    )
+    .unwrap()
+});
+
+// A span in the list: `compute{work_units=2}` or just `main`
+static SPAN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?<name>\w+)(?:\{(?<fields>[^}]*)\})?").unwrap());
+
+// The message, followed by any number of `key=value` fields
+static MESSAGE_AND_FIELDS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^(?<message>.*?)(?<fields>(?:\s*\w+=(?:"(?:[^"\\]|\\.)*"|\S+))*)$"#).unwrap()
+});
+
+static FIELD: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?<key>\w+)=(?:"(?<quoted>(?:[^"\\]|\\.)*)"|(?<bare>\S+))"#).unwrap()
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "TRACE" => Ok(Level::Trace),
+            "DEBUG" => Ok(Level::Debug),
+            "INFO" => Ok(Level::Info),
+            "WARN" => Ok(Level::Warn),
+            "ERROR" => Ok(Level::Error),
+            _ => Err(format!("Unknown level: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Level::Trace => "TRACE",
+            Level::Debug => "DEBUG",
+            Level::Info => "INFO",
+            Level::Warn => "WARN",
+            Level::Error => "ERROR",
+        };
+        f.write_str(name)
+    }
+}
+
+impl Serialize for Level {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Fields in the order they appeared in the line. A `serde_json::Map`
+/// would sort the keys alphabetically.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Fields(pub Vec<(String, Value)>);
+
+impl Fields {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.0.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+}
+
+impl Serialize for Fields {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in &self.0 {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    pub name: String,
+    pub fields: Fields,
+}
+
+// Same layout as the JSON formatter: the fields, then the span name
+impl Serialize for Span {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.fields.0.len() + 1))?;
+        for (key, value) in &self.fields.0 {
+            map.serialize_entry(key, value)?;
+        }
+        map.serialize_entry("name", &self.name)?;
+        map.end()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct Event {
+    pub timestamp: String,
+    pub level: Level,
+    pub fields: Fields,
+    pub target: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub spans: Vec<Span>,
+}
+
+/// Parses one line of `env_logger` or `tracing` output.
+/// Returns `None` if the line has neither format.
+pub fn parse_line(line: &str) -> Option<Event> {
+    // Borrows the line unchanged when it has no colors
+    let line = ANSI_COLOR.replace_all(line, "");
+    let (caps, is_tracing) = match TRACING.captures(&line) {
+        Some(caps) => (caps, true),
+        None => (ENV_LOGGER.captures(&line)?, false),
+    };
+    let level = caps["level"].parse().ok()?;
+
+    // `env_logger` doesn't print fields, so the whole rest is the message
+    let fields = if is_tracing {
+        parse_message_and_fields(&caps["rest"])
+    } else {
+        Fields(vec![("message".to_string(), Value::from(&caps["rest"]))])
+    };
+
+    let spans: Vec<Span> = caps
+        .name("spans")
+        .map(|m| {
+            SPAN.captures_iter(m.as_str())
+                .map(|c| parse_span(&c))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(Event {
+        timestamp: caps["ts"].to_string(),
+        level,
+        fields,
+        target: caps["target"].to_string(),
+        span: spans.last().cloned(),
+        spans,
+    })
+}
+
+fn parse_span(caps: &regex::Captures) -> Span {
+    Span {
+        name: caps["name"].to_string(),
+        fields: caps
+            .name("fields")
+            .map(|m| parse_fields(m.as_str()))
+            .unwrap_or_default(),
+    }
+}
+
+fn parse_message_and_fields(rest: &str) -> Fields {
+    // Always matches: both groups can be empty
+    let caps = MESSAGE_AND_FIELDS.captures(rest).unwrap();
+    let mut fields = parse_fields(&caps["fields"]);
+    let message = caps["message"].trim();
+    if !message.is_empty() {
+        fields
+            .0
+            .insert(0, ("message".to_string(), Value::from(message)));
+    }
+    fields
+}
+
+fn parse_fields(input: &str) -> Fields {
+    Fields(
+        FIELD
+            .captures_iter(input)
+            .map(|caps| {
+                let value = match (caps.name("quoted"), caps.name("bare")) {
+                    (Some(quoted), _) => Value::from(unescape(quoted.as_str())),
+                    (_, Some(bare)) => typed_value(bare.as_str()),
+                    _ => unreachable!("one of the alternatives always matches"),
+                };
+                (caps["key"].to_string(), value)
+            })
+            .collect(),
+    )
+}
+
+// Unquoted values become numbers or booleans when they look like them
+fn typed_value(raw: &str) -> Value {
+    if let Ok(n) = raw.parse::<i64>() {
+        Value::from(n)
+    } else if let Ok(x) = raw.parse::<f64>() {
+        Value::from(x)
+    } else if let Ok(b) = raw.parse::<bool>() {
+        Value::from(b)
+    } else {
+        Value::from(raw)
+    }
+}
+
+fn unescape(quoted: &str) -> String {
+    quoted.replace(r#"\""#, "\"").replace(r"\\", r"\")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_env_logger_lines() {
+        let event =
+            parse_line("[2024-10-25T05:47:13Z WARN  levels] This is a warning message.").unwrap();
+        assert_eq!(event.level, Level::Warn);
+        assert_eq!(event.target, "levels");
+        assert_eq!(
+            event.fields.get("message"),
+            Some(&json!("This is a warning message."))
+        );
+    }
+
+    #[test]
+    fn parses_tracing_fields_with_types() {
+        let event = parse_line(
+            r#"2024-10-26T05:52:54.508894Z ERROR basic: Task failed error="An error occurred" code=7 retry=false"#,
+        )
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&event.fields).unwrap(),
+            json!({"message": "Task failed", "error": "An error occurred", "code": 7, "retry": false})
+        );
+    }
+
+    #[test]
+    fn parses_span_lists() {
+        let event = parse_line(
+            "2024-10-26T06:04:16.105746Z  INFO main:compute{work_units=2}:nested_compute: span: Performing nested computation",
+        )
+        .unwrap();
+        let names: Vec<_> = event.spans.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["main", "compute", "nested_compute"]);
+        assert_eq!(event.spans[1].fields.get("work_units"), Some(&json!(2)));
+        assert_eq!(event.target, "span");
+    }
+
+    #[test]
+    fn matches_the_json_formatter() {
+        let event = parse_line(
+            r#"2024-10-28T18:15:40.066587Z  INFO request{request_id="req-42"}:handle_order{order_id=7 amount=1500.0}: json: Order received items=3"#,
+        )
+        .unwrap();
+        // The same line as the JSON formatter output in Chapter 5
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"timestamp":"2024-10-28T18:15:40.066587Z","level":"INFO","fields":{"message":"Order received","items":3},"target":"json","span":{"order_id":7,"amount":1500.0,"name":"handle_order"},"spans":[{"request_id":"req-42","name":"request"},{"order_id":7,"amount":1500.0,"name":"handle_order"}]}"#
+        );
+    }
+
+    #[test]
+    fn keeps_module_paths_and_escaped_quotes() {
+        let event =
+            parse_line(r#"2024-10-26T05:52:54Z DEBUG app::db: Query sql="SELECT \"id\"""#).unwrap();
+        assert_eq!(event.target, "app::db");
+        assert!(event.spans.is_empty());
+        assert_eq!(event.fields.get("sql"), Some(&json!(r#"SELECT "id""#)));
+    }
+
+    #[test]
+    fn ignores_terminal_colors() {
+        let colored = "\x1b[2m2024-10-26T05:52:54.508777Z\x1b[0m \x1b[34mDEBUG\x1b[0m \x1b[2mbasic\x1b[0m\x1b[2m:\x1b[0m Current value \x1b[3mvalue\x1b[0m\x1b[2m=\x1b[0m42";
+        let plain = "2024-10-26T05:52:54.508777Z DEBUG basic: Current value value=42";
+        assert_eq!(parse_line(colored), parse_line(plain));
+        assert!(parse_line(plain).is_some());
+    }
+
+    #[test]
+    fn rejects_other_lines() {
+        assert_eq!(parse_line("hello world"), None);
+        assert_eq!(
+            parse_line("[2024-10-25T05:47:13Z LOUD x] no such level"),
+            None
+        );
+        assert_eq!(parse_line(""), None);
+    }
+
+    #[test]
+    fn orders_levels() {
+        assert!(Level::Error > Level::Warn);
+        assert_eq!("warn".parse::<Level>(), Ok(Level::Warn));
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::ExitCode;
+use text_processing::{parse_line, Level};
+
+const USAGE: &str = "Usage: logparse [--level LEVEL] [FILE]";
+
+// Reads text logs from a file or stdin and writes one JSON object per line.
+// Unrecognized lines and the totals go to stderr.
+fn main() -> ExitCode {
+    let mut min_level = Level::Trace;
+    let mut path = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--level" => match args.next().map(|l| l.parse()) {
+                Some(Ok(level)) => min_level = level,
+                Some(Err(e)) => return fail(&e),
+                None => return fail(USAGE),
+            },
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            _ => path = Some(arg),
+        }
+    }
+
+    let input: Box<dyn BufRead> = match &path {
+        Some(path) => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(e) => return fail(&format!("Cannot open {}: {}", path, e)),
+        },
+        None => Box::new(io::stdin().lock()),
+    };
+
+    let mut out = io::stdout().lock();
+    let mut counts = BTreeMap::new();
+    let mut skipped = 0;
+    for (number, line) in input.lines().enumerate() {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => return fail(&format!("Read error: {}", e)),
+        };
+        let Some(event) = parse_line(&line) else {
+            if !line.trim().is_empty() {
+                eprintln!("Skipped line {}: {}", number + 1, line);
+                skipped += 1;
+            }
+            continue;
+        };
+        *counts.entry(event.level).or_insert(0) += 1;
+        if event.level >= min_level {
+            let json = serde_json::to_string(&event).expect("events always serialize");
+            if writeln!(out, "{}", json).is_err() {
+                // The reader went away, for example `logparse | head`
+                return ExitCode::SUCCESS;
+            }
+        }
+    }
+
+    let totals: Vec<String> = counts
+        .iter()
+        .rev()
+        .map(|(level, count)| format!("{} {}", level, count))
+        .collect();
+    eprintln!("Parsed: {}; skipped: {}", totals.join(", "), skipped);
+    ExitCode::SUCCESS
+}
+
+fn fail(message: &str) -> ExitCode {
+    eprintln!("{}", message);
+    ExitCode::FAILURE
+}
@@ -0,0 +1,181 @@
+//! Packs a directory, such as the `sample_dir` of Chapter 1's
+//! `file_collector`, into `.tar.gz` and `.zip` archives and unpacks them.
+//! Files are streamed through the encoders, never read into memory whole.
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Seek, Write};
+use std::path::{Path, PathBuf};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    TarGz,
+    Zip,
+}
+
+impl Format {
+    /// Picks the format from the file name: `.tar.gz`, `.tgz`, or `.zip`
+    pub fn from_path(path: &Path) -> Option<Format> {
+        let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Format::TarGz)
+        } else if name.ends_with(".zip") {
+            Some(Format::Zip)
+        } else {
+            None
+        }
+    }
+}
+
+/// Every file below `dir`, relative to it, sorted so that archives of the
+/// same directory list their entries in the same order
+pub fn collect_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(current) = pending.pop() {
+        for entry in fs::read_dir(&current)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                pending.push(entry.path());
+            } else if file_type.is_file() {
+                files.push(entry.path().strip_prefix(dir).unwrap().to_path_buf());
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Writes a gzip-compressed tar archive of `dir` into `writer`.
+/// Returns the number of files.
+pub fn create_tar_gz<W: Write>(dir: &Path, writer: W) -> io::Result<usize> {
+    write_tar_gz(dir, &collect_files(dir)?, writer)
+}
+
+fn write_tar_gz<W: Write>(dir: &Path, files: &[PathBuf], writer: W) -> io::Result<usize> {
+    let encoder = GzEncoder::new(writer, Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+    // Fixed timestamps and owners: the same files give the same archive
+    builder.mode(tar::HeaderMode::Deterministic);
+
+    for relative in files {
+        // Copies the file into the archive in chunks
+        builder.append_path_with_name(dir.join(relative), relative)?;
+    }
+
+    // Both layers have to be finished: `into_inner` writes the tar end
+    // marker, and `finish` the gzip trailer with the checksum
+    builder.into_inner()?.finish()?.flush()?;
+    Ok(files.len())
+}
+
+/// Unpacks a `.tar.gz` stream into `dest`. Returns the names of entries
+/// that were skipped because they would be written outside `dest`.
+pub fn extract_tar_gz<R: Read>(reader: R, dest: &Path) -> io::Result<Vec<String>> {
+    let mut archive = tar::Archive::new(GzDecoder::new(reader));
+    let mut skipped = Vec::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        // `unpack_in` strips a leading `/`, and for paths with `..` it
+        // writes nothing and returns `false` instead of an error
+        if !entry.unpack_in(dest)? {
+            skipped.push(String::from_utf8_lossy(&entry.path_bytes()).into_owned());
+        }
+    }
+    Ok(skipped)
+}
+
+/// Writes a zip archive of `dir` into `writer`. Returns the number of files.
+pub fn create_zip<W: Write + Seek>(dir: &Path, writer: W) -> io::Result<usize> {
+    write_zip(dir, &collect_files(dir)?, writer)
+}
+
+fn write_zip<W: Write + Seek>(dir: &Path, files: &[PathBuf], writer: W) -> io::Result<usize> {
+    let mut zip = ZipWriter::new(writer);
+    let options = SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Deflated)
+        .unix_permissions(0o644);
+
+    for relative in files {
+        // Zip entry names always use `/`, also on Windows
+        let name: Vec<_> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect();
+        zip.start_file(name.join("/"), options)?;
+        let mut file = File::open(dir.join(relative))?;
+        io::copy(&mut file, &mut zip)?;
+    }
+
+    // Writes the central directory, the table of contents at the end
+    zip.finish()?.flush()?;
+    Ok(files.len())
+}
+
+/// Unpacks a zip archive into `dest`. Returns the names of entries that
+/// were skipped because they would be written outside `dest`.
+pub fn extract_zip<R: Read + Seek>(reader: R, dest: &Path) -> io::Result<Vec<String>> {
+    let mut archive = ZipArchive::new(reader)?;
+    let mut skipped = Vec::new();
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        // `enclosed_name` is `None` for absolute paths and paths with `..`
+        let Some(relative) = entry.enclosed_name() else {
+            skipped.push(entry.name().to_string());
+            continue;
+        };
+        let path = dest.join(relative);
+        if entry.is_dir() {
+            fs::create_dir_all(&path)?;
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut out = File::create(&path)?;
+        io::copy(&mut entry, &mut out)?;
+    }
+    Ok(skipped)
+}
+
+/// Creates an archive file in the format given by its extension.
+/// The archive may be inside `dir`; it isn't packed into itself.
+pub fn create(dir: &Path, archive: &Path) -> io::Result<usize> {
+    let format = Format::from_path(archive).ok_or_else(|| unknown_format(archive))?;
+    let file = BufWriter::new(File::create(archive)?);
+
+    // Compared as canonical paths, because `dir` and `archive` may be
+    // written differently, such as `.` and `./backup.zip`
+    let own_path = archive.canonicalize()?;
+    let dir = dir.canonicalize()?;
+    let mut files = collect_files(&dir)?;
+    files.retain(|relative| dir.join(relative) != own_path);
+
+    match format {
+        Format::TarGz => write_tar_gz(&dir, &files, file),
+        Format::Zip => write_zip(&dir, &files, file),
+    }
+}
+
+/// Extracts an archive file in the format given by its extension
+pub fn extract(archive: &Path, dest: &Path) -> io::Result<Vec<String>> {
+    let format = Format::from_path(archive).ok_or_else(|| unknown_format(archive))?;
+    let file = BufReader::new(File::open(archive)?);
+    fs::create_dir_all(dest)?;
+    match format {
+        Format::TarGz => extract_tar_gz(file, dest),
+        Format::Zip => extract_zip(file, dest),
+    }
+}
+
+fn unknown_format(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("{}: expected a .tar.gz, .tgz, or .zip file", path.display()),
+    )
+}
@@ -0,0 +1,37 @@
+use std::path::Path;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage:
+  archive create <DIR> <ARCHIVE>     Pack DIR into ARCHIVE (.tar.gz, .tgz, or .zip)
+  archive extract <ARCHIVE> <DIR>    Unpack ARCHIVE into DIR";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["create", dir, archive] => {
+            archive::create(Path::new(dir), Path::new(archive)).map(|count| {
+                let size = std::fs::metadata(archive).map(|m| m.len()).unwrap_or(0);
+                println!("Packed {} files into {} ({} bytes)", count, archive, size);
+            })
+        }
+        ["extract", archive, dir] => {
+            archive::extract(Path::new(archive), Path::new(dir)).map(|skipped| {
+                for name in skipped {
+                    eprintln!("Skipped unsafe entry: {}", name);
+                }
+                println!("Extracted {} into {}", archive, dir);
+            })
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
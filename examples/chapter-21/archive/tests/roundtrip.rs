@@ -0,0 +1,160 @@
+use archive::{
+    collect_files, create, create_tar_gz, create_zip, extract, extract_tar_gz, extract_zip,
+};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs;
+use std::io::{Cursor, Write};
+use std::path::Path;
+
+// A tree with nested directories, text, binary data, an empty file,
+// and a file large enough that loading it whole would be noticeable
+fn make_tree(root: &Path) {
+    fs::create_dir_all(root.join("docs/nested")).unwrap();
+    fs::write(root.join("file1.txt"), "Hello, World!\n").unwrap();
+    fs::write(root.join("docs/readme.md"), "# Readme\n").unwrap();
+    fs::write(root.join("docs/nested/empty"), "").unwrap();
+    let binary: Vec<u8> = (0..=255).collect();
+    fs::write(root.join("docs/nested/bytes.bin"), binary).unwrap();
+    let large: String = (0..200_000).map(|i| format!("line {}\n", i)).collect();
+    fs::write(root.join("large.log"), large).unwrap();
+}
+
+fn assert_same_tree(a: &Path, b: &Path) {
+    let files = collect_files(a).unwrap();
+    assert_eq!(files, collect_files(b).unwrap());
+    for file in files {
+        assert_eq!(
+            fs::read(a.join(&file)).unwrap(),
+            fs::read(b.join(&file)).unwrap(),
+            "{} differs",
+            file.display()
+        );
+    }
+}
+
+#[test]
+fn tar_gz_roundtrip() {
+    let work = tempfile::tempdir().unwrap();
+    let (src, dest) = (work.path().join("src"), work.path().join("dest"));
+    make_tree(&src);
+    let archive = work.path().join("backup.tar.gz");
+
+    assert_eq!(create(&src, &archive).unwrap(), 5);
+    extract(&archive, &dest).unwrap();
+
+    assert_same_tree(&src, &dest);
+    // Repetitive text compresses well
+    assert!(
+        fs::metadata(&archive).unwrap().len()
+            < fs::metadata(src.join("large.log")).unwrap().len() / 4
+    );
+}
+
+#[test]
+fn zip_roundtrip() {
+    let work = tempfile::tempdir().unwrap();
+    let (src, dest) = (work.path().join("src"), work.path().join("dest"));
+    make_tree(&src);
+    let archive = work.path().join("backup.zip");
+
+    assert_eq!(create(&src, &archive).unwrap(), 5);
+    let skipped = extract(&archive, &dest).unwrap();
+
+    assert!(skipped.is_empty());
+    assert_same_tree(&src, &dest);
+}
+
+#[test]
+fn archives_work_in_memory() {
+    let work = tempfile::tempdir().unwrap();
+    make_tree(work.path());
+
+    // Any `Write` works for tar.gz, any `Write + Seek` for zip
+    let mut tar_gz = Vec::new();
+    create_tar_gz(work.path(), &mut tar_gz).unwrap();
+    let mut zip = Cursor::new(Vec::new());
+    create_zip(work.path(), &mut zip).unwrap();
+
+    assert_eq!(&tar_gz[..2], [0x1f, 0x8b]); // gzip magic number
+    assert_eq!(&zip.get_ref()[..4], b"PK\x03\x04");
+}
+
+#[test]
+fn tar_gz_is_reproducible() {
+    let work = tempfile::tempdir().unwrap();
+    make_tree(work.path());
+    let (mut first, mut second) = (Vec::new(), Vec::new());
+    create_tar_gz(work.path(), &mut first).unwrap();
+    create_tar_gz(work.path(), &mut second).unwrap();
+    assert_eq!(first, second);
+}
+
+#[test]
+fn zip_entries_outside_the_destination_are_skipped() {
+    // Build a malicious archive by hand
+    let mut buffer = Cursor::new(Vec::new());
+    let mut zip = zip::ZipWriter::new(&mut buffer);
+    let options = zip::write::SimpleFileOptions::default();
+    zip.start_file("ok.txt", options).unwrap();
+    zip.write_all(b"fine").unwrap();
+    zip.start_file("../escaped.txt", options).unwrap();
+    zip.write_all(b"evil").unwrap();
+    zip.finish().unwrap();
+
+    let work = tempfile::tempdir().unwrap();
+    let dest = work.path().join("dest");
+    buffer.set_position(0);
+    let skipped = extract_zip(buffer, &dest).unwrap();
+
+    assert_eq!(skipped, ["../escaped.txt"]);
+    assert!(dest.join("ok.txt").exists());
+    assert!(!work.path().join("escaped.txt").exists());
+}
+
+#[test]
+fn tar_entries_outside_the_destination_are_skipped() {
+    // `Builder::append_data` rejects `..`, so the name is written into
+    // the header directly
+    let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+    for (name, data) in [("ok.txt", b"fine"), ("../escaped.txt", b"evil")] {
+        let mut header = tar::Header::new_gnu();
+        header.as_old_mut().name[..name.len()].copy_from_slice(name.as_bytes());
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder.append(&header, &data[..]).unwrap();
+    }
+    let archive = builder.into_inner().unwrap().finish().unwrap();
+
+    let work = tempfile::tempdir().unwrap();
+    let dest = work.path().join("dest");
+    fs::create_dir(&dest).unwrap();
+    let skipped = extract_tar_gz(&archive[..], &dest).unwrap();
+
+    assert_eq!(skipped, ["../escaped.txt"]);
+    assert!(dest.join("ok.txt").exists());
+    assert!(!work.path().join("escaped.txt").exists());
+}
+
+#[test]
+fn archives_inside_the_directory_skip_themselves() {
+    let work = tempfile::tempdir().unwrap();
+    make_tree(work.path());
+    for name in ["backup.tar.gz", "backup.zip"] {
+        let archive = work.path().join(name);
+        assert_eq!(create(work.path(), &archive).unwrap(), 5, "{}", name);
+
+        let dest = tempfile::tempdir().unwrap();
+        extract(&archive, dest.path()).unwrap();
+        assert!(!dest.path().join(name).exists(), "{}", name);
+        fs::remove_file(archive).unwrap();
+    }
+}
+
+#[test]
+fn unknown_extensions_are_rejected() {
+    let work = tempfile::tempdir().unwrap();
+    let err = create(work.path(), &work.path().join("backup.rar")).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+}
@@ -0,0 +1,132 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use tempfile::NamedTempFile;
+
+/// Replaces the file at `path` with whatever `write` produces.
+/// Readers see either the complete old file or the complete new one:
+/// never a half-written file, even if the program crashes midway.
+fn write_atomic<F>(path: &Path, write: F) -> io::Result<()>
+where
+    F: FnOnce(&mut fs::File) -> io::Result<()>,
+{
+    // The temporary file must be in the same directory: a rename
+    // is only atomic within one file system
+    let dir = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let mut temp = NamedTempFile::new_in(dir)?;
+
+    // If `write` fails, `temp` is deleted when it goes out of scope
+    write(temp.as_file_mut())?;
+
+    // Flush the data to the disk before the rename makes it visible.
+    // Otherwise, a power failure could leave an empty file under the new name.
+    temp.as_file().sync_all()?;
+
+    // `persist` renames the file over the target
+    temp.persist(path).map_err(|e| e.error)?;
+    Ok(())
+}
+
+// The same idea with only the standard library, for a single writer
+fn write_atomic_std(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let temp = path.with_extension("tmp");
+    let mut file = fs::File::create(&temp)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    fs::rename(&temp, path)
+}
+
+fn main() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let config = dir.path().join("config.json");
+
+    write_atomic(&config, |f| f.write_all(br#"{"version": 1}"#))?;
+    println!("Written:   {}", fs::read_to_string(&config)?);
+
+    let result = write_atomic(&config, |f| {
+        f.write_all(br#"{"version": "#)?;
+        Err(io::Error::other("serializer failed halfway"))
+    });
+    println!("Failed:    {:?}", result.map_err(|e| e.to_string()));
+    println!("Unchanged: {}", fs::read_to_string(&config)?);
+
+    write_atomic_std(&config, br#"{"version": 2}"#)?;
+    println!("Replaced:  {}", fs::read_to_string(&config)?);
+
+    let names: Vec<_> = fs::read_dir(dir.path())?
+        .map(|e| e.map(|e| e.file_name()))
+        .collect::<Result<_, _>>()?;
+    println!("Files:     {:?}", names);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file_count(dir: &Path) -> usize {
+        fs::read_dir(dir).unwrap().count()
+    }
+
+    #[test]
+    fn creates_and_replaces() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.txt");
+        write_atomic(&path, |f| f.write_all(b"first")).unwrap();
+        write_atomic(&path, |f| f.write_all(b"second")).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+        assert_eq!(file_count(dir.path()), 1);
+    }
+
+    #[test]
+    fn failure_keeps_the_old_file_and_no_leftovers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.txt");
+        fs::write(&path, "old").unwrap();
+
+        let result = write_atomic(&path, |f| {
+            f.write_all(b"partial")?;
+            Err(io::Error::other("boom"))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "old");
+        assert_eq!(file_count(dir.path()), 1);
+    }
+
+    #[test]
+    fn readers_never_see_partial_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.txt");
+        let big_a = vec![b'a'; 1 << 20];
+        let big_b = vec![b'b'; 1 << 20];
+        write_atomic(&path, |f| f.write_all(&big_a)).unwrap();
+
+        let reader_path = path.clone();
+        let reader = std::thread::spawn(move || {
+            for _ in 0..200 {
+                let data = fs::read(&reader_path).unwrap();
+                assert_eq!(data.len(), 1 << 20);
+                assert!(data.iter().all(|&b| b == data[0]));
+            }
+        });
+        for i in 0..20 {
+            let data = if i % 2 == 0 { &big_b } else { &big_a };
+            write_atomic(&path, |f| f.write_all(data)).unwrap();
+        }
+        reader.join().unwrap();
+    }
+
+    #[test]
+    fn std_version_replaces() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.json");
+        write_atomic_std(&path, b"1").unwrap();
+        write_atomic_std(&path, b"2").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "2");
+        assert_eq!(file_count(dir.path()), 1);
+    }
+}
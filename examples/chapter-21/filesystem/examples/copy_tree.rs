@@ -0,0 +1,167 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Default, PartialEq)]
+struct CopyStats {
+    dirs: usize,
+    files: usize,
+    bytes: u64,
+    skipped: Vec<PathBuf>,
+}
+
+/// Copies `src` and everything below it into `dst`.
+/// Symbolic links are skipped and reported: following them could copy
+/// files from outside the tree or loop forever.
+fn copy_tree(src: &Path, dst: &Path) -> io::Result<CopyStats> {
+    let mut stats = CopyStats::default();
+    // Refuse to copy a directory into itself, which would never finish
+    if dst.starts_with(src) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} is inside {}", dst.display(), src.display()),
+        ));
+    }
+    copy_dir(src, dst, &mut stats)?;
+    Ok(stats)
+}
+
+fn copy_dir(src: &Path, dst: &Path, stats: &mut CopyStats) -> io::Result<()> {
+    fs::create_dir_all(dst)?;
+    stats.dirs += 1;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let target = dst.join(entry.file_name());
+        // `DirEntry::file_type` doesn't follow symlinks, unlike `Path::is_dir`
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            copy_dir(&entry.path(), &target, stats)?;
+        } else if file_type.is_file() {
+            // `fs::copy` also copies the permission bits
+            stats.bytes += fs::copy(entry.path(), &target)?;
+            stats.files += 1;
+        } else {
+            stats.skipped.push(entry.path());
+        }
+    }
+    Ok(())
+}
+
+/// Like `get_files` in Chapter 1's `file_collector`, but recursive:
+/// every file below `dir`, relative to it, in a stable order
+fn collect_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(current) = pending.pop() {
+        for entry in fs::read_dir(&current)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                pending.push(entry.path());
+            } else if file_type.is_file() {
+                let relative = entry.path().strip_prefix(dir).unwrap().to_path_buf();
+                files.push(relative);
+            }
+        }
+    }
+    // `read_dir` returns entries in no particular order
+    files.sort();
+    Ok(files)
+}
+
+fn main() -> io::Result<()> {
+    let work = tempfile::tempdir()?;
+    let src = work.path().join("project");
+    fs::create_dir_all(src.join("src/bin"))?;
+    fs::create_dir_all(src.join("empty"))?;
+    fs::write(src.join("Cargo.toml"), "[package]\nname = \"demo\"\n")?;
+    fs::write(src.join("src/main.rs"), "fn main() {}\n")?;
+    fs::write(src.join("src/bin/tool.rs"), "fn main() {}\n")?;
+    #[cfg(unix)]
+    std::os::unix::fs::symlink("/etc", src.join("etc-link"))?;
+
+    let dst = work.path().join("backup");
+    let stats = copy_tree(&src, &dst)?;
+    println!(
+        "Copied {} directories, {} files, {} bytes",
+        stats.dirs, stats.files, stats.bytes
+    );
+    for path in &stats.skipped {
+        println!(
+            "Skipped: {}",
+            path.strip_prefix(work.path()).unwrap().display()
+        );
+    }
+    for file in collect_files(&dst)? {
+        println!("  {}", file.display());
+    }
+
+    let err = copy_tree(&src, &src.join("copy")).unwrap_err();
+    println!(
+        "Error: {}",
+        err.to_string()
+            .replace(&*work.path().to_string_lossy(), "<tmp>")
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_tree(root: &Path) {
+        fs::create_dir_all(root.join("a/b/c")).unwrap();
+        fs::write(root.join("top.txt"), "top").unwrap();
+        fs::write(root.join("a/one.txt"), "1").unwrap();
+        fs::write(root.join("a/b/c/deep.txt"), "deep").unwrap();
+    }
+
+    #[test]
+    fn copies_every_file_with_contents() {
+        let work = tempfile::tempdir().unwrap();
+        let (src, dst) = (work.path().join("src"), work.path().join("dst"));
+        make_tree(&src);
+
+        let stats = copy_tree(&src, &dst).unwrap();
+
+        assert_eq!((stats.dirs, stats.files, stats.bytes), (4, 3, 8));
+        assert_eq!(collect_files(&src).unwrap(), collect_files(&dst).unwrap());
+        assert_eq!(
+            fs::read_to_string(dst.join("a/b/c/deep.txt")).unwrap(),
+            "deep"
+        );
+    }
+
+    #[test]
+    fn collects_relative_paths_in_order() {
+        let work = tempfile::tempdir().unwrap();
+        make_tree(work.path());
+        let files = collect_files(work.path()).unwrap();
+        let expected: Vec<PathBuf> = ["a/b/c/deep.txt", "a/one.txt", "top.txt"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+        assert_eq!(files, expected);
+    }
+
+    #[test]
+    fn refuses_to_copy_into_itself() {
+        let work = tempfile::tempdir().unwrap();
+        make_tree(work.path());
+        let err = copy_tree(work.path(), &work.path().join("a/copy")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn skips_symlinks() {
+        let work = tempfile::tempdir().unwrap();
+        let src = work.path().join("src");
+        make_tree(&src);
+        std::os::unix::fs::symlink(&src, src.join("loop")).unwrap();
+
+        let stats = copy_tree(&src, &work.path().join("dst")).unwrap();
+        assert_eq!(stats.skipped, vec![src.join("loop")]);
+        assert_eq!(stats.files, 3);
+    }
+}
@@ -0,0 +1,116 @@
+use std::fs::{File, OpenOptions, TryLockError};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::thread;
+
+// Increments a number stored in a file. Several processes can run this at
+// the same time: the exclusive lock makes the read-modify-write a unit.
+fn increment(path: &Path) -> io::Result<u64> {
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)?;
+
+    // Blocks until no other handle holds a lock on the file
+    file.lock()?;
+
+    let mut text = String::new();
+    file.read_to_string(&mut text)?;
+    let value = text.trim().parse::<u64>().unwrap_or(0) + 1;
+
+    file.seek(SeekFrom::Start(0))?;
+    file.set_len(0)?;
+    write!(file, "{}", value)?;
+
+    // The lock is also released when `file` is closed
+    file.unlock()?;
+    Ok(value)
+}
+
+// A lock file that keeps a second instance of a program from starting
+fn single_instance(path: &Path) -> io::Result<Option<File>> {
+    let file = File::create(path)?;
+    match file.try_lock() {
+        Ok(()) => Ok(Some(file)),
+        Err(TryLockError::WouldBlock) => Ok(None),
+        Err(TryLockError::Error(e)) => Err(e),
+    }
+}
+
+fn main() -> io::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let counter = dir.path().join("counter.txt");
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let counter = counter.clone();
+            thread::spawn(move || {
+                for _ in 0..100 {
+                    increment(&counter).unwrap();
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    println!("Counter: {}", std::fs::read_to_string(&counter)?);
+
+    let lock_path = dir.path().join("app.lock");
+    let first = single_instance(&lock_path)?;
+    println!("First instance started:  {}", first.is_some());
+    println!(
+        "Second instance started: {}",
+        single_instance(&lock_path)?.is_some()
+    );
+    drop(first);
+    println!(
+        "After the first exited:  {}",
+        single_instance(&lock_path)?.is_some()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concurrent_increments_are_not_lost() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("n");
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let path = path.clone();
+                thread::spawn(move || {
+                    for _ in 0..50 {
+                        increment(&path).unwrap();
+                    }
+                })
+            })
+            .collect();
+        handles.into_iter().for_each(|h| h.join().unwrap());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "200");
+    }
+
+    #[test]
+    fn shared_locks_allow_readers_but_not_writers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data");
+        std::fs::write(&path, "x").unwrap();
+
+        let reader1 = File::open(&path).unwrap();
+        let reader2 = File::open(&path).unwrap();
+        let writer = OpenOptions::new().write(true).open(&path).unwrap();
+
+        reader1.lock_shared().unwrap();
+        assert!(reader2.try_lock_shared().is_ok());
+        assert!(matches!(writer.try_lock(), Err(TryLockError::WouldBlock)));
+
+        reader1.unlock().unwrap();
+        reader2.unlock().unwrap();
+        assert!(writer.try_lock().is_ok());
+    }
+}
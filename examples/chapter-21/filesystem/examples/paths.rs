@@ -0,0 +1,99 @@
+use std::path::{Path, PathBuf};
+
+fn describe(path: &Path) {
+    println!("{}", path.display());
+    println!("  parent:    {:?}", path.parent());
+    println!("  file name: {:?}", path.file_name());
+    println!("  stem:      {:?}", path.file_stem());
+    println!("  extension: {:?}", path.extension());
+}
+
+// Builds "<dir>/<name>.<ext>" without string formatting
+fn output_path(dir: &Path, input: &Path, ext: &str) -> Option<PathBuf> {
+    let name = input.file_name()?;
+    Some(dir.join(name).with_extension(ext))
+}
+
+// A path relative to a base directory, as shown to a user
+fn relative_to<'a>(path: &'a Path, base: &Path) -> &'a Path {
+    path.strip_prefix(base).unwrap_or(path)
+}
+
+fn main() {
+    // `Path` is to `PathBuf` what `&str` is to `String`
+    describe(Path::new("sample_dir/reports/2024.tar.gz"));
+    describe(Path::new("/"));
+
+    let mut path = PathBuf::from("sample_dir");
+    path.push("reports");
+    path.push("summary.txt");
+    println!("Built:     {}", path.display());
+    path.set_extension("csv");
+    println!("Changed:   {}", path.display());
+    path.pop();
+    println!("Popped:    {}", path.display());
+
+    // Pushing an absolute path replaces the whole path
+    let mut base = PathBuf::from("uploads");
+    base.push("/etc/passwd");
+    println!("Replaced:  {}", base.display());
+
+    println!(
+        "Output:    {:?}",
+        output_path(Path::new("out"), Path::new("data/input.json"), "csv")
+    );
+    println!(
+        "Relative:  {}",
+        relative_to(
+            Path::new("/home/alice/project/src/main.rs"),
+            Path::new("/home/alice/project")
+        )
+        .display()
+    );
+
+    // Components and ancestors walk the path without string splitting
+    let path = Path::new("/home/alice/../bob/./notes.txt");
+    let components: Vec<_> = path.components().collect();
+    println!("{:?}", components);
+    for ancestor in Path::new("a/b/c").ancestors() {
+        println!("ancestor:  {:?}", ancestor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extension_is_only_the_last_part() {
+        let path = Path::new("backup.tar.gz");
+        assert_eq!(path.extension().unwrap(), "gz");
+        assert_eq!(path.file_stem().unwrap(), "backup.tar");
+        assert_eq!(Path::new(".bashrc").extension(), None);
+    }
+
+    #[test]
+    fn builds_output_paths() {
+        let out = output_path(Path::new("out"), Path::new("a/b/report.txt"), "html").unwrap();
+        assert_eq!(out, Path::new("out").join("report.html"));
+        assert_eq!(output_path(Path::new("out"), Path::new("/"), "html"), None);
+    }
+
+    #[test]
+    fn pushing_an_absolute_path_replaces() {
+        let mut path = PathBuf::from("uploads");
+        path.push("/tmp/x");
+        assert_eq!(path, Path::new("/tmp/x"));
+    }
+
+    #[test]
+    fn paths_compare_by_components() {
+        // Repeated and trailing separators don't matter, ".." does
+        assert_eq!(Path::new("a//b/"), Path::new("a/b"));
+        assert_ne!(Path::new("a/../b"), Path::new("b"));
+        assert_eq!(
+            relative_to(Path::new("/srv/app/log"), Path::new("/srv")),
+            Path::new("app/log")
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use std::path::Path;
+
+// Names that Windows refuses, whatever the extension: "con.txt" is invalid too
+const RESERVED: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Checks that a file name is valid on Windows, macOS, and Linux.
+/// Useful for names that come from users or from other systems.
+fn portable_name_error(name: &str) -> Option<&'static str> {
+    if name.is_empty() || name == "." || name == ".." {
+        return Some("empty or special name");
+    }
+    if name
+        .chars()
+        .any(|c| "<>:\"/\\|?*".contains(c) || c.is_control())
+    {
+        return Some("contains a character that's not allowed on Windows");
+    }
+    if name.ends_with(['.', ' ']) {
+        return Some("ends with a dot or a space, which Windows removes");
+    }
+    let stem = name.split('.').next().unwrap_or(name);
+    if RESERVED.iter().any(|r| r.eq_ignore_ascii_case(stem)) {
+        return Some("reserved device name on Windows");
+    }
+    if name.len() > 255 {
+        return Some("longer than 255 bytes");
+    }
+    None
+}
+
+// Replaces what `portable_name_error` rejects, for generated file names
+fn sanitize(name: &str) -> String {
+    let mut clean: String = name
+        .chars()
+        .map(|c| {
+            if "<>:\"/\\|?*".contains(c) || c.is_control() {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    while clean.ends_with(['.', ' ']) {
+        clean.pop();
+    }
+    if portable_name_error(&clean).is_some() {
+        clean.insert(0, '_');
+    }
+    clean
+}
+
+fn main() {
+    // Building paths with strings hard-codes one separator
+    let dir = "sample_dir";
+    let joined = format!("{}/{}", dir, "file1.txt");
+    println!("format!: {}", joined);
+    println!("join:    {}", Path::new(dir).join("file1.txt").display());
+    println!("separator on this system: {:?}", std::path::MAIN_SEPARATOR);
+
+    // "C:\\data" is a relative file name on Linux, and absolute on Windows
+    let windows_style = Path::new(r"C:\data\file.txt");
+    println!(
+        "{} is absolute: {}, components: {}",
+        windows_style.display(),
+        windows_style.is_absolute(),
+        windows_style.components().count()
+    );
+
+    for name in ["report.txt", "CON", "con.txt", "a:b", "notes.", "ok name"] {
+        match portable_name_error(name) {
+            Some(problem) => println!("{:<12} {} -> {:?}", name, problem, sanitize(name)),
+            None => println!("{:<12} portable", name),
+        }
+    }
+
+    // On Unix, file names are bytes and don't have to be valid UTF-8
+    #[cfg(unix)]
+    {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let name = OsStr::from_bytes(b"caf\xe9.txt"); // Latin-1, not UTF-8
+        println!("to_str:            {:?}", name.to_str());
+        println!("to_string_lossy:   {}", name.to_string_lossy());
+        println!("display:           {}", Path::new(name).display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_windows_problems() {
+        assert!(portable_name_error("aux.log").is_some());
+        assert!(portable_name_error("what?.txt").is_some());
+        assert!(portable_name_error("trailing ").is_some());
+        assert!(portable_name_error("..").is_some());
+        assert!(portable_name_error("console.txt").is_none());
+        assert!(portable_name_error("résumé.pdf").is_none());
+    }
+
+    #[test]
+    fn sanitized_names_are_portable() {
+        for name in ["CON", "a/b", "x.", "   ", "Q3: report?", "nul.tar.gz"] {
+            let clean = sanitize(name);
+            assert_eq!(
+                portable_name_error(&clean),
+                None,
+                "{:?} -> {:?}",
+                name,
+                clean
+            );
+        }
+        assert_eq!(sanitize("Q3: report?"), "Q3_ report_");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_names_can_be_created() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(OsStr::from_bytes(b"caf\xe9.txt"));
+        // Some file systems, such as macOS APFS, only accept UTF-8 names
+        if std::fs::write(&path, "data").is_ok() {
+            let entry = std::fs::read_dir(dir.path())
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap();
+            assert!(entry.file_name().to_str().is_none());
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::fs;
+use std::io::{Read, Seek, SeekFrom, Write};
+use tempfile::{tempdir, tempfile, Builder, NamedTempFile};
+
+// A function under test that writes a report into a directory
+fn write_report(dir: &std::path::Path, lines: &[&str]) -> std::io::Result<std::path::PathBuf> {
+    let path = dir.join("report.txt");
+    fs::write(&path, lines.join("\n"))?;
+    Ok(path)
+}
+
+fn main() -> std::io::Result<()> {
+    // An anonymous file: no name in the file system, deleted by the OS
+    // when it's closed, even if the program crashes
+    let mut scratch = tempfile()?;
+    scratch.write_all(b"intermediate data")?;
+    scratch.seek(SeekFrom::Start(0))?;
+    let mut contents = String::new();
+    scratch.read_to_string(&mut contents)?;
+    println!("Anonymous file: {}", contents);
+
+    // A named file, for passing a path to another program or library
+    let mut named = NamedTempFile::new()?;
+    writeln!(named, "key = value")?;
+    let path = named.path().to_path_buf();
+    println!("Named file exists: {}", path.exists());
+    drop(named);
+    println!("After drop:        {}", path.exists());
+
+    // A directory with a recognizable prefix, removed with everything in it
+    let dir = Builder::new().prefix("report-").tempdir()?;
+    let report = write_report(dir.path(), &["alpha", "beta"])?;
+    println!("Report: {:?}", report.file_name().unwrap());
+    println!(
+        "Prefix: {}",
+        dir.path()
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("report-")
+    );
+
+    // `keep` disables the cleanup, for example to inspect a failed run
+    let kept = tempdir()?.keep();
+    println!("Kept directory exists: {}", kept.exists());
+    fs::remove_dir(&kept)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_test_gets_its_own_directory() {
+        let dir = tempdir().unwrap();
+        let path = write_report(dir.path(), &["one", "two"]).unwrap();
+        assert_eq!(fs::read_to_string(path).unwrap(), "one\ntwo");
+        // `dir` is removed here, even if an assertion above fails
+    }
+
+    #[test]
+    fn directory_is_removed_on_drop() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().to_path_buf();
+        fs::write(path.join("file"), "x").unwrap();
+        drop(dir);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn close_reports_cleanup_errors() {
+        // `Drop` ignores errors; `close` returns them
+        let dir = tempdir().unwrap();
+        dir.close().unwrap();
+    }
+}
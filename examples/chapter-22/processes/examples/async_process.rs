@@ -0,0 +1,134 @@
+use std::io;
+use std::process::{ExitStatus, Output, Stdio};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+use tokio::task::JoinSet;
+
+/// Like `Command::output`, but gives up after `timeout`.
+/// `kill_on_drop` makes sure that the child doesn't outlive the future:
+/// when `timeout` drops it, the child is killed.
+async fn output_with_timeout(command: &mut Command, timeout: Duration) -> io::Result<Output> {
+    let program = command.as_std().get_program().to_owned();
+    command.kill_on_drop(true);
+    match tokio::time::timeout(timeout, command.output()).await {
+        Ok(output) => output,
+        Err(_) => Err(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!("{:?} timed out after {:?}", program, timeout),
+        )),
+    }
+}
+
+/// Calls `on_line` for every line of standard output while the child runs
+async fn stream_lines<F>(command: &mut Command, mut on_line: F) -> io::Result<ExitStatus>
+where
+    F: FnMut(&str),
+{
+    let mut child = command.stdout(Stdio::piped()).spawn()?;
+    let mut lines = BufReader::new(child.stdout.take().unwrap()).lines();
+    while let Some(line) = lines.next_line().await? {
+        on_line(&line);
+    }
+    child.wait().await
+}
+
+#[tokio::main]
+async fn main() -> io::Result<()> {
+    // The same API as `std::process::Command`, but `output` is a future
+    let output = Command::new("rustc").arg("--version").output().await?;
+    print!("Version: {}", String::from_utf8_lossy(&output.stdout));
+
+    // Reading lines doesn't block the runtime, so other tasks keep running
+    let start = Instant::now();
+    let script = "for i in 1 2 3; do echo \"Line $i\"; sleep 0.2; done";
+    let status = stream_lines(Command::new("sh").args(["-c", script]), |line| {
+        println!("[{:.1}s] {}", start.elapsed().as_secs_f32(), line)
+    })
+    .await?;
+    println!("Finished with {}", status);
+
+    // Start several commands at once and collect the results as they finish
+    let start = Instant::now();
+    let mut tasks = JoinSet::new();
+    for (name, seconds) in [("slow", "0.6"), ("fast", "0.2"), ("medium", "0.4")] {
+        tasks.spawn(async move {
+            let status = Command::new("sleep").arg(seconds).status().await;
+            (name, status)
+        });
+    }
+    while let Some(result) = tasks.join_next().await {
+        let (name, status) = result.expect("task panicked");
+        println!(
+            "[{:.1}s] {} finished with {}",
+            start.elapsed().as_secs_f32(),
+            name,
+            status?
+        );
+    }
+
+    // Waiting with a timeout, then killing the child ourselves
+    let mut child = Command::new("sleep").arg("10").spawn()?;
+    if tokio::time::timeout(Duration::from_millis(300), child.wait())
+        .await
+        .is_err()
+    {
+        // `kill` sends the signal and waits for the child to exit
+        child.kill().await?;
+        println!("Killed sleep 10 after 300 ms");
+    }
+
+    let result =
+        output_with_timeout(Command::new("sleep").arg("10"), Duration::from_millis(300)).await;
+    println!("With timeout: {:?}", result.map_err(|e| e.to_string()));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn collects_output() {
+        let output = output_with_timeout(Command::new("echo").arg("hello"), Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(output.stdout, b"hello\n");
+        assert!(output.status.success());
+    }
+
+    #[tokio::test]
+    async fn slow_commands_time_out() {
+        let start = Instant::now();
+        let err = output_with_timeout(Command::new("sleep").arg("30"), Duration::from_millis(100))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn streams_every_line() {
+        let mut lines = Vec::new();
+        let status = stream_lines(Command::new("seq").arg("3"), |line| {
+            lines.push(line.to_string())
+        })
+        .await
+        .unwrap();
+        assert!(status.success());
+        assert_eq!(lines, ["1", "2", "3"]);
+    }
+
+    #[tokio::test]
+    async fn commands_run_concurrently() {
+        let start = Instant::now();
+        let (a, b, c) = tokio::join!(
+            Command::new("sleep").arg("1").status(),
+            Command::new("sleep").arg("1").status(),
+            Command::new("sleep").arg("1").status(),
+        );
+        assert!(a.unwrap().success() && b.unwrap().success() && c.unwrap().success());
+        // One after another, they would take 3 s
+        assert!(start.elapsed() < Duration::from_millis(2500));
+    }
+}
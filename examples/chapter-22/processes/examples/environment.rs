@@ -0,0 +1,95 @@
+use std::env;
+use std::io;
+use std::process::Command;
+
+fn stdout(command: &mut Command) -> io::Result<String> {
+    let output = command.output()?;
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// A command that sees only the variables we choose, plus `PATH` so that it
+/// can find other programs. `LC_ALL=C` makes messages and sorting independent
+/// of the user's language, which matters when we parse the output.
+fn isolated(program: &str) -> Command {
+    let mut command = Command::new(program);
+    command.env_clear().env("LC_ALL", "C");
+    if let Some(path) = env::var_os("PATH") {
+        command.env("PATH", path);
+    }
+    command
+}
+
+fn main() -> io::Result<()> {
+    // By default, a child inherits all our environment variables
+    let home = stdout(Command::new("sh").args(["-c", "echo $HOME"]))?;
+    println!("Inherited HOME:  {}", home);
+
+    // `env` adds or overrides a variable for the child only
+    let greeting = stdout(
+        Command::new("sh")
+            .args(["-c", "echo $GREETING, $USER_NAME"])
+            .env("GREETING", "Hello")
+            .env("USER_NAME", "Alice"),
+    )?;
+    println!("Added variables: {}", greeting);
+    println!("Our GREETING:    {:?}", env::var("GREETING").ok());
+
+    // `env_remove` hides one variable
+    let removed = stdout(
+        Command::new("sh")
+            .args(["-c", "echo ${HOME:-<unset>}"])
+            .env_remove("HOME"),
+    )?;
+    println!("Removed HOME:    {}", removed);
+
+    // `env_clear` starts from an empty environment
+    let mut command = isolated("env");
+    command.env("APP_MODE", "test");
+    let all = stdout(&mut command)?;
+    let names: Vec<&str> = all.lines().filter_map(|l| l.split('=').next()).collect();
+    println!("Isolated env:    {:?}", names);
+
+    // `current_dir` sets the working directory of the child. Whether a
+    // relative program path like "./build.sh" is resolved before or after
+    // the change differs between platforms, so use absolute paths for it.
+    let dir = env::temp_dir();
+    let pwd = stdout(Command::new("pwd").current_dir(&dir))?;
+    println!("Working dir:     {}", pwd);
+    println!("Ours unchanged:  {}", env::current_dir()?.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn variables_are_set_for_the_child_only() {
+        let value = stdout(
+            Command::new("sh")
+                .args(["-c", "echo $PROCESSES_TEST_VAR"])
+                .env("PROCESSES_TEST_VAR", "42"),
+        )
+        .unwrap();
+        assert_eq!(value, "42");
+        assert!(env::var_os("PROCESSES_TEST_VAR").is_none());
+    }
+
+    #[test]
+    fn isolated_commands_see_only_chosen_variables() {
+        let mut command = isolated("env");
+        command.env("APP_MODE", "test");
+        let all = stdout(&mut command).unwrap();
+        let mut names: Vec<&str> = all.lines().filter_map(|l| l.split('=').next()).collect();
+        names.sort();
+        assert_eq!(names, ["APP_MODE", "LC_ALL", "PATH"]);
+    }
+
+    #[test]
+    fn current_dir_applies_to_the_child() {
+        let dir = env::temp_dir().canonicalize().unwrap();
+        let pwd = stdout(Command::new("pwd").arg("-P").current_dir(&dir)).unwrap();
+        assert_eq!(Path::new(&pwd), dir);
+    }
+}
@@ -0,0 +1,128 @@
+use std::io::{self, Read, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+
+/// Connects the commands like `a | b | c` in a shell and returns the
+/// output of the last one. Like `set -o pipefail`, the pipeline fails
+/// if any command fails.
+fn pipeline(commands: &mut [Command]) -> io::Result<String> {
+    let mut children = Vec::new();
+    let mut previous = None;
+    for command in commands.iter_mut() {
+        // The output of one command becomes the input of the next
+        if let Some(stdout) = previous.take() {
+            command.stdin(Stdio::from(stdout));
+        }
+        let mut child = command.stdout(Stdio::piped()).spawn()?;
+        previous = child.stdout.take();
+        children.push(child);
+    }
+
+    let mut output = String::new();
+    if let Some(mut stdout) = previous {
+        stdout.read_to_string(&mut output)?;
+    }
+    for (child, command) in children.iter_mut().zip(commands.iter()) {
+        let status = child.wait()?;
+        if !status.success() {
+            return Err(io::Error::other(format!(
+                "{:?} failed with {}",
+                command.get_program(),
+                status
+            )));
+        }
+    }
+    Ok(output)
+}
+
+/// Sends `input` to the standard input of a command and returns its output
+fn filter_through(command: &mut Command, input: &str) -> io::Result<String> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+
+    // Write from another thread: a child that produces output while it
+    // reads would otherwise block on a full stdout pipe, while we block
+    // on a full stdin pipe. Dropping `stdin` closes it, which tells the
+    // child that the input is complete.
+    let mut stdin = child.stdin.take().unwrap();
+    let input = input.to_string();
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+
+    let output = child.wait_with_output()?;
+    writer.join().expect("writer thread panicked")?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "{:?} failed with {}",
+            command.get_program(),
+            output.status
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn command(program: &str, args: &[&str]) -> Command {
+    let mut command = Command::new(program);
+    command.args(args);
+    command
+}
+
+fn main() -> io::Result<()> {
+    // ls examples | grep -v ^run | sort -r
+    let listing = pipeline(&mut [
+        command("ls", &["examples"]),
+        command("grep", &["-v", "^run"]),
+        command("sort", &["-r"]),
+    ])?;
+    println!("ls | grep | sort:\n{}", listing);
+
+    let words = "pear\napple\nbanana\napple\ncherry\n";
+    let sorted = filter_through(&mut command("sort", &["-u"]), words)?;
+    println!("sort -u:\n{}", sorted);
+
+    let counted = filter_through(&mut command("wc", &["-l"]), words)?;
+    println!("wc -l: {}", counted.trim());
+
+    match pipeline(&mut [command("false", &[]), command("cat", &[])]) {
+        Ok(_) => println!("Unexpected success"),
+        Err(e) => println!("Pipeline error: {}", e),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn connects_commands() {
+        let output = pipeline(&mut [
+            command("printf", &["b\\na\\nc\\na\\n"]),
+            command("sort", &["-u"]),
+            command("tr", &["a-z", "A-Z"]),
+        ])
+        .unwrap();
+        assert_eq!(output, "A\nB\nC\n");
+    }
+
+    #[test]
+    fn any_failing_command_fails_the_pipeline() {
+        let err = pipeline(&mut [command("false", &[]), command("cat", &[])]).unwrap_err();
+        assert!(err.to_string().contains("\"false\" failed"), "{}", err);
+    }
+
+    #[test]
+    fn filters_input() {
+        let output = filter_through(&mut command("sort", &[]), "b\nc\na\n").unwrap();
+        assert_eq!(output, "a\nb\nc\n");
+    }
+
+    #[test]
+    fn large_input_does_not_deadlock() {
+        // Much more than a pipe can hold, in both directions
+        let input = "line\n".repeat(200_000);
+        let output = filter_through(&mut command("cat", &[]), &input).unwrap();
+        assert_eq!(output.len(), input.len());
+    }
+}
@@ -0,0 +1,101 @@
+// The examples in this chapter call common Unix commands such as `ls`,
+// `sh`, and `sort`. On Windows, run them in WSL or Git Bash.
+use std::io;
+use std::process::{Command, Stdio};
+
+/// Runs a command and returns its standard output.
+/// A non-zero exit code becomes an error that includes the standard error,
+/// because that is where programs explain what went wrong.
+fn run(command: &mut Command) -> io::Result<String> {
+    let output = command.output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(io::Error::other(format!(
+            "{:?} failed with {}: {}",
+            command.get_program(),
+            output.status,
+            stderr.trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn main() -> io::Result<()> {
+    // `status` lets the child write directly to our terminal
+    let status = Command::new("rustc").arg("--version").status()?;
+    println!("rustc exited with {}", status);
+
+    // `output` captures both streams instead
+    let output = Command::new("ls")
+        .args(["-d", "/tmp", "/does-not-exist"])
+        .output()?;
+    println!("Exit code: {:?}", output.status.code());
+    println!("Stdout:    {:?}", String::from_utf8_lossy(&output.stdout));
+    println!("Stderr:    {:?}", String::from_utf8_lossy(&output.stderr));
+
+    // The helper turns failures into errors
+    let version = run(Command::new("cargo").arg("--version"))?;
+    println!("Version:   {}", version.trim());
+    if let Err(e) = run(Command::new("ls").arg("/does-not-exist")) {
+        println!("Error:     {}", e);
+    }
+
+    // A missing program is an `io::Error`, not an exit code
+    match Command::new("no-such-program").output() {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            println!("Not found: no-such-program is not installed")
+        }
+        other => println!("Unexpected: {:?}", other),
+    }
+
+    // Arguments are passed to the program as they are, without a shell,
+    // so special characters in user input can't run other commands
+    let user_input = "hello; rm -rf ~";
+    let echoed = run(Command::new("echo").arg(user_input))?;
+    println!("Echoed:    {}", echoed.trim());
+
+    // Pipes, globs, and `&&` need a shell. Pass user input as a separate
+    // argument ($1) instead of formatting it into the script.
+    let counted = run(Command::new("sh")
+        .arg("-c")
+        .arg("printf '%s' \"$1\" | wc -c")
+        .arg("sh")
+        .arg(user_input))?;
+    println!("Length:    {}", counted.trim());
+
+    // `stdin(Stdio::null())` keeps a child from waiting for keyboard input
+    let status = Command::new("cat").stdin(Stdio::null()).status()?;
+    println!("cat without input exited with {}", status);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_stdout_on_success() {
+        let stdout = run(Command::new("echo").args(["a", "b"])).unwrap();
+        assert_eq!(stdout, "a b\n");
+    }
+
+    #[test]
+    fn failures_include_stderr() {
+        let err = run(Command::new("sh").args(["-c", "echo broken >&2; exit 3"])).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("exit status: 3"), "{}", message);
+        assert!(message.ends_with("broken"), "{}", message);
+    }
+
+    #[test]
+    fn missing_programs_are_not_found() {
+        let err = run(&mut Command::new("no-such-program")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn arguments_are_not_interpreted_by_a_shell() {
+        let stdout = run(Command::new("echo").arg("$HOME; *")).unwrap();
+        assert_eq!(stdout, "$HOME; *\n");
+    }
+}
@@ -0,0 +1,114 @@
+use std::io::{self, BufRead, BufReader, Read};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Instant;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Source {
+    Stdout,
+    Stderr,
+}
+
+/// Runs a command and calls `on_line` for every line as soon as the child
+/// prints it, instead of waiting for the command to finish.
+fn run_streaming<F>(command: &mut Command, mut on_line: F) -> io::Result<ExitStatus>
+where
+    F: FnMut(Source, &str),
+{
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Both pipes must be read at the same time. A pipe holds only a few
+    // kilobytes: if we read stdout to the end first, a child that fills
+    // the stderr pipe blocks forever, and so do we.
+    let (sender, receiver) = mpsc::channel();
+    let readers = [
+        forward(child.stdout.take().unwrap(), Source::Stdout, sender.clone()),
+        forward(child.stderr.take().unwrap(), Source::Stderr, sender),
+    ];
+
+    // The loop ends when both readers are done and have dropped their senders
+    for (source, line) in receiver {
+        on_line(source, &line);
+    }
+    for reader in readers {
+        reader.join().expect("reader thread panicked")?;
+    }
+    child.wait()
+}
+
+fn forward<R: Read + Send + 'static>(
+    pipe: R,
+    source: Source,
+    sender: mpsc::Sender<(Source, String)>,
+) -> thread::JoinHandle<io::Result<()>> {
+    thread::spawn(move || {
+        for line in BufReader::new(pipe).lines() {
+            // Stop reading if the receiver is gone
+            if sender.send((source, line?)).is_err() {
+                break;
+            }
+        }
+        Ok(())
+    })
+}
+
+fn main() -> io::Result<()> {
+    let script = r#"
+        for i in 1 2 3; do
+            echo "Building step $i"
+            echo "warning: step $i is slow" >&2
+            sleep 0.3
+        done
+        exit 1
+    "#;
+
+    let start = Instant::now();
+    let status = run_streaming(Command::new("sh").args(["-c", script]), |source, line| {
+        let elapsed = start.elapsed().as_secs_f32();
+        match source {
+            Source::Stdout => println!("[{:.1}s] {}", elapsed, line),
+            Source::Stderr => println!("[{:.1}s] (stderr) {}", elapsed, line),
+        }
+    })?;
+    println!("Finished with {}", status);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(script: &str) -> (Vec<String>, Vec<String>, ExitStatus) {
+        let (mut out, mut err) = (Vec::new(), Vec::new());
+        let status =
+            run_streaming(
+                Command::new("sh").args(["-c", script]),
+                |source, line| match source {
+                    Source::Stdout => out.push(line.to_string()),
+                    Source::Stderr => err.push(line.to_string()),
+                },
+            )
+            .unwrap();
+        (out, err, status)
+    }
+
+    #[test]
+    fn separates_stdout_and_stderr() {
+        let (out, err, status) = collect("echo one; echo two >&2; echo three; exit 4");
+        assert_eq!(out, ["one", "three"]);
+        assert_eq!(err, ["two"]);
+        assert_eq!(status.code(), Some(4));
+    }
+
+    #[test]
+    fn large_output_on_both_pipes_does_not_deadlock() {
+        let (out, err, status) = collect("seq 1 100000 >&2; seq 1 100000");
+        assert!(status.success());
+        assert_eq!(out.len(), 100_000);
+        assert_eq!(err.last().map(String::as_str), Some("100000"));
+    }
+}
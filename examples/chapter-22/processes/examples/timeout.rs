@@ -0,0 +1,132 @@
+use std::io;
+#[cfg(unix)]
+use std::os::unix::process::ExitStatusExt;
+use std::process::{Child, Command, ExitStatus};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Waits for the child for at most `timeout`.
+/// Returns `None` if it is still running.
+fn wait_timeout(child: &mut Child, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        // `try_wait` checks the status without blocking
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Runs a command and kills it if it takes longer than `timeout`
+fn run_with_timeout(command: &mut Command, timeout: Duration) -> io::Result<ExitStatus> {
+    let mut child = command.spawn()?;
+    match wait_timeout(&mut child, timeout)? {
+        Some(status) => Ok(status),
+        None => {
+            // `kill` sends SIGKILL on Unix and calls TerminateProcess on
+            // Windows. `wait` then removes the finished process from the
+            // process table; without it, a zombie process would remain.
+            child.kill()?;
+            child.wait()?;
+            Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                format!("{:?} timed out after {:?}", command.get_program(), timeout),
+            ))
+        }
+    }
+}
+
+/// Asks the child to stop with SIGTERM so that it can clean up,
+/// and kills it if it is still running after `grace`
+fn terminate(child: &mut Child, grace: Duration) -> io::Result<ExitStatus> {
+    // The standard library can only send SIGKILL, so use the `kill` command.
+    // The `nix` and `libc` crates can send signals directly.
+    Command::new("kill")
+        .args(["-TERM", &child.id().to_string()])
+        .status()?;
+    match wait_timeout(child, grace)? {
+        Some(status) => Ok(status),
+        None => {
+            child.kill()?;
+            child.wait()
+        }
+    }
+}
+
+fn describe(status: ExitStatus) -> String {
+    if let Some(code) = status.code() {
+        return format!("exit code {}", code);
+    }
+    // Only Unix has signals; on Windows, every status has a code
+    #[cfg(unix)]
+    if let Some(signal) = status.signal() {
+        return format!("killed by signal {}", signal);
+    }
+    "unknown".to_string()
+}
+
+fn main() -> io::Result<()> {
+    let status = run_with_timeout(Command::new("sleep").arg("0.1"), Duration::from_secs(1))?;
+    println!("sleep 0.1: {}", describe(status));
+
+    let start = Instant::now();
+    let result = run_with_timeout(Command::new("sleep").arg("10"), Duration::from_millis(500));
+    println!(
+        "sleep 10:  {:?} after {:.1}s",
+        result.map_err(|e| e.to_string()),
+        start.elapsed().as_secs_f32()
+    );
+
+    // A script that cleans up when it receives SIGTERM
+    let mut child = Command::new("sh")
+        .args([
+            "-c",
+            "trap 'echo Cleaning up; exit 0' TERM; while true; do sleep 0.1; done",
+        ])
+        .spawn()?;
+    thread::sleep(Duration::from_millis(200));
+    let status = terminate(&mut child, Duration::from_secs(2))?;
+    println!("Graceful:  {}", describe(status));
+
+    // A script that ignores SIGTERM is killed after the grace period
+    let mut child = Command::new("sh")
+        .args(["-c", "trap '' TERM; while true; do sleep 0.1; done"])
+        .spawn()?;
+    thread::sleep(Duration::from_millis(200));
+    let status = terminate(&mut child, Duration::from_millis(500))?;
+    println!("Stubborn:  {}", describe(status));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fast_commands_finish_normally() {
+        let status = run_with_timeout(&mut Command::new("true"), Duration::from_secs(5)).unwrap();
+        assert!(status.success());
+    }
+
+    #[test]
+    fn slow_commands_are_killed() {
+        let start = Instant::now();
+        let err = run_with_timeout(Command::new("sleep").arg("30"), Duration::from_millis(100))
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        // Far below the 30 s of `sleep`, with room for a slow machine
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn terminate_sends_sigterm_first() {
+        let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+        let status = terminate(&mut child, Duration::from_secs(5)).unwrap();
+        assert_eq!(status.signal(), Some(15));
+    }
+}
@@ -0,0 +1,81 @@
+//! A `shutdown_signal` function for servers and workers that should stop
+//! cleanly, with one implementation per platform.
+
+use std::future::Future;
+use tracing::info;
+
+/// Completes when the user or the system asks the program to stop:
+/// SIGINT (Ctrl-C) or SIGTERM on Unix, and Ctrl-C, Ctrl-Break, closing the
+/// console window, or a system shutdown on Windows.
+///
+/// The handlers are installed when this function is called, not when the
+/// future is first polled, so a signal that arrives in between isn't lost.
+/// Installing them replaces the default behavior, which would end the
+/// process immediately.
+///
+/// Panics if it is called outside a Tokio runtime or the handlers can't be
+/// installed.
+pub fn shutdown_signal() -> impl Future<Output = ()> + Send {
+    let signal = platform::listen().expect("Failed to install the signal handlers");
+    async move {
+        let name = signal.await;
+        info!("Received {}, shutting down", name);
+    }
+}
+
+#[cfg(unix)]
+mod platform {
+    use std::future::Future;
+    use std::io;
+    use tokio::signal::unix::{signal, SignalKind};
+
+    pub fn listen() -> io::Result<impl Future<Output = &'static str>> {
+        // Ctrl-C in a terminal sends SIGINT. `kill`, `docker stop`,
+        // Kubernetes, and systemd send SIGTERM.
+        let mut interrupt = signal(SignalKind::interrupt())?;
+        let mut terminate = signal(SignalKind::terminate())?;
+        Ok(async move {
+            tokio::select! {
+                _ = interrupt.recv() => "SIGINT",
+                _ = terminate.recv() => "SIGTERM",
+            }
+        })
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::future::Future;
+    use std::io;
+    use tokio::signal::windows::{ctrl_break, ctrl_c, ctrl_close, ctrl_shutdown};
+
+    pub fn listen() -> io::Result<impl Future<Output = &'static str>> {
+        let mut ctrl_c = ctrl_c()?;
+        let mut ctrl_break = ctrl_break()?;
+        // After these two, Windows ends the process within a few seconds,
+        // so there is only time for a short cleanup
+        let mut close = ctrl_close()?;
+        let mut shutdown = ctrl_shutdown()?;
+        Ok(async move {
+            tokio::select! {
+                _ = ctrl_c.recv() => "Ctrl-C",
+                _ = ctrl_break.recv() => "Ctrl-Break",
+                _ = close.recv() => "console close",
+                _ = shutdown.recv() => "system shutdown",
+            }
+        })
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod platform {
+    use std::future::Future;
+    use std::io;
+
+    pub fn listen() -> io::Result<impl Future<Output = &'static str>> {
+        Ok(async {
+            let _ = tokio::signal::ctrl_c().await;
+            "Ctrl-C"
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use shutdown::shutdown_signal;
+use std::time::Duration;
+use tokio::sync::watch;
+use tracing::info;
+
+// One unit of work that shouldn't be interrupted halfway
+async fn process(job: u32) {
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    info!(job, "Job done");
+}
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt().with_target(false).init();
+
+    // The signal only flips a flag; the worker decides when it's safe to stop
+    let (stop, stopped) = watch::channel(false);
+    let signal = shutdown_signal();
+    tokio::spawn(async move {
+        signal.await;
+        let _ = stop.send(true);
+    });
+
+    println!(
+        "Worker {} started, press Ctrl-C to stop",
+        std::process::id()
+    );
+    let mut job = 1;
+    while !*stopped.borrow() {
+        process(job).await;
+        job += 1;
+    }
+
+    info!("Saving state after {} jobs", job - 1);
+    println!("Stopped cleanly");
+}
@@ -0,0 +1,52 @@
+// Runs the worker as a child process and sends it real signals
+#![cfg(unix)]
+
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Command, Stdio};
+
+// Returns whether the worker exited successfully, and its output
+fn stop_worker_with(signal: &str) -> (bool, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_shutdown"))
+        .env("NO_COLOR", "1")
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // The first line is printed after the handlers are installed
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    let mut first_line = String::new();
+    stdout.read_line(&mut first_line).unwrap();
+    assert!(
+        first_line.contains("press Ctrl-C to stop"),
+        "{}",
+        first_line
+    );
+
+    let sent = Command::new("kill")
+        .args([signal, &child.id().to_string()])
+        .status()
+        .unwrap();
+    assert!(sent.success());
+
+    // The logs go to stdout as well
+    let mut rest = String::new();
+    stdout.read_to_string(&mut rest).unwrap();
+    let status = child.wait().unwrap();
+    assert!(rest.ends_with("Stopped cleanly\n"), "{}", rest);
+    (status.success(), rest)
+}
+
+#[test]
+fn sigint_stops_the_worker_cleanly() {
+    let (success, logs) = stop_worker_with("-INT");
+    assert!(success);
+    assert!(logs.contains("Received SIGINT, shutting down"), "{}", logs);
+}
+
+#[test]
+fn sigterm_stops_the_worker_cleanly() {
+    let (success, logs) = stop_worker_with("-TERM");
+    assert!(success);
+    assert!(logs.contains("Received SIGTERM, shutting down"), "{}", logs);
+    assert!(logs.contains("Saving state"), "{}", logs);
+}
@@ -0,0 +1,255 @@
+use crate::collect::{get_files, read_preview, FileEntry};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::widgets::ListState;
+use std::io;
+use std::path::PathBuf;
+
+// With a tick every 250 ms, the directory is read again every 2 seconds
+const RELOAD_EVERY_TICKS: u64 = 8;
+const PAGE: u16 = 10;
+
+/// Everything the UI shows. The key handlers only change this state;
+/// drawing it is the job of `ui::draw`.
+pub struct App {
+    pub dir: PathBuf,
+    pub files: Vec<FileEntry>,
+    pub list_state: ListState,
+    pub preview: String,
+    pub preview_scroll: u16,
+    pub status: String,
+    pub running: bool,
+    ticks: u64,
+}
+
+impl App {
+    pub fn new(dir: PathBuf) -> io::Result<Self> {
+        let files = get_files(&dir)?;
+        let mut app = App {
+            dir,
+            files,
+            list_state: ListState::default(),
+            preview: String::new(),
+            preview_scroll: 0,
+            status: String::new(),
+            running: true,
+            ticks: 0,
+        };
+        app.select(if app.files.is_empty() { None } else { Some(0) });
+        app.status = format!("{} files", app.files.len());
+        Ok(app)
+    }
+
+    pub fn selected(&self) -> Option<&FileEntry> {
+        self.list_state.selected().and_then(|i| self.files.get(i))
+    }
+
+    pub fn on_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.running = false,
+            // Raw mode turns Ctrl-C into an ordinary key press instead of SIGINT
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.running = false
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.next(),
+            KeyCode::Up | KeyCode::Char('k') => self.previous(),
+            KeyCode::Home | KeyCode::Char('g') => self.select(Some(0)),
+            KeyCode::End | KeyCode::Char('G') => {
+                self.select(self.files.len().checked_sub(1));
+            }
+            KeyCode::PageDown | KeyCode::Char(' ') => self.scroll_preview(PAGE as i32),
+            KeyCode::PageUp => self.scroll_preview(-(PAGE as i32)),
+            KeyCode::Char('r') => self.reload(),
+            // To try out the panic hook in `tui.rs`
+            KeyCode::Char('!') => panic!("Simulated crash"),
+            _ => {}
+        }
+    }
+
+    /// Called at a fixed rate, even when no key is pressed
+    pub fn on_tick(&mut self) {
+        self.ticks += 1;
+        if self.ticks.is_multiple_of(RELOAD_EVERY_TICKS) {
+            self.reload();
+        }
+    }
+
+    fn next(&mut self) {
+        if self.files.is_empty() {
+            return;
+        }
+        let next = match self.list_state.selected() {
+            Some(i) => (i + 1) % self.files.len(),
+            None => 0,
+        };
+        self.select(Some(next));
+    }
+
+    fn previous(&mut self) {
+        if self.files.is_empty() {
+            return;
+        }
+        let previous = match self.list_state.selected() {
+            Some(0) | None => self.files.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.select(Some(previous));
+    }
+
+    fn select(&mut self, index: Option<usize>) {
+        self.list_state.select(index);
+        self.preview = match self.selected() {
+            Some(file) => read_preview(&file.path),
+            None => "No files".to_string(),
+        };
+        self.preview_scroll = 0;
+    }
+
+    fn scroll_preview(&mut self, lines: i32) {
+        let max = self.preview.lines().count().saturating_sub(1) as i32;
+        self.preview_scroll = (self.preview_scroll as i32 + lines).clamp(0, max) as u16;
+    }
+
+    /// Reads the directory again and keeps the selected file selected,
+    /// even if files were added or removed before it. The preview keeps
+    /// its scroll position only if the same file is still selected.
+    fn reload(&mut self) {
+        let files = match get_files(&self.dir) {
+            Ok(files) => files,
+            Err(e) => {
+                self.status = format!("Reload failed: {}", e);
+                return;
+            }
+        };
+        if files == self.files {
+            return;
+        }
+        let selected = self.selected().map(|f| f.path.clone());
+        self.files = files;
+        let index = selected
+            .as_ref()
+            .and_then(|path| self.files.iter().position(|f| &f.path == path))
+            .or(if self.files.is_empty() { None } else { Some(0) });
+        let scroll = self.preview_scroll;
+        self.select(index);
+        if self.selected().map(|f| &f.path) == selected.as_ref() {
+            // Clamped again, because the file may have become shorter
+            self.scroll_preview(scroll as i32);
+        }
+        self.status = format!("{} files (reloaded)", self.files.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn app_with(names: &[&str]) -> (TempDir, App) {
+        let dir = tempfile::tempdir().unwrap();
+        for name in names {
+            fs::write(dir.path().join(name), format!("contents of {}", name)).unwrap();
+        }
+        let app = App::new(dir.path().to_path_buf()).unwrap();
+        (dir, app)
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.on_key(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    fn selected_name(app: &App) -> &str {
+        &app.selected().unwrap().name
+    }
+
+    #[test]
+    fn starts_with_the_first_file_and_its_preview() {
+        let (_dir, app) = app_with(&["b.txt", "a.txt"]);
+        assert_eq!(selected_name(&app), "a.txt");
+        assert_eq!(app.preview, "contents of a.txt");
+        assert_eq!(app.status, "2 files");
+    }
+
+    #[test]
+    fn navigation_wraps_around() {
+        let (_dir, mut app) = app_with(&["a.txt", "b.txt", "c.txt"]);
+        press(&mut app, KeyCode::Up);
+        assert_eq!(selected_name(&app), "c.txt");
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(selected_name(&app), "a.txt");
+        press(&mut app, KeyCode::End);
+        assert_eq!(selected_name(&app), "c.txt");
+        assert_eq!(app.preview, "contents of c.txt");
+    }
+
+    #[test]
+    fn preview_scrolling_stays_within_the_text() {
+        let dir = tempfile::tempdir().unwrap();
+        let long: String = (1..=25).map(|i| format!("line {}\n", i)).collect();
+        fs::write(dir.path().join("long.txt"), long).unwrap();
+        let mut app = App::new(dir.path().to_path_buf()).unwrap();
+
+        press(&mut app, KeyCode::PageUp);
+        assert_eq!(app.preview_scroll, 0);
+        for _ in 0..5 {
+            press(&mut app, KeyCode::PageDown);
+        }
+        assert_eq!(app.preview_scroll, 24);
+    }
+
+    #[test]
+    fn quits_on_q_and_ctrl_c() {
+        let (_dir, mut app) = app_with(&[]);
+        press(&mut app, KeyCode::Char('q'));
+        assert!(!app.running);
+
+        let (_dir, mut app) = app_with(&[]);
+        app.on_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(!app.running);
+    }
+
+    #[test]
+    fn ticks_reload_the_directory_and_keep_the_selection() {
+        let (dir, mut app) = app_with(&["b.txt", "c.txt"]);
+        press(&mut app, KeyCode::Down);
+        assert_eq!(selected_name(&app), "c.txt");
+
+        fs::write(dir.path().join("a.txt"), "new").unwrap();
+        for _ in 0..RELOAD_EVERY_TICKS {
+            app.on_tick();
+        }
+        assert_eq!(app.files.len(), 3);
+        assert_eq!(selected_name(&app), "c.txt");
+        assert_eq!(app.status, "3 files (reloaded)");
+    }
+
+    #[test]
+    fn reloads_reset_the_scroll_only_for_another_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let long: String = (1..=25).map(|i| format!("line {}\n", i)).collect();
+        fs::write(dir.path().join("b.txt"), &long).unwrap();
+        fs::write(dir.path().join("c.txt"), &long).unwrap();
+        let mut app = App::new(dir.path().to_path_buf()).unwrap();
+        press(&mut app, KeyCode::PageDown);
+
+        // A new file before it: still `b.txt`, still scrolled
+        fs::write(dir.path().join("a.txt"), "new").unwrap();
+        press(&mut app, KeyCode::Char('r'));
+        assert_eq!(selected_name(&app), "b.txt");
+        assert_eq!(app.preview_scroll, PAGE);
+
+        // `b.txt` is gone: `a.txt` is selected and shown from the top
+        fs::remove_file(dir.path().join("b.txt")).unwrap();
+        press(&mut app, KeyCode::Char('r'));
+        assert_eq!(selected_name(&app), "a.txt");
+        assert_eq!(app.preview_scroll, 0);
+    }
+
+    #[test]
+    fn empty_directories_have_no_selection() {
+        let (_dir, mut app) = app_with(&[]);
+        press(&mut app, KeyCode::Down);
+        assert!(app.selected().is_none());
+        assert_eq!(app.preview, "No files");
+    }
+}
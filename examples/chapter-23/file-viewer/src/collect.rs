@@ -0,0 +1,94 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Larger files are cut off in the preview
+const PREVIEW_LIMIT: usize = 64 * 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileEntry {
+    pub path: PathBuf,
+    pub name: String,
+    pub size: u64,
+}
+
+/// The files in `dir`, sorted by name, like `get_files` from Chapter 1
+pub fn get_files(dir: &Path) -> io::Result<Vec<FileEntry>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if metadata.is_file() {
+            files.push(FileEntry {
+                path: entry.path(),
+                name: entry.file_name().to_string_lossy().into_owned(),
+                size: metadata.len(),
+            });
+        }
+    }
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(files)
+}
+
+/// The text to show in the preview pane
+pub fn read_preview(path: &Path) -> String {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) => return format!("Can't read the file: {}", e),
+    };
+    let shown = &bytes[..bytes.len().min(PREVIEW_LIMIT)];
+    // A NUL byte almost never appears in text files
+    if shown.contains(&0) {
+        return format!("Binary file, {} bytes", bytes.len());
+    }
+    let mut text = String::from_utf8_lossy(shown).into_owned();
+    if bytes.len() > PREVIEW_LIMIT {
+        text.push_str("\n[...]");
+    }
+    text
+}
+
+pub fn human_size(bytes: u64) -> String {
+    match bytes {
+        0..1024 => format!("{} B", bytes),
+        1024..1_048_576 => format!("{:.1} KB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_files_sorted_by_name() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("b.txt"), "bb").unwrap();
+        fs::write(dir.path().join("a.txt"), "a").unwrap();
+        fs::create_dir(dir.path().join("subdir")).unwrap();
+
+        let files = get_files(dir.path()).unwrap();
+        let names: Vec<_> = files.iter().map(|f| (f.name.as_str(), f.size)).collect();
+        assert_eq!(names, [("a.txt", 1), ("b.txt", 2)]);
+    }
+
+    #[test]
+    fn previews_text_and_detects_binary_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let text = dir.path().join("notes.txt");
+        let binary = dir.path().join("image.bin");
+        fs::write(&text, "hello\nworld").unwrap();
+        fs::write(&binary, [0x89, b'P', b'N', b'G', 0, 0]).unwrap();
+
+        assert_eq!(read_preview(&text), "hello\nworld");
+        assert_eq!(read_preview(&binary), "Binary file, 6 bytes");
+        assert!(read_preview(&dir.path().join("missing")).starts_with("Can't read"));
+    }
+
+    #[test]
+    fn formats_sizes() {
+        assert_eq!(human_size(12), "12 B");
+        assert_eq!(human_size(2048), "2.0 KB");
+        assert_eq!(human_size(3 * 1_048_576), "3.0 MB");
+    }
+}
@@ -0,0 +1,46 @@
+use ratatui::crossterm::event::{self, Event as TermEvent, KeyEvent, KeyEventKind};
+use std::io;
+use std::time::{Duration, Instant};
+
+pub enum Event {
+    Key(KeyEvent),
+    Resize,
+    // Sent at a fixed rate, for updates that don't depend on input
+    Tick,
+}
+
+pub struct Events {
+    tick_rate: Duration,
+    last_tick: Instant,
+}
+
+impl Events {
+    pub fn new(tick_rate: Duration) -> Self {
+        Events {
+            tick_rate,
+            last_tick: Instant::now(),
+        }
+    }
+
+    /// Blocks until a key is pressed, the terminal is resized, or it's
+    /// time for the next tick, whichever comes first
+    pub fn next(&mut self) -> io::Result<Event> {
+        loop {
+            let timeout = self.tick_rate.saturating_sub(self.last_tick.elapsed());
+            if event::poll(timeout)? {
+                match event::read()? {
+                    // Windows also reports key releases; only react to presses
+                    TermEvent::Key(key) if key.kind == KeyEventKind::Press => {
+                        return Ok(Event::Key(key))
+                    }
+                    TermEvent::Resize(_, _) => return Ok(Event::Resize),
+                    _ => {}
+                }
+            }
+            if self.last_tick.elapsed() >= self.tick_rate {
+                self.last_tick = Instant::now();
+                return Ok(Event::Tick);
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+mod app;
+mod collect;
+mod event;
+mod tui;
+mod ui;
+
+use app::App;
+use event::{Event, Events};
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+fn main() -> io::Result<()> {
+    // The sample directory of the file_collector project from Chapter 1
+    let dir = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("../../chapter-1/file_collector/sample_dir"));
+
+    // Fail before switching the terminal, so that the error is readable
+    let mut app = App::new(dir)?;
+
+    let mut terminal = tui::init()?;
+    let result = run(&mut terminal, &mut app);
+    // Restore the terminal even if `run` failed
+    tui::restore()?;
+    result
+}
+
+fn run(terminal: &mut tui::Tui, app: &mut App) -> io::Result<()> {
+    let mut events = Events::new(Duration::from_millis(250));
+    while app.running {
+        terminal.draw(|frame| ui::draw(frame, app))?;
+        match events.next()? {
+            Event::Key(key) => app.on_key(key),
+            Event::Tick => app.on_tick(),
+            // The next `draw` adapts to the new size
+            Event::Resize => {}
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use ratatui::backend::CrosstermBackend;
+use ratatui::crossterm::cursor::Show;
+use ratatui::crossterm::execute;
+use ratatui::crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use ratatui::Terminal;
+use std::io::{self, stdout, Stdout};
+use std::panic;
+
+pub type Tui = Terminal<CrosstermBackend<Stdout>>;
+
+/// Switches the terminal to raw mode and the alternate screen.
+/// Raw mode delivers every key press immediately, without echo and without
+/// waiting for Enter. The alternate screen keeps the shell's scrollback
+/// intact and is thrown away when we leave it.
+pub fn init() -> io::Result<Tui> {
+    install_panic_hook();
+    enable_raw_mode()?;
+    execute!(stdout(), EnterAlternateScreen)?;
+    Terminal::new(CrosstermBackend::new(stdout()))
+}
+
+/// Puts the terminal back the way we found it
+pub fn restore() -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(stdout(), LeaveAlternateScreen, Show)
+}
+
+// Without this, a panic would print its message into the alternate screen,
+// where nobody can read it, and leave the shell in raw mode
+fn install_panic_hook() {
+    let original = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let _ = restore();
+        original(info);
+    }));
+}
@@ -0,0 +1,95 @@
+use crate::app::App;
+use crate::collect::human_size;
+use ratatui::layout::{Constraint, Layout, Margin};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{
+    Block, List, ListItem, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+};
+use ratatui::Frame;
+
+/// Draws the whole screen from the application state.
+/// ratatui redraws everything on every frame and only sends the cells
+/// that changed to the terminal.
+pub fn draw(frame: &mut Frame, app: &mut App) {
+    let [main, status_bar] =
+        Layout::vertical([Constraint::Min(3), Constraint::Length(1)]).areas(frame.area());
+    let [list_area, preview_area] =
+        Layout::horizontal([Constraint::Percentage(35), Constraint::Percentage(65)]).areas(main);
+
+    let items: Vec<ListItem> = app
+        .files
+        .iter()
+        .map(|file| {
+            ListItem::new(Line::from(vec![
+                Span::raw(file.name.as_str()),
+                Span::raw(format!(" {}", human_size(file.size))).dark_gray(),
+            ]))
+        })
+        .collect();
+    let dir_name = app.dir.file_name().unwrap_or(app.dir.as_os_str());
+    let list = List::new(items)
+        .block(Block::bordered().title(format!(" {} ", dir_name.to_string_lossy())))
+        .highlight_style(Style::new().reversed())
+        .highlight_symbol("> ");
+    // The list state remembers the selection and how far the list is scrolled
+    frame.render_stateful_widget(list, list_area, &mut app.list_state);
+
+    let title = match app.selected() {
+        Some(file) => format!(" {} ", file.name),
+        None => " Preview ".to_string(),
+    };
+    let preview = Paragraph::new(app.preview.as_str())
+        .block(Block::bordered().title(title))
+        .scroll((app.preview_scroll, 0));
+    frame.render_widget(preview, preview_area);
+
+    let lines = app.preview.lines().count();
+    if lines > preview_area.height.saturating_sub(2) as usize {
+        let mut scrollbar = ScrollbarState::new(lines).position(app.preview_scroll as usize);
+        frame.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            preview_area.inner(Margin::new(0, 1)),
+            &mut scrollbar,
+        );
+    }
+
+    let help = " q quit | ↑↓ select | PgUp/PgDn scroll | r reload ";
+    let status = Line::from(vec![help.bold(), Span::raw(format!(" {}", app.status))]);
+    frame.render_widget(Paragraph::new(status).reversed(), status_bar);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+    use std::fs;
+
+    #[test]
+    fn renders_the_list_the_preview_and_the_status_bar() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("file1.txt"), "Hello from file 1").unwrap();
+        fs::write(dir.path().join("file2.txt"), "Hello from file 2").unwrap();
+        let mut app = App::new(dir.path().to_path_buf()).unwrap();
+
+        // A backend that draws into a buffer instead of a real terminal
+        let mut terminal = Terminal::new(TestBackend::new(60, 8)).unwrap();
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let screen: Vec<String> = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect()
+            })
+            .collect();
+        assert!(screen[1].contains("> file1.txt 17 B"), "{:?}", screen);
+        assert!(screen[2].contains("  file2.txt 17 B"), "{:?}", screen);
+        assert!(screen[0].contains(" file1.txt "), "{:?}", screen);
+        assert!(screen[1].contains("Hello from file 1"), "{:?}", screen);
+        assert!(screen[7].starts_with(" q quit"), "{:?}", screen);
+        assert!(screen[7].contains("2 files"), "{:?}", screen);
+    }
+}
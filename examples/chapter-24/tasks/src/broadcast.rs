@@ -0,0 +1,136 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tokio::sync::broadcast;
+
+/// The async counterpart of `EventBus`: one tokio broadcast channel per
+/// event type. Each subscriber gets a `Receiver` and reads the events in
+/// its own task, at its own pace, so a slow subscriber never blocks the
+/// code that publishes.
+///
+/// Share it between tasks with `Arc<BroadcastBus>`.
+pub struct BroadcastBus {
+    // Each value is a `broadcast::Sender<E>` for the `E` of its key
+    senders: Mutex<HashMap<TypeId, Box<dyn Any + Send + Sync>>>,
+    capacity: usize,
+}
+
+impl BroadcastBus {
+    /// `capacity` is the number of events a receiver may fall behind
+    /// before it starts losing the oldest ones. Panics if it is 0, like
+    /// `broadcast::channel`.
+    pub fn new(capacity: usize) -> Self {
+        // Checked here, not when the first channel is created, which would
+        // panic while holding the lock and poison the bus
+        assert!(capacity > 0, "the capacity must be at least 1");
+        BroadcastBus {
+            senders: Mutex::new(HashMap::new()),
+            capacity,
+        }
+    }
+
+    /// Receives every event of type `E` published from now on
+    pub fn subscribe<E: Clone + Send + 'static>(&self) -> broadcast::Receiver<E> {
+        self.sender::<E>().subscribe()
+    }
+
+    /// Returns the number of receivers that will see the event
+    pub fn publish<E: Clone + Send + 'static>(&self, event: E) -> usize {
+        // `send` only fails when there are no receivers, which isn't an
+        // error for a bus
+        self.sender::<E>().send(event).unwrap_or(0)
+    }
+
+    fn sender<E: Clone + Send + 'static>(&self) -> broadcast::Sender<E> {
+        let mut senders = self.senders.lock().unwrap();
+        senders
+            .entry(TypeId::of::<E>())
+            .or_insert_with(|| Box::new(broadcast::channel::<E>(self.capacity).0))
+            .downcast_ref::<broadcast::Sender<E>>()
+            .expect("senders are stored under the TypeId of their event")
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{TaskAdded, TaskCompleted};
+    use crate::task::Priority;
+    use std::sync::Arc;
+    use tokio::sync::broadcast::error::RecvError;
+
+    fn added(id: u32) -> TaskAdded {
+        TaskAdded {
+            id,
+            title: format!("Task {}", id),
+            priority: Priority::High,
+        }
+    }
+
+    #[tokio::test]
+    async fn every_subscriber_receives_every_event_in_its_own_task() {
+        let bus = Arc::new(BroadcastBus::new(16));
+
+        let mut handles = Vec::new();
+        for _ in 0..2 {
+            let mut added_events = bus.subscribe::<TaskAdded>();
+            handles.push(tokio::spawn(async move {
+                let mut ids = Vec::new();
+                while let Ok(event) = added_events.recv().await {
+                    ids.push(event.id);
+                    if ids.len() == 3 {
+                        break;
+                    }
+                }
+                ids
+            }));
+        }
+
+        let publisher = Arc::clone(&bus);
+        tokio::spawn(async move {
+            for id in 1..=3 {
+                assert_eq!(publisher.publish(added(id)), 2);
+            }
+        })
+        .await
+        .unwrap();
+
+        for handle in handles {
+            assert_eq!(handle.await.unwrap(), [1, 2, 3]);
+        }
+    }
+
+    #[tokio::test]
+    async fn channels_are_separate_per_event_type() {
+        let bus = BroadcastBus::new(16);
+        let mut completed = bus.subscribe::<TaskCompleted>();
+
+        assert_eq!(bus.publish(added(1)), 0);
+        bus.publish(TaskCompleted {
+            id: 1,
+            title: "Task 1".into(),
+        });
+        assert_eq!(completed.recv().await.unwrap().id, 1);
+        assert!(completed.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn slow_subscribers_skip_the_oldest_events() {
+        let bus = BroadcastBus::new(2);
+        let mut slow = bus.subscribe::<TaskAdded>();
+        for id in 1..=5 {
+            bus.publish(added(id));
+        }
+        // Events 1 to 3 were overwritten; the receiver learns how many
+        assert_eq!(slow.recv().await.unwrap_err(), RecvError::Lagged(3));
+        assert_eq!(slow.recv().await.unwrap().id, 4);
+        assert_eq!(slow.recv().await.unwrap().id, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity must be at least 1")]
+    fn a_capacity_of_zero_is_rejected() {
+        BroadcastBus::new(0);
+    }
+}
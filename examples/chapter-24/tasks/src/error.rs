@@ -0,0 +1,22 @@
+use std::io;
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum TaskError {
+    #[error("Task {0} not found")]
+    NotFound(u32),
+    #[error("Task {0} is already done")]
+    AlreadyDone(u32),
+    #[error("The title must not be empty")]
+    EmptyTitle,
+    #[error("Failed to read {}: {source}", path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("Failed to write {}: {source}", path.display())]
+    Write { path: PathBuf, source: io::Error },
+    #[error("{} is not a valid task file: {source}", path.display())]
+    Parse {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
@@ -0,0 +1,181 @@
+use crate::task::{Priority, Task};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+// One type per kind of event, so subscribers choose what they hear about
+// by type, and the compiler checks that they handle the right fields
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskAdded {
+    pub id: u32,
+    pub title: String,
+    pub priority: Priority,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskCompleted {
+    pub id: u32,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskRemoved {
+    pub id: u32,
+    pub title: String,
+}
+
+impl From<&Task> for TaskAdded {
+    fn from(task: &Task) -> Self {
+        TaskAdded {
+            id: task.id,
+            title: task.title.clone(),
+            priority: task.priority,
+        }
+    }
+}
+
+impl From<&Task> for TaskCompleted {
+    fn from(task: &Task) -> Self {
+        TaskCompleted {
+            id: task.id,
+            title: task.title.clone(),
+        }
+    }
+}
+
+impl From<&Task> for TaskRemoved {
+    fn from(task: &Task) -> Self {
+        TaskRemoved {
+            id: task.id,
+            title: task.title.clone(),
+        }
+    }
+}
+
+/// Something that reacts to events of type `E`. Closures that take `&E`
+/// are subscribers too.
+pub trait Subscriber<E> {
+    fn handle(&mut self, event: &E);
+}
+
+impl<E, F: FnMut(&E)> Subscriber<E> for F {
+    fn handle(&mut self, event: &E) {
+        self(event)
+    }
+}
+
+// The handlers of all event types live in one map, so their event type is
+// erased to `dyn Any` and restored with `downcast_ref`
+type Handler = Box<dyn FnMut(&dyn Any)>;
+
+/// Delivers each published event to the subscribers of its type, in the
+/// order they subscribed. The code that publishes an event doesn't know
+/// who, if anyone, listens.
+#[derive(Default)]
+pub struct EventBus {
+    handlers: HashMap<TypeId, Vec<Handler>>,
+}
+
+impl EventBus {
+    pub fn subscribe<E: 'static>(&mut self, mut subscriber: impl Subscriber<E> + 'static) {
+        self.handlers
+            .entry(TypeId::of::<E>())
+            .or_default()
+            .push(Box::new(move |event: &dyn Any| {
+                // Handlers are stored under the `TypeId` of `E`, so this
+                // always succeeds
+                if let Some(event) = event.downcast_ref::<E>() {
+                    subscriber.handle(event);
+                }
+            }));
+    }
+
+    /// Returns the number of subscribers that received the event
+    pub fn publish<E: 'static>(&mut self, event: E) -> usize {
+        let Some(handlers) = self.handlers.get_mut(&TypeId::of::<E>()) else {
+            return 0;
+        };
+        for handler in handlers.iter_mut() {
+            handler(&event);
+        }
+        handlers.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn added(id: u32) -> TaskAdded {
+        TaskAdded {
+            id,
+            title: format!("Task {}", id),
+            priority: Priority::Normal,
+        }
+    }
+
+    #[test]
+    fn delivers_events_to_subscribers_of_their_type() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let mut bus = EventBus::default();
+
+        let log = Rc::clone(&seen);
+        bus.subscribe(move |e: &TaskAdded| log.borrow_mut().push(format!("added {}", e.id)));
+        let log = Rc::clone(&seen);
+        bus.subscribe(move |e: &TaskRemoved| log.borrow_mut().push(format!("removed {}", e.id)));
+
+        assert_eq!(bus.publish(added(1)), 1);
+        assert_eq!(
+            bus.publish(TaskRemoved {
+                id: 1,
+                title: "Task 1".into()
+            }),
+            1
+        );
+        assert_eq!(*seen.borrow(), ["added 1", "removed 1"]);
+    }
+
+    #[test]
+    fn events_without_subscribers_are_dropped() {
+        let mut bus = EventBus::default();
+        bus.subscribe(|_: &TaskAdded| panic!("wrong event type"));
+        assert_eq!(
+            bus.publish(TaskCompleted {
+                id: 1,
+                title: "Task 1".into()
+            }),
+            0
+        );
+    }
+
+    #[test]
+    fn trait_objects_and_closures_subscribe_alike() {
+        #[derive(Default)]
+        struct Counter(Rc<RefCell<u32>>);
+
+        impl Subscriber<TaskAdded> for Counter {
+            fn handle(&mut self, _: &TaskAdded) {
+                *self.0.borrow_mut() += 1;
+            }
+        }
+
+        let count = Rc::new(RefCell::new(0));
+        let order = Rc::new(RefCell::new(Vec::new()));
+        let mut bus = EventBus::default();
+        bus.subscribe(Counter(Rc::clone(&count)));
+        let first = Rc::clone(&order);
+        bus.subscribe(move |e: &TaskAdded| first.borrow_mut().push(("first", e.id)));
+        let second = Rc::clone(&order);
+        bus.subscribe(move |e: &TaskAdded| second.borrow_mut().push(("second", e.id)));
+
+        assert_eq!(bus.publish(added(1)), 3);
+        bus.publish(added(2));
+        assert_eq!(*count.borrow(), 2);
+        assert_eq!(
+            *order.borrow(),
+            [("first", 1), ("second", 1), ("first", 2), ("second", 2)]
+        );
+    }
+}
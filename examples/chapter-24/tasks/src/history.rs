@@ -0,0 +1,58 @@
+use crate::events::{EventBus, Subscriber, TaskAdded, TaskCompleted, TaskRemoved};
+use chrono::{SecondsFormat, Utc};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use tracing::warn;
+
+/// Appends one line per event to a history file. It's a subscriber like
+/// the confirmations in `main.rs`: the commands that change tasks don't
+/// know it exists.
+#[derive(Clone)]
+pub struct History {
+    path: PathBuf,
+}
+
+impl History {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        History { path: path.into() }
+    }
+
+    pub fn subscribe_to(&self, bus: &mut EventBus) {
+        bus.subscribe::<TaskAdded>(self.clone());
+        bus.subscribe::<TaskCompleted>(self.clone());
+        bus.subscribe::<TaskRemoved>(self.clone());
+    }
+
+    // The tasks are already saved when an event is published, so a
+    // history that can't be written is only worth a warning
+    fn append(&self, action: &str, id: u32, title: &str) {
+        let time = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+        let result = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| writeln!(file, "{} {} {}: {}", time, action, id, title));
+        if let Err(e) = result {
+            warn!(path = %self.path.display(), error = %e, "Failed to write history");
+        }
+    }
+}
+
+impl Subscriber<TaskAdded> for History {
+    fn handle(&mut self, event: &TaskAdded) {
+        self.append("added", event.id, &event.title);
+    }
+}
+
+impl Subscriber<TaskCompleted> for History {
+    fn handle(&mut self, event: &TaskCompleted) {
+        self.append("completed", event.id, &event.title);
+    }
+}
+
+impl Subscriber<TaskRemoved> for History {
+    fn handle(&mut self, event: &TaskRemoved) {
+        self.append("removed", event.id, &event.title);
+    }
+}
@@ -0,0 +1,14 @@
+pub mod broadcast;
+pub mod error;
+pub mod events;
+pub mod history;
+pub mod store;
+pub mod table;
+pub mod task;
+
+pub use broadcast::BroadcastBus;
+pub use error::TaskError;
+pub use events::{EventBus, Subscriber, TaskAdded, TaskCompleted, TaskRemoved};
+pub use history::History;
+pub use store::{Store, TaskList};
+pub use task::{Priority, Task};
@@ -0,0 +1,146 @@
+use clap::{ArgAction, Parser, Subcommand};
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use tasks::{
+    table, EventBus, History, Priority, Store, TaskAdded, TaskCompleted, TaskError, TaskList,
+    TaskRemoved,
+};
+use tracing::{debug, info};
+use tracing_subscriber::EnvFilter;
+
+/// A small task manager that keeps its tasks in a JSON file
+#[derive(Parser)]
+#[command(name = "tasks", version)]
+struct Cli {
+    /// The file that stores the tasks
+    #[arg(long, env = "TASKS_FILE", default_value = "tasks.json", global = true)]
+    file: PathBuf,
+
+    /// Append a line for every change to this file
+    #[arg(long, env = "TASKS_HISTORY", global = true)]
+    history: Option<PathBuf>,
+
+    /// Log what happens to stderr: -v for info, -vv for debug
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Adds a task
+    Add {
+        /// What needs to be done; several words don't need quotes
+        #[arg(required = true)]
+        title: Vec<String>,
+        #[arg(short, long, value_enum, default_value_t = Priority::Normal)]
+        priority: Priority,
+    },
+    /// Lists the open tasks
+    List {
+        /// Include the tasks that are done
+        #[arg(short, long)]
+        all: bool,
+    },
+    /// Marks a task as done
+    Done { id: u32 },
+    /// Removes a task
+    Remove { id: u32 },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+
+    // Colors are for people; scripts and pipes get plain text
+    if !io::stdout().is_terminal() {
+        colored::control::set_override(false);
+    }
+
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn init_logging(verbose: u8) {
+    let default = match verbose {
+        0 => "warn",
+        1 => "info",
+        _ => "debug",
+    };
+    // RUST_LOG still wins, for example RUST_LOG=tasks=debug
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default));
+    // Logs go to stderr, so they never mix with the output on stdout
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .init();
+}
+
+// The commands publish what happened; these subscribers decide what
+// the user sees and what goes to the log
+fn subscribe_output(bus: &mut EventBus) {
+    bus.subscribe(|e: &TaskAdded| println!("Added task {}: {}", e.id, e.title));
+    bus.subscribe(|e: &TaskCompleted| println!("Completed task {}: {}", e.id, e.title));
+    bus.subscribe(|e: &TaskRemoved| println!("Removed task {}: {}", e.id, e.title));
+
+    bus.subscribe(|e: &TaskAdded| info!(id = e.id, priority = %e.priority, "Task added"));
+    bus.subscribe(|e: &TaskCompleted| info!(id = e.id, "Task completed"));
+    bus.subscribe(|e: &TaskRemoved| info!(id = e.id, "Task removed"));
+}
+
+fn run(cli: Cli) -> Result<(), TaskError> {
+    let store = Store::new(&cli.file);
+    let mut list = store.load()?;
+
+    let mut bus = EventBus::default();
+    subscribe_output(&mut bus);
+    if let Some(path) = &cli.history {
+        History::new(path).subscribe_to(&mut bus);
+    }
+
+    match cli.command {
+        Commands::Add { title, priority } => {
+            let event = TaskAdded::from(list.add(&title.join(" "), priority)?);
+            save_then_publish(&store, &list, &mut bus, event)?;
+        }
+        Commands::List { all } => {
+            let tasks = list.list(all);
+            debug!(shown = tasks.len(), "Listing tasks");
+            if tasks.is_empty() {
+                println!("No tasks. Add one with: tasks add <TITLE>");
+            } else {
+                print!("{}", table::render(&tasks));
+            }
+        }
+        Commands::Done { id } => {
+            let event = TaskCompleted::from(list.complete(id)?);
+            save_then_publish(&store, &list, &mut bus, event)?;
+        }
+        Commands::Remove { id } => {
+            let event = TaskRemoved::from(&list.remove(id)?);
+            save_then_publish(&store, &list, &mut bus, event)?;
+        }
+    }
+    Ok(())
+}
+
+// Every change is saved before anyone hears about it: if saving fails,
+// nothing is printed or logged for a change that didn't happen
+fn save_then_publish<E: 'static>(
+    store: &Store,
+    list: &TaskList,
+    bus: &mut EventBus,
+    event: E,
+) -> Result<(), TaskError> {
+    store.save(list)?;
+    bus.publish(event);
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use crate::error::TaskError;
+use crate::task::{Priority, Task};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use tempfile::NamedTempFile;
+use tracing::{debug, info};
+
+/// All tasks, as they are stored in the JSON file
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TaskList {
+    // IDs are never reused, so "tasks done 3" can't hit a newer task
+    // after task 3 was removed
+    next_id: u32,
+    tasks: Vec<Task>,
+}
+
+impl TaskList {
+    pub fn add(&mut self, title: &str, priority: Priority) -> Result<&Task, TaskError> {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(TaskError::EmptyTitle);
+        }
+        self.next_id += 1;
+        self.tasks.push(Task {
+            id: self.next_id,
+            title: title.to_string(),
+            priority,
+            created_at: Utc::now(),
+            done_at: None,
+        });
+        Ok(self.tasks.last().unwrap())
+    }
+
+    pub fn complete(&mut self, id: u32) -> Result<&Task, TaskError> {
+        let task = self.get_mut(id)?;
+        if task.is_done() {
+            return Err(TaskError::AlreadyDone(id));
+        }
+        task.done_at = Some(Utc::now());
+        Ok(task)
+    }
+
+    pub fn remove(&mut self, id: u32) -> Result<Task, TaskError> {
+        let index = self
+            .tasks
+            .iter()
+            .position(|t| t.id == id)
+            .ok_or(TaskError::NotFound(id))?;
+        Ok(self.tasks.remove(index))
+    }
+
+    /// Open tasks first, the most important ones at the top
+    pub fn list(&self, include_done: bool) -> Vec<&Task> {
+        let mut tasks: Vec<&Task> = self
+            .tasks
+            .iter()
+            .filter(|t| include_done || !t.is_done())
+            .collect();
+        tasks.sort_by_key(|t| (t.is_done(), std::cmp::Reverse(t.priority), t.id));
+        tasks
+    }
+
+    fn get_mut(&mut self, id: u32) -> Result<&mut Task, TaskError> {
+        self.tasks
+            .iter_mut()
+            .find(|t| t.id == id)
+            .ok_or(TaskError::NotFound(id))
+    }
+}
+
+/// Loads and saves the task list as a JSON file
+pub struct Store {
+    path: PathBuf,
+}
+
+impl Store {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Store { path: path.into() }
+    }
+
+    /// A missing file is an empty list, so the first `add` just works
+    pub fn load(&self) -> Result<TaskList, TaskError> {
+        let json = match fs::read_to_string(&self.path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                info!(path = %self.path.display(), "No task file yet, starting empty");
+                return Ok(TaskList::default());
+            }
+            Err(source) => {
+                return Err(TaskError::Read {
+                    path: self.path.clone(),
+                    source,
+                })
+            }
+        };
+        let list: TaskList = serde_json::from_str(&json).map_err(|source| TaskError::Parse {
+            path: self.path.clone(),
+            source,
+        })?;
+        info!(path = %self.path.display(), tasks = list.tasks.len(), "Loaded tasks");
+        Ok(list)
+    }
+
+    /// Writes the whole list atomically, as in Chapter 21: a crash while
+    /// saving leaves the previous version, never a half-written file
+    pub fn save(&self, list: &TaskList) -> Result<(), TaskError> {
+        let write_error = |source| TaskError::Write {
+            path: self.path.clone(),
+            source,
+        };
+        let dir = self
+            .path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let mut temp = NamedTempFile::new_in(dir).map_err(write_error)?;
+        serde_json::to_writer_pretty(&mut temp, list).map_err(|e| write_error(e.into()))?;
+        temp.write_all(b"\n").map_err(write_error)?;
+        temp.as_file().sync_all().map_err(write_error)?;
+        temp.persist(&self.path).map_err(|e| write_error(e.error))?;
+        debug!(path = %self.path.display(), tasks = list.tasks.len(), "Saved tasks");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_never_reused() {
+        let mut list = TaskList::default();
+        list.add("first", Priority::Normal).unwrap();
+        list.add("second", Priority::Normal).unwrap();
+        list.remove(2).unwrap();
+        assert_eq!(list.add("third", Priority::Normal).unwrap().id, 3);
+    }
+
+    #[test]
+    fn lists_open_tasks_by_priority() {
+        let mut list = TaskList::default();
+        list.add("low", Priority::Low).unwrap();
+        list.add("high", Priority::High).unwrap();
+        list.add("normal", Priority::Normal).unwrap();
+        list.add("urgent", Priority::High).unwrap();
+        list.complete(2).unwrap();
+
+        let titles =
+            |tasks: Vec<&Task>| -> Vec<String> { tasks.iter().map(|t| t.title.clone()).collect() };
+        assert_eq!(titles(list.list(false)), ["urgent", "normal", "low"]);
+        assert_eq!(titles(list.list(true)), ["urgent", "normal", "low", "high"]);
+    }
+
+    #[test]
+    fn reports_invalid_operations() {
+        let mut list = TaskList::default();
+        assert!(matches!(
+            list.add("  ", Priority::Low),
+            Err(TaskError::EmptyTitle)
+        ));
+        list.add("task", Priority::Low).unwrap();
+        list.complete(1).unwrap();
+        assert!(matches!(list.complete(1), Err(TaskError::AlreadyDone(1))));
+        assert!(matches!(list.remove(7), Err(TaskError::NotFound(7))));
+    }
+
+    #[test]
+    fn saves_and_loads() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = Store::new(dir.path().join("tasks.json"));
+        assert!(store.load().unwrap().list(true).is_empty());
+
+        let mut list = TaskList::default();
+        list.add("Write tests", Priority::High).unwrap();
+        store.save(&list).unwrap();
+
+        let loaded = store.load().unwrap();
+        assert_eq!(loaded.list(true), list.list(true));
+        assert_eq!(loaded.next_id, 1);
+    }
+}
@@ -0,0 +1,79 @@
+use crate::task::{Priority, Task};
+use chrono::Local;
+use colored::{ColoredString, Colorize};
+
+/// Formats the tasks as a table with one row per task.
+/// Every cell is padded before it's colored: the escape codes for colors
+/// would otherwise count as characters and break the alignment.
+pub fn render(tasks: &[&Task]) -> String {
+    let id_width = tasks
+        .iter()
+        .map(|t| t.id.to_string().len())
+        .max()
+        .unwrap_or(0)
+        .max(2);
+
+    let header = format!(
+        "{:>id_width$}  {:<4}  {:<8}  {:<10}  {}",
+        "ID", "DONE", "PRIORITY", "CREATED", "TITLE"
+    );
+    let mut table = format!("{}\n", header.bold());
+    for task in tasks {
+        let done = task.is_done();
+        let status = if done {
+            "[x] ".green()
+        } else {
+            "[ ] ".normal()
+        };
+        let priority = format!("{:<8}", task.priority);
+        let priority = match task.priority {
+            Priority::High => priority.red().bold(),
+            Priority::Normal => priority.normal(),
+            Priority::Low => priority.dimmed(),
+        };
+        // Stored in UTC, shown in the user's time zone
+        let created = task.created_at.with_timezone(&Local).format("%Y-%m-%d");
+        let title: ColoredString = if done {
+            task.title.as_str().dimmed()
+        } else {
+            task.title.as_str().normal()
+        };
+        table.push_str(&format!(
+            "{:>id_width$}  {}  {}  {}  {}\n",
+            task.id, status, priority, created, title
+        ));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn task(id: u32, title: &str, priority: Priority, done: bool) -> Task {
+        let created_at = Utc.with_ymd_and_hms(2024, 11, 20, 12, 0, 0).unwrap();
+        Task {
+            id,
+            title: title.to_string(),
+            priority,
+            created_at,
+            done_at: done.then_some(created_at),
+        }
+    }
+
+    #[test]
+    fn aligns_columns_without_colors() {
+        colored::control::set_override(false);
+        let tasks = [
+            task(7, "Write chapter", Priority::High, false),
+            task(12, "Review PR", Priority::Low, true),
+        ];
+        let table = render(&tasks.iter().collect::<Vec<_>>());
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "ID  DONE  PRIORITY  CREATED     TITLE");
+        assert!(lines[1].starts_with(" 7  [ ]   high      2024-11-2"));
+        assert!(lines[1].ends_with("  Write chapter"));
+        assert!(lines[2].starts_with("12  [x]   low       2024-11-2"));
+    }
+}
@@ -0,0 +1,220 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::fs;
+use tempfile::TempDir;
+
+// Every test works on its own task file in a temporary directory
+fn tasks(dir: &TempDir) -> Command {
+    let mut command = Command::cargo_bin("tasks").unwrap();
+    command
+        .env("TASKS_FILE", dir.path().join("tasks.json"))
+        .env_remove("RUST_LOG")
+        .env_remove("TASKS_HISTORY");
+    command
+}
+
+#[test]
+fn add_and_list() {
+    let dir = TempDir::new().unwrap();
+    tasks(&dir)
+        .args(["add", "Write", "the", "report"])
+        .assert()
+        .success()
+        .stdout("Added task 1: Write the report\n");
+    tasks(&dir)
+        .args(["add", "Fix the build", "--priority", "high"])
+        .assert()
+        .success()
+        .stdout("Added task 2: Fix the build\n");
+
+    let output = tasks(&dir).arg("list").assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], "ID  DONE  PRIORITY  CREATED     TITLE");
+    // The high priority task comes first, and there are no color codes
+    assert!(lines[1].starts_with(" 2  [ ]   high      "), "{}", stdout);
+    assert!(lines[1].ends_with("  Fix the build"), "{}", stdout);
+    assert!(lines[2].starts_with(" 1  [ ]   normal    "), "{}", stdout);
+}
+
+#[test]
+fn done_tasks_are_hidden_unless_all() {
+    let dir = TempDir::new().unwrap();
+    tasks(&dir).args(["add", "Buy milk"]).assert().success();
+    tasks(&dir)
+        .args(["done", "1"])
+        .assert()
+        .success()
+        .stdout("Completed task 1: Buy milk\n");
+
+    tasks(&dir)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout("No tasks. Add one with: tasks add <TITLE>\n");
+    tasks(&dir)
+        .args(["list", "--all"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(" 1  [x]   normal"));
+}
+
+#[test]
+fn remove_keeps_ids_unique() {
+    let dir = TempDir::new().unwrap();
+    tasks(&dir).args(["add", "First"]).assert().success();
+    tasks(&dir).args(["add", "Second"]).assert().success();
+    tasks(&dir)
+        .args(["remove", "2"])
+        .assert()
+        .success()
+        .stdout("Removed task 2: Second\n");
+    tasks(&dir)
+        .args(["add", "Third"])
+        .assert()
+        .success()
+        .stdout("Added task 3: Third\n");
+}
+
+#[test]
+fn tasks_are_stored_as_json() {
+    let dir = TempDir::new().unwrap();
+    tasks(&dir)
+        .args(["add", "-p", "low", "Water the plants"])
+        .assert()
+        .success();
+
+    let json: Value =
+        serde_json::from_str(&fs::read_to_string(dir.path().join("tasks.json")).unwrap()).unwrap();
+    assert_eq!(json["next_id"], 1);
+    assert_eq!(json["tasks"][0]["title"], "Water the plants");
+    assert_eq!(json["tasks"][0]["priority"], "low");
+    assert!(json["tasks"][0].get("done_at").is_none());
+}
+
+#[test]
+fn unknown_ids_are_errors() {
+    let dir = TempDir::new().unwrap();
+    tasks(&dir)
+        .args(["done", "42"])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr("Error: Task 42 not found\n");
+}
+
+#[test]
+fn failed_saves_print_nothing() {
+    let dir = TempDir::new().unwrap();
+    // The directory for the task file doesn't exist, so saving fails
+    let mut command = tasks(&dir);
+    command.env("TASKS_FILE", dir.path().join("missing").join("tasks.json"));
+    command
+        .args(["add", "Lost"])
+        .assert()
+        .failure()
+        .code(1)
+        .stdout("")
+        .stderr(predicate::str::contains("Error: Failed to write"));
+}
+
+#[test]
+fn completing_twice_is_an_error() {
+    let dir = TempDir::new().unwrap();
+    tasks(&dir).args(["add", "Once"]).assert().success();
+    tasks(&dir).args(["done", "1"]).assert().success();
+    tasks(&dir)
+        .args(["done", "1"])
+        .assert()
+        .failure()
+        .stderr("Error: Task 1 is already done\n");
+}
+
+#[test]
+fn invalid_arguments_are_rejected_by_clap() {
+    let dir = TempDir::new().unwrap();
+    tasks(&dir)
+        .arg("add")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "required arguments were not provided",
+        ));
+    tasks(&dir)
+        .args(["add", "Task", "--priority", "urgent"])
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("invalid value 'urgent'"));
+    tasks(&dir)
+        .args(["done", "first"])
+        .assert()
+        .failure()
+        .code(2);
+}
+
+#[test]
+fn corrupt_files_are_reported_with_their_path() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("tasks.json"), "{ not json").unwrap();
+    tasks(&dir)
+        .arg("list")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "tasks.json is not a valid task file",
+        ));
+}
+
+#[test]
+fn verbose_logs_go_to_stderr() {
+    let dir = TempDir::new().unwrap();
+    tasks(&dir)
+        .args(["-v", "add", "Logged"])
+        .assert()
+        .success()
+        .stdout("Added task 1: Logged\n")
+        .stderr(predicate::str::contains("Task added"));
+}
+
+#[test]
+fn history_records_every_change() {
+    let dir = TempDir::new().unwrap();
+    let history = dir.path().join("history.log");
+    tasks(&dir).args(["add", "Plan"]).assert().success();
+    tasks(&dir)
+        .env("TASKS_HISTORY", &history)
+        .args(["add", "Build"])
+        .assert()
+        .success();
+    tasks(&dir)
+        .env("TASKS_HISTORY", &history)
+        .args(["done", "2"])
+        .assert()
+        .success();
+    tasks(&dir)
+        .args(["--history"])
+        .arg(&history)
+        .args(["remove", "1"])
+        .assert()
+        .success();
+    // A failed command changes nothing, so it isn't recorded
+    tasks(&dir)
+        .env("TASKS_HISTORY", &history)
+        .args(["done", "7"])
+        .assert()
+        .failure();
+
+    let lines: Vec<String> = fs::read_to_string(&history)
+        .unwrap()
+        .lines()
+        .map(|line| line.split_once(' ').unwrap().1.to_string())
+        .collect();
+    assert_eq!(
+        lines,
+        ["added 2: Build", "completed 2: Build", "removed 1: Plan"]
+    );
+}
@@ -0,0 +1,182 @@
+use clap::Parser;
+use reqwest::redirect::Policy;
+use reqwest::{Client, StatusCode};
+use serde_json::json;
+use shortener::{app, config, AppState, Config, CreatedLink, Db, Link};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+use tokio::task::JoinSet;
+
+#[derive(Parser)]
+#[command(about = "Sends many redirect requests to the shortener and measures them")]
+struct Args {
+    /// A running server, e.g. http://127.0.0.1:3000.
+    /// Without it, a server with a temporary database is started in-process.
+    #[arg(long)]
+    url: Option<String>,
+
+    /// Total number of requests
+    #[arg(short = 'n', long, default_value_t = 5000)]
+    requests: usize,
+
+    /// Requests in flight at the same time
+    #[arg(short, long, default_value_t = 50)]
+    concurrency: usize,
+}
+
+struct Report {
+    requests: usize,
+    errors: usize,
+    elapsed: Duration,
+    // Sorted, for the percentiles
+    latencies: Vec<Duration>,
+}
+
+impl Report {
+    fn percentile(&self, p: f64) -> Duration {
+        if self.latencies.is_empty() {
+            return Duration::ZERO;
+        }
+        let index = ((self.latencies.len() - 1) as f64 * p).round() as usize;
+        self.latencies[index]
+    }
+
+    fn print(&self) {
+        println!("Requests:    {} ({} errors)", self.requests, self.errors);
+        println!("Time:        {:.2?}", self.elapsed);
+        println!(
+            "Throughput:  {:.0} requests/s",
+            self.requests as f64 / self.elapsed.as_secs_f64()
+        );
+        println!(
+            "Latency:     p50 {:.2?}, p90 {:.2?}, p99 {:.2?}, max {:.2?}",
+            self.percentile(0.5),
+            self.percentile(0.9),
+            self.percentile(0.99),
+            self.latencies.last().copied().unwrap_or_default()
+        );
+    }
+}
+
+/// Starts the service on a random port, as the tests of Chapter 11 do
+async fn start_server(dir: &tempfile::TempDir) -> String {
+    let config = Config {
+        database: config::Database {
+            url: format!("sqlite:{}", dir.path().join("load.db").display()),
+            max_connections: 5,
+        },
+        ..Config::default()
+    };
+    let db = Db::connect(&config.database).await.unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let app = app(AppState::new(db, config));
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    base_url
+}
+
+async fn run(client: &Client, target: &str, requests: usize, concurrency: usize) -> Report {
+    // Every worker takes the next request number until all are sent,
+    // so exactly `concurrency` requests are in flight
+    let next = Arc::new(AtomicUsize::new(0));
+    let start = Instant::now();
+    let mut workers = JoinSet::new();
+    for _ in 0..concurrency {
+        let (client, target, next) = (client.clone(), target.to_string(), next.clone());
+        workers.spawn(async move {
+            let (mut latencies, mut errors) = (Vec::new(), 0);
+            while next.fetch_add(1, Ordering::Relaxed) < requests {
+                let sent = Instant::now();
+                match client.get(&target).send().await {
+                    Ok(response) if response.status() == StatusCode::TEMPORARY_REDIRECT => {
+                        latencies.push(sent.elapsed())
+                    }
+                    _ => errors += 1,
+                }
+            }
+            (latencies, errors)
+        });
+    }
+
+    let mut report = Report {
+        requests,
+        errors: 0,
+        elapsed: Duration::ZERO,
+        latencies: Vec::with_capacity(requests),
+    };
+    while let Some(result) = workers.join_next().await {
+        let (latencies, errors) = result.unwrap();
+        report.latencies.extend(latencies);
+        report.errors += errors;
+    }
+    report.elapsed = start.elapsed();
+    report.latencies.sort();
+    report
+}
+
+async fn load_test(base_url: &str, requests: usize, concurrency: usize) -> (Report, Link) {
+    // The client must not follow the redirects: we measure the shortener,
+    // not the target site
+    let client = Client::builder().redirect(Policy::none()).build().unwrap();
+
+    let created: CreatedLink = client
+        .post(format!("{}/links", base_url))
+        .json(&json!({ "url": "https://www.rust-lang.org/" }))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let target = format!("{}/{}", base_url, created.id);
+    let report = run(&client, &target, requests, concurrency).await;
+
+    let stats: Link = client
+        .get(format!("{}/links/{}", base_url, created.id))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    (report, stats)
+}
+
+#[tokio::main]
+async fn main() {
+    let args = Args::parse();
+    let dir = tempfile::tempdir().unwrap();
+    let base_url = match args.url {
+        Some(url) => url.trim_end_matches('/').to_string(),
+        None => start_server(&dir).await,
+    };
+
+    println!(
+        "Sending {} requests to {} with {} connections",
+        args.requests, base_url, args.concurrency
+    );
+    let (report, stats) = load_test(&base_url, args.requests, args.concurrency).await;
+    report.print();
+    println!("Hits counted by the server: {}", stats.hits);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn every_request_is_redirected_and_counted() {
+        let dir = tempfile::tempdir().unwrap();
+        let base_url = start_server(&dir).await;
+        let (report, stats) = load_test(&base_url, 200, 10).await;
+        assert_eq!(report.errors, 0);
+        assert_eq!(report.latencies.len(), 200);
+        assert_eq!(stats.hits, 200);
+        assert!(report.percentile(0.5) <= report.percentile(0.99));
+    }
+}
@@ -0,0 +1,6 @@
+CREATE TABLE links (
+    id         TEXT PRIMARY KEY NOT NULL,
+    url        TEXT NOT NULL,
+    hits       INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
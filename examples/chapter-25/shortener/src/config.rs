@@ -0,0 +1,144 @@
+// figment::Error is large, and both `Config::load` and the `Jail` closures
+// in the tests return it by value, as figment's own methods do
+#![allow(clippy::result_large_err)]
+
+use figment::providers::{Env, Format, Serialized, Toml};
+use figment::{Figment, Profile};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// All settings of the service, as in the `settings` example of Chapter 4
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Config {
+    /// The public address that short links start with
+    pub base_url: String,
+    /// Number of base62 characters in generated ids
+    pub id_length: usize,
+    /// "text" for people, "json" for log collectors
+    pub log_format: LogFormat,
+    pub server: Server,
+    pub database: Database,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Server {
+    pub host: String,
+    pub port: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Database {
+    pub url: String,
+    pub max_connections: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            base_url: "http://127.0.0.1:3000".to_string(),
+            id_length: 7,
+            log_format: LogFormat::Text,
+            server: Server {
+                host: "127.0.0.1".to_string(),
+                port: 3000,
+            },
+            database: Database {
+                url: "sqlite:shortener.db".to_string(),
+                max_connections: 5,
+            },
+        }
+    }
+}
+
+impl Config {
+    /// Later layers override earlier ones:
+    /// defaults < config file < SHORTENER_* environment variables.
+    /// SHORTENER_PROFILE selects a profile from the file (default: dev).
+    pub fn figment(path: impl AsRef<Path>) -> Figment {
+        Figment::from(Serialized::defaults(Config::default()))
+            .merge(Toml::file(path).nested())
+            // SHORTENER_SERVER__PORT=8080 sets server.port
+            .merge(
+                Env::prefixed("SHORTENER_")
+                    .ignore(&["PROFILE", "CONFIG"])
+                    .split("__")
+                    .global(),
+            )
+            .select(Profile::from_env_or("SHORTENER_PROFILE", "dev"))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> figment::Result<Config> {
+        let config: Config = Config::figment(path).extract()?;
+        // Errors that serde can't catch get the same error type
+        if !(4..=32).contains(&config.id_length) {
+            return Err(figment::Error::from(format!(
+                "id_length must be between 4 and 32, not {}",
+                config.id_length
+            )));
+        }
+        Ok(config)
+    }
+
+    pub fn address(&self) -> String {
+        format!("{}:{}", self.server.host, self.server.port)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use figment::Jail;
+
+    const CONFIG: &str = r#"
+        [default]
+        id_length = 6
+
+        [default.server]
+        port = 8080
+
+        [prod]
+        base_url = "https://sho.rt"
+        log_format = "json"
+    "#;
+
+    #[test]
+    fn defaults_file_and_environment() {
+        Jail::expect_with(|jail| {
+            assert_eq!(Config::load("Shortener.toml")?, Config::default());
+
+            jail.create_file("Shortener.toml", CONFIG)?;
+            let config = Config::load("Shortener.toml")?;
+            assert_eq!(config.id_length, 6);
+            assert_eq!(config.server.port, 8080);
+            assert_eq!(config.log_format, LogFormat::Text);
+
+            jail.set_env("SHORTENER_SERVER__PORT", "9000");
+            jail.set_env("SHORTENER_PROFILE", "prod");
+            let config = Config::load("Shortener.toml")?;
+            assert_eq!(config.server.port, 9000);
+            assert_eq!(config.base_url, "https://sho.rt");
+            assert_eq!(config.log_format, LogFormat::Json);
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        Jail::expect_with(|jail| {
+            jail.set_env("SHORTENER_ID_LENGTH", "2");
+            let err = Config::load("Shortener.toml").unwrap_err();
+            assert!(err.to_string().contains("id_length"), "{}", err);
+
+            jail.set_env("SHORTENER_LOG_FORMAT", "xml");
+            assert!(Config::load("Shortener.toml").is_err());
+            Ok(())
+        });
+    }
+}
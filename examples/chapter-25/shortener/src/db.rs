@@ -0,0 +1,136 @@
+use crate::config;
+use serde::{Deserialize, Serialize};
+use sqlx::migrate::Migrator;
+use sqlx::sqlite::{
+    SqliteConnectOptions, SqliteJournalMode, SqlitePool, SqlitePoolOptions, SqliteSynchronous,
+};
+use std::str::FromStr;
+
+// The migrations are embedded into the binary, as in Chapter 10
+static MIGRATOR: Migrator = sqlx::migrate!();
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Link {
+    pub id: String,
+    pub url: String,
+    pub hits: i64,
+    pub created_at: String,
+}
+
+/// All SQL of the service is in this type, so the handlers don't
+/// depend on the database
+#[derive(Clone)]
+pub struct Db {
+    pool: SqlitePool,
+}
+
+impl Db {
+    /// Opens the database, creating the file if needed, and brings the
+    /// schema up to date
+    pub async fn connect(config: &config::Database) -> Result<Db, sqlx::Error> {
+        let options = SqliteConnectOptions::from_str(&config.url)?
+            .create_if_missing(true)
+            // Every redirect writes to the database. In WAL mode, readers
+            // don't wait for writers, and with `synchronous = NORMAL` a
+            // commit doesn't wait for the disk. The load test shows the
+            // difference.
+            .journal_mode(SqliteJournalMode::Wal)
+            .synchronous(SqliteSynchronous::Normal);
+        // Every connection to `sqlite::memory:` opens a separate empty database
+        let max_connections = if config.url.contains(":memory:") {
+            1
+        } else {
+            config.max_connections
+        };
+        let pool = SqlitePoolOptions::new()
+            .max_connections(max_connections)
+            .connect_with(options)
+            .await?;
+        MIGRATOR.run(&pool).await?;
+        Ok(Db { pool })
+    }
+
+    /// Returns `false` if the id is already taken
+    pub async fn insert(&self, id: &str, url: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("INSERT INTO links (id, url) VALUES (?, ?)")
+            .bind(id)
+            .bind(url)
+            .execute(&self.pool)
+            .await;
+        match result {
+            Ok(_) => Ok(true),
+            Err(sqlx::Error::Database(e)) if e.is_unique_violation() => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Counts the visit and returns the target in one statement
+    pub async fn resolve(&self, id: &str) -> Result<Option<String>, sqlx::Error> {
+        sqlx::query_scalar("UPDATE links SET hits = hits + 1 WHERE id = ? RETURNING url")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn find(&self, id: &str) -> Result<Option<Link>, sqlx::Error> {
+        sqlx::query_as("SELECT id, url, hits, created_at FROM links WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    /// Lets the server check that the database answers
+    pub async fn ping(&self) -> Result<(), sqlx::Error> {
+        sqlx::query("SELECT 1").execute(&self.pool).await?;
+        Ok(())
+    }
+
+    /// Waits for the queries in progress and closes all connections
+    pub async fn close(&self) {
+        self.pool.close().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn memory_db() -> Db {
+        let config = config::Database {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 5,
+        };
+        Db::connect(&config).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn insert_resolve_and_count() {
+        let db = memory_db().await;
+        assert!(db.insert("abc", "https://www.rust-lang.org").await.unwrap());
+        assert!(!db.insert("abc", "https://crates.io").await.unwrap());
+
+        for _ in 0..3 {
+            let url = db.resolve("abc").await.unwrap();
+            assert_eq!(url.as_deref(), Some("https://www.rust-lang.org"));
+        }
+        let link = db.find("abc").await.unwrap().unwrap();
+        assert_eq!(link.hits, 3);
+        assert!(db.resolve("missing").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn links_survive_a_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = config::Database {
+            url: format!("sqlite:{}", dir.path().join("links.db").display()),
+            max_connections: 5,
+        };
+        let db = Db::connect(&config).await.unwrap();
+        db.insert("abc", "https://docs.rs").await.unwrap();
+        db.close().await;
+
+        // Running the migrations again on an existing database does nothing
+        let db = Db::connect(&config).await.unwrap();
+        assert_eq!(db.resolve("abc").await.unwrap().unwrap(), "https://docs.rs");
+    }
+}
@@ -0,0 +1,38 @@
+use axum::extract::rejection::JsonRejection;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde_json::json;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ApiError {
+    #[error("Link '{0}' not found")]
+    NotFound(String),
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(String),
+    #[error("No free id found, try again")]
+    NoFreeId,
+    #[error("{}", .0.body_text())]
+    Json(#[from] JsonRejection),
+    // The details are logged, but not sent to the client
+    #[error("Internal server error")]
+    Database(#[from] sqlx::Error),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            ApiError::NotFound(_) => StatusCode::NOT_FOUND,
+            ApiError::InvalidUrl(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::NoFreeId => StatusCode::SERVICE_UNAVAILABLE,
+            ApiError::Json(rejection) => rejection.status(),
+            ApiError::Database(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        match &self {
+            ApiError::Database(e) => tracing::error!(error = %e, "Database error"),
+            _ => tracing::warn!(%status, error = %self, "Request failed"),
+        }
+        (status, Json(json!({ "error": self.to_string() }))).into_response()
+    }
+}
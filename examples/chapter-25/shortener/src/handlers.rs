@@ -0,0 +1,114 @@
+use crate::db::Link;
+use crate::error::ApiError;
+use crate::id;
+use crate::AppState;
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::Redirect;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tracing::{debug, info};
+use url::Url;
+
+// A new id is tried a few times before giving up. If this ever fails,
+// the ids are too short for the number of links.
+const MAX_ATTEMPTS: usize = 5;
+const MAX_URL_LENGTH: usize = 2048;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateLink {
+    pub url: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreatedLink {
+    pub id: String,
+    pub url: String,
+    pub short_url: String,
+}
+
+pub async fn create_link(
+    State(state): State<AppState>,
+    payload: Result<Json<CreateLink>, JsonRejection>,
+) -> Result<(StatusCode, Json<CreatedLink>), ApiError> {
+    let Json(input) = payload?;
+    let url = validate_url(&input.url, &state.config.base_url)?;
+
+    for attempt in 1..=MAX_ATTEMPTS {
+        // `thread_rng()` can't be held across `.await`, so the id is
+        // generated in its own statement
+        let id = id::random_id(&mut rand::thread_rng(), state.config.id_length);
+        if state.db.insert(&id, url.as_str()).await? {
+            info!(%id, url = %url, "Link created");
+            let short_url = format!("{}/{}", state.config.base_url.trim_end_matches('/'), id);
+            let link = CreatedLink {
+                id,
+                url: url.into(),
+                short_url,
+            };
+            return Ok((StatusCode::CREATED, Json(link)));
+        }
+        debug!(%id, attempt, "Id already taken");
+    }
+    Err(ApiError::NoFreeId)
+}
+
+pub async fn follow_link(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Redirect, ApiError> {
+    if !id::is_valid(&id) {
+        return Err(ApiError::NotFound(id));
+    }
+    match state.db.resolve(&id).await? {
+        // 307 instead of 301 or 308: browsers cache permanent redirects
+        // and would stop coming back, so their visits wouldn't be counted
+        Some(url) => Ok(Redirect::temporary(&url)),
+        None => Err(ApiError::NotFound(id)),
+    }
+}
+
+pub async fn link_stats(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Link>, ApiError> {
+    if !id::is_valid(&id) {
+        return Err(ApiError::NotFound(id));
+    }
+    let link = state.db.find(&id).await?;
+    link.map(Json).ok_or(ApiError::NotFound(id))
+}
+
+/// For load balancers and container orchestrators
+pub async fn health(State(state): State<AppState>) -> Result<Json<Value>, ApiError> {
+    state.db.ping().await?;
+    Ok(Json(json!({ "status": "ok" })))
+}
+
+// Only absolute http and https URLs. Without this check, the service
+// would redirect to `javascript:` URLs or to itself.
+fn validate_url(input: &str, base_url: &str) -> Result<Url, ApiError> {
+    if input.len() > MAX_URL_LENGTH {
+        return Err(ApiError::InvalidUrl(format!(
+            "longer than {} characters",
+            MAX_URL_LENGTH
+        )));
+    }
+    let url = Url::parse(input.trim()).map_err(|e| ApiError::InvalidUrl(e.to_string()))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(ApiError::InvalidUrl(format!(
+            "unsupported scheme '{}'",
+            url.scheme()
+        )));
+    }
+    if url.host().is_none() {
+        return Err(ApiError::InvalidUrl("missing host".to_string()));
+    }
+    // A short link to another short link could form a redirect loop
+    if Url::parse(base_url).is_ok_and(|base| base.origin() == url.origin()) {
+        return Err(ApiError::InvalidUrl("points to this service".to_string()));
+    }
+    Ok(url)
+}
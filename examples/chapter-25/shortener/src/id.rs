@@ -0,0 +1,69 @@
+use rand::distributions::{Distribution, Uniform};
+use rand::Rng;
+
+/// Digits, then upper and lower case letters: 62 characters that are safe
+/// in a URL path without escaping
+pub const ALPHABET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// A random id of `length` base62 characters.
+///
+/// Random ids don't reveal how many links exist, and can't be guessed by
+/// counting, as sequential ids could. With 7 characters there are
+/// 62^7 = 3.5 trillion ids, so collisions are rare, but the caller still
+/// has to handle them.
+pub fn random_id<R: Rng + ?Sized>(rng: &mut R, length: usize) -> String {
+    // `Uniform` picks every character with the same probability.
+    // `rng.gen::<u8>() % 62` would prefer the first 8 characters.
+    let index = Uniform::from(0..ALPHABET.len());
+    index
+        .sample_iter(rng)
+        .take(length)
+        .map(|i| ALPHABET[i] as char)
+        .collect()
+}
+
+/// Checks an id from a request before it reaches the database
+pub fn is_valid(id: &str) -> bool {
+    (1..=32).contains(&id.len()) && id.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn ids_have_the_requested_length_and_alphabet() {
+        let mut rng = rand::thread_rng();
+        for length in [4, 7, 32] {
+            let id = random_id(&mut rng, length);
+            assert_eq!(id.len(), length);
+            assert!(is_valid(&id), "{}", id);
+        }
+    }
+
+    #[test]
+    fn seeded_generators_are_reproducible() {
+        let first = random_id(&mut StdRng::seed_from_u64(42), 7);
+        let second = random_id(&mut StdRng::seed_from_u64(42), 7);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn all_characters_are_used() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let used: HashSet<char> = random_id(&mut rng, 10_000).chars().collect();
+        assert_eq!(used.len(), 62);
+    }
+
+    #[test]
+    fn validates_ids_from_requests() {
+        assert!(is_valid("aZ09"));
+        assert!(!is_valid(""));
+        assert!(!is_valid("with-dash"));
+        assert!(!is_valid("..%2F"));
+        assert!(!is_valid(&"a".repeat(33)));
+    }
+}
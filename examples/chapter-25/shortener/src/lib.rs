@@ -0,0 +1,46 @@
+pub mod config;
+pub mod db;
+mod error;
+mod handlers;
+pub mod id;
+
+pub use config::{Config, LogFormat};
+pub use db::{Db, Link};
+pub use error::ApiError;
+pub use handlers::{CreateLink, CreatedLink};
+
+use axum::routing::{get, post};
+use axum::Router;
+use std::sync::Arc;
+use tower_http::trace::{DefaultMakeSpan, DefaultOnResponse, TraceLayer};
+use tracing::Level;
+
+// Cloned for every request: a pool handle and an `Arc`
+#[derive(Clone)]
+pub struct AppState {
+    db: Db,
+    config: Arc<Config>,
+}
+
+impl AppState {
+    pub fn new(db: Db, config: Config) -> Self {
+        AppState {
+            db,
+            config: Arc::new(config),
+        }
+    }
+}
+
+pub fn app(state: AppState) -> Router {
+    Router::new()
+        .route("/links", post(handlers::create_link))
+        .route("/links/:id", get(handlers::link_stats))
+        .route("/health", get(handlers::health))
+        .route("/:id", get(handlers::follow_link))
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(DefaultMakeSpan::new().level(Level::INFO))
+                .on_response(DefaultOnResponse::new().level(Level::INFO)),
+        )
+        .with_state(state)
+}
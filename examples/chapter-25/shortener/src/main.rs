@@ -0,0 +1,54 @@
+use shortener::{app, AppState, Config, Db, LogFormat};
+use shutdown::shutdown_signal;
+use tokio::net::TcpListener;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+
+fn init_logging(format: LogFormat) {
+    // RUST_LOG overrides the default, e.g. RUST_LOG=shortener=debug
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new("shortener=info,tower_http=info,shutdown=info"));
+    let subscriber = tracing_subscriber::fmt().with_env_filter(filter);
+    match format {
+        LogFormat::Text => subscriber.init(),
+        // One JSON object per line. The request span is in the "spans" list.
+        LogFormat::Json => subscriber.json().with_current_span(false).init(),
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let path = std::env::var("SHORTENER_CONFIG").unwrap_or_else(|_| "Shortener.toml".into());
+    let config = match Config::load(&path) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Invalid configuration: {}", e);
+            std::process::exit(1);
+        }
+    };
+    init_logging(config.log_format);
+
+    let db = Db::connect(&config.database)
+        .await
+        .expect("Failed to open the database");
+    info!(url = %config.database.url, "Database ready");
+
+    let listener = TcpListener::bind(config.address())
+        .await
+        .expect("Failed to bind the address");
+    info!(
+        address = %listener.local_addr().unwrap(),
+        base_url = %config.base_url,
+        "Listening"
+    );
+
+    axum::serve(listener, app(AppState::new(db.clone(), config)))
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .unwrap();
+
+    // After the last request: close the connections so that SQLite can
+    // clean up its journal files
+    db.close().await;
+    info!("Stopped");
+}
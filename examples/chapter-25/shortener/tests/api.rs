@@ -0,0 +1,141 @@
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use axum::Router;
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use shortener::{app, config, AppState, Config, Db};
+use tower::ServiceExt;
+
+async fn test_app() -> Router {
+    let config = Config {
+        base_url: "https://sho.rt/".to_string(),
+        database: config::Database {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 1,
+        },
+        ..Config::default()
+    };
+    let db = Db::connect(&config.database).await.unwrap();
+    app(AppState::new(db, config))
+}
+
+// Sends one request to the router without starting a server
+async fn send(app: &Router, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(request(method, uri, body))
+        .await
+        .unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let json = if bytes.is_empty() {
+        Value::Null
+    } else {
+        serde_json::from_slice(&bytes).unwrap()
+    };
+    (status, json)
+}
+
+fn request(method: &str, uri: &str, body: Option<Value>) -> Request<Body> {
+    let request = Request::builder().method(method).uri(uri);
+    match body {
+        Some(body) => request
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => request.body(Body::empty()),
+    }
+    .unwrap()
+}
+
+async fn shorten(app: &Router, url: &str) -> Value {
+    let (status, json) = send(app, "POST", "/links", Some(json!({ "url": url }))).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", json);
+    json
+}
+
+#[tokio::test]
+async fn shorten_and_follow() {
+    let app = test_app().await;
+    let link = shorten(&app, "https://www.rust-lang.org/learn").await;
+    let id = link["id"].as_str().unwrap();
+    assert_eq!(id.len(), 7);
+    assert_eq!(link["short_url"], format!("https://sho.rt/{}", id));
+
+    let response = app
+        .clone()
+        .oneshot(request("GET", &format!("/{}", id), None))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::TEMPORARY_REDIRECT);
+    assert_eq!(
+        response.headers()[header::LOCATION],
+        "https://www.rust-lang.org/learn"
+    );
+}
+
+#[tokio::test]
+async fn visits_are_counted() {
+    let app = test_app().await;
+    let link = shorten(&app, "https://crates.io").await;
+    let id = link["id"].as_str().unwrap();
+    for _ in 0..3 {
+        send(&app, "GET", &format!("/{}", id), None).await;
+    }
+
+    let (status, stats) = send(&app, "GET", &format!("/links/{}", id), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(stats["hits"], 3);
+    // The URL is stored normalized
+    assert_eq!(stats["url"], "https://crates.io/");
+}
+
+#[tokio::test]
+async fn every_link_gets_its_own_id() {
+    let app = test_app().await;
+    let first = shorten(&app, "https://docs.rs").await;
+    let second = shorten(&app, "https://docs.rs").await;
+    assert_ne!(first["id"], second["id"]);
+}
+
+#[tokio::test]
+async fn rejects_invalid_urls() {
+    let app = test_app().await;
+    for url in [
+        "not a url",
+        "javascript:alert(1)",
+        "ftp://example.com",
+        "/relative",
+        "https://sho.rt/abc1234",
+        "HTTPS://SHO.RT:443/x",
+    ] {
+        let (status, json) = send(&app, "POST", "/links", Some(json!({ "url": url }))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", url);
+        assert!(json["error"].as_str().unwrap().starts_with("Invalid URL"));
+    }
+
+    let long = format!("https://example.com/{}", "a".repeat(3000));
+    let (status, _) = send(&app, "POST", "/links", Some(json!({ "url": long }))).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = send(&app, "POST", "/links", Some(json!({ "link": "x" }))).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn unknown_links_are_not_found() {
+    let app = test_app().await;
+    let (status, json) = send(&app, "GET", "/nothing", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(json["error"], "Link 'nothing' not found");
+
+    let (status, _) = send(&app, "GET", "/links/bad%20id", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn health_checks_the_database() {
+    let app = test_app().await;
+    let (status, json) = send(&app, "GET", "/health", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json, json!({ "status": "ok" }));
+}
@@ -0,0 +1,178 @@
+use caching::CacheStats;
+use http_client::base_url;
+use moka::future::Cache;
+use reqwest::Client;
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// The reqwest client from Chapter 11 with an in-memory cache in front of it.
+///
+/// `moka` is a concurrent cache: it can be shared between tasks without a
+/// `Mutex`, and cloning it only clones a handle.
+#[derive(Clone)]
+struct CachedClient {
+    client: Client,
+    // The values are `Arc`s, because the cache clones them on every hit
+    cache: Cache<String, Arc<Value>>,
+    stats: Arc<CacheStats>,
+}
+
+impl CachedClient {
+    fn new(ttl: Duration, max_entries: u64) -> Self {
+        CachedClient {
+            client: Client::builder()
+                .timeout(Duration::from_secs(10))
+                .build()
+                .unwrap(),
+            cache: Cache::builder()
+                // Entries expire `ttl` after they were inserted, so the
+                // data is never older than that
+                .time_to_live(ttl)
+                .max_capacity(max_entries)
+                .build(),
+            stats: Arc::default(),
+        }
+    }
+
+    /// Returns the cached response, or sends the request.
+    ///
+    /// If several tasks ask for the same URL at the same time, only one
+    /// request is sent and the others wait for its result. Errors are not
+    /// cached, so the next call tries again.
+    async fn get_json(&self, url: &str) -> Result<Arc<Value>, Arc<reqwest::Error>> {
+        let entry = self
+            .cache
+            .entry_by_ref(url)
+            .or_try_insert_with(async {
+                let response = self.client.get(url).send().await?.error_for_status()?;
+                Ok(Arc::new(response.json::<Value>().await?))
+            })
+            .await?;
+        // `is_fresh` is true only for the call that ran the request
+        if entry.is_fresh() {
+            self.stats.miss();
+        } else {
+            self.stats.hit();
+        }
+        Ok(entry.into_value())
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Arc<reqwest::Error>> {
+    let client = CachedClient::new(Duration::from_secs(2), 1_000);
+    let uuid_url = format!("{}/uuid", base_url());
+
+    // httpbin returns a new UUID for every request, so the same UUID
+    // means the response came from the cache
+    for _ in 0..3 {
+        let start = Instant::now();
+        let json = client.get_json(&uuid_url).await?;
+        println!("{} in {:.2?}", json["uuid"], start.elapsed());
+    }
+
+    // Ten tasks ask for a slow URL at the same time: one request is sent
+    let delay_url = format!("{}/delay/1", base_url());
+    let start = Instant::now();
+    let tasks: Vec<_> = (0..10)
+        .map(|_| {
+            let (client, url) = (client.clone(), delay_url.clone());
+            tokio::spawn(async move { client.get_json(&url).await })
+        })
+        .collect();
+    for task in tasks {
+        task.await.unwrap()?;
+    }
+    println!(
+        "10 concurrent requests for /delay/1 in {:.2?}",
+        start.elapsed()
+    );
+
+    tokio::time::sleep(Duration::from_millis(2100)).await;
+    let json = client.get_json(&uuid_url).await?;
+    println!("{} after the TTL", json["uuid"]);
+
+    println!("Cache: {}", client.stats);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn server_with(route: &str, response: ResponseTemplate, requests: u64) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(route))
+            .respond_with(response)
+            // Checked when the server is dropped
+            .expect(requests)
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn repeated_requests_are_served_from_the_cache() {
+        let response = ResponseTemplate::new(200).set_body_json(json!({ "n": 1 }));
+        let server = server_with("/data", response, 1).await;
+        let client = CachedClient::new(Duration::from_secs(60), 100);
+        let url = format!("{}/data", server.uri());
+
+        for _ in 0..5 {
+            assert_eq!(client.get_json(&url).await.unwrap()["n"], 1);
+        }
+        assert_eq!((client.stats.hits(), client.stats.misses()), (4, 1));
+    }
+
+    #[tokio::test]
+    async fn entries_expire_after_the_ttl() {
+        let response = ResponseTemplate::new(200).set_body_json(json!({}));
+        let server = server_with("/data", response, 2).await;
+        let client = CachedClient::new(Duration::from_millis(200), 100);
+        let url = format!("{}/data", server.uri());
+
+        client.get_json(&url).await.unwrap();
+        client.get_json(&url).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        client.get_json(&url).await.unwrap();
+        assert_eq!(client.stats.misses(), 2);
+    }
+
+    #[tokio::test]
+    async fn concurrent_misses_send_one_request() {
+        let response = ResponseTemplate::new(200)
+            .set_body_json(json!({}))
+            .set_delay(Duration::from_millis(200));
+        let server = server_with("/slow", response, 1).await;
+        let client = CachedClient::new(Duration::from_secs(60), 100);
+        let url = format!("{}/slow", server.uri());
+
+        let tasks: Vec<_> = (0..10)
+            .map(|_| {
+                let (client, url) = (client.clone(), url.clone());
+                tokio::spawn(async move { client.get_json(&url).await })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap().unwrap();
+        }
+        assert_eq!((client.stats.hits(), client.stats.misses()), (9, 1));
+    }
+
+    #[tokio::test]
+    async fn errors_are_not_cached() {
+        let server = server_with("/broken", ResponseTemplate::new(500), 2).await;
+        let client = CachedClient::new(Duration::from_secs(60), 100);
+        let url = format!("{}/broken", server.uri());
+
+        let err = client.get_json(&url).await.unwrap_err();
+        assert_eq!(err.status().map(|s| s.as_u16()), Some(500));
+        assert!(client.get_json(&url).await.is_err());
+        assert_eq!(client.cache.get(&url).await, None);
+    }
+}
@@ -0,0 +1,150 @@
+use caching::CacheStats;
+use lru::LruCache;
+use std::num::NonZeroUsize;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Profile {
+    id: u32,
+    name: String,
+}
+
+/// Stands in for a slow database query or a call to another service
+fn load_profile(id: u32) -> Profile {
+    thread::sleep(Duration::from_millis(2));
+    Profile {
+        id,
+        name: format!("user-{}", id),
+    }
+}
+
+/// Keeps the `capacity` most recently used profiles.
+///
+/// A `HashMap` that keeps everything grows without limit. An LRU (least
+/// recently used) cache has a fixed size and, when it is full, drops the
+/// entry that hasn't been used for the longest time.
+struct ProfileCache<F> {
+    cache: LruCache<u32, Profile>,
+    load: F,
+    stats: CacheStats,
+}
+
+impl<F: Fn(u32) -> Profile> ProfileCache<F> {
+    fn new(capacity: usize, load: F) -> Self {
+        ProfileCache {
+            cache: LruCache::new(NonZeroUsize::new(capacity).expect("capacity must not be 0")),
+            load,
+            stats: CacheStats::default(),
+        }
+    }
+
+    fn get(&mut self, id: u32) -> &Profile {
+        // `get` needs `&mut self`: it moves the entry to the front
+        if self.cache.contains(&id) {
+            self.stats.hit();
+        } else {
+            self.stats.miss();
+        }
+        self.cache.get_or_insert(id, || (self.load)(id))
+    }
+}
+
+/// 4 out of 5 requests ask for one of 4 popular users, the rest for one of
+/// 96 others: a typical workload, where a few keys get most of the traffic
+fn workload(requests: u32) -> impl Iterator<Item = u32> {
+    (0..requests).map(|i| {
+        if i % 5 == 0 {
+            5 + (i / 5 * 37) % 96
+        } else {
+            i % 5
+        }
+    })
+}
+
+fn main() {
+    // Watch the least recently used entry go
+    let mut cache = LruCache::new(NonZeroUsize::new(3).unwrap());
+    cache.put("a", 1);
+    cache.put("b", 2);
+    cache.put("c", 3);
+    cache.get(&"a");
+    cache.put("d", 4);
+    let keys: Vec<_> = cache.iter().map(|(key, _)| *key).collect();
+    println!("After using a and adding d: {:?} (most recent first)", keys);
+
+    println!();
+    for capacity in [1, 4, 5, 100] {
+        let mut profiles = ProfileCache::new(capacity, load_profile);
+        let start = Instant::now();
+        for id in workload(1000) {
+            assert_eq!(profiles.get(id).id, id);
+        }
+        println!(
+            "Capacity {:>3}: {:>7.2?}, {}",
+            capacity,
+            start.elapsed(),
+            profiles.stats
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn profile(id: u32) -> Profile {
+        Profile {
+            id,
+            name: format!("user-{}", id),
+        }
+    }
+
+    #[test]
+    fn loads_each_profile_once_while_it_fits() {
+        let loads = Cell::new(0);
+        let mut profiles = ProfileCache::new(2, |id| {
+            loads.set(loads.get() + 1);
+            profile(id)
+        });
+        assert_eq!(profiles.get(1).name, "user-1");
+        profiles.get(2);
+        profiles.get(1);
+        profiles.get(2);
+        assert_eq!(loads.get(), 2);
+        assert_eq!((profiles.stats.hits(), profiles.stats.misses()), (2, 2));
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used_entry() {
+        let loads = Cell::new(0);
+        let mut profiles = ProfileCache::new(2, |id| {
+            loads.set(loads.get() + 1);
+            profile(id)
+        });
+        profiles.get(1);
+        profiles.get(2);
+        // Using 1 makes 2 the least recently used entry
+        profiles.get(1);
+        profiles.get(3);
+        assert!(profiles.cache.contains(&1));
+        assert!(!profiles.cache.contains(&2));
+
+        profiles.get(2);
+        assert_eq!(loads.get(), 4);
+    }
+
+    #[test]
+    fn larger_caches_hit_more_often() {
+        let ratio = |capacity| {
+            let mut profiles = ProfileCache::new(capacity, profile);
+            workload(1000).for_each(|id| {
+                profiles.get(id);
+            });
+            profiles.stats.hit_ratio()
+        };
+        assert!(ratio(1) < ratio(5));
+        assert!(ratio(5) < ratio(100));
+    }
+}
@@ -0,0 +1,155 @@
+use caching::CacheStats;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Instant;
+
+/// The textbook definition: fib(n - 1) and fib(n - 2) compute the same
+/// values again and again, so the number of calls grows exponentially
+fn fib_naive(n: u64, calls: &mut u64) -> u128 {
+    *calls += 1;
+    if n < 2 {
+        return n as u128;
+    }
+    fib_naive(n - 1, calls) + fib_naive(n - 2, calls)
+}
+
+/// The same recursion, but every result is computed only once
+fn fib_memo(n: u64, memo: &mut HashMap<u64, u128>, calls: &mut u64) -> u128 {
+    *calls += 1;
+    if n < 2 {
+        return n as u128;
+    }
+    if let Some(&result) = memo.get(&n) {
+        return result;
+    }
+    // `memo.entry(n).or_insert_with(...)` doesn't work here: the entry
+    // borrows the map mutably while the recursive calls need it too
+    let result = fib_memo(n - 1, memo, calls) + fib_memo(n - 2, memo, calls);
+    memo.insert(n, result);
+    result
+}
+
+/// Remembers the results of any function with one argument.
+///
+/// Only pure functions can be memoized: the result must depend on the
+/// argument alone, or the cache returns stale values.
+struct Memoized<A, R, F> {
+    function: F,
+    cache: HashMap<A, R>,
+    stats: CacheStats,
+}
+
+impl<A, R, F> Memoized<A, R, F>
+where
+    A: Hash + Eq + Clone,
+    R: Clone,
+    F: Fn(&A) -> R,
+{
+    fn new(function: F) -> Self {
+        Memoized {
+            function,
+            cache: HashMap::new(),
+            stats: CacheStats::default(),
+        }
+    }
+
+    fn get(&mut self, arg: A) -> R {
+        if let Some(result) = self.cache.get(&arg) {
+            self.stats.hit();
+            return result.clone();
+        }
+        self.stats.miss();
+        let result = (self.function)(&arg);
+        self.cache.insert(arg, result.clone());
+        result
+    }
+}
+
+/// Deliberately slow: tries every divisor
+fn count_primes_below(limit: &u32) -> usize {
+    (2..*limit)
+        .filter(|&n| (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0))
+        .count()
+}
+
+fn main() {
+    let start = Instant::now();
+    let mut calls = 0;
+    let result = fib_naive(35, &mut calls);
+    println!(
+        "Naive:    fib(35) = {} with {} calls in {:.2?}",
+        result,
+        calls,
+        start.elapsed()
+    );
+
+    let start = Instant::now();
+    let mut calls = 0;
+    let result = fib_memo(35, &mut HashMap::new(), &mut calls);
+    println!(
+        "Memoized: fib(35) = {} with {} calls in {:.2?}",
+        result,
+        calls,
+        start.elapsed()
+    );
+
+    // The naive version wouldn't finish this in our lifetime
+    let mut calls = 0;
+    let result = fib_memo(150, &mut HashMap::new(), &mut calls);
+    println!("Memoized: fib(150) = {} with {} calls", result, calls);
+
+    let mut primes = Memoized::new(count_primes_below);
+    for limit in [2_000_000, 1_000, 2_000_000, 2_000_000] {
+        let start = Instant::now();
+        let count = primes.get(limit);
+        println!(
+            "Primes below {:>9}: {:>6} in {:.2?}",
+            limit,
+            count,
+            start.elapsed()
+        );
+    }
+    println!("Cache: {}", primes.stats);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn both_versions_agree() {
+        for n in 0..25 {
+            let (mut naive_calls, mut memo_calls) = (0, 0);
+            assert_eq!(
+                fib_naive(n, &mut naive_calls),
+                fib_memo(n, &mut HashMap::new(), &mut memo_calls)
+            );
+        }
+    }
+
+    #[test]
+    fn memoization_makes_the_calls_linear() {
+        let mut calls = 0;
+        assert_eq!(
+            fib_memo(90, &mut HashMap::new(), &mut calls),
+            2880067194370816120
+        );
+        // Each n from 2 to 90 is computed once and looked up once
+        assert!(calls < 2 * 90, "{} calls", calls);
+    }
+
+    #[test]
+    fn memoized_calls_the_function_once_per_argument() {
+        let calls = Cell::new(0);
+        let mut square = Memoized::new(|x: &u32| {
+            calls.set(calls.get() + 1);
+            x * x
+        });
+        assert_eq!(square.get(4), 16);
+        assert_eq!(square.get(4), 16);
+        assert_eq!(square.get(5), 25);
+        assert_eq!(calls.get(), 2);
+        assert_eq!((square.stats.hits(), square.stats.misses()), (1, 2));
+    }
+}
@@ -0,0 +1,69 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Hit and miss counters for a cache.
+///
+/// The counters are atomic, so a `&CacheStats` can be shared between
+/// threads and tasks without a lock.
+#[derive(Debug, Default)]
+pub struct CacheStats {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CacheStats {
+    pub fn hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// The share of lookups answered from the cache, between 0 and 1
+    pub fn hit_ratio(&self) -> f64 {
+        let total = self.hits() + self.misses();
+        if total == 0 {
+            0.0
+        } else {
+            self.hits() as f64 / total as f64
+        }
+    }
+}
+
+impl fmt::Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, hit ratio {:.0}%",
+            self.hits(),
+            self.misses(),
+            self.hit_ratio() * 100.0
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_hits_and_misses() {
+        let stats = CacheStats::default();
+        assert_eq!(stats.hit_ratio(), 0.0);
+        stats.miss();
+        stats.hit();
+        stats.hit();
+        stats.hit();
+        assert_eq!(stats.hit_ratio(), 0.75);
+        assert_eq!(stats.to_string(), "3 hits, 1 misses, hit ratio 75%");
+    }
+}
@@ -0,0 +1,29 @@
+use http_client::base_url;
+use http_client::limited::limited_client;
+use std::time::Instant;
+
+#[tokio::main]
+async fn main() -> Result<(), reqwest_middleware::Error> {
+    let client = limited_client(3, 2.0);
+    let url = format!("{}/get", base_url());
+
+    // All 8 requests start at once; the middleware spaces them out
+    let start = Instant::now();
+    let requests = (1..=8).map(|i| {
+        let (client, url) = (client.clone(), url.clone());
+        tokio::spawn(async move {
+            let response = client.get(&url).send().await?;
+            println!(
+                "Request {} finished after {:.1}s: {}",
+                i,
+                start.elapsed().as_secs_f32(),
+                response.status()
+            );
+            Ok::<_, reqwest_middleware::Error>(())
+        })
+    });
+    for request in requests.collect::<Vec<_>>() {
+        request.await.unwrap()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use governor::clock::FakeRelativeClock;
+use governor::{Quota, RateLimiter as Governor};
+use rate_limit::{RateLimiter, SlidingWindow, TokenBucket};
+use std::num::NonZeroU32;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const SLOT: Duration = Duration::from_millis(50);
+
+/// A burst of 10 requests, then one request every 50 ms (20 per second)
+/// for 3 seconds. Returns a timeline with one character per 50 ms slot:
+/// the number of accepted requests, or '.' for none.
+fn simulate(mut accept: impl FnMut(Duration) -> bool) -> String {
+    let mut timeline = String::new();
+    for slot in 0..60u32 {
+        let at = SLOT * slot;
+        let requests = if slot == 0 { 10 } else { 1 };
+        let accepted = (0..requests).filter(|_| accept(at)).count();
+        timeline.push(match accepted {
+            0 => '.',
+            n if n < 10 => char::from_digit(n as u32, 10).unwrap(),
+            _ => '*',
+        });
+        if slot % 20 == 19 {
+            timeline.push(' ');
+        }
+    }
+    timeline.trim_end().to_string()
+}
+
+fn with_limiter<L: RateLimiter>(mut limiter: L, start: Instant) -> impl FnMut(Duration) -> bool {
+    move |at| limiter.try_acquire_at(start + at).is_ok()
+}
+
+fn with_governor(per_second: u32) -> impl FnMut(Duration) -> bool {
+    // governor takes the time from a clock. A fake clock makes the
+    // simulation deterministic, just like passing `now` to our limiters.
+    // Clones share the same time
+    let clock = FakeRelativeClock::default();
+    let quota = Quota::per_second(NonZeroU32::new(per_second).unwrap());
+    let limiter = Governor::direct_with_clock(quota, clock.clone());
+    let mut elapsed = Duration::ZERO;
+    move |at| {
+        clock.advance(at - elapsed);
+        elapsed = at;
+        limiter.check().is_ok()
+    }
+}
+
+/// Average time of one check when `threads` threads share one limiter
+fn bench(threads: usize, check: impl Fn() + Sync) -> Duration {
+    const CHECKS: u32 = 1_000_000;
+    let start = Instant::now();
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                for _ in 0..CHECKS {
+                    check();
+                }
+            });
+        }
+    });
+    start.elapsed() / (CHECKS * threads as u32)
+}
+
+fn main() {
+    let start = Instant::now();
+    println!("Limit: 10 requests per second. Each character is 50 ms:");
+    println!(
+        "Token bucket:   {}",
+        simulate(with_limiter(TokenBucket::new_at(10, 10.0, start), start))
+    );
+    println!(
+        "Sliding window: {}",
+        simulate(with_limiter(
+            SlidingWindow::new(10, Duration::from_secs(1)),
+            start
+        ))
+    );
+    println!("governor:       {}", simulate(with_governor(10)));
+
+    // A limit that is never reached, so every check succeeds
+    let bucket = Mutex::new(TokenBucket::new(u32::MAX, 1e12));
+    let window = Mutex::new(SlidingWindow::new(100, Duration::from_nanos(1)));
+    let governor = Governor::direct(Quota::per_second(NonZeroU32::MAX));
+
+    println!();
+    println!("Time per check:   1 thread   4 threads");
+    for (name, check) in [
+        (
+            "Token bucket  ",
+            Arc::new(|| {
+                let _ = bucket.lock().unwrap().try_acquire();
+            }) as Arc<dyn Fn() + Sync + Send>,
+        ),
+        (
+            "Sliding window",
+            Arc::new(|| {
+                let _ = window.lock().unwrap().try_acquire();
+            }),
+        ),
+        (
+            "governor      ",
+            Arc::new(|| {
+                let _ = governor.check();
+            }),
+        ),
+    ] {
+        println!(
+            "{}  {:>9.1?}  {:>10.1?}",
+            name,
+            bench(1, &*check),
+            bench(4, &*check)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_bucket_and_governor_accept_the_same_requests() {
+        let start = Instant::now();
+        let ours = simulate(with_limiter(TokenBucket::new_at(10, 10.0, start), start));
+        let governor = simulate(with_governor(10));
+        assert_eq!(ours, governor);
+        assert!(ours.starts_with("*.1.1.1"), "{}", ours);
+    }
+
+    #[test]
+    fn sliding_window_accepts_bursts_once_per_window() {
+        let start = Instant::now();
+        let timeline = simulate(with_limiter(
+            SlidingWindow::new(10, Duration::from_secs(1)),
+            start,
+        ));
+        // Nothing after the initial burst until it leaves the window,
+        // then 10 requests in a row
+        assert!(
+            timeline.starts_with("*................... 1111111111.........."),
+            "{}",
+            timeline
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use crate::RateLimiter;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// One limiter per key, such as a client's IP address or user id, so that
+/// one busy client can't use up the limit of everyone else.
+///
+/// Clients that haven't sent a request for `idle_timeout` are forgotten,
+/// so the map doesn't grow with every address that ever connected. For a
+/// token bucket that is refilled within `idle_timeout`, forgetting it
+/// changes nothing: a new bucket starts full, too.
+pub struct KeyedLimiter<K, L> {
+    state: Mutex<State<K, L>>,
+    new_limiter: Box<dyn Fn() -> L + Send + Sync>,
+    idle_timeout: Duration,
+}
+
+struct State<K, L> {
+    // Each limiter with the time of its last request
+    limiters: HashMap<K, (L, Instant)>,
+    last_sweep: Instant,
+}
+
+impl<K: Hash + Eq, L: RateLimiter> KeyedLimiter<K, L> {
+    pub fn new(
+        idle_timeout: Duration,
+        new_limiter: impl Fn() -> L + Send + Sync + 'static,
+    ) -> Self {
+        KeyedLimiter {
+            state: Mutex::new(State {
+                limiters: HashMap::new(),
+                last_sweep: Instant::now(),
+            }),
+            new_limiter: Box::new(new_limiter),
+            idle_timeout,
+        }
+    }
+
+    pub fn check(&self, key: K) -> Result<(), Duration> {
+        self.check_at(key, Instant::now())
+    }
+
+    pub fn check_at(&self, key: K, now: Instant) -> Result<(), Duration> {
+        let mut state = self.state.lock().unwrap();
+        // At most one sweep per `idle_timeout`, so its cost is spread over
+        // many requests
+        if now.saturating_duration_since(state.last_sweep) >= self.idle_timeout {
+            let idle_timeout = self.idle_timeout;
+            state.limiters.retain(|_, (_, last_used)| {
+                now.saturating_duration_since(*last_used) < idle_timeout
+            });
+            state.last_sweep = now;
+        }
+        let (limiter, last_used) = state
+            .limiters
+            .entry(key)
+            .or_insert_with(|| ((self.new_limiter)(), now));
+        *last_used = now;
+        limiter.try_acquire_at(now)
+    }
+
+    /// Number of clients currently tracked
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().limiters.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TokenBucket;
+
+    #[test]
+    fn every_key_has_its_own_limit() {
+        let limiter = KeyedLimiter::new(Duration::from_secs(60), || TokenBucket::new(2, 1.0));
+        let now = Instant::now();
+        assert!(limiter.check_at("alice", now).is_ok());
+        assert!(limiter.check_at("alice", now).is_ok());
+        assert!(limiter.check_at("alice", now).is_err());
+        // Bob isn't affected by Alice's requests
+        assert!(limiter.check_at("bob", now).is_ok());
+        assert_eq!(limiter.len(), 2);
+    }
+
+    #[test]
+    fn idle_keys_are_forgotten() {
+        let limiter = KeyedLimiter::new(Duration::from_secs(60), || TokenBucket::new(2, 1.0));
+        let start = Instant::now();
+        limiter.check_at("alice", start).unwrap();
+        limiter
+            .check_at("bob", start + Duration::from_secs(30))
+            .unwrap();
+
+        limiter
+            .check_at("carol", start + Duration::from_secs(61))
+            .unwrap();
+        assert_eq!(limiter.len(), 2, "alice should be gone");
+    }
+}
@@ -0,0 +1,42 @@
+//! Rate limiters built from scratch: a token bucket and a sliding window,
+//! a map with one limiter per client, and an axum middleware.
+
+mod keyed;
+pub mod middleware;
+mod sliding_window;
+mod token_bucket;
+
+pub use keyed::KeyedLimiter;
+pub use sliding_window::SlidingWindow;
+pub use token_bucket::TokenBucket;
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Decides whether a request may be sent or served now.
+///
+/// The current time is a parameter, so tests can simulate minutes of
+/// traffic without sleeping.
+pub trait RateLimiter {
+    /// Takes one permit, or returns how long to wait for the next one
+    fn try_acquire_at(&mut self, now: Instant) -> Result<(), Duration>;
+
+    fn try_acquire(&mut self) -> Result<(), Duration> {
+        self.try_acquire_at(Instant::now())
+    }
+}
+
+/// Waits until `limiter` grants a permit.
+///
+/// The lock is released while sleeping, so other tasks can use the
+/// limiter in the meantime. Whoever asks first after the wait gets the
+/// permit, so a task may have to wait more than once.
+pub async fn until_ready<L: RateLimiter>(limiter: &Mutex<L>) {
+    loop {
+        let result = limiter.lock().unwrap().try_acquire();
+        match result {
+            Ok(()) => return,
+            Err(wait) => tokio::time::sleep(wait).await,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{KeyedLimiter, RateLimiter};
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde_json::json;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use tracing::warn;
+
+/// Limits the requests per client IP address. Use it with
+/// `middleware::from_fn_with_state(limiter, rate_limit::<TokenBucket>)`.
+///
+/// The address comes from `ConnectInfo`, so the server must be started
+/// with `into_make_service_with_connect_info::<SocketAddr>()`. Without it,
+/// as in tests that call the router directly, all requests share one limit.
+///
+/// Behind a reverse proxy, every request comes from the proxy's address.
+/// Then the key has to come from a header such as `X-Forwarded-For`, and
+/// only if the proxy sets it, because clients can send it too.
+pub async fn rate_limit<L>(
+    State(limiter): State<Arc<KeyedLimiter<IpAddr, L>>>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    request: Request,
+    next: Next,
+) -> Response
+where
+    L: RateLimiter + Send + 'static,
+{
+    let ip = connect_info
+        .map(|ConnectInfo(addr)| addr.ip())
+        .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+    match limiter.check(ip) {
+        Ok(()) => next.run(request).await,
+        Err(wait) => {
+            // Retry-After is in whole seconds, rounded up
+            let seconds = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+            warn!(%ip, retry_after = seconds, "Rate limit exceeded");
+            let body = json!({ "error": format!("Too many requests, retry in {} s", seconds) });
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, seconds.to_string())],
+                Json(body),
+            )
+                .into_response()
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::RateLimiter;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Allows at most `limit` requests in any period of length `window`.
+///
+/// A fixed window ("100 requests per calendar minute") can let through
+/// twice the limit: 100 requests at the end of one minute and 100 more at
+/// the start of the next. The sliding window remembers the time of every
+/// accepted request and always looks back exactly one `window`.
+#[derive(Debug, Clone)]
+pub struct SlidingWindow {
+    limit: usize,
+    window: Duration,
+    // The times of the accepted requests in the current window, oldest first
+    log: VecDeque<Instant>,
+}
+
+impl SlidingWindow {
+    pub fn new(limit: usize, window: Duration) -> Self {
+        assert!(limit > 0, "limit must be at least 1");
+        SlidingWindow {
+            limit,
+            window,
+            log: VecDeque::with_capacity(limit),
+        }
+    }
+
+    /// Requests accepted in the window that ends at `now`
+    pub fn count_at(&mut self, now: Instant) -> usize {
+        self.evict(now);
+        self.log.len()
+    }
+
+    fn evict(&mut self, now: Instant) {
+        while let Some(&oldest) = self.log.front() {
+            if now.saturating_duration_since(oldest) >= self.window {
+                self.log.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+impl RateLimiter for SlidingWindow {
+    fn try_acquire_at(&mut self, now: Instant) -> Result<(), Duration> {
+        self.evict(now);
+        if self.log.len() < self.limit {
+            self.log.push_back(now);
+            Ok(())
+        } else {
+            // A permit becomes free when the oldest request leaves the window
+            let oldest = self.log[0];
+            Err(self.window - now.saturating_duration_since(oldest))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows_the_limit_in_any_window() {
+        let start = Instant::now();
+        let mut window = SlidingWindow::new(3, Duration::from_secs(1));
+        let at = |ms| start + Duration::from_millis(ms);
+
+        assert!(window.try_acquire_at(at(0)).is_ok());
+        assert!(window.try_acquire_at(at(400)).is_ok());
+        assert!(window.try_acquire_at(at(800)).is_ok());
+        // The first request leaves the window at 1000 ms
+        assert_eq!(
+            window.try_acquire_at(at(900)),
+            Err(Duration::from_millis(100))
+        );
+        assert!(window.try_acquire_at(at(1000)).is_ok());
+        assert!(window.try_acquire_at(at(1100)).is_err());
+        assert_eq!(window.count_at(at(1500)), 2);
+    }
+
+    #[test]
+    fn no_double_burst_at_window_boundaries() {
+        let start = Instant::now();
+        let mut window = SlidingWindow::new(100, Duration::from_secs(60));
+        // 100 requests at the end of the first minute...
+        let end_of_minute = start + Duration::from_secs(59);
+        let first = (0..100)
+            .filter(|_| window.try_acquire_at(end_of_minute).is_ok())
+            .count();
+        // ...and none at the start of the next one
+        let next_minute = start + Duration::from_secs(61);
+        let second = (0..100)
+            .filter(|_| window.try_acquire_at(next_minute).is_ok())
+            .count();
+        assert_eq!((first, second), (100, 0));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::RateLimiter;
+use std::time::{Duration, Instant};
+
+/// A bucket holds up to `capacity` tokens and is refilled at a constant
+/// rate. Every request takes a token, and requests are rejected while the
+/// bucket is empty.
+///
+/// A full bucket allows a burst of `capacity` requests at once; after
+/// that, requests get through at the refill rate.
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_second: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Starts full. Panics if `capacity` is 0 or `refill_per_second`
+    /// isn't positive.
+    pub fn new(capacity: u32, refill_per_second: f64) -> Self {
+        Self::new_at(capacity, refill_per_second, Instant::now())
+    }
+
+    pub fn new_at(capacity: u32, refill_per_second: f64, now: Instant) -> Self {
+        assert!(capacity > 0, "capacity must be at least 1");
+        assert!(refill_per_second > 0.0, "refill rate must be positive");
+        TokenBucket {
+            capacity: capacity as f64,
+            tokens: capacity as f64,
+            refill_per_second,
+            last_refill: now,
+        }
+    }
+
+    /// Tokens available at `now`, without taking one
+    pub fn available_at(&mut self, now: Instant) -> u32 {
+        self.refill(now);
+        self.tokens as u32
+    }
+
+    // Instead of a timer that adds tokens, the tokens for the time since
+    // the last call are added on every call
+    fn refill(&mut self, now: Instant) {
+        // `saturating_duration_since`: a time from before the last refill
+        // counts as no time at all
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens =
+            (self.tokens + elapsed.as_secs_f64() * self.refill_per_second).min(self.capacity);
+        self.last_refill = self.last_refill.max(now);
+    }
+}
+
+impl RateLimiter for TokenBucket {
+    fn try_acquire_at(&mut self, now: Instant) -> Result<(), Duration> {
+        self.refill(now);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            let missing = 1.0 - self.tokens;
+            Err(Duration::from_secs_f64(missing / self.refill_per_second))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows_a_burst_then_the_refill_rate() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new_at(3, 2.0, start);
+        for _ in 0..3 {
+            assert!(bucket.try_acquire_at(start).is_ok());
+        }
+        // Empty: the next token arrives after half a second
+        assert_eq!(
+            bucket.try_acquire_at(start),
+            Err(Duration::from_millis(500))
+        );
+        assert!(bucket
+            .try_acquire_at(start + Duration::from_millis(499))
+            .is_err());
+        assert!(bucket
+            .try_acquire_at(start + Duration::from_millis(500))
+            .is_ok());
+    }
+
+    #[test]
+    fn never_holds_more_than_the_capacity() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new_at(5, 10.0, start);
+        assert_eq!(bucket.available_at(start + Duration::from_secs(60)), 5);
+    }
+
+    #[test]
+    fn sustained_rate_matches_the_refill_rate() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new_at(1, 10.0, start);
+        // A request every millisecond for 10 seconds
+        let allowed = (0..10_000)
+            .filter(|ms| {
+                bucket
+                    .try_acquire_at(start + Duration::from_millis(*ms))
+                    .is_ok()
+            })
+            .count();
+        // The first token plus 10 per second
+        assert_eq!(allowed, 1 + 100 - 1);
+    }
+}
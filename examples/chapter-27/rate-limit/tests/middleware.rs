@@ -0,0 +1,79 @@
+use axum::body::Body;
+use axum::extract::connect_info::MockConnectInfo;
+use axum::http::{header, Request, StatusCode};
+use axum::routing::get;
+use axum::{middleware, Router};
+use rate_limit::middleware::rate_limit;
+use rate_limit::{KeyedLimiter, SlidingWindow, TokenBucket};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tower::ServiceExt;
+
+fn app_with_token_bucket(burst: u32) -> Router {
+    let limiter = Arc::new(KeyedLimiter::new(Duration::from_secs(60), move || {
+        TokenBucket::new(burst, 1.0)
+    }));
+    Router::new()
+        .route("/", get(|| async { "Hello" }))
+        .layer(middleware::from_fn_with_state(
+            limiter,
+            rate_limit::<TokenBucket>,
+        ))
+}
+
+async fn status_from(app: &Router, addr: &str) -> (StatusCode, Option<String>) {
+    let addr: SocketAddr = addr.parse().unwrap();
+    let response = app
+        .clone()
+        // Provides the client address that a real server would
+        .layer(MockConnectInfo(addr))
+        .oneshot(Request::get("/").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let retry_after = response
+        .headers()
+        .get(header::RETRY_AFTER)
+        .map(|value| value.to_str().unwrap().to_string());
+    (response.status(), retry_after)
+}
+
+#[tokio::test]
+async fn rejects_requests_over_the_limit() {
+    let app = app_with_token_bucket(2);
+    assert_eq!(status_from(&app, "10.0.0.1:5000").await.0, StatusCode::OK);
+    assert_eq!(status_from(&app, "10.0.0.1:5001").await.0, StatusCode::OK);
+
+    let (status, retry_after) = status_from(&app, "10.0.0.1:5002").await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(retry_after.as_deref(), Some("1"));
+}
+
+#[tokio::test]
+async fn clients_are_limited_separately() {
+    let app = app_with_token_bucket(1);
+    assert_eq!(status_from(&app, "10.0.0.1:5000").await.0, StatusCode::OK);
+    assert_eq!(
+        status_from(&app, "10.0.0.1:5000").await.0,
+        StatusCode::TOO_MANY_REQUESTS
+    );
+    assert_eq!(status_from(&app, "10.0.0.2:5000").await.0, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn works_with_any_limiter() {
+    let limiter = Arc::new(KeyedLimiter::new(Duration::from_secs(60), || {
+        SlidingWindow::new(1, Duration::from_secs(10))
+    }));
+    let app =
+        Router::new()
+            .route("/", get(|| async { "Hello" }))
+            .layer(middleware::from_fn_with_state(
+                limiter,
+                rate_limit::<SlidingWindow>,
+            ));
+    assert_eq!(status_from(&app, "10.0.0.1:5000").await.0, StatusCode::OK);
+    let (status, retry_after) = status_from(&app, "10.0.0.1:5000").await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(retry_after.as_deref(), Some("10"));
+}
@@ -0,0 +1,51 @@
+use scheduler::{supervise, WorkerConfig};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+
+// An importer that processes batches and sends a heartbeat after each one.
+// To show the supervisor at work, its first run hangs and its second run
+// fails; the third one finishes.
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| EnvFilter::new("scheduler=info,heartbeat=info")),
+        )
+        .init();
+
+    let config = WorkerConfig {
+        stall_timeout: Duration::from_secs(2),
+        restart_delay: Duration::from_millis(500),
+        shutdown_grace: Duration::from_secs(1),
+        ..WorkerConfig::default()
+    };
+
+    let runs = Arc::new(AtomicU32::new(0));
+    let handle = supervise("importer", config, move |heartbeat, token| {
+        let run = runs.fetch_add(1, Ordering::SeqCst) + 1;
+        async move {
+            for batch in 1..=5 {
+                if token.is_cancelled() {
+                    return Ok(());
+                }
+                tokio::time::sleep(Duration::from_millis(300)).await;
+                match (run, batch) {
+                    // A lock that is never released
+                    (1, 3) => std::future::pending::<()>().await,
+                    (2, 2) => return Err("database is locked".into()),
+                    _ => {}
+                }
+                info!(batch, "Imported batch");
+                heartbeat.beat();
+            }
+            Ok(())
+        }
+    });
+
+    let exit = handle.join().await;
+    info!(?exit, "Supervisor stopped");
+}
@@ -0,0 +1,41 @@
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+/// Controls a job that runs in the background.
+///
+/// Dropping the handle doesn't stop the job: like a `JoinHandle`, it only
+/// detaches it. Call `cancel` to stop it.
+#[derive(Debug)]
+pub struct JobHandle {
+    token: CancellationToken,
+    task: JoinHandle<()>,
+}
+
+impl JobHandle {
+    pub(crate) fn new(token: CancellationToken, task: JoinHandle<()>) -> Self {
+        JobHandle { token, task }
+    }
+
+    /// Asks the job to stop. A run that has already started is finished
+    /// first, unlike with `JoinHandle::abort`, which would stop it at its
+    /// next `.await`, possibly halfway through writing a file.
+    pub fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Waits until the job has stopped, after a cancellation or, for a
+    /// one-shot job, after its only run
+    pub async fn join(self) {
+        // A panic in the job ends its task; that's reported by the panic
+        // message already
+        let _ = self.task.await;
+    }
+}
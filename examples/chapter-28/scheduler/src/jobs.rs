@@ -0,0 +1,190 @@
+use crate::JobHandle;
+use chrono::{DateTime, Local, NaiveTime, TimeZone};
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::{self, MissedTickBehavior};
+use tokio_util::sync::CancellationToken;
+
+/// Runs `job` every `period`, the first time right away.
+///
+/// If a run takes longer than the period, the missed ticks are skipped
+/// instead of being run back to back to catch up.
+pub fn every<F, Fut>(period: Duration, mut job: F) -> JobHandle
+where
+    F: FnMut() -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let token = CancellationToken::new();
+    let cancelled = token.clone();
+    let task = tokio::spawn(async move {
+        let mut interval = time::interval(period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        loop {
+            tokio::select! {
+                // Checked first, so a cancelled job doesn't start another run
+                biased;
+                _ = cancelled.cancelled() => return,
+                _ = interval.tick() => job().await,
+            }
+        }
+    });
+    JobHandle::new(token, task)
+}
+
+/// Runs `job` every day when the local clock shows `at`, like the cron
+/// line `30 7 * * *` for 07:30.
+///
+/// The next run is computed from the calendar every time, not by adding
+/// 24 hours, which would be an hour off after a daylight saving change.
+pub fn every_day_at<F, Fut>(at: NaiveTime, mut job: F) -> JobHandle
+where
+    F: FnMut() -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let token = CancellationToken::new();
+    let cancelled = token.clone();
+    let task = tokio::spawn(async move {
+        let mut next = next_daily(at, Local::now());
+        loop {
+            let delay = (next - Local::now()).to_std().unwrap_or(Duration::ZERO);
+            tokio::select! {
+                biased;
+                _ = cancelled.cancelled() => return,
+                _ = time::sleep(delay) => job().await,
+            }
+            // From the planned time, not from `now`: a timer that fires a
+            // moment early must not run the job twice on the same day
+            next = next_daily(at, next);
+        }
+    });
+    JobHandle::new(token, task)
+}
+
+/// The first time after `now` when the clock shows `at`
+pub fn next_daily<Tz: TimeZone>(at: NaiveTime, now: DateTime<Tz>) -> DateTime<Tz> {
+    let mut date = now.date_naive();
+    loop {
+        // `earliest` picks the first of the two 02:30s when the clocks go
+        // back, and is `None` on the day they skip 02:30
+        let candidate = now
+            .timezone()
+            .from_local_datetime(&date.and_time(at))
+            .earliest();
+        if let Some(candidate) = candidate.filter(|c| *c > now) {
+            return candidate;
+        }
+        date = date.succ_opt().expect("date out of range");
+    }
+}
+
+/// Runs `job` once after `delay`, unless it's cancelled first
+pub fn after<Fut>(delay: Duration, job: Fut) -> JobHandle
+where
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let token = CancellationToken::new();
+    let cancelled = token.clone();
+    let task = tokio::spawn(async move {
+        tokio::select! {
+            biased;
+            _ = cancelled.cancelled() => {}
+            _ = time::sleep(delay) => job.await,
+        }
+    });
+    JobHandle::new(token, task)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    fn counter() -> (Arc<AtomicU32>, impl FnMut() -> std::future::Ready<()>) {
+        let runs = Arc::new(AtomicU32::new(0));
+        let count = runs.clone();
+        (runs, move || {
+            count.fetch_add(1, Ordering::SeqCst);
+            std::future::ready(())
+        })
+    }
+
+    // With a paused clock, Tokio jumps to the next timer whenever all
+    // tasks are waiting, so these tests take milliseconds, not seconds
+    #[tokio::test(start_paused = true)]
+    async fn periodic_jobs_run_every_period_until_cancelled() {
+        let (runs, job) = counter();
+        let handle = every(Duration::from_secs(10), job);
+
+        // At 0, 10, 20 and 30 seconds
+        time::sleep(Duration::from_secs(35)).await;
+        assert_eq!(runs.load(Ordering::SeqCst), 4);
+
+        handle.cancel();
+        time::sleep(Duration::from_secs(60)).await;
+        assert_eq!(runs.load(Ordering::SeqCst), 4);
+        assert!(handle.is_finished());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cancelling_lets_the_current_run_finish() {
+        let finished = Arc::new(AtomicU32::new(0));
+        let count = finished.clone();
+        let handle = every(Duration::from_secs(60), move || {
+            let count = count.clone();
+            async move {
+                time::sleep(Duration::from_secs(5)).await;
+                count.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+        time::sleep(Duration::from_secs(1)).await;
+        handle.cancel();
+        handle.join().await;
+        assert_eq!(finished.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn delayed_jobs_run_once_after_the_delay() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let count = runs.clone();
+        let handle = after(Duration::from_secs(30), async move {
+            count.fetch_add(1, Ordering::SeqCst);
+        });
+        time::sleep(Duration::from_secs(29)).await;
+        assert_eq!(runs.load(Ordering::SeqCst), 0);
+        handle.join().await;
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cancelled_delayed_jobs_never_run() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let count = runs.clone();
+        let handle = after(Duration::from_secs(30), async move {
+            count.fetch_add(1, Ordering::SeqCst);
+        });
+        handle.cancel();
+        handle.join().await;
+        time::sleep(Duration::from_secs(60)).await;
+        assert_eq!(runs.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn next_daily_is_today_or_tomorrow() {
+        let at = NaiveTime::from_hms_opt(7, 30, 0).unwrap();
+        let time = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            next_daily(at, time("2024-03-09T06:00:00Z")),
+            time("2024-03-09T07:30:00Z")
+        );
+        assert_eq!(
+            next_daily(at, time("2024-03-09T07:30:00Z")),
+            time("2024-03-10T07:30:00Z")
+        );
+        assert_eq!(
+            next_daily(at, time("2024-12-31T23:00:00Z")),
+            time("2025-01-01T07:30:00Z")
+        );
+    }
+}
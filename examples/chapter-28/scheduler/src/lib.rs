@@ -0,0 +1,19 @@
+//! A small job scheduler on top of Tokio: periodic jobs, jobs at a time of
+//! day, one-shot delayed jobs, handles to cancel them, and a scheduler that
+//! saves its pending jobs to disk so they survive a restart. A supervisor
+//! keeps long-running workers alive, restarting them when they fail or
+//! stop sending heartbeats.
+
+mod error;
+mod handle;
+mod jobs;
+mod scheduler;
+mod store;
+mod worker;
+
+pub use error::SchedulerError;
+pub use handle::JobHandle;
+pub use jobs::{after, every, every_day_at, next_daily};
+pub use scheduler::Scheduler;
+pub use store::{JobList, JobStore, PendingJob};
+pub use worker::{supervise, Heartbeat, WorkError, WorkerConfig, WorkerExit, WorkerHandle};
@@ -0,0 +1,58 @@
+use chrono::NaiveTime;
+use scheduler::{every, every_day_at, Scheduler};
+use serde_json::json;
+use shutdown::shutdown_signal;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+
+const USAGE: &str = "Usage: scheduler [<seconds> <message>]";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| EnvFilter::new("scheduler=info,shutdown=info")),
+        )
+        .init();
+
+    let mut scheduler = Scheduler::open("jobs.json")?;
+    scheduler.register("remind", |payload| async move {
+        println!("Reminder: {}", payload["message"].as_str().unwrap_or("?"));
+    });
+    scheduler.resume()?;
+
+    // `scheduler 30 "Stretch your legs"` adds a reminder in 30 seconds
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.as_slice() {
+        [] => {}
+        [seconds, message] => {
+            let seconds: u64 = seconds.parse().map_err(|_| USAGE)?;
+            let payload = json!({ "message": message });
+            scheduler.schedule("remind", payload, Duration::from_secs(seconds))?;
+        }
+        _ => return Err(USAGE.into()),
+    }
+
+    let scheduler = Arc::new(scheduler);
+    let status = {
+        let scheduler = scheduler.clone();
+        every(Duration::from_secs(10), move || {
+            let pending = scheduler.pending().len();
+            async move { info!(pending, "Waiting for jobs") }
+        })
+    };
+    let morning = every_day_at(NaiveTime::from_hms_opt(9, 0, 0).unwrap(), || async {
+        println!("Good morning!");
+    });
+
+    shutdown_signal().await;
+    // The pending reminders stay in jobs.json for the next start
+    status.cancel();
+    morning.cancel();
+    status.join().await;
+    morning.join().await;
+    Ok(())
+}
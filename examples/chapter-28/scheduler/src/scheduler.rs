@@ -0,0 +1,270 @@
+use crate::{after, JobHandle, JobList, JobStore, PendingJob, SchedulerError};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{error, info};
+
+type Handler = Arc<dyn Fn(Value) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+
+/// Runs one-shot jobs at a given time and keeps the pending ones in a
+/// JSON file, so they still run after the program is restarted.
+///
+/// A job is removed from the file after its handler has finished. If the
+/// program stops while a handler runs, the job runs again after the
+/// restart: every job runs *at least* once, so handlers should be safe to
+/// repeat.
+///
+/// Dropping the scheduler stops the jobs that haven't started yet; they
+/// stay in the file for the next start.
+pub struct Scheduler {
+    handlers: HashMap<String, Handler>,
+    shared: Arc<Shared>,
+}
+
+// Shared with the running jobs, which remove themselves when they finish
+struct Shared {
+    store: JobStore,
+    state: Mutex<State>,
+}
+
+struct State {
+    list: JobList,
+    running: HashMap<u64, JobHandle>,
+}
+
+impl Scheduler {
+    /// Loads the pending jobs from `path`. They are started by `resume`,
+    /// after the handlers for their kinds are registered.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, SchedulerError> {
+        let store = JobStore::new(path);
+        let list = store.load()?;
+        Ok(Scheduler {
+            handlers: HashMap::new(),
+            shared: Arc::new(Shared {
+                store,
+                state: Mutex::new(State {
+                    list,
+                    running: HashMap::new(),
+                }),
+            }),
+        })
+    }
+
+    /// Sets the code that runs jobs of the given kind
+    pub fn register<F, Fut>(&mut self, kind: &str, handler: F)
+    where
+        F: Fn(Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        // Every handler returns its own future type, so the futures are
+        // boxed to store the handlers in one map
+        let handler: Handler = Arc::new(move |payload| Box::pin(handler(payload)));
+        self.handlers.insert(kind.to_string(), handler);
+    }
+
+    /// Starts the jobs loaded from the file. Jobs that were due while the
+    /// program wasn't running start right away. Returns the number of jobs.
+    pub fn resume(&self) -> Result<usize, SchedulerError> {
+        let mut state = self.shared.state.lock().unwrap();
+        // Checked before anything starts, so a missing handler is noticed
+        // at startup and not when the job is due
+        if let Some(job) = state
+            .list
+            .jobs()
+            .iter()
+            .find(|job| !self.handlers.contains_key(&job.kind))
+        {
+            return Err(SchedulerError::UnknownKind(job.kind.clone()));
+        }
+        let jobs = state.list.jobs().to_vec();
+        for job in &jobs {
+            if !state.running.contains_key(&job.id) {
+                self.start(job, &mut state);
+            }
+        }
+        info!(jobs = jobs.len(), "Resumed pending jobs");
+        Ok(jobs.len())
+    }
+
+    /// Runs a job of the given kind after `delay` and returns its ID
+    pub fn schedule(
+        &self,
+        kind: &str,
+        payload: Value,
+        delay: Duration,
+    ) -> Result<u64, SchedulerError> {
+        // A delay too long for chrono is too long for anybody to wait
+        let delay = chrono::Duration::from_std(delay).unwrap_or(chrono::Duration::MAX);
+        let run_at = Utc::now()
+            .checked_add_signed(delay)
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        self.schedule_at(kind, payload, run_at)
+    }
+
+    /// Runs a job of the given kind at `run_at` and returns its ID
+    pub fn schedule_at(
+        &self,
+        kind: &str,
+        payload: Value,
+        run_at: DateTime<Utc>,
+    ) -> Result<u64, SchedulerError> {
+        if !self.handlers.contains_key(kind) {
+            return Err(SchedulerError::UnknownKind(kind.to_string()));
+        }
+        let mut state = self.shared.state.lock().unwrap();
+        let job = state.list.add(kind, payload, run_at).clone();
+        // Saved before it starts: once this returns, the job survives a crash
+        if let Err(e) = self.shared.store.save(&state.list) {
+            state.list.remove(job.id);
+            return Err(e);
+        }
+        self.start(&job, &mut state);
+        info!(id = job.id, kind, %run_at, "Scheduled job");
+        Ok(job.id)
+    }
+
+    /// Removes a pending job. Returns `false` if there is no such job,
+    /// for example because it has already run.
+    pub fn cancel(&self, id: u64) -> Result<bool, SchedulerError> {
+        let mut state = self.shared.state.lock().unwrap();
+        let Some(job) = state.list.remove(id) else {
+            return Ok(false);
+        };
+        if let Err(e) = self.shared.store.save(&state.list) {
+            state.list.restore(job);
+            return Err(e);
+        }
+        if let Some(handle) = state.running.remove(&id) {
+            handle.cancel();
+        }
+        info!(id, "Cancelled job");
+        Ok(true)
+    }
+
+    /// The jobs that haven't finished yet, in the order they were scheduled
+    pub fn pending(&self) -> Vec<PendingJob> {
+        self.shared.state.lock().unwrap().list.jobs().to_vec()
+    }
+
+    // Called with the lock held, so the job can't finish and try to
+    // remove itself before its handle is stored
+    fn start(&self, job: &PendingJob, state: &mut State) {
+        let handler = self.handlers[&job.kind].clone();
+        let delay = (job.run_at - Utc::now()).to_std().unwrap_or(Duration::ZERO);
+        let (id, payload, shared) = (job.id, job.payload.clone(), self.shared.clone());
+        let handle = after(delay, async move {
+            handler(payload).await;
+            shared.finish(id);
+        });
+        state.running.insert(id, handle);
+    }
+}
+
+impl Drop for Scheduler {
+    fn drop(&mut self) {
+        for handle in self.shared.state.lock().unwrap().running.values() {
+            handle.cancel();
+        }
+    }
+}
+
+impl Shared {
+    fn finish(&self, id: u64) {
+        let mut state = self.state.lock().unwrap();
+        state.running.remove(&id);
+        if state.list.remove(id).is_some() {
+            // The file is small, so saving it blocks the task only briefly
+            if let Err(e) = self.store.save(&state.list) {
+                error!(id, "Job finished but can't be removed from the file: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tokio::sync::mpsc;
+    use tokio::time::sleep;
+
+    // A scheduler whose "send" jobs report their payload through a channel
+    fn scheduler(path: &std::path::Path) -> (Scheduler, mpsc::UnboundedReceiver<Value>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut scheduler = Scheduler::open(path).unwrap();
+        scheduler.register("send", move |payload| {
+            let tx = tx.clone();
+            async move {
+                tx.send(payload).unwrap();
+            }
+        });
+        (scheduler, rx)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn runs_jobs_and_removes_them_from_the_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jobs.json");
+        let (scheduler, mut rx) = scheduler(&path);
+
+        scheduler
+            .schedule("send", json!(2), Duration::from_secs(20))
+            .unwrap();
+        scheduler
+            .schedule("send", json!(1), Duration::from_secs(10))
+            .unwrap();
+        assert_eq!(JobStore::new(&path).load().unwrap().jobs().len(), 2);
+
+        assert_eq!(rx.recv().await, Some(json!(1)));
+        assert_eq!(rx.recv().await, Some(json!(2)));
+        sleep(Duration::from_millis(1)).await;
+        assert!(scheduler.pending().is_empty());
+        assert!(JobStore::new(&path).load().unwrap().jobs().is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cancelled_jobs_do_not_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let (scheduler, mut rx) = scheduler(&dir.path().join("jobs.json"));
+        let id = scheduler
+            .schedule("send", json!("cancelled"), Duration::from_secs(10))
+            .unwrap();
+        scheduler
+            .schedule("send", json!("kept"), Duration::from_secs(20))
+            .unwrap();
+
+        assert!(scheduler.cancel(id).unwrap());
+        assert!(!scheduler.cancel(id).unwrap());
+        assert_eq!(rx.recv().await, Some(json!("kept")));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pending_jobs_survive_a_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jobs.json");
+        {
+            let (scheduler, _rx) = scheduler(&path);
+            scheduler
+                .schedule("send", json!("after restart"), Duration::from_secs(60))
+                .unwrap();
+            // The "program" stops before the job is due
+        }
+
+        let (scheduler, mut rx) = scheduler(&path);
+        assert_eq!(scheduler.resume().unwrap(), 1);
+        assert_eq!(rx.recv().await, Some(json!("after restart")));
+    }
+
+    #[tokio::test]
+    async fn rejects_jobs_without_a_handler() {
+        let dir = tempfile::tempdir().unwrap();
+        let (scheduler, _rx) = scheduler(&dir.path().join("jobs.json"));
+        let result = scheduler.schedule("email", Value::Null, Duration::ZERO);
+        assert!(matches!(result, Err(SchedulerError::UnknownKind(kind)) if kind == "email"));
+    }
+}
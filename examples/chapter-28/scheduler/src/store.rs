@@ -0,0 +1,136 @@
+use crate::SchedulerError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use tempfile::NamedTempFile;
+use tracing::{debug, info};
+
+/// A job that hasn't run yet, as it is saved in the job file.
+///
+/// A closure can't be written to a file, so a saved job is only data: its
+/// `kind` selects the handler that runs it, and `payload` holds the
+/// handler's arguments.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingJob {
+    pub id: u64,
+    pub kind: String,
+    pub payload: Value,
+    // A wall-clock time, because an `Instant` means nothing after a restart
+    pub run_at: DateTime<Utc>,
+}
+
+/// All pending jobs, as they are stored in the JSON file
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct JobList {
+    next_id: u64,
+    jobs: Vec<PendingJob>,
+}
+
+impl JobList {
+    pub fn add(&mut self, kind: &str, payload: Value, run_at: DateTime<Utc>) -> &PendingJob {
+        self.next_id += 1;
+        self.jobs.push(PendingJob {
+            id: self.next_id,
+            kind: kind.to_string(),
+            payload,
+            run_at,
+        });
+        self.jobs.last().unwrap()
+    }
+
+    pub fn remove(&mut self, id: u64) -> Option<PendingJob> {
+        let index = self.jobs.iter().position(|job| job.id == id)?;
+        Some(self.jobs.remove(index))
+    }
+
+    // Puts back a job that was removed, at its old position
+    pub(crate) fn restore(&mut self, job: PendingJob) {
+        let index = self.jobs.partition_point(|other| other.id < job.id);
+        self.jobs.insert(index, job);
+    }
+
+    pub fn jobs(&self) -> &[PendingJob] {
+        &self.jobs
+    }
+}
+
+/// Loads and saves the pending jobs as a JSON file
+pub struct JobStore {
+    path: PathBuf,
+}
+
+impl JobStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        JobStore { path: path.into() }
+    }
+
+    /// A missing file means no pending jobs
+    pub fn load(&self) -> Result<JobList, SchedulerError> {
+        let json = match fs::read_to_string(&self.path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(JobList::default()),
+            Err(source) => {
+                return Err(SchedulerError::Read {
+                    path: self.path.clone(),
+                    source,
+                })
+            }
+        };
+        let list: JobList =
+            serde_json::from_str(&json).map_err(|source| SchedulerError::Parse {
+                path: self.path.clone(),
+                source,
+            })?;
+        info!(path = %self.path.display(), jobs = list.jobs.len(), "Loaded pending jobs");
+        Ok(list)
+    }
+
+    /// The atomic write of `Store::save` from Chapter 24. The scheduler
+    /// calls it with its lock held after every change, so two saves never
+    /// race and a job is on disk before its timer starts.
+    pub fn save(&self, list: &JobList) -> Result<(), SchedulerError> {
+        let write_error = |source| SchedulerError::Write {
+            path: self.path.clone(),
+            source,
+        };
+        let dir = self
+            .path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let mut temp = NamedTempFile::new_in(dir).map_err(write_error)?;
+        serde_json::to_writer_pretty(&mut temp, list).map_err(|e| write_error(e.into()))?;
+        temp.write_all(b"\n").map_err(write_error)?;
+        temp.as_file().sync_all().map_err(write_error)?;
+        temp.persist(&self.path).map_err(|e| write_error(e.error))?;
+        debug!(path = %self.path.display(), jobs = list.jobs.len(), "Saved pending jobs");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn saves_and_loads() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = JobStore::new(dir.path().join("jobs.json"));
+        assert!(store.load().unwrap().jobs().is_empty());
+
+        let mut list = JobList::default();
+        list.add("email", json!({ "to": "alice@example.com" }), Utc::now());
+        list.add("email", json!({ "to": "bob@example.com" }), Utc::now());
+        list.remove(1);
+        store.save(&list).unwrap();
+
+        let mut loaded = store.load().unwrap();
+        assert_eq!(loaded.jobs(), list.jobs());
+        // IDs are never reused
+        assert_eq!(loaded.add("email", Value::Null, Utc::now()).id, 3);
+    }
+}
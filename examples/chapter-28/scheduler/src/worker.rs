@@ -0,0 +1,378 @@
+use std::error::Error;
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio::time::{self, Instant};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info, info_span, warn, Instrument, Span};
+
+/// What a worker returns when a run fails. Any error type converts to it
+/// with `?`.
+pub type WorkError = Box<dyn Error + Send + Sync>;
+
+/// Given to each run of a worker, which calls `beat` whenever it makes
+/// progress. A worker that doesn't beat for `WorkerConfig::stall_timeout`
+/// is considered stuck, and is stopped and restarted. Dropping the
+/// `Heartbeat` doesn't turn this off: a run that drops it can't beat
+/// anymore, so it stalls `stall_timeout` after its last beat.
+pub struct Heartbeat {
+    sender: watch::Sender<Instant>,
+}
+
+impl Heartbeat {
+    pub fn beat(&self) {
+        self.sender.send_replace(Instant::now());
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkerConfig {
+    /// How long a run may go without a heartbeat
+    pub stall_timeout: Duration,
+    /// The delay before the first restart; it doubles after each failure
+    pub restart_delay: Duration,
+    pub max_restart_delay: Duration,
+    /// After this many restarts in a row, the supervisor gives up
+    pub max_restarts: u32,
+    /// After a cancellation, how long a run may take to stop by itself
+    /// before it's aborted
+    pub shutdown_grace: Duration,
+}
+
+impl Default for WorkerConfig {
+    fn default() -> Self {
+        WorkerConfig {
+            stall_timeout: Duration::from_secs(30),
+            restart_delay: Duration::from_secs(1),
+            max_restart_delay: Duration::from_secs(60),
+            max_restarts: 10,
+            shutdown_grace: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Why the supervisor stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkerExit {
+    /// A run returned `Ok`
+    Finished,
+    /// The worker was cancelled through its handle
+    Cancelled,
+    /// The runs kept failing or stalling, `max_restarts` times in a row
+    GaveUp,
+}
+
+// How a single run ended
+enum RunOutcome {
+    Finished,
+    Failed(String),
+    Panicked,
+    Stalled,
+    Cancelled,
+}
+
+/// Controls a supervised worker
+pub struct WorkerHandle {
+    token: CancellationToken,
+    restarts: Arc<AtomicU32>,
+    task: JoinHandle<WorkerExit>,
+}
+
+impl WorkerHandle {
+    /// Asks the current run to stop, and the supervisor not to restart it
+    pub fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    /// The number of restarts so far
+    pub fn restarts(&self) -> u32 {
+        self.restarts.load(Ordering::SeqCst)
+    }
+
+    pub async fn join(self) -> WorkerExit {
+        self.task.await.expect("the supervisor doesn't panic")
+    }
+}
+
+/// Runs `work` in the background and keeps it running.
+///
+/// Every run gets a `Heartbeat` and a `CancellationToken`. The supervisor
+/// watches each run and starts a new one, after a growing delay, when the
+/// run returns an error, panics, or stops sending heartbeats. A run that
+/// returns `Ok` ends the worker.
+///
+/// Everything is logged with `tracing`, inside a `worker` span with the
+/// worker's name and the number of the run.
+pub fn supervise<F, Fut>(name: &str, config: WorkerConfig, mut work: F) -> WorkerHandle
+where
+    F: FnMut(Heartbeat, CancellationToken) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<(), WorkError>> + Send + 'static,
+{
+    let token = CancellationToken::new();
+    let restarts = Arc::new(AtomicU32::new(0));
+    let cancelled = token.clone();
+    let restart_count = restarts.clone();
+    let name = name.to_string();
+
+    let task = tokio::spawn(async move {
+        let mut delay = config.restart_delay;
+        let mut failures_in_a_row = 0;
+        for run in 1.. {
+            let span = info_span!("worker", name = %name, run);
+            // Everything about this run, including the delay before the
+            // next one, is logged inside its span
+            let step = async {
+                let (sender, beats) = watch::channel(Instant::now());
+                let run_token = cancelled.child_token();
+                let started = Instant::now();
+                let task = tokio::spawn(
+                    work(Heartbeat { sender }, run_token.clone()).instrument(Span::current()),
+                );
+                match watch_run(task, beats, &run_token, &cancelled, &config).await {
+                    RunOutcome::Finished => {
+                        info!("Worker finished");
+                        return Some(WorkerExit::Finished);
+                    }
+                    RunOutcome::Cancelled => {
+                        info!("Worker cancelled");
+                        return Some(WorkerExit::Cancelled);
+                    }
+                    RunOutcome::Failed(e) => warn!(error = %e, "Worker failed"),
+                    RunOutcome::Panicked => error!("Worker panicked"),
+                    RunOutcome::Stalled => warn!(
+                        timeout = ?config.stall_timeout,
+                        "Worker stalled, no heartbeat in time"
+                    ),
+                }
+
+                // A run that was healthy for a while starts the count over,
+                // so occasional failures over a long time never add up to
+                // giving up
+                if started.elapsed() > config.max_restart_delay {
+                    failures_in_a_row = 0;
+                    delay = config.restart_delay;
+                }
+                failures_in_a_row += 1;
+                if failures_in_a_row > config.max_restarts {
+                    error!(restarts = config.max_restarts, "Giving up on worker");
+                    return Some(WorkerExit::GaveUp);
+                }
+
+                info!(?delay, "Restarting worker");
+                tokio::select! {
+                    biased;
+                    _ = cancelled.cancelled() => {
+                        info!("Worker cancelled");
+                        return Some(WorkerExit::Cancelled);
+                    }
+                    _ = time::sleep(delay) => {}
+                }
+                delay = (delay * 2).min(config.max_restart_delay);
+                restart_count.fetch_add(1, Ordering::SeqCst);
+                None
+            };
+            if let Some(exit) = step.instrument(span).await {
+                return exit;
+            }
+        }
+        unreachable!("the loop only ends with a return")
+    });
+
+    WorkerHandle {
+        token,
+        restarts,
+        task,
+    }
+}
+
+// The watchdog: waits for the run to end, and stops it when its heartbeats
+// stop or the worker is cancelled
+async fn watch_run(
+    mut run: JoinHandle<Result<(), WorkError>>,
+    mut beats: watch::Receiver<Instant>,
+    run_token: &CancellationToken,
+    cancelled: &CancellationToken,
+    config: &WorkerConfig,
+) -> RunOutcome {
+    info!("Worker started");
+    // Cleared when the run drops its `Heartbeat`, which ends the heartbeats
+    // but not the deadline
+    let mut watching = true;
+    loop {
+        let deadline = *beats.borrow_and_update() + config.stall_timeout;
+        tokio::select! {
+            biased;
+            result = &mut run => {
+                return match result {
+                    Ok(Ok(())) if cancelled.is_cancelled() => RunOutcome::Cancelled,
+                    Ok(Ok(())) => RunOutcome::Finished,
+                    Ok(Err(e)) => RunOutcome::Failed(e.to_string()),
+                    Err(e) if e.is_panic() => RunOutcome::Panicked,
+                    Err(_) => RunOutcome::Cancelled,
+                };
+            }
+            _ = cancelled.cancelled() => {
+                // The run sees the cancellation through its child token
+                if time::timeout(config.shutdown_grace, &mut run).await.is_err() {
+                    warn!(grace = ?config.shutdown_grace, "Worker ignored the cancellation, aborting it");
+                    run.abort();
+                }
+                return RunOutcome::Cancelled;
+            }
+            changed = beats.changed(), if watching => {
+                match changed {
+                    Ok(()) => debug!("Heartbeat"),
+                    // The run dropped its `Heartbeat`, so no more beats can
+                    // come, and the deadline of the last one stays
+                    Err(_) => watching = false,
+                }
+            }
+            _ = time::sleep_until(deadline) => {
+                // Give it a chance to stop cleanly, then abort it
+                run_token.cancel();
+                if time::timeout(config.shutdown_grace, &mut run).await.is_err() {
+                    run.abort();
+                }
+                return RunOutcome::Stalled;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn config() -> WorkerConfig {
+        WorkerConfig {
+            stall_timeout: Duration::from_secs(10),
+            restart_delay: Duration::from_secs(1),
+            max_restart_delay: Duration::from_secs(60),
+            max_restarts: 3,
+            shutdown_grace: Duration::from_secs(2),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_healthy_worker_runs_until_it_finishes() {
+        let handle = supervise("healthy", config(), |heartbeat, _| async move {
+            // Five minutes of work, with a heartbeat every 5 seconds
+            for _ in 0..60 {
+                time::sleep(Duration::from_secs(5)).await;
+                heartbeat.beat();
+            }
+            Ok(())
+        });
+        assert_eq!(handle.join().await, WorkerExit::Finished);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn failed_and_panicked_runs_are_restarted() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let count = runs.clone();
+        let handle = supervise("flaky", config(), move |_, _| {
+            let run = count.fetch_add(1, Ordering::SeqCst) + 1;
+            async move {
+                match run {
+                    1 => Err("connection refused".into()),
+                    2 => panic!("bug in run 2"),
+                    _ => Ok(()),
+                }
+            }
+        });
+        let restarts = handle.restarts.clone();
+        assert_eq!(handle.join().await, WorkerExit::Finished);
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+        assert_eq!(restarts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stalled_runs_are_stopped_and_restarted() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let count = runs.clone();
+        let handle = supervise("stuck", config(), move |heartbeat, _| {
+            let run = count.fetch_add(1, Ordering::SeqCst) + 1;
+            async move {
+                heartbeat.beat();
+                if run == 1 {
+                    // Hangs without ever checking its token
+                    std::future::pending::<()>().await;
+                }
+                Ok(())
+            }
+        });
+        let start = Instant::now();
+        assert_eq!(handle.join().await, WorkerExit::Finished);
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+        // Stall timeout, grace period, then the first restart delay
+        assert_eq!(start.elapsed(), Duration::from_secs(10 + 2 + 1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn restart_delays_double_until_giving_up() {
+        let started = Arc::new(Mutex::new(Vec::new()));
+        let times = started.clone();
+        let begin = Instant::now();
+        let handle = supervise("broken", config(), move |_, _| {
+            times.lock().unwrap().push(begin.elapsed().as_secs());
+            async { Err("always fails".into()) }
+        });
+        assert_eq!(handle.join().await, WorkerExit::GaveUp);
+        // Delays of 1, 2, and 4 seconds, then no more than 3 restarts
+        assert_eq!(*started.lock().unwrap(), [0, 1, 3, 7]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cancelling_stops_the_run_and_the_restarts() {
+        let handle = supervise("polite", config(), |heartbeat, token| async move {
+            loop {
+                tokio::select! {
+                    _ = token.cancelled() => return Ok(()),
+                    _ = time::sleep(Duration::from_secs(1)) => heartbeat.beat(),
+                }
+            }
+        });
+        time::sleep(Duration::from_secs(30)).await;
+        handle.cancel();
+        assert_eq!(handle.join().await, WorkerExit::Cancelled);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn runs_that_ignore_cancellation_are_aborted_after_the_grace_period() {
+        let handle = supervise("stubborn", config(), |heartbeat, _| async move {
+            loop {
+                time::sleep(Duration::from_secs(1)).await;
+                heartbeat.beat();
+            }
+        });
+        time::sleep(Duration::from_secs(5)).await;
+        let start = Instant::now();
+        handle.cancel();
+        assert_eq!(handle.join().await, WorkerExit::Cancelled);
+        assert_eq!(start.elapsed(), Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn runs_that_drop_their_heartbeat_stall() {
+        // Drops its `Heartbeat` at once and never checks its token
+        let handle = supervise("silent", config(), |_, _| async {
+            std::future::pending::<()>().await;
+            Ok(())
+        });
+        // Stalled after 10 s, aborted after the 2 s grace period, and
+        // restarted after the 1 s delay
+        time::sleep(Duration::from_millis(12_500)).await;
+        assert_eq!(handle.restarts(), 0);
+        time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(handle.restarts(), 1);
+
+        let start = Instant::now();
+        handle.cancel();
+        assert_eq!(handle.join().await, WorkerExit::Cancelled);
+        assert_eq!(start.elapsed(), Duration::from_secs(2));
+    }
+}
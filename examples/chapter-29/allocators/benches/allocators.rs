@@ -0,0 +1,23 @@
+use allocators::workload::{churn, churn_threads};
+use allocators::{Selected, TrackingAlloc, SELECTED};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// The benchmark uses whichever allocator the features select. Compare them
+// with Criterion's baselines:
+//
+//     cargo bench --bench allocators -- --save-baseline system
+//     cargo bench --bench allocators --features mimalloc -- --baseline system
+#[global_allocator]
+static ALLOC: TrackingAlloc<Selected> = TrackingAlloc::new(SELECTED);
+
+fn bench_allocators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("allocator");
+    group.bench_function("churn", |b| b.iter(|| churn(black_box(10_000))));
+    group.bench_function("churn_4_threads", |b| {
+        b.iter(|| churn_threads(4, black_box(10_000)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_allocators);
+criterion_main!(benches);
@@ -0,0 +1,31 @@
+//! A global allocator wrapper that tracks the total and peak memory of a
+//! program, on top of the system allocator, mimalloc, or jemalloc.
+
+mod tracking;
+pub mod workload;
+
+pub use tracking::{AllocReport, ReportOnExit, TrackingAlloc};
+
+// The allocator under the tracking wrapper, chosen by the features. The
+// `cfg`s cover every combination, so enabling both features still builds.
+
+#[cfg(feature = "mimalloc")]
+pub type Selected = mimalloc::MiMalloc;
+#[cfg(feature = "mimalloc")]
+pub const SELECTED: Selected = mimalloc::MiMalloc;
+#[cfg(feature = "mimalloc")]
+pub const SELECTED_NAME: &str = "mimalloc";
+
+#[cfg(all(feature = "jemalloc", not(feature = "mimalloc")))]
+pub type Selected = tikv_jemallocator::Jemalloc;
+#[cfg(all(feature = "jemalloc", not(feature = "mimalloc")))]
+pub const SELECTED: Selected = tikv_jemallocator::Jemalloc;
+#[cfg(all(feature = "jemalloc", not(feature = "mimalloc")))]
+pub const SELECTED_NAME: &str = "jemalloc";
+
+#[cfg(not(any(feature = "mimalloc", feature = "jemalloc")))]
+pub type Selected = std::alloc::System;
+#[cfg(not(any(feature = "mimalloc", feature = "jemalloc")))]
+pub const SELECTED: Selected = std::alloc::System;
+#[cfg(not(any(feature = "mimalloc", feature = "jemalloc")))]
+pub const SELECTED_NAME: &str = "system";
@@ -0,0 +1,19 @@
+use allocators::workload::churn_threads;
+use allocators::{Selected, TrackingAlloc, SELECTED, SELECTED_NAME};
+use std::time::Instant;
+
+#[global_allocator]
+static ALLOC: TrackingAlloc<Selected> = TrackingAlloc::new(SELECTED);
+
+fn main() {
+    // Declared first, so it's dropped last, after everything else in `main`
+    let _report = ALLOC.report_on_exit(SELECTED_NAME);
+
+    let start = Instant::now();
+    let kept = churn_threads(4, 200_000);
+    println!("Kept {} values in {:.2?}", kept, start.elapsed());
+
+    // A leak: the report shows it as memory still in use at exit
+    let leaked: &'static mut [u8] = Box::leak(vec![0u8; 1_000_000].into_boxed_slice());
+    println!("Leaked {} bytes", leaked.len());
+}
@@ -0,0 +1,194 @@
+use std::alloc::{GlobalAlloc, Layout};
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+/// Wraps another allocator and tracks, for the whole program, how much
+/// memory was allocated and how much was in use at most.
+///
+/// Unlike the per-thread counter in the `profiling` crate, the counters are
+/// shared by all threads: the peak is a property of the whole process.
+/// `Relaxed` atomics are enough, because the counters don't protect any
+/// other data; they only need to add up correctly.
+pub struct TrackingAlloc<A> {
+    inner: A,
+    allocations: AtomicUsize,
+    reallocations: AtomicUsize,
+    total_bytes: AtomicUsize,
+    current_bytes: AtomicUsize,
+    peak_bytes: AtomicUsize,
+}
+
+impl<A> TrackingAlloc<A> {
+    /// A `const fn`, so it can initialize the `static` that
+    /// `#[global_allocator]` requires
+    pub const fn new(inner: A) -> Self {
+        TrackingAlloc {
+            inner,
+            allocations: AtomicUsize::new(0),
+            reallocations: AtomicUsize::new(0),
+            total_bytes: AtomicUsize::new(0),
+            current_bytes: AtomicUsize::new(0),
+            peak_bytes: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn report(&self) -> AllocReport {
+        AllocReport {
+            allocations: self.allocations.load(Relaxed),
+            reallocations: self.reallocations.load(Relaxed),
+            total_bytes: self.total_bytes.load(Relaxed),
+            current_bytes: self.current_bytes.load(Relaxed),
+            peak_bytes: self.peak_bytes.load(Relaxed),
+        }
+    }
+
+    /// Starts measuring a new peak from the memory in use now
+    pub fn reset_peak(&self) {
+        self.peak_bytes
+            .store(self.current_bytes.load(Relaxed), Relaxed);
+    }
+
+    /// Returns a guard that prints the report to stderr when it's dropped,
+    /// at the end of `main`
+    pub fn report_on_exit(&'static self, name: &'static str) -> ReportOnExit<A> {
+        ReportOnExit { alloc: self, name }
+    }
+
+    fn grow(&self, bytes: usize) {
+        let current = self.current_bytes.fetch_add(bytes, Relaxed) + bytes;
+        self.peak_bytes.fetch_max(current, Relaxed);
+    }
+
+    fn shrink(&self, bytes: usize) {
+        self.current_bytes.fetch_sub(bytes, Relaxed);
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for TrackingAlloc<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc(layout);
+        // A null pointer means the allocation failed: nothing to count
+        if !ptr.is_null() {
+            self.allocations.fetch_add(1, Relaxed);
+            self.total_bytes.fetch_add(layout.size(), Relaxed);
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        // Forwarded, not left to the default, because the inner allocator
+        // may get zeroed memory cheaper than by writing zeros
+        let ptr = self.inner.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.allocations.fetch_add(1, Relaxed);
+            self.total_bytes.fetch_add(layout.size(), Relaxed);
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout);
+        self.shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = self.inner.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.reallocations.fetch_add(1, Relaxed);
+            if new_size > layout.size() {
+                let added = new_size - layout.size();
+                self.total_bytes.fetch_add(added, Relaxed);
+                self.grow(added);
+            } else {
+                self.shrink(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocReport {
+    pub allocations: usize,
+    pub reallocations: usize,
+    /// All bytes ever allocated, including memory that was freed again
+    pub total_bytes: usize,
+    pub current_bytes: usize,
+    pub peak_bytes: usize,
+}
+
+impl fmt::Display for AllocReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mb = |bytes: usize| bytes as f64 / 1_000_000.0;
+        writeln!(f, "allocations:   {}", self.allocations)?;
+        writeln!(f, "reallocations: {}", self.reallocations)?;
+        writeln!(f, "allocated:     {:.1} MB in total", mb(self.total_bytes))?;
+        writeln!(f, "peak:          {:.1} MB", mb(self.peak_bytes))?;
+        write!(f, "still in use:  {:.1} MB", mb(self.current_bytes))
+    }
+}
+
+/// Prints the allocator's report when dropped. It isn't printed when the
+/// program calls `std::process::exit` or panics with `panic = "abort"`,
+/// because then nothing is dropped.
+pub struct ReportOnExit<A: 'static> {
+    alloc: &'static TrackingAlloc<A>,
+    name: &'static str,
+}
+
+impl<A> Drop for ReportOnExit<A> {
+    fn drop(&mut self) {
+        eprintln!("Memory report ({} allocator):", self.name);
+        eprintln!("{}", self.alloc.report());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::alloc::System;
+
+    // A local instance, not the global allocator: only the calls in the
+    // test are counted, whatever other tests do at the same time
+    #[test]
+    fn tracks_total_current_and_peak() {
+        let alloc = TrackingAlloc::new(System);
+        let small = Layout::from_size_align(100, 8).unwrap();
+        let large = Layout::from_size_align(1_000, 8).unwrap();
+        unsafe {
+            let a = alloc.alloc(small);
+            let b = alloc.alloc(large);
+            alloc.dealloc(b, large);
+            let a = alloc.realloc(a, small, 300);
+            let report = alloc.report();
+            assert_eq!(report.allocations, 2);
+            assert_eq!(report.reallocations, 1);
+            assert_eq!(report.total_bytes, 1_300);
+            assert_eq!(report.peak_bytes, 1_100);
+            assert_eq!(report.current_bytes, 300);
+
+            alloc.reset_peak();
+            assert_eq!(alloc.report().peak_bytes, 300);
+            alloc.dealloc(a, Layout::from_size_align(300, 8).unwrap());
+        }
+        assert_eq!(alloc.report().current_bytes, 0);
+    }
+
+    #[test]
+    fn formats_the_report() {
+        let report = AllocReport {
+            allocations: 10,
+            reallocations: 2,
+            total_bytes: 2_500_000,
+            current_bytes: 0,
+            peak_bytes: 1_200_000,
+        };
+        assert_eq!(
+            report.to_string(),
+            "allocations:   10\nreallocations: 2\nallocated:     2.5 MB in total\n\
+             peak:          1.2 MB\nstill in use:  0.0 MB"
+        );
+    }
+}
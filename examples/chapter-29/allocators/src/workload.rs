@@ -0,0 +1,49 @@
+//! Work that allocates a lot of small, short-lived objects, where
+//! allocators differ the most.
+
+use std::collections::HashMap;
+use std::thread;
+
+/// Builds a map of `n` entries with string keys and vector values, keeps
+/// every third entry, and returns the number of values left
+pub fn churn(n: usize) -> usize {
+    let mut map: HashMap<String, Vec<u32>> = HashMap::new();
+    for i in 0..n {
+        let values = (0..(i % 16) as u32).collect();
+        map.insert(format!("key-{}", i), values);
+    }
+    map.retain(|key, _| {
+        let i: usize = key["key-".len()..].parse().unwrap();
+        i.is_multiple_of(3)
+    });
+    map.values().map(Vec::len).sum()
+}
+
+/// `churn` on several threads at once. The system allocator on some
+/// platforms shares one pool between threads, which then wait for each
+/// other; mimalloc and jemalloc give each thread its own.
+pub fn churn_threads(threads: usize, n: usize) -> usize {
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads).map(|_| scope.spawn(|| churn(n))).collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_every_third_entry() {
+        // Entries 0, 3, ..., 45 have `i % 16` values, which takes every
+        // count from 0 to 15 once
+        assert_eq!(churn(48), (0..16).sum());
+    }
+
+    #[test]
+    fn keeps_the_same_entries_on_every_thread() {
+        let single = churn(1_000);
+        assert!(single > 0);
+        assert_eq!(churn_threads(4, 1_000), 4 * single);
+    }
+}
@@ -0,0 +1,55 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use profiling::capacity::{
+    csv_line_by_line, csv_with_capacity, squares_push, squares_with_capacity,
+};
+use profiling::cow::{escape_html, escape_html_owned, longest, longest_cloned};
+use profiling::workload::{generate_text, top_words_fast, top_words_slow};
+
+// Each group measures a "before" and an "after" version of the same work,
+// so Criterion shows them next to each other
+
+fn bench_cow(c: &mut Criterion) {
+    let mut group = c.benchmark_group("escape_html");
+    let text = "A line of ordinary text, as most input is";
+    group.bench_function("owned", |b| b.iter(|| escape_html_owned(black_box(text))));
+    group.bench_function("cow", |b| b.iter(|| escape_html(black_box(text))));
+    group.finish();
+
+    let mut group = c.benchmark_group("longest");
+    let words: Vec<String> = generate_text(1_000).split(' ').map(String::from).collect();
+    group.bench_function("cloned", |b| b.iter(|| longest_cloned(black_box(&words))));
+    group.bench_function("borrowed", |b| {
+        b.iter(|| longest(black_box(&words)).map(str::len))
+    });
+    group.finish();
+}
+
+fn bench_capacity(c: &mut Criterion) {
+    let mut group = c.benchmark_group("squares");
+    group.bench_function("push", |b| b.iter(|| squares_push(black_box(10_000))));
+    group.bench_function("with_capacity", |b| {
+        b.iter(|| squares_with_capacity(black_box(10_000)))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("csv");
+    let rows: Vec<(u32, &str)> = (0..1_000).map(|i| (i, "Alice Smith")).collect();
+    group.bench_function("line_by_line", |b| {
+        b.iter(|| csv_line_by_line(black_box(&rows)))
+    });
+    group.bench_function("with_capacity", |b| {
+        b.iter(|| csv_with_capacity(black_box(&rows)))
+    });
+    group.finish();
+}
+
+fn bench_workload(c: &mut Criterion) {
+    let mut group = c.benchmark_group("top_words");
+    let text = generate_text(100_000);
+    group.bench_function("slow", |b| b.iter(|| top_words_slow(black_box(&text), 3)));
+    group.bench_function("fast", |b| b.iter(|| top_words_fast(black_box(&text), 3)));
+    group.finish();
+}
+
+criterion_group!(benches, bench_cow, bench_capacity, bench_workload);
+criterion_main!(benches);
@@ -0,0 +1,104 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// The allocations made by one thread
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Calls to `alloc`, and to `realloc`, which usually allocates anew
+    pub allocations: usize,
+    pub deallocations: usize,
+    /// The total size of all allocations, not the memory in use
+    pub bytes: usize,
+}
+
+thread_local! {
+    // Counting per thread keeps tests that run in parallel, and Criterion's
+    // own threads, from counting each other's allocations. A `const`
+    // initializer and `Cell`s without destructors mean that using it never
+    // allocates, which would call the allocator again.
+    static STATS: Cell<AllocStats> = const {
+        Cell::new(AllocStats { allocations: 0, deallocations: 0, bytes: 0 })
+    };
+}
+
+fn record(update: impl FnOnce(&mut AllocStats)) {
+    // `try_with` fails while the thread is shutting down; those
+    // allocations aren't counted
+    let _ = STATS.try_with(|stats| {
+        let mut current = stats.get();
+        update(&mut current);
+        stats.set(current);
+    });
+}
+
+/// Wraps the system allocator and counts what passes through it.
+///
+/// Register it in a binary, a test, or a benchmark with:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOC: CountingAlloc = CountingAlloc;
+/// ```
+pub struct CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(|stats| {
+            stats.allocations += 1;
+            stats.bytes += layout.size();
+        });
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record(|stats| stats.deallocations += 1);
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(|stats| {
+            stats.allocations += 1;
+            stats.bytes += new_size;
+        });
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Runs `f` and returns its result with the allocations it made on this
+/// thread. Without `CountingAlloc` as the global allocator, the counts are
+/// always zero.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, AllocStats) {
+    let before = STATS.with(Cell::get);
+    let result = f();
+    let after = STATS.with(Cell::get);
+    let stats = AllocStats {
+        allocations: after.allocations - before.allocations,
+        deallocations: after.deallocations - before.deallocations,
+        bytes: after.bytes - before.bytes,
+    };
+    (result, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations_and_bytes() {
+        let (_, stats) = measure(|| {
+            let boxed = Box::new([0u8; 100]);
+            drop(boxed);
+        });
+        assert_eq!(
+            stats,
+            AllocStats {
+                allocations: 1,
+                deallocations: 1,
+                bytes: 100
+            }
+        );
+
+        let (_, stats) = measure(|| 1 + 1);
+        assert_eq!(stats, AllocStats::default());
+    }
+}
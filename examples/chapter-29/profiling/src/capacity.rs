@@ -0,0 +1,71 @@
+//! Pre-allocating: a `Vec` or `String` that grows one element at a time
+//! moves its contents to a bigger buffer whenever it's full. When the final
+//! size is known, or can be estimated, allocate it once.
+
+/// Before: the vector grows step by step
+pub fn squares_push(n: u64) -> Vec<u64> {
+    let mut squares = Vec::new();
+    for i in 0..n {
+        squares.push(i * i);
+    }
+    squares
+}
+
+/// After: one allocation of the right size
+pub fn squares_with_capacity(n: u64) -> Vec<u64> {
+    let mut squares = Vec::with_capacity(n as usize);
+    for i in 0..n {
+        squares.push(i * i);
+    }
+    squares
+}
+
+/// Before: the string grows as lines are added
+pub fn csv_line_by_line(rows: &[(u32, &str)]) -> String {
+    let mut csv = String::new();
+    for (id, name) in rows {
+        csv.push_str(&format!("{},{}\n", id, name));
+    }
+    csv
+}
+
+/// After: estimate the size, and write into the string directly instead
+/// of creating a temporary `String` per line with `format!`
+pub fn csv_with_capacity(rows: &[(u32, &str)]) -> String {
+    use std::fmt::Write;
+
+    // Ten digits for the id, a comma, and a newline
+    let estimate: usize = rows.iter().map(|(_, name)| name.len() + 12).sum();
+    let mut csv = String::with_capacity(estimate);
+    for (id, name) in rows {
+        // Writing to a `String` can't fail
+        writeln!(csv, "{},{}", id, name).unwrap();
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alloc_counter::measure;
+
+    #[test]
+    fn with_capacity_allocates_once() {
+        let (pushed, before) = measure(|| squares_push(1000));
+        let (reserved, after) = measure(|| squares_with_capacity(1000));
+        assert_eq!(pushed, reserved);
+        // 4, 8, 16, ..., 1024 elements
+        assert_eq!(before.allocations, 9);
+        assert_eq!(after.allocations, 1);
+    }
+
+    #[test]
+    fn csv_without_temporary_strings() {
+        let rows: Vec<(u32, &str)> = (0..100).map(|i| (i, "Alice")).collect();
+        let (slow, before) = measure(|| csv_line_by_line(&rows));
+        let (fast, after) = measure(|| csv_with_capacity(&rows));
+        assert_eq!(slow, fast);
+        assert!(before.allocations > 100);
+        assert_eq!(after.allocations, 1);
+    }
+}
@@ -0,0 +1,78 @@
+//! Avoiding copies: return borrows where the caller only reads, and `Cow`
+//! where a function only sometimes needs to change its input.
+
+use std::borrow::Cow;
+
+/// Before: always returns a new `String`, even when there is nothing to
+/// escape, which is most of the time
+pub fn escape_html_owned(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// After: borrows the input when it needs no escaping, and only allocates
+/// when it does
+pub fn escape_html(text: &str) -> Cow<'_, str> {
+    if !text.contains(['<', '>', '&', '"']) {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(escape_html_owned(text))
+}
+
+/// Before: clones the longest word, although the caller only looks at it
+pub fn longest_cloned(words: &[String]) -> Option<String> {
+    words.iter().max_by_key(|word| word.len()).cloned()
+}
+
+/// After: returns a reference into the slice, no copy
+pub fn longest(words: &[String]) -> Option<&str> {
+    words
+        .iter()
+        .max_by_key(|word| word.len())
+        .map(String::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alloc_counter::measure;
+
+    #[test]
+    fn escapes_the_same_way() {
+        let text = r#"<a href="x">Tom & Jerry</a>"#;
+        let expected = "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&lt;/a&gt;";
+        assert_eq!(escape_html_owned(text), expected);
+        assert_eq!(escape_html(text), expected);
+    }
+
+    #[test]
+    fn cow_only_allocates_when_it_changes_something() {
+        let (escaped, stats) = measure(|| escape_html("plain text"));
+        assert!(matches!(escaped, Cow::Borrowed(_)));
+        assert_eq!(stats.allocations, 0);
+
+        let (_, stats) = measure(|| escape_html_owned("plain text"));
+        assert!(stats.allocations > 0);
+    }
+
+    #[test]
+    fn borrowing_copies_nothing() {
+        let words: Vec<String> = ["a", "abc", "ab"].map(String::from).to_vec();
+        let (longest, stats) = measure(|| longest(&words));
+        assert_eq!(longest, Some("abc"));
+        assert_eq!(stats.allocations, 0);
+
+        let (cloned, stats) = measure(|| longest_cloned(&words));
+        assert_eq!(cloned.as_deref(), Some("abc"));
+        assert_eq!(stats.allocations, 1);
+    }
+}
@@ -0,0 +1,13 @@
+//! Measuring and reducing the work a program does: a global allocator
+//! that counts allocations, and three common fixes, each with a slow
+//! "before" and a faster "after" version.
+
+pub mod alloc_counter;
+pub mod capacity;
+pub mod cow;
+pub mod workload;
+
+// Unit tests that count allocations need the counting allocator, too
+#[cfg(test)]
+#[global_allocator]
+static ALLOC: alloc_counter::CountingAlloc = alloc_counter::CountingAlloc;
@@ -0,0 +1,44 @@
+use profiling::alloc_counter::{measure, CountingAlloc};
+use profiling::workload::{generate_text, top_words_fast, top_words_slow};
+use std::time::Instant;
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+// Runs the workload long enough for a profiler to collect samples:
+//
+//     cargo run --release -- slow 50
+//     cargo flamegraph -- slow 50
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let version = args.next().unwrap_or_else(|| "slow".to_string());
+    let rounds: usize = args.next().and_then(|s| s.parse().ok()).unwrap_or(20);
+    let top_words = match version.as_str() {
+        "slow" => top_words_slow,
+        "fast" => top_words_fast,
+        other => {
+            eprintln!("Unknown version '{}', expected 'slow' or 'fast'", other);
+            std::process::exit(1);
+        }
+    };
+
+    let text = generate_text(1_000_000);
+    let start = Instant::now();
+    let (top, stats) = measure(|| {
+        let mut top = Vec::new();
+        for _ in 0..rounds {
+            top = top_words(&text, 3);
+        }
+        top
+    });
+
+    println!("Top words: {:?}", top);
+    println!(
+        "{} rounds of '{}' in {:.2?}: {} allocations, {} MB allocated",
+        rounds,
+        version,
+        start.elapsed(),
+        stats.allocations,
+        stats.bytes / 1_000_000
+    );
+}
@@ -0,0 +1,118 @@
+//! A workload that keeps the CPU busy long enough to profile: counting the
+//! most frequent words in a large text. The slow version makes the usual
+//! mistakes; a flame graph shows where its time goes.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+const WORDS: [&str; 16] = [
+    "the", "quick", "brown", "fox", "jumps", "over", "lazy", "dog", "rust", "memory", "fast",
+    "safe", "borrow", "checker", "heap", "stack",
+];
+
+/// Generates `count` words with some capitalization and punctuation.
+/// A simple linear congruential generator keeps the text the same on
+/// every run, without a dependency.
+pub fn generate_text(count: usize) -> String {
+    let mut seed: u64 = 42;
+    let mut text = String::with_capacity(count * 8);
+    for i in 0..count {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        // The upper bits of an LCG are the most random ones
+        let word = WORDS[(seed >> 60) as usize];
+        if i % 10 == 0 {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                text.extend(first.to_uppercase());
+                text.push_str(chars.as_str());
+            }
+        } else {
+            text.push_str(word);
+        }
+        text.push_str(if i % 7 == 6 { ". " } else { " " });
+    }
+    text
+}
+
+/// Before: a new `String` for every word, a `String` key for every lookup,
+/// and a full sort to find the top few
+pub fn top_words_slow(text: &str, n: usize) -> Vec<(String, usize)> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for word in text.split_whitespace() {
+        let word = word
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase();
+        *counts.entry(word.clone()).or_insert(0) += 1;
+    }
+
+    let mut sorted: Vec<(String, usize)> = counts.into_iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    sorted.truncate(n);
+    sorted
+}
+
+/// Lowercases only words that contain uppercase letters
+fn normalize(word: &str) -> Cow<'_, str> {
+    let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+    if word.chars().any(char::is_uppercase) {
+        Cow::Owned(word.to_lowercase())
+    } else {
+        Cow::Borrowed(word)
+    }
+}
+
+/// After: keys borrow from the text where possible, and only the top `n`
+/// entries are sorted
+pub fn top_words_fast(text: &str, n: usize) -> Vec<(String, usize)> {
+    let mut counts: HashMap<Cow<str>, usize> = HashMap::with_capacity(64);
+    for word in text.split_whitespace() {
+        *counts.entry(normalize(word)).or_insert(0) += 1;
+    }
+
+    let mut entries: Vec<(Cow<str>, usize)> = counts.into_iter().collect();
+    let by_count = |a: &(Cow<str>, usize), b: &(Cow<str>, usize)| b.1.cmp(&a.1).then(a.0.cmp(&b.0));
+    if n < entries.len() {
+        // Moves the top `n` to the front, in no particular order, without
+        // sorting the rest
+        entries.select_nth_unstable_by(n, by_count);
+        entries.truncate(n);
+    }
+    entries.sort_unstable_by(by_count);
+    entries
+        .into_iter()
+        .map(|(word, count)| (word.into_owned(), count))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alloc_counter::measure;
+
+    #[test]
+    fn both_versions_agree() {
+        let text = generate_text(10_000);
+        let slow = top_words_slow(&text, 5);
+        assert_eq!(slow.len(), 5);
+        assert_eq!(top_words_fast(&text, 5), slow);
+        assert_eq!(top_words_fast(&text, 100), top_words_slow(&text, 100));
+    }
+
+    #[test]
+    fn normalizes_words() {
+        let text = "The dog. the DOG, dog!";
+        assert_eq!(top_words_fast(text, 1), [("dog".to_string(), 3)]);
+    }
+
+    #[test]
+    fn fast_version_allocates_far_less() {
+        let text = generate_text(10_000);
+        let (_, slow) = measure(|| top_words_slow(&text, 5));
+        let (_, fast) = measure(|| top_words_fast(&text, 5));
+        assert!(slow.allocations > 20_000, "{:?}", slow);
+        // One lowercase copy for every tenth word, plus the map and results
+        assert!(fast.allocations < 1_500, "{:?}", fast);
+    }
+}
@@ -0,0 +1,30 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use simd::{dot_chunked, dot_i32, dot_iter, dot_naive, sample};
+
+fn bench_dot(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dot");
+    for len in [1_000, 100_000] {
+        let a = sample(len, 1);
+        let b = sample(len, 2);
+        let a_int: Vec<i32> = a.iter().map(|x| (x * 100.0) as i32).collect();
+        let b_int: Vec<i32> = b.iter().map(|x| (x * 100.0) as i32).collect();
+
+        group.throughput(Throughput::Elements(len as u64));
+        group.bench_with_input(BenchmarkId::new("naive", len), &len, |bench, _| {
+            bench.iter(|| dot_naive(black_box(&a), black_box(&b)))
+        });
+        group.bench_with_input(BenchmarkId::new("iter", len), &len, |bench, _| {
+            bench.iter(|| dot_iter(black_box(&a), black_box(&b)))
+        });
+        group.bench_with_input(BenchmarkId::new("chunked", len), &len, |bench, _| {
+            bench.iter(|| dot_chunked(black_box(&a), black_box(&b)))
+        });
+        group.bench_with_input(BenchmarkId::new("i32", len), &len, |bench, _| {
+            bench.iter(|| dot_i32(black_box(&a_int), black_box(&b_int)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_dot);
+criterion_main!(benches);
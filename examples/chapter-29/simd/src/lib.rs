@@ -0,0 +1,107 @@
+//! The dot product of two vectors, written three ways, to show when the
+//! compiler turns a loop into SIMD instructions and when it can't.
+//!
+//! SIMD ("single instruction, multiple data") instructions work on several
+//! numbers at once: with SSE, four `f32`s per instruction, with AVX, eight.
+//! The compiler uses them on its own, *auto-vectorization*, when it can
+//! prove that the result doesn't change.
+
+/// A plain indexed loop
+pub fn dot_naive(a: &[f32], b: &[f32]) -> f32 {
+    assert_eq!(a.len(), b.len());
+    let mut sum = 0.0;
+    for i in 0..a.len() {
+        sum += a[i] * b[i];
+    }
+    sum
+}
+
+/// The idiomatic version. The iterators remove the bounds checks, but the
+/// additions still happen one after another, in the same order.
+pub fn dot_iter(a: &[f32], b: &[f32]) -> f32 {
+    assert_eq!(a.len(), b.len());
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+const LANES: usize = 8;
+
+/// Eight independent sums, one per lane, added together at the end.
+///
+/// Floating-point addition isn't associative: `(a + b) + c` can differ from
+/// `a + (b + c)` in the last bits. The compiler must keep the order of the
+/// two versions above, one addition at a time, so it can't vectorize them.
+/// Here, the order is ours to choose, and the eight sums map directly onto
+/// SIMD registers. The result may differ slightly from the other versions.
+pub fn dot_chunked(a: &[f32], b: &[f32]) -> f32 {
+    assert_eq!(a.len(), b.len());
+    let mut sums = [0.0f32; LANES];
+
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let tail: f32 = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(x, y)| x * y)
+        .sum();
+
+    for (x, y) in a_chunks.zip(b_chunks) {
+        for lane in 0..LANES {
+            sums[lane] += x[lane] * y[lane];
+        }
+    }
+    sums.iter().sum::<f32>() + tail
+}
+
+/// Integer addition is associative, so this simple loop is vectorized
+/// without any help
+pub fn dot_i32(a: &[i32], b: &[i32]) -> i32 {
+    assert_eq!(a.len(), b.len());
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| x.wrapping_mul(*y))
+        .fold(0, i32::wrapping_add)
+}
+
+/// Test data: values between -1 and 1, the same on every run
+pub fn sample(len: usize, seed: u32) -> Vec<f32> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (state >> 8) as f32 / (1 << 23) as f32 - 1.0
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_versions_compute_the_dot_product() {
+        let a = [1.0, 2.0, 3.0];
+        let b = [4.0, 5.0, 6.0];
+        assert_eq!(dot_naive(&a, &b), 32.0);
+        assert_eq!(dot_iter(&a, &b), 32.0);
+        assert_eq!(dot_chunked(&a, &b), 32.0);
+        assert_eq!(dot_i32(&[1, 2, 3], &[4, 5, 6]), 32);
+    }
+
+    #[test]
+    fn chunked_handles_the_remainder() {
+        let a: Vec<f32> = (1..=19).map(|i| i as f32).collect();
+        let ones = vec![1.0; 19];
+        assert_eq!(dot_chunked(&a, &ones), 190.0);
+    }
+
+    #[test]
+    fn a_different_order_gives_almost_the_same_result() {
+        let a = sample(100_000, 1);
+        let b = sample(100_000, 2);
+        let naive = dot_naive(&a, &b);
+        let chunked = dot_chunked(&a, &b);
+        assert_eq!(dot_iter(&a, &b), naive);
+        assert!((naive - chunked).abs() < 1e-3 * naive.abs().max(1.0));
+    }
+}
@@ -0,0 +1,55 @@
+//! The commentary of the benchmark as assertions. Timings of unoptimized
+//! code say nothing, so these tests only run in release mode:
+//!
+//!     cargo test --release -p simd
+
+use simd::{dot_chunked, dot_iter, dot_naive, sample};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// The fastest of many runs is the least disturbed by other processes
+fn fastest(f: impl Fn() -> f32) -> Duration {
+    (0..50)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore = "timings need `cargo test --release`")]
+fn relative_speed() {
+    let a = sample(100_000, 1);
+    let b = sample(100_000, 2);
+    let naive = fastest(|| dot_naive(black_box(&a), black_box(&b)));
+    let iter = fastest(|| dot_iter(black_box(&a), black_box(&b)));
+    let chunked = fastest(|| dot_chunked(black_box(&a), black_box(&b)));
+    println!(
+        "naive: {:?}, iter: {:?}, chunked: {:?}",
+        naive, iter, chunked
+    );
+
+    // Iterators remove bounds checks, but the additions stay in order:
+    // both versions wait for one addition before the next one starts
+    assert!(
+        iter < naive * 3 / 2,
+        "iter should be about as fast as naive"
+    );
+    assert!(
+        naive < iter * 3 / 2,
+        "naive should be about as fast as iter"
+    );
+
+    // Independent sums run in SIMD registers, several at a time
+    assert!(
+        chunked * 2 < naive,
+        "chunked should be much faster than naive"
+    );
+    assert!(
+        chunked * 2 < iter,
+        "chunked should be much faster than iter"
+    );
+}
@@ -0,0 +1,44 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use profiling::alloc_counter::{measure, CountingAlloc};
+use small_collections::{sample_lines, tokenize_small, tokenize_std};
+
+// Counting costs a little time, the same for both versions
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+fn bench_tokenize(c: &mut Criterion) {
+    let lines = sample_lines(1_000);
+
+    // Criterion measures time; the allocations are printed once up front
+    let (_, std) = measure(|| lines.iter().map(|l| tokenize_std(l).len()).sum::<usize>());
+    let (_, small) = measure(|| lines.iter().map(|l| tokenize_small(l).len()).sum::<usize>());
+    println!(
+        "Allocations for {} lines: std {}, small {}",
+        lines.len(),
+        std.allocations,
+        small.allocations
+    );
+
+    let mut group = c.benchmark_group("tokenize");
+    group.throughput(Throughput::Elements(lines.len() as u64));
+    group.bench_function("vec_string", |b| {
+        b.iter(|| {
+            black_box(&lines)
+                .iter()
+                .map(|l| tokenize_std(l).len())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("smallvec_compact_str", |b| {
+        b.iter(|| {
+            black_box(&lines)
+                .iter()
+                .map(|l| tokenize_small(l).len())
+                .sum::<usize>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_tokenize);
+criterion_main!(benches);
@@ -0,0 +1,99 @@
+//! Tokenizing log lines into owned tokens, with the standard collections
+//! and with collections that store small contents inline, without a heap
+//! allocation.
+
+use compact_str::CompactString;
+use smallvec::SmallVec;
+
+/// Before: a `Vec` of `String`s. Every token is a heap allocation, and so
+/// is the vector.
+pub fn tokenize_std(line: &str) -> Vec<String> {
+    line.split_whitespace().map(String::from).collect()
+}
+
+/// Up to 8 tokens are stored inside the `SmallVec` itself; a ninth moves
+/// all of them to the heap, like a `Vec`
+pub type Tokens = SmallVec<[CompactString; 8]>;
+
+/// After: a `CompactString` of up to 24 bytes is stored inline, in the
+/// same 24 bytes a `String` uses for its pointer, length, and capacity
+pub fn tokenize_small(line: &str) -> Tokens {
+    line.split_whitespace().map(CompactString::from).collect()
+}
+
+/// Sample log lines with exactly five short tokens each
+pub fn sample_lines(count: usize) -> Vec<String> {
+    let users = ["alice", "bob", "carol", "dave"];
+    let actions = ["login", "logout", "upload", "download", "delete"];
+    (0..count)
+        .map(|i| {
+            format!(
+                "2024-05-01T12:{:02}:{:02} INFO user={} action={} ip=10.0.{}.{}",
+                i / 60 % 60,
+                i % 60,
+                users[i % users.len()],
+                actions[i % actions.len()],
+                i / 256 % 256,
+                i % 256
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOC: profiling::alloc_counter::CountingAlloc = profiling::alloc_counter::CountingAlloc;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use profiling::alloc_counter::measure;
+    use std::mem::size_of;
+
+    const LINE: &str = "2024-05-01T12:00:00 INFO user=alice action=login ip=10.0.0.1";
+
+    #[test]
+    fn both_versions_find_the_same_tokens() {
+        let std = tokenize_std(LINE);
+        let small = tokenize_small(LINE);
+        assert_eq!(std.len(), 5);
+        assert!(std.iter().zip(&small).all(|(a, b)| a == b));
+        // The sample lines have the same shape
+        let lines = sample_lines(1_000);
+        assert!(lines.iter().all(|line| tokenize_std(line).len() == 5));
+    }
+
+    #[test]
+    fn short_tokens_need_no_allocation() {
+        let (std, stats) = measure(|| tokenize_std(LINE));
+        // Five strings, and a vector that grows from 4 to 8 elements
+        assert_eq!(stats.allocations, 5 + 2);
+        drop(std);
+
+        let (small, stats) = measure(|| tokenize_small(LINE));
+        assert!(!small.spilled());
+        assert_eq!(stats.allocations, 0);
+    }
+
+    #[test]
+    fn long_tokens_and_many_tokens_go_to_the_heap() {
+        // One token longer than 24 bytes: one allocation for it
+        let line = "INFO path=/home/alice/documents/report.pdf";
+        let (tokens, stats) = measure(|| tokenize_small(line));
+        assert!(tokens[1].is_heap_allocated());
+        assert_eq!(stats.allocations, 1);
+
+        // Nine tokens don't fit into 8 inline slots
+        let (tokens, _) = measure(|| tokenize_small("a b c d e f g h i"));
+        assert!(tokens.spilled());
+    }
+
+    #[test]
+    fn inline_storage_makes_the_values_bigger() {
+        assert_eq!(size_of::<String>(), 24);
+        assert_eq!(size_of::<CompactString>(), 24);
+        assert_eq!(size_of::<Vec<String>>(), 24);
+        // 8 inline slots of 24 bytes, plus the length
+        assert_eq!(size_of::<Tokens>(), 8 * 24 + 8);
+    }
+}
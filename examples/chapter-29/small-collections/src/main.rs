@@ -0,0 +1,31 @@
+use profiling::alloc_counter::{measure, CountingAlloc};
+use small_collections::{sample_lines, tokenize_small, tokenize_std};
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+fn main() {
+    let lines = sample_lines(10_000);
+    println!("Example line: {}", lines[0]);
+
+    // Keeping the tokens, as a program that stores them would
+    let (std, std_stats) = measure(|| lines.iter().map(|l| tokenize_std(l)).collect::<Vec<_>>());
+    let (small, small_stats) =
+        measure(|| lines.iter().map(|l| tokenize_small(l)).collect::<Vec<_>>());
+
+    println!(
+        "Vec<String>:                      {:>6} allocations, {:>4} KB",
+        std_stats.allocations,
+        std_stats.bytes / 1000
+    );
+    println!(
+        "SmallVec<[CompactString; 8]>:     {:>6} allocations, {:>4} KB",
+        small_stats.allocations,
+        small_stats.bytes / 1000
+    );
+    println!(
+        "Tokens: {} and {}",
+        std.iter().map(Vec::len).sum::<usize>(),
+        small.iter().map(|t| t.len()).sum::<usize>()
+    );
+}
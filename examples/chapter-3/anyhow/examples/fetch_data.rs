@@ -0,0 +1,196 @@
+use anyhow::{Context, Result};
+use reqwest::blocking::Client;
+use std::thread::sleep;
+use std::time::Duration;
+
+// The `fetch_data` function from the command-line tool, extended with
+// a timeout, a status check, and retries so that it can be tested.
+// `wait` is called with each backoff delay; the program passes `sleep`,
+// and tests pass a closure that doesn't wait at all.
+fn fetch_data(
+    client: &Client,
+    url: &str,
+    max_retries: u32,
+    mut wait: impl FnMut(Duration),
+) -> Result<String> {
+    let mut attempt = 0;
+    loop {
+        match try_fetch(client, url) {
+            Ok(data) => return Ok(data),
+            Err(Attempt::Retry(e)) if attempt < max_retries => {
+                attempt += 1;
+                eprintln!("Attempt {} failed: {:#}. Retrying...", attempt, e);
+                wait(Duration::from_millis(100 * 2u64.pow(attempt - 1)));
+            }
+            Err(Attempt::Retry(e)) | Err(Attempt::Fail(e)) => {
+                return Err(e.context(format!("Failed to fetch data from '{}'", url)));
+            }
+        }
+    }
+}
+
+enum Attempt {
+    // A temporary failure: the next attempt may succeed
+    Retry(anyhow::Error),
+    // A permanent failure: retrying would not help
+    Fail(anyhow::Error),
+}
+
+fn try_fetch(client: &Client, url: &str) -> Result<String, Attempt> {
+    let response = client.get(url).send().map_err(|e| {
+        if e.is_timeout() || e.is_connect() {
+            Attempt::Retry(e.into())
+        } else {
+            Attempt::Fail(e.into())
+        }
+    })?;
+
+    let status = response.status();
+    if status.is_server_error() {
+        return Err(Attempt::Retry(anyhow::anyhow!("Server error: {}", status)));
+    }
+    if !status.is_success() {
+        return Err(Attempt::Fail(anyhow::anyhow!(
+            "Unexpected status: {}",
+            status
+        )));
+    }
+
+    // A timeout can also happen while the body is being read
+    response
+        .text()
+        .context("Failed to read response body")
+        .map_err(Attempt::Retry)
+}
+
+fn build_client(timeout: Duration) -> Result<Client> {
+    Client::builder()
+        .timeout(timeout)
+        .build()
+        .context("Failed to build HTTP client")
+}
+
+fn main() -> Result<()> {
+    let url = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "https://httpbin.org/status/503".to_string());
+    let client = build_client(Duration::from_secs(5))?;
+
+    let data = fetch_data(&client, &url, 2, sleep)?;
+    println!("Received {} bytes", data.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httpmock::prelude::*;
+
+    #[test]
+    fn requests_the_expected_path() {
+        // Every test gets its own server on a random local port
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/api/data");
+            then.status(200).body("hello");
+        });
+
+        let client = build_client(Duration::from_secs(1)).unwrap();
+        let data = fetch_data(&client, &server.url("/api/data"), 0, |_| {}).unwrap();
+
+        assert_eq!(data, "hello");
+        // Fails if the function called a different path or no path at all
+        mock.assert();
+    }
+
+    #[test]
+    fn server_errors_are_retried_and_reported() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.path("/api/data");
+            then.status(500);
+        });
+
+        let client = build_client(Duration::from_secs(1)).unwrap();
+        let mut waits = Vec::new();
+        let err = fetch_data(&client, &server.url("/api/data"), 2, |delay| {
+            waits.push(delay)
+        })
+        .unwrap_err();
+
+        // One initial attempt plus two retries, with a growing delay
+        mock.assert_hits(3);
+        assert_eq!(
+            waits,
+            [Duration::from_millis(100), Duration::from_millis(200)]
+        );
+        let message = format!("{:#}", err);
+        assert!(message.contains("Failed to fetch data from"));
+        assert!(message.contains("500 Internal Server Error"));
+    }
+
+    #[test]
+    fn client_errors_are_not_retried() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.path("/api/data");
+            then.status(404);
+        });
+
+        let client = build_client(Duration::from_secs(1)).unwrap();
+        let err = fetch_data(&client, &server.url("/api/data"), 2, |_| {}).unwrap_err();
+
+        mock.assert_hits(1);
+        assert!(format!("{:#}", err).contains("404 Not Found"));
+    }
+
+    #[test]
+    fn retries_until_the_server_recovers() {
+        let server = MockServer::start();
+        let mut failing = Some(server.mock(|when, then| {
+            when.path("/api/data");
+            then.status(503);
+        }));
+        let mut healthy = None;
+
+        // The server recovers while the client waits after the first
+        // failure, so no real time has to pass
+        let client = build_client(Duration::from_secs(1)).unwrap();
+        let data = fetch_data(&client, &server.url("/api/data"), 3, |_| {
+            if let Some(mut mock) = failing.take() {
+                mock.assert_hits(1);
+                mock.delete();
+                healthy = Some(server.mock(|when, then| {
+                    when.path("/api/data");
+                    then.status(200).body("recovered");
+                }));
+            }
+        })
+        .unwrap();
+
+        assert_eq!(data, "recovered");
+        healthy.unwrap().assert_hits(1);
+    }
+
+    #[test]
+    fn slow_responses_time_out() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.path("/api/data");
+            then.status(200)
+                .body("too late")
+                .delay(Duration::from_millis(500));
+        });
+
+        let client = build_client(Duration::from_millis(100)).unwrap();
+        let err = fetch_data(&client, &server.url("/api/data"), 1, |_| {}).unwrap_err();
+
+        // The timeout counts as a temporary failure, so it was retried once
+        mock.assert_hits(2);
+        let is_timeout = err
+            .chain()
+            .filter_map(|cause| cause.downcast_ref::<reqwest::Error>())
+            .any(|e| e.is_timeout());
+        assert!(is_timeout, "expected a timeout, got: {:#}", err);
+    }
+}
@@ -0,0 +1,153 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::num::ParseIntError;
+
+// Low-level error: something went wrong while reading the config file
+#[derive(Debug)]
+pub enum ConfigError {
+    Read { path: String, source: io::Error },
+    InvalidPort { value: String, source: ParseIntError },
+    MissingKey(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Read { path, .. } => write!(f, "failed to read config file `{}`", path),
+            ConfigError::InvalidPort { value, .. } => write!(f, "invalid port `{}`", value),
+            ConfigError::MissingKey(key) => write!(f, "missing key `{}`", key),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    // The underlying cause, if there is one
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Read { source, .. } => Some(source),
+            ConfigError::InvalidPort { source, .. } => Some(source),
+            ConfigError::MissingKey(_) => None,
+        }
+    }
+}
+
+// High-level error: what the application was trying to do
+#[derive(Debug)]
+pub enum AppError {
+    Startup(ConfigError),
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::Startup(_) => write!(f, "application failed to start"),
+        }
+    }
+}
+
+impl Error for AppError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AppError::Startup(e) => Some(e),
+        }
+    }
+}
+
+// Lets `?` convert a ConfigError into an AppError
+impl From<ConfigError> for AppError {
+    fn from(error: ConfigError) -> Self {
+        AppError::Startup(error)
+    }
+}
+
+fn parse_port(contents: &str) -> Result<u16, ConfigError> {
+    let value = contents
+        .lines()
+        .find_map(|line| line.strip_prefix("port="))
+        .ok_or_else(|| ConfigError::MissingKey("port".to_string()))?;
+
+    value.trim().parse().map_err(|e| ConfigError::InvalidPort {
+        value: value.to_string(),
+        source: e,
+    })
+}
+
+fn load_port(path: &str) -> Result<u16, ConfigError> {
+    let contents = fs::read_to_string(path).map_err(|e| ConfigError::Read {
+        path: path.to_string(),
+        source: e,
+    })?;
+    parse_port(&contents)
+}
+
+fn start(path: &str) -> Result<u16, AppError> {
+    let port = load_port(path)?;
+    Ok(port)
+}
+
+// Walks the chain of causes and collects every message
+fn error_chain(error: &dyn Error) -> Vec<String> {
+    let mut messages = vec![error.to_string()];
+    let mut current = error.source();
+    while let Some(cause) = current {
+        messages.push(cause.to_string());
+        current = cause.source();
+    }
+    messages
+}
+
+fn main() {
+    match start("server.conf") {
+        Ok(port) => println!("Listening on port {}", port),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            for cause in error_chain(&e).iter().skip(1) {
+                eprintln!("  caused by: {}", cause);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_file_has_io_error_as_root_cause() {
+        let error = start("does-not-exist.conf").unwrap_err();
+        let chain = error_chain(&error);
+
+        assert_eq!(chain.len(), 3);
+        assert_eq!(chain[0], "application failed to start");
+        assert_eq!(chain[1], "failed to read config file `does-not-exist.conf`");
+
+        let config_error = error.source().unwrap();
+        let io_error = config_error.source().unwrap();
+        assert!(io_error.downcast_ref::<io::Error>().is_some());
+    }
+
+    #[test]
+    fn invalid_port_keeps_parse_error() {
+        let error = parse_port("port=http").unwrap_err();
+        assert_eq!(error.to_string(), "invalid port `http`");
+        assert!(error
+            .source()
+            .unwrap()
+            .downcast_ref::<ParseIntError>()
+            .is_some());
+    }
+
+    #[test]
+    fn missing_key_has_no_source() {
+        let error = parse_port("host=localhost").unwrap_err();
+        assert_eq!(error.to_string(), "missing key `port`");
+        assert!(error.source().is_none());
+    }
+
+    #[test]
+    fn valid_port_is_parsed() {
+        assert_eq!(parse_port("host=localhost\nport=8080").unwrap(), 8080);
+    }
+}
@@ -0,0 +1,60 @@
+use itertools::Itertools;
+use std::fs;
+use std::num::ParseIntError;
+
+// Strategy 1: stop at the first bad line and return its error
+fn parse_all(contents: &str) -> Result<Vec<i32>, ParseIntError> {
+    contents
+        .lines()
+        .map(|line| line.trim().parse::<i32>())
+        .collect() // Vec<Result<i32, _>> becomes Result<Vec<i32>, _>
+}
+
+// Strategy 2: skip bad lines, but report each one
+fn parse_valid(contents: &str) -> Vec<i32> {
+    contents
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| match line.trim().parse::<i32>() {
+            Ok(number) => Some(number),
+            Err(e) => {
+                eprintln!("Skipping line {} ({:?}): {}", index + 1, line, e);
+                None
+            }
+        })
+        .collect()
+}
+
+// Strategy 3: work with the plain numbers, stop at the first error
+fn sum_all(contents: &str) -> Result<i32, ParseIntError> {
+    itertools::process_results(
+        contents.lines().map(|line| line.trim().parse::<i32>()),
+        |numbers| numbers.sum(),
+    )
+}
+
+fn main() -> Result<(), std::io::Error> {
+    let contents = fs::read_to_string("numbers.txt")?;
+
+    match parse_all(&contents) {
+        Ok(numbers) => println!("All numbers: {:?}", numbers),
+        Err(e) => println!("collect::<Result<Vec<_>, _>>() failed: {}", e),
+    }
+
+    let numbers = parse_valid(&contents);
+    println!("Valid numbers: {:?}", numbers);
+
+    match sum_all(&contents) {
+        Ok(sum) => println!("Sum: {}", sum),
+        Err(e) => println!("process_results() failed: {}", e),
+    }
+
+    // Errors can also be collected separately from the values
+    let (valid, invalid): (Vec<_>, Vec<_>) = contents
+        .lines()
+        .map(|line| line.trim().parse::<i32>())
+        .partition_result();
+    println!("{} valid, {} invalid", valid.len(), invalid.len());
+
+    Ok(())
+}
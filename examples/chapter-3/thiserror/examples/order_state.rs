@@ -0,0 +1,245 @@
+use std::fmt;
+use thiserror::Error;
+
+/// The lifecycle of an order:
+///
+/// ```text
+/// Created ──pay──> Paid ──ship──> Shipped ──deliver──> Delivered
+///    │               │
+///    └────cancel─────┴──────> Cancelled
+/// ```
+///
+/// Every state carries the data that only exists in that state: there is
+/// no tracking number before the order is shipped.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderState {
+    Created,
+    Paid { payment_id: String },
+    Shipped { payment_id: String, tracking: String },
+    Delivered { tracking: String },
+    Cancelled { reason: String, refund: Option<String> },
+}
+
+impl fmt::Display for OrderState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            OrderState::Created => "created",
+            OrderState::Paid { .. } => "paid",
+            OrderState::Shipped { .. } => "shipped",
+            OrderState::Delivered { .. } => "delivered",
+            OrderState::Cancelled { .. } => "cancelled",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Order {
+    pub id: u32,
+    pub total_cents: u64,
+    pub state: OrderState,
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum TransitionError {
+    #[error("Cannot {action} order {id}: it is {state}")]
+    NotAllowed {
+        id: u32,
+        action: &'static str,
+        state: String,
+    },
+    #[error("Payment of {paid} cents doesn't match the total of {total} cents")]
+    WrongAmount { paid: u64, total: u64 },
+    #[error("The tracking number must not be empty")]
+    EmptyTracking,
+}
+
+impl Order {
+    pub fn new(id: u32, total_cents: u64) -> Self {
+        Order {
+            id,
+            total_cents,
+            state: OrderState::Created,
+        }
+    }
+
+    // Each transition returns a new order and leaves `self` unchanged, so
+    // after an error the caller still has the order in its old state
+
+    pub fn pay(&self, amount_cents: u64, payment_id: &str) -> Result<Order, TransitionError> {
+        match &self.state {
+            OrderState::Created if amount_cents != self.total_cents => {
+                Err(TransitionError::WrongAmount {
+                    paid: amount_cents,
+                    total: self.total_cents,
+                })
+            }
+            OrderState::Created => Ok(self.with_state(OrderState::Paid {
+                payment_id: payment_id.to_string(),
+            })),
+            _ => Err(self.not_allowed("pay")),
+        }
+    }
+
+    pub fn ship(&self, tracking: &str) -> Result<Order, TransitionError> {
+        match &self.state {
+            OrderState::Paid { .. } if tracking.trim().is_empty() => {
+                Err(TransitionError::EmptyTracking)
+            }
+            OrderState::Paid { payment_id } => Ok(self.with_state(OrderState::Shipped {
+                payment_id: payment_id.clone(),
+                tracking: tracking.trim().to_string(),
+            })),
+            _ => Err(self.not_allowed("ship")),
+        }
+    }
+
+    pub fn deliver(&self) -> Result<Order, TransitionError> {
+        match &self.state {
+            OrderState::Shipped { tracking, .. } => Ok(self.with_state(OrderState::Delivered {
+                tracking: tracking.clone(),
+            })),
+            _ => Err(self.not_allowed("deliver")),
+        }
+    }
+
+    /// A paid order is refunded; a shipped order can't be cancelled anymore
+    pub fn cancel(&self, reason: &str) -> Result<Order, TransitionError> {
+        let refund = match &self.state {
+            OrderState::Created => None,
+            OrderState::Paid { payment_id } => Some(payment_id.clone()),
+            _ => return Err(self.not_allowed("cancel")),
+        };
+        Ok(self.with_state(OrderState::Cancelled {
+            reason: reason.to_string(),
+            refund,
+        }))
+    }
+
+    /// Delivered and cancelled orders never change again
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self.state,
+            OrderState::Delivered { .. } | OrderState::Cancelled { .. }
+        )
+    }
+
+    fn with_state(&self, state: OrderState) -> Order {
+        Order {
+            state,
+            ..self.clone()
+        }
+    }
+
+    fn not_allowed(&self, action: &'static str) -> TransitionError {
+        TransitionError::NotAllowed {
+            id: self.id,
+            action,
+            state: self.state.to_string(),
+        }
+    }
+}
+
+fn main() -> Result<(), TransitionError> {
+    let order = Order::new(1, 4_999);
+    println!("Order {}: {}", order.id, order.state);
+
+    // `?` stops at the first transition that isn't allowed
+    let order = order.pay(4_999, "pay_123")?;
+    println!("Order {}: {}", order.id, order.state);
+    let order = order.ship("1Z999AA10123456784")?;
+    println!("Order {}: {}", order.id, order.state);
+
+    // Invalid transitions are errors, and `order` is still shipped
+    if let Err(e) = order.cancel("Changed my mind") {
+        println!("Error: {}", e);
+    }
+    if let Err(e) = order.pay(4_999, "pay_456") {
+        println!("Error: {}", e);
+    }
+
+    let order = order.deliver()?;
+    println!("Order {}: {:?}, final: {}", order.id, order.state, order.is_final());
+
+    let other = Order::new(2, 1_500).pay(1_500, "pay_789")?.cancel("Out of stock")?;
+    println!("Order {}: {:?}", other.id, other.state);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follows_the_happy_path() {
+        let order = Order::new(1, 100)
+            .pay(100, "pay_1")
+            .and_then(|o| o.ship("TRACK-1"))
+            .and_then(|o| o.deliver())
+            .unwrap();
+        assert_eq!(
+            order.state,
+            OrderState::Delivered {
+                tracking: "TRACK-1".to_string()
+            }
+        );
+        assert!(order.is_final());
+    }
+
+    #[test]
+    fn rejects_transitions_that_skip_a_state() {
+        let order = Order::new(7, 100);
+        assert_eq!(
+            order.ship("TRACK-1"),
+            Err(TransitionError::NotAllowed {
+                id: 7,
+                action: "ship",
+                state: "created".to_string()
+            })
+        );
+        assert!(order.deliver().is_err());
+    }
+
+    #[test]
+    fn paid_orders_are_refunded_when_cancelled() {
+        let order = Order::new(1, 100).pay(100, "pay_1").unwrap();
+        let cancelled = order.cancel("Out of stock").unwrap();
+        assert_eq!(
+            cancelled.state,
+            OrderState::Cancelled {
+                reason: "Out of stock".to_string(),
+                refund: Some("pay_1".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn shipped_and_final_orders_cannot_be_cancelled() {
+        let shipped = Order::new(1, 100)
+            .pay(100, "pay_1")
+            .and_then(|o| o.ship("TRACK-1"))
+            .unwrap();
+        let err = shipped.cancel("Too late").unwrap_err();
+        assert_eq!(err.to_string(), "Cannot cancel order 1: it is shipped");
+
+        let cancelled = Order::new(2, 100).cancel("Duplicate").unwrap();
+        assert!(cancelled.cancel("Again").is_err());
+        assert!(cancelled.pay(100, "pay_2").is_err());
+    }
+
+    #[test]
+    fn validates_the_data_of_a_transition() {
+        let order = Order::new(1, 100);
+        assert_eq!(
+            order.pay(90, "pay_1"),
+            Err(TransitionError::WrongAmount {
+                paid: 90,
+                total: 100
+            })
+        );
+        let paid = order.pay(100, "pay_1").unwrap();
+        assert_eq!(paid.ship("  "), Err(TransitionError::EmptyTracking));
+        // The failed calls didn't change anything
+        assert_eq!(order.state, OrderState::Created);
+    }
+}
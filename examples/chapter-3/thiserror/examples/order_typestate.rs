@@ -0,0 +1,184 @@
+use thiserror::Error;
+
+// Each state is its own type, holding the data of that state
+#[derive(Debug)]
+pub struct Created;
+
+#[derive(Debug)]
+pub struct Paid {
+    payment_id: String,
+}
+
+#[derive(Debug)]
+pub struct Shipped {
+    tracking: String,
+}
+
+#[derive(Debug)]
+pub struct Delivered;
+
+#[derive(Debug)]
+pub struct Cancelled {
+    refund: Option<String>,
+}
+
+/// An order whose state is part of its type: `Order<Paid>` has a `ship`
+/// method, and `Order<Created>` doesn't. Calling a transition that isn't
+/// allowed is a compile error instead of a runtime error.
+#[derive(Debug)]
+pub struct Order<S> {
+    id: u32,
+    total_cents: u64,
+    state: S,
+}
+
+/// A failed transition gives the order back, because `ship` took it by
+/// value, and it would be lost otherwise
+#[derive(Debug, Error)]
+#[error("Invalid tracking number {tracking:?} for order {}", order.id)]
+pub struct InvalidTracking {
+    pub order: Order<Paid>,
+    pub tracking: String,
+}
+
+// Methods for orders in any state
+impl<S> Order<S> {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn total_cents(&self) -> u64 {
+        self.total_cents
+    }
+
+    // Moves the fields into an order of another state
+    fn into_state<T>(self, state: T) -> Order<T> {
+        Order {
+            id: self.id,
+            total_cents: self.total_cents,
+            state,
+        }
+    }
+}
+
+impl Order<Created> {
+    pub fn new(id: u32, total_cents: u64) -> Self {
+        Order {
+            id,
+            total_cents,
+            state: Created,
+        }
+    }
+
+    // `self` is moved: after `pay`, the `Order<Created>` can't be used again
+    pub fn pay(self, payment_id: &str) -> Order<Paid> {
+        self.into_state(Paid {
+            payment_id: payment_id.to_string(),
+        })
+    }
+
+    pub fn cancel(self) -> Order<Cancelled> {
+        self.into_state(Cancelled { refund: None })
+    }
+}
+
+impl Order<Paid> {
+    pub fn payment_id(&self) -> &str {
+        &self.state.payment_id
+    }
+
+    // Some checks can only happen at runtime, so transitions can still fail
+    pub fn ship(self, tracking: &str) -> Result<Order<Shipped>, InvalidTracking> {
+        let tracking = tracking.trim();
+        if tracking.is_empty() {
+            return Err(InvalidTracking {
+                order: self,
+                tracking: tracking.to_string(),
+            });
+        }
+        Ok(self.into_state(Shipped {
+            tracking: tracking.to_string(),
+        }))
+    }
+
+    pub fn cancel(self) -> Order<Cancelled> {
+        let refund = Some(self.state.payment_id.clone());
+        self.into_state(Cancelled { refund })
+    }
+}
+
+impl Order<Shipped> {
+    pub fn tracking(&self) -> &str {
+        &self.state.tracking
+    }
+
+    pub fn deliver(self) -> Order<Delivered> {
+        self.into_state(Delivered)
+    }
+}
+
+impl Order<Cancelled> {
+    /// The payment to refund, if the order was paid
+    pub fn refund(&self) -> Option<&str> {
+        self.state.refund.as_deref()
+    }
+}
+
+fn main() {
+    let order = Order::new(1, 4_999);
+    let order = order.pay("pay_123");
+    println!("Order {} paid with {}", order.id(), order.payment_id());
+
+    // These lines don't compile:
+    // order.deliver();         // no method `deliver` for `Order<Paid>`
+    // Order::new(2, 100).ship("1Z999");  // no method `ship` for `Order<Created>`
+
+    let order = match order.ship(" ") {
+        Ok(shipped) => shipped,
+        Err(e) => {
+            println!("Error: {}", e);
+            // The error gives the paid order back, so we can try again
+            e.order.ship("1Z999AA10123456784").unwrap()
+        }
+    };
+    println!("Order {} shipped: {}", order.id(), order.tracking());
+
+    let order = order.deliver();
+    println!("Order {} delivered: {:?}", order.id(), order);
+
+    let cancelled = Order::new(2, 1_500).pay("pay_789").cancel();
+    println!(
+        "Order {} cancelled, refund: {:?}",
+        cancelled.id(),
+        cancelled.refund()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follows_the_happy_path() {
+        let order = Order::new(1, 100).pay("pay_1").ship("TRACK-1").unwrap();
+        assert_eq!(order.tracking(), "TRACK-1");
+        let order = order.deliver();
+        assert_eq!((order.id(), order.total_cents()), (1, 100));
+    }
+
+    #[test]
+    fn only_paid_orders_are_refunded() {
+        assert_eq!(Order::new(1, 100).cancel().refund(), None);
+        assert_eq!(
+            Order::new(2, 100).pay("pay_2").cancel().refund(),
+            Some("pay_2")
+        );
+    }
+
+    #[test]
+    fn a_failed_transition_returns_the_order() {
+        let err = Order::new(3, 100).pay("pay_3").ship("").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid tracking number \"\" for order 3");
+        assert_eq!(err.order.payment_id(), "pay_3");
+    }
+}
@@ -0,0 +1,14 @@
+// A bug that compiles, runs, and usually prints the right answer. Only
+// Miri notices:
+//
+//     cargo +nightly miri run -p unsafe-basics --example use_after_free
+
+fn main() {
+    let numbers = vec![1, 2, 3];
+    let first: *const i32 = &numbers[0];
+    drop(numbers);
+
+    // SAFETY: none. `numbers` was freed, and `first` dangles.
+    let value = unsafe { *first };
+    println!("The first number was {}", value);
+}
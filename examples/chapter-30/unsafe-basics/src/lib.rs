@@ -0,0 +1,19 @@
+//! The building blocks of unsafe Rust, each wrapped in a safe API. Run the
+//! tests under Miri, which detects undefined behavior as it happens:
+//!
+//! ```text
+//! cargo +nightly miri test -p unsafe-basics
+//! ```
+
+// Every unsafe operation needs its own `unsafe` block, even inside an
+// `unsafe fn`, and every `unsafe` block needs a `SAFETY` comment
+#![deny(unsafe_op_in_unsafe_fn)]
+#![warn(clippy::undocumented_unsafe_blocks)]
+
+pub mod my_vec;
+pub mod raw_pointers;
+pub mod split;
+pub mod uninit;
+pub mod unsafe_fn;
+
+pub use my_vec::MyVec;
@@ -0,0 +1,192 @@
+//! A growable array like `Vec<T>`, with a safe API over raw memory.
+//!
+//! The invariants that every method relies on and keeps:
+//!
+//! - `ptr` points to an allocation for `cap` elements, or is dangling when
+//!   `cap` is 0.
+//! - The first `len` elements are initialized, the rest are not.
+//! - `len <= cap`.
+
+use std::alloc::{self, Layout};
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::ptr::{self, NonNull};
+
+pub struct MyVec<T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    len: usize,
+    // Tells the compiler that a `MyVec<T>` owns `T`s, for drop checking
+    // and for `Send` and `Sync`
+    _owns: PhantomData<T>,
+}
+
+impl<T> MyVec<T> {
+    pub fn new() -> Self {
+        assert!(
+            std::mem::size_of::<T>() != 0,
+            "zero-sized types are not supported"
+        );
+        MyVec {
+            ptr: NonNull::dangling(),
+            cap: 0,
+            len: 0,
+            _owns: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    pub fn push(&mut self, value: T) {
+        if self.len == self.cap {
+            self.grow();
+        }
+        // SAFETY: `len < cap` after growing, so the slot is inside the
+        // allocation, and it's uninitialized, so nothing is overwritten
+        unsafe { self.ptr.as_ptr().add(self.len).write(value) };
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: the element at the old `len - 1` is initialized. After
+        // decreasing `len`, it counts as uninitialized, so the value is
+        // moved out exactly once.
+        Some(unsafe { self.ptr.as_ptr().add(self.len).read() })
+    }
+
+    fn grow(&mut self) {
+        let new_cap = if self.cap == 0 { 4 } else { self.cap * 2 };
+        let new_layout = Layout::array::<T>(new_cap).expect("capacity overflow");
+        let new_ptr = if self.cap == 0 {
+            // SAFETY: the layout has a non-zero size, because `T` isn't
+            // zero-sized and `new_cap` is at least 4
+            unsafe { alloc::alloc(new_layout) }
+        } else {
+            let old_layout = Layout::array::<T>(self.cap).unwrap();
+            // SAFETY: `ptr` was allocated with `old_layout`, and the new
+            // size is non-zero. `realloc` moves the initialized elements.
+            unsafe { alloc::realloc(self.ptr.as_ptr().cast(), old_layout, new_layout.size()) }
+        };
+        self.ptr = match NonNull::new(new_ptr.cast()) {
+            Some(ptr) => ptr,
+            None => alloc::handle_alloc_error(new_layout),
+        };
+        self.cap = new_cap;
+    }
+}
+
+impl<T> Default for MyVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Indexing, iteration, `len`, `first`, `contains`, and every other slice
+// method come from `Deref` to a slice
+impl<T> Deref for MyVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // SAFETY: the first `len` elements are initialized, and `ptr` is
+        // non-null and aligned even when `len` is 0
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> DerefMut for MyVec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        // SAFETY: as in `deref`, and `&mut self` guarantees exclusive access
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> Drop for MyVec<T> {
+    fn drop(&mut self) {
+        // SAFETY: drops the `len` initialized elements in place, once
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr.as_ptr(), self.len));
+        }
+        if self.cap > 0 {
+            let layout = Layout::array::<T>(self.cap).unwrap();
+            // SAFETY: `ptr` was allocated with this layout
+            unsafe { alloc::dealloc(self.ptr.as_ptr().cast(), layout) };
+        }
+    }
+}
+
+// SAFETY: `MyVec<T>` owns its `T`s like a `Vec<T>`, so it can be sent to
+// or shared with another thread exactly when `T` can. The raw pointer
+// inside would otherwise make it neither `Send` nor `Sync`.
+unsafe impl<T: Send> Send for MyVec<T> {}
+// SAFETY: see `Send`
+unsafe impl<T: Sync> Sync for MyVec<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn pushes_grows_and_pops() {
+        let mut v = MyVec::new();
+        for i in 0..10 {
+            v.push(i);
+        }
+        assert_eq!(v.len(), 10);
+        assert_eq!(v.capacity(), 16);
+        assert_eq!(v[3], 3);
+        assert_eq!(v.iter().sum::<i32>(), 45);
+        assert_eq!(v.pop(), Some(9));
+        v[0] = 100;
+        assert_eq!(&v[..3], [100, 1, 2]);
+    }
+
+    #[test]
+    fn owns_heap_values() {
+        let mut v = MyVec::new();
+        v.push(String::from("a"));
+        v.push(String::from("b"));
+        v.push(String::from("c"));
+        assert_eq!(v.pop().as_deref(), Some("c"));
+        v.sort_by(|a, b| b.cmp(a));
+        assert_eq!(v.join(","), "b,a");
+        // The remaining strings are freed by `Drop`. Miri reports a leak
+        // if they aren't, and a double free if they are freed twice.
+    }
+
+    #[test]
+    fn drops_every_element_exactly_once() {
+        let counter = Rc::new(());
+        {
+            let mut v = MyVec::new();
+            for _ in 0..5 {
+                v.push(Rc::clone(&counter));
+            }
+            drop(v.pop());
+            assert_eq!(Rc::strong_count(&counter), 5);
+        }
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn empty_vectors_are_fine() {
+        let mut v: MyVec<u8> = MyVec::new();
+        assert!(v.is_empty());
+        assert_eq!(v.pop(), None);
+        assert_eq!(v.first(), None);
+    }
+}
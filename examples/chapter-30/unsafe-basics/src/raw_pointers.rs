@@ -0,0 +1,67 @@
+//! Raw pointers, `*const T` and `*mut T`, are references without the
+//! rules: they may be null, dangling, unaligned, or aliased. Creating one
+//! is safe; reading or writing through one is `unsafe`, because only the
+//! programmer knows whether it points to valid data.
+
+/// Swaps two values through raw pointers, like `std::ptr::swap`.
+pub fn swap<T>(a: &mut T, b: &mut T) {
+    let a: *mut T = a;
+    let b: *mut T = b;
+    // SAFETY: both pointers come from `&mut` references, so they are valid,
+    // aligned, and can't overlap: two `&mut` never point to the same value.
+    // `read` makes a bitwise copy of `a`; `write` overwrites without
+    // dropping, so no value is dropped twice or lost.
+    unsafe {
+        let tmp = a.read();
+        a.write(b.read());
+        b.write(tmp);
+    }
+}
+
+/// Sums a slice by walking a pointer from its first to its last element
+pub fn sum_by_pointer(values: &[i64]) -> i64 {
+    let mut sum = 0;
+    let mut ptr = values.as_ptr();
+    // One past the last element: a valid pointer to compare with, but
+    // never to read
+    // SAFETY: `add(len)` stays within the slice's allocation, or one past it
+    let end = unsafe { ptr.add(values.len()) };
+    while ptr != end {
+        // SAFETY: `ptr` is between the start and `end`, so it points to an
+        // element of the slice, which is borrowed for the whole loop
+        unsafe {
+            sum += *ptr;
+            ptr = ptr.add(1);
+        }
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swaps_values_that_own_heap_memory() {
+        let mut a = String::from("left");
+        let mut b = String::from("right");
+        swap(&mut a, &mut b);
+        assert_eq!((a.as_str(), b.as_str()), ("right", "left"));
+    }
+
+    #[test]
+    fn walks_a_slice() {
+        assert_eq!(sum_by_pointer(&[1, 2, 3, 4]), 10);
+        assert_eq!(sum_by_pointer(&[]), 0);
+    }
+
+    #[test]
+    fn creating_dangling_pointers_is_safe() {
+        let ptr: *const i32 = {
+            let x = 42;
+            &x
+        };
+        // Only dereferencing `ptr` would be undefined behavior
+        assert!(!ptr.is_null());
+    }
+}
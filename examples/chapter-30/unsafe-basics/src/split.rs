@@ -0,0 +1,62 @@
+//! Two mutable borrows of one slice. The borrow checker only sees that
+//! both halves come from `values`, not that they don't overlap:
+//!
+//! ```compile_fail,E0499
+//! let mut values = [1, 2, 3, 4];
+//! let left = &mut values[..2];
+//! // error: cannot borrow `values` as mutable more than once at a time
+//! let right = &mut values[2..];
+//! left[0] += right[0];
+//! ```
+//!
+//! `split_at_mut` in the standard library solves this with a few lines
+//! of unsafe code. This is the same function.
+
+/// Splits `values` into two mutable halves at `mid`
+pub fn split_at_mut<T>(values: &mut [T], mid: usize) -> (&mut [T], &mut [T]) {
+    let len = values.len();
+    // Checked here, in safe code: everything below relies on it
+    assert!(
+        mid <= len,
+        "mid {} is out of bounds for length {}",
+        mid,
+        len
+    );
+    let ptr = values.as_mut_ptr();
+    // SAFETY: `mid <= len`, so `[0, mid)` and `[mid, len)` are both inside
+    // the slice and don't overlap. The returned slices borrow `values`, so
+    // nothing else can use it while they are alive.
+    unsafe {
+        (
+            std::slice::from_raw_parts_mut(ptr, mid),
+            std::slice::from_raw_parts_mut(ptr.add(mid), len - mid),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn both_halves_can_be_changed_together() {
+        let mut values = [1, 2, 3, 4, 5];
+        let (left, right) = split_at_mut(&mut values, 2);
+        left[0] += right[0];
+        right[2] = 50;
+        assert_eq!(values, [4, 2, 3, 4, 50]);
+    }
+
+    #[test]
+    fn splits_at_the_edges() {
+        let mut values = [1, 2];
+        assert_eq!(split_at_mut(&mut values, 0).0.len(), 0);
+        assert_eq!(split_at_mut(&mut values, 2).1.len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn rejects_a_split_past_the_end() {
+        split_at_mut(&mut [1, 2], 3);
+    }
+}
@@ -0,0 +1,50 @@
+//! `MaybeUninit<T>` is memory for a `T` that may not hold a valid `T` yet.
+//! Creating a `String` or a reference from uninitialized memory is
+//! undefined behavior even if the value is never used, so such memory
+//! must be wrapped in `MaybeUninit` until it's written.
+
+use std::mem::MaybeUninit;
+
+/// Builds an array by calling `f` for each index, like `std::array::from_fn`.
+///
+/// If `f` panics halfway, the elements created so far are leaked, not
+/// dropped. That's safe: leaking memory is not undefined behavior.
+pub fn array_from_fn<T, const N: usize>(mut f: impl FnMut(usize) -> T) -> [T; N] {
+    let mut array: [MaybeUninit<T>; N] = [const { MaybeUninit::uninit() }; N];
+    for (index, slot) in array.iter_mut().enumerate() {
+        slot.write(f(index));
+    }
+    // SAFETY: the loop wrote every element, and `[MaybeUninit<T>; N]` has
+    // the same layout as `[T; N]`
+    unsafe { array.as_ptr().cast::<[T; N]>().read() }
+}
+
+/// Fills `buffer` with the numbers from `start`, as an API that writes
+/// into memory provided by the caller would. Returns the initialized part.
+pub fn fill_counting(buffer: &mut [MaybeUninit<u32>], start: u32) -> &mut [u32] {
+    for (offset, slot) in buffer.iter_mut().enumerate() {
+        slot.write(start + offset as u32);
+    }
+    // SAFETY: every element was written, and `MaybeUninit<u32>` has the
+    // same layout as `u32`
+    unsafe { &mut *(buffer as *mut [MaybeUninit<u32>] as *mut [u32]) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_arrays_of_heap_values() {
+        let names: [String; 3] = array_from_fn(|i| format!("item{}", i));
+        assert_eq!(names, ["item0", "item1", "item2"]);
+    }
+
+    #[test]
+    fn fills_a_buffer() {
+        let mut buffer = [MaybeUninit::uninit(); 4];
+        let filled = fill_counting(&mut buffer, 10);
+        filled[0] = 0;
+        assert_eq!(filled, [0, 11, 12, 13]);
+    }
+}
@@ -0,0 +1,46 @@
+//! An `unsafe fn` has a precondition that the compiler can't check. The
+//! caller promises to meet it by calling the function in an `unsafe` block;
+//! the `# Safety` section of the documentation says what the promise is.
+
+/// Returns the element at `index` without checking the bounds.
+///
+/// # Safety
+///
+/// `index` must be less than `values.len()`.
+pub unsafe fn get_unchecked(values: &[u32], index: usize) -> u32 {
+    // SAFETY: the caller guarantees that `index` is in bounds
+    unsafe { *values.as_ptr().add(index) }
+}
+
+/// Sums every `step`-th element. The loop condition is the bounds check,
+/// so each access can skip its own.
+pub fn sum_every(values: &[u32], step: usize) -> u32 {
+    assert!(step > 0, "step must be positive");
+    let mut sum = 0;
+    let mut index = 0;
+    while index < values.len() {
+        // SAFETY: `index < values.len()` was checked on the line above
+        sum += unsafe { get_unchecked(values, index) };
+        index += step;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sums_every_nth_element() {
+        let values: Vec<u32> = (1..=10).collect();
+        assert_eq!(sum_every(&values, 1), 55);
+        assert_eq!(sum_every(&values, 3), 1 + 4 + 7 + 10);
+        assert_eq!(sum_every(&[], 2), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be positive")]
+    fn rejects_a_zero_step() {
+        sum_every(&[1, 2], 0);
+    }
+}
@@ -0,0 +1,129 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Event {
+    Click { x: i32, y: i32 },
+    Key(char),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct CallbackId(u32);
+
+// Every closure has its own, unnamed type, so a collection of closures
+// stores them as trait objects: `Box<dyn FnMut(&Event)>`. `FnMut`
+// allows the callbacks to keep state between calls.
+type Callback = Box<dyn FnMut(&Event)>;
+
+#[derive(Default)]
+struct Registry {
+    callbacks: BTreeMap<CallbackId, Callback>,
+    next_id: u32,
+}
+
+impl Registry {
+    // `'static` because the registry keeps the closure after `on` returns,
+    // so it can't borrow local variables of the caller
+    fn on(&mut self, callback: impl FnMut(&Event) + 'static) -> CallbackId {
+        let id = CallbackId(self.next_id);
+        self.next_id += 1;
+        self.callbacks.insert(id, Box::new(callback));
+        id
+    }
+
+    fn remove(&mut self, id: CallbackId) -> bool {
+        self.callbacks.remove(&id).is_some()
+    }
+
+    // Calls every callback, in the order they were registered
+    fn emit(&mut self, event: &Event) {
+        for callback in self.callbacks.values_mut() {
+            callback(event);
+        }
+    }
+}
+
+fn main() {
+    let mut registry = Registry::default();
+
+    // A callback that only prints
+    registry.on(|event| println!("Logged: {:?}", event));
+
+    // A callback with its own state, moved into the closure
+    let mut clicks = 0;
+    registry.on(move |event| {
+        if let Event::Click { .. } = event {
+            clicks += 1;
+            println!("Click number {}", clicks);
+        }
+    });
+
+    // A callback whose state is shared with the caller through `Rc<RefCell>`
+    let typed = Rc::new(RefCell::new(String::new()));
+    let typed_in_callback = Rc::clone(&typed);
+    let keys = registry.on(move |event| {
+        if let Event::Key(c) = event {
+            typed_in_callback.borrow_mut().push(*c);
+        }
+    });
+
+    registry.emit(&Event::Click { x: 10, y: 20 });
+    registry.emit(&Event::Key('h'));
+    registry.emit(&Event::Key('i'));
+    registry.remove(keys);
+    registry.emit(&Event::Key('!'));
+    registry.emit(&Event::Click { x: 0, y: 0 });
+
+    println!("Typed: {}", typed.borrow());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn callbacks_run_in_registration_order() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut registry = Registry::default();
+        for name in ["first", "second"] {
+            let log = Rc::clone(&log);
+            registry.on(move |_| log.borrow_mut().push(name));
+        }
+        registry.emit(&Event::Key('a'));
+        assert_eq!(*log.borrow(), ["first", "second"]);
+    }
+
+    #[test]
+    fn removed_callbacks_are_not_called() {
+        let count = Rc::new(RefCell::new(0));
+        let mut registry = Registry::default();
+        let counter = Rc::clone(&count);
+        let id = registry.on(move |_| *counter.borrow_mut() += 1);
+
+        registry.emit(&Event::Key('a'));
+        assert!(registry.remove(id));
+        assert!(!registry.remove(id));
+        registry.emit(&Event::Key('b'));
+        assert_eq!(*count.borrow(), 1);
+        // The closure, and its clone of the `Rc`, were dropped
+        assert_eq!(Rc::strong_count(&count), 1);
+    }
+
+    #[test]
+    fn callbacks_keep_their_own_state() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let mut registry = Registry::default();
+        let seen_in_callback = Rc::clone(&seen);
+        let mut total_x = 0;
+        registry.on(move |event| {
+            if let Event::Click { x, .. } = event {
+                total_x += x;
+                seen_in_callback.borrow_mut().push(total_x);
+            }
+        });
+        registry.emit(&Event::Click { x: 5, y: 0 });
+        registry.emit(&Event::Click { x: 7, y: 0 });
+        assert_eq!(*seen.borrow(), [5, 12]);
+    }
+}
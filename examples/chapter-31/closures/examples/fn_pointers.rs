@@ -0,0 +1,166 @@
+use std::ffi::{c_int, c_void};
+
+// A function pointer, `fn(i32) -> i32`, is a plain address of a function.
+// Unlike a closure, it can't capture anything, but it has a name for its
+// type, is `Copy`, and has the same size on every call.
+fn double(x: i32) -> i32 {
+    x * 2
+}
+
+fn square(x: i32) -> i32 {
+    x * x
+}
+
+type Operation = fn(i32) -> i32;
+
+// A table of operations, looked up by name
+const OPERATIONS: [(&str, Operation); 3] = [
+    ("double", double),
+    ("square", square),
+    // A closure that captures nothing converts to a function pointer
+    ("negate", |x| -x),
+];
+
+fn apply(name: &str, x: i32) -> Option<i32> {
+    let (_, operation) = OPERATIONS.iter().find(|(n, _)| *n == name)?;
+    Some(operation(x))
+}
+
+// `qsort` from the C standard library. C functions take callbacks as
+// `extern "C"` function pointers, which use the C calling convention.
+extern "C" {
+    fn qsort(
+        base: *mut c_void,
+        count: usize,
+        size: usize,
+        compare: unsafe extern "C" fn(*const c_void, *const c_void) -> c_int,
+    );
+}
+
+/// # Safety
+///
+/// `a` and `b` must point to valid `i32`s.
+unsafe extern "C" fn compare_i32(a: *const c_void, b: *const c_void) -> c_int {
+    // SAFETY: guaranteed by the caller; `qsort` passes pointers into the
+    // `[i32]` from `sort_with_qsort`
+    let (a, b) = unsafe { (*(a as *const i32), *(b as *const i32)) };
+    a.cmp(&b) as c_int
+}
+
+fn sort_with_qsort(values: &mut [i32]) {
+    // SAFETY: the pointer, count, and element size describe `values`, and
+    // `compare_i32` compares `i32`s
+    unsafe {
+        qsort(
+            values.as_mut_ptr().cast(),
+            values.len(),
+            std::mem::size_of::<i32>(),
+            compare_i32,
+        )
+    };
+}
+
+// Many C APIs take a callback together with a `void *user_data` pointer,
+// which they pass back to the callback unchanged. This function stands in
+// for such an API: it calls `callback` for every value.
+///
+/// # Safety
+///
+/// `values` must point to `count` valid `i32`s, and `user_data` must be
+/// what `callback` expects.
+unsafe extern "C" fn c_for_each(
+    values: *const i32,
+    count: usize,
+    callback: unsafe extern "C" fn(i32, *mut c_void),
+    user_data: *mut c_void,
+) {
+    for i in 0..count {
+        // SAFETY: guaranteed by the caller
+        unsafe { callback(*values.add(i), user_data) };
+    }
+}
+
+// The user data is how a closure gets through a C API: pass a pointer to
+// the closure as `user_data`, and a non-capturing "trampoline" function,
+// generic over the closure type, as the callback. The trampoline turns the
+// pointer back into the closure and calls it.
+fn for_each_with_closure<F: FnMut(i32)>(values: &[i32], mut f: F) {
+    /// # Safety
+    ///
+    /// `user_data` must point to a live `F` that nothing else uses
+    /// during the call.
+    unsafe extern "C" fn trampoline<F: FnMut(i32)>(value: i32, user_data: *mut c_void) {
+        // SAFETY: guaranteed by the caller
+        let f = unsafe { &mut *(user_data as *mut F) };
+        f(value);
+    }
+
+    // SAFETY: the pointer and length describe `values`, and `user_data` is
+    // the `&mut F` that `trampoline::<F>` expects. It's alive until
+    // `c_for_each` returns.
+    unsafe {
+        c_for_each(
+            values.as_ptr(),
+            values.len(),
+            trampoline::<F>,
+            &mut f as *mut F as *mut c_void,
+        )
+    };
+}
+
+fn main() {
+    for name in ["double", "square", "negate", "cube"] {
+        println!("{}(7) = {:?}", name, apply(name, 7));
+    }
+
+    let mut values = [42, -3, 17, 0, 8];
+    sort_with_qsort(&mut values);
+    println!("Sorted by qsort: {:?}", values);
+
+    let mut sum = 0;
+    let mut seen = Vec::new();
+    for_each_with_closure(&values, |v| {
+        sum += v;
+        seen.push(v);
+    });
+    println!("The C-style API saw {:?}, sum {}", seen, sum);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn function_pointers_are_plain_values() {
+        let f: fn(i32) -> i32 = double;
+        let g = f;
+        assert_eq!(f(3) + g(4), 14);
+        assert_eq!(
+            std::mem::size_of::<fn(i32) -> i32>(),
+            std::mem::size_of::<usize>()
+        );
+        assert_eq!(apply("square", -4), Some(16));
+        assert_eq!(apply("unknown", 1), None);
+    }
+
+    #[test]
+    fn function_pointers_work_with_iterators() {
+        let doubled: Vec<i32> = [1, 2, 3].into_iter().map(double).collect();
+        assert_eq!(doubled, [2, 4, 6]);
+    }
+
+    #[test]
+    fn qsort_sorts_through_a_callback() {
+        let mut values = [5, i32::MIN, 3, i32::MAX, -1];
+        sort_with_qsort(&mut values);
+        assert_eq!(values, [i32::MIN, -1, 3, 5, i32::MAX]);
+        sort_with_qsort(&mut []);
+    }
+
+    #[test]
+    fn closures_pass_through_user_data() {
+        let mut product = 1;
+        for_each_with_closure(&[2, 3, 7], |v| product *= v);
+        assert_eq!(product, 42);
+    }
+}
@@ -0,0 +1,99 @@
+// A closure implements one, two, or all three of the `Fn` traits,
+// depending on what it does with the variables it captures:
+//
+// - `Fn`: only reads them. It can be called any number of times, even
+//   from several places at once.
+// - `FnMut`: changes them. It can be called many times, but only through
+//   a mutable reference, one call at a time.
+// - `FnOnce`: moves them out. It can be called only once.
+//
+// Every `Fn` is also `FnMut`, and every `FnMut` is also `FnOnce`, so a
+// function that asks for `FnOnce` accepts every closure.
+
+// Calls `f` for every value. `Fn` is enough, because `f` doesn't change
+// anything.
+fn count_matching(values: &[i32], f: impl Fn(i32) -> bool) -> usize {
+    values.iter().filter(|&&v| f(v)).count()
+}
+
+// Calls `f` for every value, and lets it change its captured state
+fn for_each_value(values: &[i32], mut f: impl FnMut(i32)) {
+    for &v in values {
+        f(v);
+    }
+}
+
+// Calls `f` at most once, so `f` may give away what it captured
+fn run_once<T>(f: impl FnOnce() -> T) -> T {
+    f()
+}
+
+fn main() {
+    let values = [3, 8, 1, 9, 4];
+
+    // `Fn`: reads `limit`
+    let limit = 4;
+    let above_limit = |v: i32| v > limit;
+    println!("Above {}: {}", limit, count_matching(&values, above_limit));
+    // Still usable: it only borrowed `limit`, and `above_limit` is `Copy`
+    println!("Is 5 above? {}", above_limit(5));
+
+    // `FnMut`: changes `total` and `largest`
+    let mut total = 0;
+    let mut largest = i32::MIN;
+    for_each_value(&values, |v| {
+        total += v;
+        largest = largest.max(v);
+    });
+    println!("Total: {}, largest: {}", total, largest);
+
+    // `FnOnce`: moves `report` out of the closure
+    let report = format!("{} values", values.len());
+    let take_report = move || report;
+    println!("Report: {}", run_once(take_report));
+
+    // `move` changes how variables are captured, not which trait the
+    // closure implements: this closure owns `name`, but only reads it
+    let name = String::from("Ferris");
+    let greet = move || format!("Hello, {}!", name);
+    println!("{} {}", greet(), greet());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fn_closures_can_be_called_many_times() {
+        let even = |v: i32| v % 2 == 0;
+        assert_eq!(count_matching(&[1, 2, 4], even), 2);
+        assert_eq!(count_matching(&[6], even), 1);
+    }
+
+    #[test]
+    fn fn_mut_closures_change_their_captures() {
+        let mut seen = Vec::new();
+        for_each_value(&[1, 2, 3], |v| seen.push(v * 10));
+        assert_eq!(seen, [10, 20, 30]);
+    }
+
+    #[test]
+    fn fn_once_accepts_every_kind_of_closure() {
+        let owned = vec![1, 2, 3];
+        assert_eq!(run_once(move || owned), [1, 2, 3]);
+
+        let mut calls = 0;
+        run_once(|| calls += 1);
+        assert_eq!(calls, 1);
+
+        assert_eq!(run_once(|| "read only"), "read only");
+    }
+
+    #[test]
+    fn functions_work_where_closures_do() {
+        fn is_positive(v: i32) -> bool {
+            v > 0
+        }
+        assert_eq!(count_matching(&[-1, 0, 5], is_positive), 1);
+    }
+}
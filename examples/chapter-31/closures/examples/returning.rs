@@ -0,0 +1,100 @@
+// Closures can be returned like any other value. Because each closure has
+// its own unnamed type, the return type is written as `impl Fn...`: "some
+// type that implements this trait".
+
+// The closure captures `n`. `move` is required, because `n` is a local
+// variable of `make_adder` and would be gone when the closure runs.
+fn make_adder(n: i32) -> impl Fn(i32) -> i32 {
+    move |x| x + n
+}
+
+// A closure that changes its captured state is `FnMut`, and so must be
+// the caller's variable: `let mut next = make_counter();`
+fn make_counter() -> impl FnMut() -> u32 {
+    let mut count = 0;
+    move || {
+        count += 1;
+        count
+    }
+}
+
+// Combines two functions into one. The result captures both.
+fn compose<A, B, C>(f: impl Fn(A) -> B, g: impl Fn(B) -> C) -> impl Fn(A) -> C {
+    move |x| g(f(x))
+}
+
+// `impl Fn` stands for a single type, so two branches that return different
+// closures need a trait object instead
+fn make_rounding(mode: &str) -> Box<dyn Fn(f64) -> f64> {
+    match mode {
+        "up" => Box::new(f64::ceil),
+        "down" => Box::new(f64::floor),
+        _ => {
+            let step = mode.parse().unwrap_or(1.0);
+            Box::new(move |x: f64| (x / step).round() * step)
+        }
+    }
+}
+
+fn main() {
+    let add_five = make_adder(5);
+    println!("2 + 5 = {}", add_five(2));
+
+    let mut next_ticket = make_counter();
+    let mut next_order = make_counter();
+    println!(
+        "Tickets: {}, {}, {}; orders: {}",
+        next_ticket(),
+        next_ticket(),
+        next_ticket(),
+        next_order()
+    );
+
+    let shout = compose(|s: &str| s.trim().to_uppercase(), |s| s + "!");
+    println!("{}", shout("  hello "));
+
+    for mode in ["up", "down", "0.25"] {
+        let round = make_rounding(mode);
+        println!("Rounding 2.6 {}: {}", mode, round(2.6));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adders_capture_their_argument() {
+        let add_one = make_adder(1);
+        let add_ten = make_adder(10);
+        assert_eq!((add_one(1), add_ten(1)), (2, 11));
+        let results: Vec<i32> = [1, 2].into_iter().map(make_adder(100)).collect();
+        assert_eq!(results, [101, 102]);
+    }
+
+    #[test]
+    fn counters_are_independent() {
+        let mut a = make_counter();
+        let mut b = make_counter();
+        assert_eq!((a(), a(), b()), (1, 2, 1));
+    }
+
+    #[test]
+    fn compose_chains_functions() {
+        let len_plus_one = compose(str::len, make_adder_usize(1));
+        assert_eq!(len_plus_one("abc"), 4);
+
+        fn make_adder_usize(n: usize) -> impl Fn(usize) -> usize {
+            move |x| x + n
+        }
+    }
+
+    #[test]
+    fn boxed_closures_are_chosen_at_runtime() {
+        assert_eq!(make_rounding("up")(1.1), 2.0);
+        assert_eq!(make_rounding("down")(1.9), 1.0);
+        assert_eq!(make_rounding("0.5")(1.3), 1.5);
+        let roundings: Vec<_> = ["up", "down"].into_iter().map(make_rounding).collect();
+        assert_eq!(roundings[1](-0.5), -1.0);
+    }
+}
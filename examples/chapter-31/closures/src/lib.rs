@@ -0,0 +1,101 @@
+//! The examples in `examples/` show closures that work. The snippets below
+//! show the most common capture mistakes; each one is a `compile_fail`
+//! test, so `cargo test` fails if it ever starts to compile.
+//!
+//! # A closure that outlives the variables it borrows
+//!
+//! A closure borrows what it uses, but a new thread may run longer than
+//! the function that started it:
+//!
+//! ```compile_fail,E0373
+//! let name = String::from("worker");
+//! // error: closure may outlive the current function, but it borrows `name`
+//! let handle = std::thread::spawn(|| println!("Hello from {}", name));
+//! handle.join().unwrap();
+//! ```
+//!
+//! The fix is `move`, which makes the closure own `name`:
+//!
+//! ```
+//! let name = String::from("worker");
+//! let handle = std::thread::spawn(move || println!("Hello from {}", name));
+//! handle.join().unwrap();
+//! ```
+//!
+//! # Using a variable after moving it into a closure
+//!
+//! After `move`, the variable belongs to the closure:
+//!
+//! ```compile_fail,E0382
+//! let names = vec![String::from("a"), String::from("b")];
+//! let count = move || names.len();
+//! // error: borrow of moved value: `names`
+//! println!("{} names: {:?}", count(), names);
+//! ```
+//!
+//! Clone it first when both need it, or, as here, don't use `move` when a
+//! borrow is enough.
+//!
+//! # Calling an `FnMut` closure without `mut`
+//!
+//! Calling a closure that changes its captures changes the closure itself:
+//!
+//! ```compile_fail,E0596
+//! let mut count = 0;
+//! let increment = || count += 1;
+//! // error: cannot borrow `increment` as mutable
+//! increment();
+//! ```
+//!
+//! The variable that holds it must be `mut`: `let mut increment = ...`.
+//!
+//! # Reading a variable while a closure borrows it mutably
+//!
+//! A closure that changes a variable holds a mutable borrow of it for as
+//! long as the closure is alive:
+//!
+//! ```compile_fail,E0502
+//! let mut log = Vec::new();
+//! let mut record = |line: &str| log.push(line.to_string());
+//! record("start");
+//! // error: cannot borrow `log` as immutable because it is also borrowed as mutable
+//! println!("{} lines", log.len());
+//! record("end");
+//! ```
+//!
+//! Reading `log` is fine once the closure is no longer used.
+//!
+//! # Passing an `FnOnce` closure where `Fn` is needed
+//!
+//! A closure that moves a captured value out can only run once, so it
+//! doesn't fit an API that may call it many times:
+//!
+//! ```compile_fail,E0525
+//! fn call_twice(f: impl Fn() -> String) -> String {
+//!     f() + &f()
+//! }
+//!
+//! let greeting = String::from("hi");
+//! // error: expected a closure that implements the `Fn` trait, but this
+//! // closure only implements `FnOnce`
+//! call_twice(move || greeting);
+//! ```
+//!
+//! Returning `greeting.clone()` instead makes the closure `Fn`.
+//!
+//! # Storing a borrowing closure in a `'static` registry
+//!
+//! A `Box<dyn Fn()>` in a struct defaults to `Box<dyn Fn() + 'static>`, so
+//! it can't hold a closure that borrows a local variable:
+//!
+//! ```compile_fail,E0597
+//! let mut callbacks: Vec<Box<dyn Fn()>> = Vec::new();
+//! {
+//!     let message = String::from("hello");
+//!     // error: `message` does not live long enough
+//!     callbacks.push(Box::new(|| println!("{}", message)));
+//! }
+//! callbacks[0]();
+//! ```
+//!
+//! `move` fixes this one too: the closure takes `message` with it.
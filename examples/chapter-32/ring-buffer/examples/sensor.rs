@@ -0,0 +1,43 @@
+// Keeps the last readings of a temperature sensor and prints their average,
+// the way a microcontroller's main loop would. Here, the readings come from
+// a list instead of an analog-to-digital converter.
+use std::io::Write;
+
+use ring_buffer::RingBuffer;
+
+// Readings in tenths of a degree, with two glitches
+const READINGS: [i16; 10] = [215, 216, 218, 217, 950, 219, 220, -400, 221, 223];
+
+fn average(window: &RingBuffer<i16, 4>) -> Option<i32> {
+    let sum: i32 = window.iter().map(|&r| i32::from(r)).sum();
+    (!window.is_empty()).then(|| sum / window.len() as i32)
+}
+
+fn main() {
+    let mut window: RingBuffer<i16, 4> = RingBuffer::new();
+    // A small log that doesn't allocate, written with `write!`
+    let mut log: RingBuffer<u8, 32> = RingBuffer::new();
+
+    for (tick, &reading) in READINGS.iter().enumerate() {
+        // Readings far from the recent average are glitches, not weather
+        if let Some(avg) = average(&window) {
+            if (i32::from(reading) - avg).abs() > 100 {
+                println!("tick {tick}: skipping {reading}");
+                // A full log keeps the oldest messages; `write!` reports it
+                if write!(log, "glitch {reading} at tick {tick}; ").is_err() {
+                    println!("tick {tick}: log is full");
+                }
+                continue;
+            }
+        }
+
+        let dropped = window.push_overwrite(reading);
+        println!(
+            "tick {tick}: {window:?} average {}, dropped {dropped:?}",
+            average(&window).unwrap()
+        );
+    }
+
+    let text: Vec<u8> = log.iter().copied().collect();
+    println!("log: {}", String::from_utf8_lossy(&text));
+}
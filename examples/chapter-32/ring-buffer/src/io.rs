@@ -0,0 +1,22 @@
+use std::io;
+
+use crate::{Ring, Storage};
+
+/// Writes as many bytes as fit. When none fit, `write_all` and `write!`
+/// return an error of kind `WriteZero`.
+impl<S: Storage<Item = u8>> io::Write for Ring<S> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut written = 0;
+        for &byte in buf {
+            if self.push(byte).is_err() {
+                break;
+            }
+            written += 1;
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+//! A fixed-capacity ring buffer that works without an operating system.
+//!
+//! The crate only uses `core`, so it builds for bare-metal targets such as
+//! `thumbv7em-none-eabihf`. Two features add more when the target has them:
+//!
+//! - `alloc`: [`HeapRingBuffer`], with a capacity chosen at runtime
+//! - `std` (on by default): `std::error::Error` for [`Full`], and
+//!   `std::io::Write` for byte buffers
+
+#![no_std]
+
+// `no_std` removes `std` from the crate, and these bring back the parts the
+// enabled features need
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+mod io;
+mod ring;
+mod storage;
+
+pub use ring::{Full, Ring};
+pub use storage::Storage;
+
+/// A ring buffer of `N` values, stored inline. Needs no allocator, so it can
+/// live in a `static` or on the stack of a microcontroller.
+pub type RingBuffer<T, const N: usize> = Ring<[Option<T>; N]>;
+
+/// A ring buffer whose capacity is chosen when it's created.
+#[cfg(feature = "alloc")]
+pub type HeapRingBuffer<T> = Ring<alloc::boxed::Box<[Option<T>]>>;
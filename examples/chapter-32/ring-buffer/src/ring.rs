@@ -0,0 +1,148 @@
+use core::fmt;
+
+use crate::Storage;
+
+/// A first-in, first-out queue with a fixed capacity.
+///
+/// Use it through [`RingBuffer`](crate::RingBuffer) or
+/// [`HeapRingBuffer`](crate::HeapRingBuffer).
+pub struct Ring<S> {
+    slots: S,
+    // Index of the oldest value
+    head: usize,
+    len: usize,
+}
+
+/// The buffer was full. Holds the value that didn't fit, so the caller
+/// doesn't lose it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Full<T>(pub T);
+
+impl<T> fmt::Display for Full<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ring buffer is full")
+    }
+}
+
+// Only where `std` exists; the trait is in `core` since Rust 1.81, but many
+// embedded projects stay on older compilers
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for Full<T> {}
+
+impl<T, const N: usize> Ring<[Option<T>; N]> {
+    /// Creates an empty buffer. It's a `const fn`, so the buffer can be a
+    /// `static`.
+    pub const fn new() -> Self {
+        Ring {
+            slots: [const { None }; N],
+            head: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<T, const N: usize> Default for Ring<[Option<T>; N]> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Ring<alloc::boxed::Box<[Option<T>]>> {
+    /// Creates an empty buffer that holds up to `capacity` values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Ring {
+            slots: (0..capacity).map(|_| None).collect(),
+            head: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<S: Storage> Ring<S> {
+    pub fn capacity(&self) -> usize {
+        self.slots.slots().len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Adds a value at the back, or gives it back if the buffer is full.
+    pub fn push(&mut self, value: S::Item) -> Result<(), Full<S::Item>> {
+        if self.is_full() {
+            return Err(Full(value));
+        }
+        let tail = (self.head + self.len) % self.capacity();
+        self.slots.slots_mut()[tail] = Some(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Adds a value at the back, dropping the oldest one if the buffer is
+    /// full. Returns the dropped value. Useful for keeping the latest
+    /// readings of a sensor.
+    pub fn push_overwrite(&mut self, value: S::Item) -> Option<S::Item> {
+        if self.capacity() == 0 {
+            return Some(value);
+        }
+        let oldest = if self.is_full() { self.pop() } else { None };
+        // There is room now, so this can't fail
+        let _ = self.push(value);
+        oldest
+    }
+
+    /// Removes and returns the oldest value.
+    pub fn pop(&mut self) -> Option<S::Item> {
+        if self.is_empty() {
+            return None;
+        }
+        let value = self.slots.slots_mut()[self.head].take();
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+        value
+    }
+
+    /// Returns the oldest value without removing it.
+    pub fn peek(&self) -> Option<&S::Item> {
+        self.slots.slots().get(self.head)?.as_ref()
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
+
+    /// Iterates from the oldest value to the newest.
+    pub fn iter(&self) -> impl Iterator<Item = &S::Item> {
+        // The values start at `head` and may wrap around to the beginning;
+        // the free slots between them are `None`
+        let (wrapped, from_head) = self.slots.slots().split_at(self.head);
+        from_head.iter().chain(wrapped).filter_map(Option::as_ref)
+    }
+
+    /// Copies the values, from the oldest to the newest, into a `Vec`.
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> alloc::vec::Vec<S::Item>
+    where
+        S::Item: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<S: Storage> fmt::Debug for Ring<S>
+where
+    S::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,35 @@
+/// Where a [`Ring`](crate::Ring) keeps its values.
+///
+/// Each slot is `None` when it's free, so the buffer needs no `unsafe` code
+/// and no `Default` values for `T`.
+pub trait Storage {
+    type Item;
+
+    fn slots(&self) -> &[Option<Self::Item>];
+    fn slots_mut(&mut self) -> &mut [Option<Self::Item>];
+}
+
+impl<T, const N: usize> Storage for [Option<T>; N] {
+    type Item = T;
+
+    fn slots(&self) -> &[Option<T>] {
+        self
+    }
+
+    fn slots_mut(&mut self) -> &mut [Option<T>] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Storage for alloc::boxed::Box<[Option<T>]> {
+    type Item = T;
+
+    fn slots(&self) -> &[Option<T>] {
+        self
+    }
+
+    fn slots_mut(&mut self) -> &mut [Option<T>] {
+        self
+    }
+}
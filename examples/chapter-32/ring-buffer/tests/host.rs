@@ -0,0 +1,165 @@
+//! Host-side tests. The library is `no_std`, but tests run on the computer
+//! that builds them, so they can use `std`, including `VecDeque` as a model
+//! to compare against.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use ring_buffer::{Full, RingBuffer};
+
+/// A small pseudo-random generator (xorshift), so that the tests need no
+/// dependencies and fail the same way every time.
+struct Rng(u32);
+
+impl Rng {
+    fn next(&mut self) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0
+    }
+}
+
+#[test]
+fn values_come_out_in_the_order_they_went_in() {
+    let mut buffer: RingBuffer<u32, 3> = RingBuffer::new();
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.push(1), Ok(()));
+    assert_eq!(buffer.push(2), Ok(()));
+    assert_eq!(buffer.push(3), Ok(()));
+    assert!(buffer.is_full());
+    assert_eq!(buffer.push(4), Err(Full(4)));
+
+    assert_eq!(buffer.peek(), Some(&1));
+    assert_eq!(buffer.pop(), Some(1));
+    assert_eq!(buffer.push(4), Ok(()));
+    assert_eq!(format!("{buffer:?}"), "[2, 3, 4]");
+    assert_eq!(buffer.pop(), Some(2));
+    assert_eq!(buffer.pop(), Some(3));
+    assert_eq!(buffer.pop(), Some(4));
+    assert_eq!(buffer.pop(), None);
+}
+
+#[test]
+fn push_overwrite_keeps_the_newest_values() {
+    let mut buffer: RingBuffer<u32, 3> = RingBuffer::new();
+    for value in 1..=3 {
+        assert_eq!(buffer.push_overwrite(value), None);
+    }
+    assert_eq!(buffer.push_overwrite(4), Some(1));
+    assert_eq!(buffer.push_overwrite(5), Some(2));
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [3, 4, 5]);
+}
+
+#[test]
+fn a_zero_capacity_buffer_accepts_nothing() {
+    let mut buffer: RingBuffer<u32, 0> = RingBuffer::new();
+    assert!(buffer.is_full());
+    assert_eq!(buffer.push(1), Err(Full(1)));
+    assert_eq!(buffer.push_overwrite(1), Some(1));
+    assert_eq!(buffer.peek(), None);
+    assert_eq!(buffer.pop(), None);
+}
+
+#[test]
+fn values_are_dropped_with_the_buffer() {
+    use std::rc::Rc;
+
+    let value = Rc::new(());
+    {
+        let mut buffer: RingBuffer<Rc<()>, 4> = RingBuffer::new();
+        for _ in 0..6 {
+            buffer.push_overwrite(value.clone());
+        }
+        assert_eq!(Rc::strong_count(&value), 5);
+        buffer.pop();
+        assert_eq!(Rc::strong_count(&value), 4);
+    }
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn behaves_like_a_vec_deque() {
+    let mut rng = Rng(2024);
+    let mut buffer: RingBuffer<u32, 7> = RingBuffer::new();
+    let mut model = VecDeque::new();
+
+    for step in 0..10_000 {
+        let value = rng.next();
+        match value % 4 {
+            0 | 1 => {
+                let expected = if model.len() < 7 {
+                    model.push_back(value);
+                    Ok(())
+                } else {
+                    Err(Full(value))
+                };
+                assert_eq!(buffer.push(value), expected, "step {step}");
+            }
+            2 => {
+                let expected = if model.len() == 7 { model.pop_front() } else { None };
+                model.push_back(value);
+                assert_eq!(buffer.push_overwrite(value), expected, "step {step}");
+            }
+            _ => assert_eq!(buffer.pop(), model.pop_front(), "step {step}"),
+        }
+        assert_eq!(buffer.len(), model.len());
+        assert_eq!(buffer.peek(), model.front());
+        assert!(buffer.iter().eq(model.iter()), "step {step}");
+    }
+}
+
+// `new` is a `const fn`, so a buffer can be a `static`, shared between an
+// interrupt handler and the main loop. On a microcontroller, the `Mutex`
+// would come from the `critical-section` crate.
+static EVENTS: Mutex<RingBuffer<&str, 4>> = Mutex::new(RingBuffer::new());
+
+#[test]
+fn works_as_a_static() {
+    EVENTS.lock().unwrap().push("button pressed").unwrap();
+    EVENTS.lock().unwrap().push("button released").unwrap();
+    let mut events = EVENTS.lock().unwrap();
+    assert_eq!(events.pop(), Some("button pressed"));
+    assert_eq!(events.pop(), Some("button released"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn heap_buffers_choose_their_capacity_at_runtime() {
+    use ring_buffer::HeapRingBuffer;
+
+    let capacity = "5".parse().unwrap();
+    let mut buffer: HeapRingBuffer<char> = HeapRingBuffer::with_capacity(capacity);
+    assert_eq!(buffer.capacity(), 5);
+    for c in "ring buffer".chars() {
+        buffer.push_overwrite(c);
+    }
+    assert_eq!(buffer.to_vec(), ['u', 'f', 'f', 'e', 'r']);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn byte_buffers_are_writers() {
+    use std::io::{ErrorKind, Write};
+
+    let mut log: RingBuffer<u8, 8> = RingBuffer::new();
+    write!(log, "ok {}", 42).unwrap();
+    assert_eq!(log.to_vec(), b"ok 42");
+
+    let error = write!(log, "overflow").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::WriteZero);
+    assert!(log.is_full());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn full_is_a_std_error() {
+    fn push_twice(buffer: &mut RingBuffer<u8, 1>) -> Result<(), Box<dyn std::error::Error>> {
+        buffer.push(1)?;
+        buffer.push(2)?;
+        Ok(())
+    }
+
+    let error = push_twice(&mut RingBuffer::new()).unwrap_err();
+    assert_eq!(error.to_string(), "ring buffer is full");
+}
@@ -0,0 +1,86 @@
+//! CPU features, detected when the program runs. A binary compiled for
+//! `x86_64` runs on processors from 2003 to today, so it has to ask which
+//! instructions this one has.
+
+/// The optional instruction sets that this CPU supports.
+#[cfg(target_arch = "x86_64")]
+pub fn features() -> Vec<&'static str> {
+    let mut found = Vec::new();
+    // A macro and not a loop, because the feature name must be a literal
+    macro_rules! check {
+        ($($feature:tt),*) => {
+            $(if std::arch::is_x86_feature_detected!($feature) {
+                found.push($feature);
+            })*
+        };
+    }
+    check!("sse2", "sse4.2", "avx", "avx2", "avx512f", "aes", "sha");
+    found
+}
+
+#[cfg(target_arch = "aarch64")]
+pub fn features() -> Vec<&'static str> {
+    let mut found = Vec::new();
+    macro_rules! check {
+        ($($feature:tt),*) => {
+            $(if std::arch::is_aarch64_feature_detected!($feature) {
+                found.push($feature);
+            })*
+        };
+    }
+    check!("neon", "aes", "sha2", "crc", "sve");
+    found
+}
+
+/// Other architectures report no features rather than failing to compile.
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+pub fn features() -> Vec<&'static str> {
+    Vec::new()
+}
+
+/// Features the compiler may use everywhere in the program, because they
+/// were enabled at compile time, for example with
+/// `-C target-cpu=native`.
+pub fn compiled_with() -> Vec<&'static str> {
+    let mut enabled = Vec::new();
+    if cfg!(target_feature = "sse2") {
+        enabled.push("sse2");
+    }
+    if cfg!(target_feature = "avx2") {
+        enabled.push("avx2");
+    }
+    if cfg!(target_feature = "neon") {
+        enabled.push("neon");
+    }
+    enabled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn features_enabled_at_compile_time_are_detected() {
+        // The program couldn't run at all otherwise
+        let detected = features();
+        for feature in compiled_with() {
+            assert!(detected.contains(&feature), "{feature} not detected");
+        }
+    }
+
+    // SSE2 is part of every x86_64 processor
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn x86_64_always_has_sse2() {
+        assert!(features().contains(&"sse2"));
+        assert!(compiled_with().contains(&"sse2"));
+    }
+
+    // And NEON of every aarch64 processor
+    #[cfg(target_arch = "aarch64")]
+    #[test]
+    fn aarch64_always_has_neon() {
+        assert!(features().contains(&"neon"));
+        assert!(compiled_with().contains(&"neon"));
+    }
+}
@@ -0,0 +1,107 @@
+//! Reports what the program was compiled for and what it runs on. Each
+//! module has code paths for several targets, selected with `#[cfg]`.
+
+pub mod cpu;
+pub mod memory;
+
+use std::fmt;
+
+/// The target the binary was compiled for. All fields are known at compile
+/// time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Target {
+    pub arch: &'static str,
+    pub os: &'static str,
+    pub env: &'static str,
+    pub pointer_width: u32,
+    pub endian: &'static str,
+    /// Whether the C runtime is linked into the binary
+    pub static_crt: bool,
+}
+
+impl Target {
+    pub const fn current() -> Target {
+        Target {
+            arch: std::env::consts::ARCH,
+            os: std::env::consts::OS,
+            env: ENV,
+            pointer_width: usize::BITS,
+            endian: if cfg!(target_endian = "little") {
+                "little"
+            } else {
+                "big"
+            },
+            static_crt: cfg!(target_feature = "crt-static"),
+        }
+    }
+}
+
+// `std::env::consts` has no constant for the C library, so it's chosen
+// with `#[cfg]`, like `ARCH` and `OS` are inside `std`
+#[cfg(target_env = "gnu")]
+const ENV: &str = "gnu";
+#[cfg(target_env = "musl")]
+const ENV: &str = "musl";
+#[cfg(target_env = "msvc")]
+const ENV: &str = "msvc";
+#[cfg(not(any(target_env = "gnu", target_env = "musl", target_env = "msvc")))]
+const ENV: &str = "none";
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}-{} ({}-bit, {} endian, {} C runtime)",
+            self.arch,
+            self.os,
+            self.env,
+            self.pointer_width,
+            self.endian,
+            if self.static_crt { "static" } else { "dynamic" }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_the_target_it_was_compiled_for() {
+        let target = Target::current();
+        assert_eq!(target.arch, std::env::consts::ARCH);
+        assert_eq!(target.os, std::env::consts::OS);
+        assert_eq!(
+            target.pointer_width as usize,
+            std::mem::size_of::<usize>() * 8
+        );
+    }
+
+    #[cfg(all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"))]
+    #[test]
+    fn linux_gnu_builds_link_glibc_dynamically() {
+        let target = Target::current();
+        assert_eq!(target.env, "gnu");
+        assert!(!target.static_crt);
+        assert_eq!(
+            target.to_string(),
+            "x86_64-linux-gnu (64-bit, little endian, dynamic C runtime)"
+        );
+    }
+
+    #[cfg(target_env = "musl")]
+    #[test]
+    fn musl_builds_are_static() {
+        let target = Target::current();
+        assert_eq!(target.env, "musl");
+        assert!(target.static_crt);
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    #[test]
+    fn aarch64_is_little_endian_and_64_bit() {
+        let target = Target::current();
+        assert_eq!(target.pointer_width, 64);
+        assert_eq!(target.endian, "little");
+    }
+}
@@ -0,0 +1,11 @@
+use platform_info::{cpu, memory, Target};
+
+fn main() {
+    println!("Compiled for: {}", Target::current());
+    println!("CPU features: {}", cpu::features().join(", "));
+    println!("Compiled with: {}", cpu::compiled_with().join(", "));
+    match memory::total() {
+        Some(bytes) => println!("Memory: {} MiB", bytes / 1024 / 1024),
+        None => println!("Memory: unknown on this system"),
+    }
+}
@@ -0,0 +1,55 @@
+//! The total memory of the machine, read the way each operating system
+//! provides it.
+
+/// Total memory in bytes, if the operating system tells us.
+#[cfg(target_os = "linux")]
+pub fn total() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    parse_meminfo(&meminfo)
+}
+
+// A real program would ask `sysctl` on macOS and `GlobalMemoryStatusEx` on
+// Windows; here, other systems simply don't know
+#[cfg(not(target_os = "linux"))]
+pub fn total() -> Option<u64> {
+    None
+}
+
+/// Parses the `MemTotal` line of `/proc/meminfo`. Plain string handling, so
+/// it's compiled and tested on every platform, not only on Linux.
+pub fn parse_meminfo(meminfo: &str) -> Option<u64> {
+    let line = meminfo.lines().find(|line| line.starts_with("MemTotal:"))?;
+    let kilobytes: u64 = line
+        .trim_start_matches("MemTotal:")
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_mem_total() {
+        let meminfo = "MemTotal:       16318480 kB\nMemFree:         1024000 kB\n";
+        assert_eq!(parse_meminfo(meminfo), Some(16_318_480 * 1024));
+        assert_eq!(parse_meminfo("MemFree: 1 kB\n"), None);
+        assert_eq!(parse_meminfo("MemTotal: lots\n"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn linux_reports_its_memory() {
+        assert!(total().unwrap() > 0);
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    #[test]
+    fn other_systems_report_nothing() {
+        assert_eq!(total(), None);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{Parcel, Quote, QuoteError, Region};
+
+mod private {
+    /// A public trait in a private module. Other crates can't name it, so
+    /// they can't implement it, and so they can't implement `Carrier`.
+    pub trait Sealed {}
+}
+
+/// A company that ships parcels.
+///
+/// This trait is *sealed*: it can be used, but not implemented, outside
+/// this crate. Adding a method to a trait that others implement breaks
+/// their code; adding one to a sealed trait doesn't.
+pub trait Carrier: private::Sealed {
+    fn name(&self) -> &'static str;
+
+    fn quote(&self, parcel: &Parcel, region: Region) -> Result<Quote, QuoteError>;
+}
+
+/// The national post: cheap and slow.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Post;
+
+/// A courier: fast, and takes heavier parcels.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Courier;
+
+impl private::Sealed for Post {}
+impl private::Sealed for Courier {}
+
+impl Carrier for Post {
+    fn name(&self) -> &'static str {
+        "Post"
+    }
+
+    fn quote(&self, parcel: &Parcel, region: Region) -> Result<Quote, QuoteError> {
+        check(parcel, 20_000, 100)?;
+        let (base, per_kg, days) = match region {
+            Region::Domestic => (450, 100, 3),
+            Region::Europe => (900, 250, 7),
+            Region::World => (1500, 600, 14),
+        };
+        Ok(Quote {
+            carrier: self.name(),
+            price_cents: base + per_kg * parcel.weight_grams.div_ceil(1000),
+            days,
+        })
+    }
+}
+
+impl Carrier for Courier {
+    fn name(&self) -> &'static str {
+        "Courier"
+    }
+
+    fn quote(&self, parcel: &Parcel, region: Region) -> Result<Quote, QuoteError> {
+        check(parcel, 50_000, 150)?;
+        let (base, days) = match region {
+            Region::Domestic => (1200, 1),
+            Region::Europe => (2500, 2),
+            Region::World => return Err(QuoteError::NoService(region)),
+        };
+        // Large, light parcels are charged by their volume. `check` limits
+        // each side to 150 cm, so the result fits in a `u32`
+        let volumetric_grams = (parcel.volume_cm3() / 5) as u32;
+        let billed_kg = parcel.weight_grams.max(volumetric_grams).div_ceil(1000);
+        Ok(Quote {
+            carrier: self.name(),
+            price_cents: base + 200 * billed_kg,
+            days,
+        })
+    }
+}
+
+fn check(parcel: &Parcel, max_grams: u32, max_cm: u32) -> Result<(), QuoteError> {
+    if parcel.weight_grams > max_grams {
+        return Err(QuoteError::TooHeavy { max_grams });
+    }
+    if parcel.longest_side_cm() > max_cm {
+        return Err(QuoteError::TooLong { max_cm });
+    }
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+use crate::Region;
+
+/// Why a carrier can't take a parcel. `#[non_exhaustive]`, so that a new
+/// carrier can bring a new reason in a minor version.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum QuoteError {
+    #[error("the parcel weighs more than {max_grams} g")]
+    TooHeavy { max_grams: u32 },
+    #[error("the parcel is longer than {max_cm} cm")]
+    TooLong { max_cm: u32 },
+    #[error("no service to {0:?}")]
+    NoService(Region),
+}
@@ -0,0 +1,111 @@
+//! Shipping quotes for parcels.
+//!
+//! ```
+//! use shipping::prelude::*;
+//!
+//! let parcel = Parcel::new(1_200, [30, 20, 10]);
+//! let quote = shipping::cheapest(&parcel, Region::Europe).unwrap();
+//! assert_eq!(quote.carrier(), "Post");
+//! ```
+//!
+//! The API is designed so that new versions can add to it without breaking
+//! the code of its users. The snippets below show what users can't do, and
+//! why that leaves room for the crate to grow. Each one is a `compile_fail`
+//! test.
+//!
+//! # Matching a `#[non_exhaustive]` enum
+//!
+//! New regions may be added in a minor version, so a `match` outside this
+//! crate needs a wildcard arm:
+//!
+//! ```compile_fail,E0004
+//! use shipping::Region;
+//!
+//! fn zone(region: Region) -> u8 {
+//!     // error: non-exhaustive patterns: `_` not covered
+//!     match region {
+//!         Region::Domestic => 1,
+//!         Region::Europe => 2,
+//!         Region::World => 3,
+//!     }
+//! }
+//! ```
+//!
+//! # Building a `#[non_exhaustive]` struct with a literal
+//!
+//! New fields may be added too, so `Parcel` can only be created with
+//! `Parcel::new`:
+//!
+//! ```compile_fail,E0639
+//! // error: cannot create non-exhaustive struct using struct expression
+//! let parcel = shipping::Parcel {
+//!     weight_grams: 500,
+//!     dimensions_cm: [10, 10, 10],
+//! };
+//! ```
+//!
+//! # Implementing a sealed trait
+//!
+//! `Carrier` can gain new methods, because no type outside this crate can
+//! implement it:
+//!
+//! ```compile_fail,E0277
+//! use shipping::{Carrier, Parcel, Quote, QuoteError, Region};
+//!
+//! struct Pigeon;
+//!
+//! // error: the trait bound `Pigeon: Sealed` is not satisfied
+//! impl Carrier for Pigeon {
+//!     fn name(&self) -> &'static str {
+//!         "Pigeon"
+//!     }
+//!
+//!     fn quote(&self, _: &Parcel, _: Region) -> Result<Quote, QuoteError> {
+//!         Err(QuoteError::NoService(Region::World))
+//!     }
+//! }
+//! ```
+
+mod carrier;
+mod error;
+mod parcel;
+pub mod prelude;
+mod quote;
+
+// The modules are private, and everything public is re-exported here. The
+// files can be split or renamed without changing any path that users write
+pub use carrier::{Carrier, Courier, Post};
+pub use error::QuoteError;
+pub use parcel::{Parcel, Region};
+pub use quote::Quote;
+
+/// The cheapest quote from all carriers, or the error of the last one if
+/// none of them takes the parcel.
+pub fn cheapest(parcel: &Parcel, region: Region) -> Result<Quote, QuoteError> {
+    let carriers: [&dyn Carrier; 2] = [&Post, &Courier];
+    let mut best: Option<Quote> = None;
+    let mut last_error = QuoteError::NoService(region);
+    for carrier in carriers {
+        match carrier.quote(parcel, region) {
+            Ok(quote)
+                if best
+                    .as_ref()
+                    .is_none_or(|b| quote.price_cents() < b.price_cents()) =>
+            {
+                best = Some(quote)
+            }
+            Ok(_) => {}
+            Err(e) => last_error = e,
+        }
+    }
+    best.ok_or(last_error)
+}
+
+/// The cheapest quote, or `None`.
+#[deprecated(
+    since = "0.2.0",
+    note = "use `cheapest`, which says why no carrier takes the parcel"
+)]
+pub fn best_quote(parcel: &Parcel, region: Region) -> Option<Quote> {
+    cheapest(parcel, region).ok()
+}
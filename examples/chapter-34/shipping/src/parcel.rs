@@ -0,0 +1,54 @@
+/// A parcel to ship.
+///
+/// `#[non_exhaustive]` keeps other crates from building it with a struct
+/// literal or destructuring it without `..`, so a future version can add a
+/// field, such as a declared value, without breaking them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Parcel {
+    pub weight_grams: u32,
+    /// Length, width, and height
+    pub dimensions_cm: [u32; 3],
+}
+
+impl Parcel {
+    pub fn new(weight_grams: u32, dimensions_cm: [u32; 3]) -> Parcel {
+        Parcel {
+            weight_grams,
+            dimensions_cm,
+        }
+    }
+
+    /// The volume in cubic centimeters. A `u64`, because the product of
+    /// three `u32` sides doesn't fit in a `u32`.
+    pub fn volume_cm3(&self) -> u64 {
+        self.dimensions_cm
+            .iter()
+            .map(|&side| u64::from(side))
+            .product()
+    }
+
+    /// The volume in whole liters, or `u32::MAX` if it doesn't fit.
+    #[deprecated(
+        since = "0.2.0",
+        note = "rounds down to whole liters; use `volume_cm3` instead"
+    )]
+    pub fn volume(&self) -> u32 {
+        u32::try_from(self.volume_cm3() / 1000).unwrap_or(u32::MAX)
+    }
+
+    /// The longest side, which decides whether a parcel fits a carrier's
+    /// machines.
+    pub fn longest_side_cm(&self) -> u32 {
+        self.dimensions_cm.into_iter().max().unwrap_or(0)
+    }
+}
+
+/// Where the parcel goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Region {
+    Domestic,
+    Europe,
+    World,
+}
@@ -0,0 +1,10 @@
+//! The types and traits most programs need, for a single glob import:
+//!
+//! ```
+//! use shipping::prelude::*;
+//! ```
+//!
+//! Adding an item here can clash with a name in the user's code, so the
+//! prelude stays small, and everything else is imported by name.
+
+pub use crate::{Carrier, Parcel, Quote, QuoteError, Region};
@@ -0,0 +1,25 @@
+/// A price and delivery time from one carrier.
+///
+/// The fields are private and read through methods. A new version can
+/// store them differently, or compute one of them, without a breaking
+/// change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quote {
+    pub(crate) carrier: &'static str,
+    pub(crate) price_cents: u32,
+    pub(crate) days: u32,
+}
+
+impl Quote {
+    pub fn carrier(&self) -> &'static str {
+        self.carrier
+    }
+
+    pub fn price_cents(&self) -> u32 {
+        self.price_cents
+    }
+
+    pub fn days(&self) -> u32 {
+        self.days
+    }
+}
@@ -0,0 +1,36 @@
+//! Snapshots the public API of the crate. Any change to it, such as a
+//! removed function, a new enum variant, or a changed signature, shows up as
+//! a failing test with a diff, and is accepted with `cargo insta review`.
+//!
+//! Reads the API from rustdoc's JSON output, which needs a nightly
+//! toolchain. The JSON format changes between nightlies, so the test uses
+//! the one that this version of `public-api` is written for, and is
+//! ignored by a plain `cargo test`:
+//!
+//! ```text
+//! rustup toolchain install nightly-2025-11-22
+//! cargo test --test public_api -- --ignored
+//! ```
+
+#[test]
+#[ignore = "needs the pinned nightly toolchain, see the module docs"]
+fn public_api() {
+    let json = rustdoc_json::Builder::default()
+        // "nightly-2025-11-22" for public-api 0.52
+        .toolchain(public_api::MINIMUM_NIGHTLY_RUST_VERSION)
+        .manifest_path(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+        // A separate directory, so that rustdoc doesn't wait for the lock
+        // on the `target` directory that this test runs from
+        .target_dir(concat!(env!("CARGO_TARGET_TMPDIR"), "/public-api"))
+        .silent(true)
+        .build()
+        .expect("building rustdoc JSON needs the pinned nightly toolchain");
+    // Leave out the impls that every type gets, such as `Send` or
+    // `From<T> for T`, and keep what this crate wrote
+    let api = public_api::Builder::from_rustdoc_json(json)
+        .omit_blanket_impls(true)
+        .omit_auto_trait_impls(true)
+        .build()
+        .unwrap();
+    insta::assert_snapshot!(api);
+}
@@ -0,0 +1,84 @@
+//! Uses the crate the way another crate would. `#[non_exhaustive]` has no
+//! effect inside the crate that declares it, so these tests live in
+//! `tests/`, where they see the same rules as a user.
+
+use shipping::prelude::*;
+use shipping::{Courier, Post};
+
+fn zone(region: Region) -> u8 {
+    match region {
+        Region::Domestic => 1,
+        Region::Europe => 2,
+        Region::World => 3,
+        // Required: a future version may add a region
+        _ => 0,
+    }
+}
+
+#[test]
+fn users_match_regions_with_a_wildcard() {
+    assert_eq!(zone(Region::Domestic), 1);
+    assert_eq!(zone(Region::World), 3);
+}
+
+#[test]
+fn parcels_are_created_with_new_and_destructured_with_rest() {
+    let parcel = Parcel::new(1_200, [30, 20, 10]);
+    // `..` is required, for the fields that may be added later
+    let Parcel { weight_grams, .. } = parcel;
+    assert_eq!(weight_grams, 1_200);
+    assert_eq!(parcel.volume_cm3(), 6_000);
+    // 8 billion cm³ would overflow a `u32`
+    assert_eq!(Parcel::new(1, [2_000; 3]).volume_cm3(), 8_000_000_000);
+}
+
+#[test]
+fn picks_the_cheapest_carrier() {
+    let small = Parcel::new(1_200, [30, 20, 10]);
+    let quote = shipping::cheapest(&small, Region::Domestic).unwrap();
+    assert_eq!(
+        (quote.carrier(), quote.price_cents(), quote.days()),
+        ("Post", 650, 3)
+    );
+
+    // Too heavy for the post, so only the courier quotes
+    let heavy = Parcel::new(25_000, [60, 40, 40]);
+    let quote = shipping::cheapest(&heavy, Region::Europe).unwrap();
+    assert_eq!(quote.carrier(), "Courier");
+}
+
+#[test]
+fn explains_why_nobody_takes_a_parcel() {
+    let heavy = Parcel::new(25_000, [60, 40, 40]);
+    let error = shipping::cheapest(&heavy, Region::World).unwrap_err();
+    assert_eq!(error, QuoteError::NoService(Region::World));
+    assert_eq!(
+        Post.quote(&heavy, Region::World).unwrap_err().to_string(),
+        "the parcel weighs more than 20000 g"
+    );
+    let long = Parcel::new(1_000, [200, 10, 10]);
+    assert_eq!(
+        Courier.quote(&long, Region::Domestic),
+        Err(QuoteError::TooLong { max_cm: 150 })
+    );
+}
+
+#[test]
+fn carriers_work_as_trait_objects() {
+    let carriers: Vec<Box<dyn Carrier>> = vec![Box::new(Post), Box::new(Courier)];
+    let names: Vec<_> = carriers.iter().map(|c| c.name()).collect();
+    assert_eq!(names, ["Post", "Courier"]);
+}
+
+// Deprecated items keep working until the next major version. Code that
+// still uses them opts out of the warning explicitly
+#[test]
+#[allow(deprecated)]
+fn deprecated_items_still_work() {
+    let parcel = Parcel::new(1_200, [30, 20, 10]);
+    assert_eq!(parcel.volume(), 6);
+    assert_eq!(
+        shipping::best_quote(&parcel, Region::Domestic),
+        shipping::cheapest(&parcel, Region::Domestic).ok()
+    );
+}
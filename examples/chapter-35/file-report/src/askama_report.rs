@@ -0,0 +1,28 @@
+use crate::Report;
+use askama::Template;
+
+// The templates are compiled into Rust code, so a misspelled field is a
+// compile error. The file extension turns HTML escaping on or off.
+
+/// A field that doesn't exist is caught by the compiler:
+///
+/// ```compile_fail,E0609
+/// use askama::Template;
+///
+/// #[derive(Template)]
+/// #[template(source = "{{ report.size }}", ext = "txt")]
+/// struct Broken<'a> {
+///     report: &'a file_report::Report,
+/// }
+/// ```
+#[derive(Template)]
+#[template(path = "askama/report.html")]
+pub struct HtmlReport<'a> {
+    pub report: &'a Report,
+}
+
+#[derive(Template)]
+#[template(path = "askama/report.txt")]
+pub struct TextReport<'a> {
+    pub report: &'a Report,
+}
@@ -0,0 +1,7 @@
+mod askama_report;
+mod stats;
+mod tera_report;
+
+pub use askama_report::{HtmlReport, TextReport};
+pub use stats::{FileStats, Report};
+pub use tera_report::TeraReports;
@@ -0,0 +1,65 @@
+use clap::{Parser, ValueEnum};
+use file_report::{HtmlReport, Report, TeraReports, TextReport};
+use std::error::Error;
+use std::path::PathBuf;
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Engine {
+    /// Templates compiled into the binary
+    Askama,
+    /// Templates read from the disk at startup
+    Tera,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Html,
+    Text,
+}
+
+/// Prints a report about the files in a directory
+#[derive(Parser)]
+struct Args {
+    /// The directory to report on
+    // By default, the sample directory of Chapter 1's file_collector
+    #[arg(default_value = "../../chapter-1/file_collector/sample_dir")]
+    dir: PathBuf,
+    #[arg(long, value_enum, default_value = "text")]
+    format: Format,
+    #[arg(long, value_enum, default_value = "askama")]
+    engine: Engine,
+    /// Where the tera templates are
+    #[arg(long, default_value = "templates/tera")]
+    templates: PathBuf,
+}
+
+fn main() {
+    let args = Args::parse();
+    // `Display` shows tera's errors with the line of the template
+    if let Err(e) = run(&args) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    let report = Report::collect(&args.dir)?;
+
+    let output = match args.engine {
+        Engine::Askama => match args.format {
+            Format::Html => HtmlReport { report: &report }.to_string(),
+            Format::Text => TextReport { report: &report }.to_string(),
+        },
+        Engine::Tera => {
+            let templates = TeraReports::load(&args.templates)?;
+            let name = match args.format {
+                Format::Html => "report.html",
+                Format::Text => "report.txt",
+            };
+            templates.render(name, &report)?
+        }
+    };
+    // askama drops the newline at the end of a template file, tera keeps it
+    println!("{}", output.trim_end());
+    Ok(())
+}
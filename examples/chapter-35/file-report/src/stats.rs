@@ -0,0 +1,72 @@
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// What the report shows about one file
+#[derive(Debug, Clone, Serialize)]
+pub struct FileStats {
+    pub name: String,
+    pub bytes: u64,
+    pub lines: usize,
+    pub words: usize,
+    /// `None` for an empty file
+    pub first_line: Option<String>,
+}
+
+/// `Serialize` lets tera read the report; askama reads the fields directly
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub directory: String,
+    pub files: Vec<FileStats>,
+    /// Files that aren't UTF-8 text, such as images
+    pub skipped: Vec<String>,
+    pub total_bytes: u64,
+    pub total_lines: usize,
+    pub total_words: usize,
+}
+
+impl Report {
+    /// Collects the files of one directory, like `get_files` in Chapter 1
+    pub fn collect(dir: &Path) -> io::Result<Report> {
+        let mut files = Vec::new();
+        let mut skipped = Vec::new();
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if !path.is_file() {
+                continue;
+            }
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            match fs::read_to_string(&path) {
+                Ok(content) => files.push(FileStats::new(name, &content)),
+                Err(e) if e.kind() == io::ErrorKind::InvalidData => skipped.push(name),
+                Err(e) => return Err(e),
+            }
+        }
+        // `read_dir` returns the entries in no particular order
+        files.sort_by(|a, b| a.name.cmp(&b.name));
+        skipped.sort();
+
+        Ok(Report {
+            directory: dir.display().to_string(),
+            total_bytes: files.iter().map(|f| f.bytes).sum(),
+            total_lines: files.iter().map(|f| f.lines).sum(),
+            total_words: files.iter().map(|f| f.words).sum(),
+            files,
+            skipped,
+        })
+    }
+}
+
+impl FileStats {
+    fn new(name: String, content: &str) -> FileStats {
+        FileStats {
+            name,
+            bytes: content.len() as u64,
+            lines: content.lines().count(),
+            words: content.split_whitespace().count(),
+            first_line: content.lines().next().map(str::to_string),
+        }
+    }
+}
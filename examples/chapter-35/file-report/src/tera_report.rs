@@ -0,0 +1,25 @@
+use crate::Report;
+use std::path::Path;
+use tera::{Context, Tera};
+
+/// Templates that are read and parsed when the program starts
+pub struct TeraReports {
+    tera: Tera,
+}
+
+impl TeraReports {
+    /// Loads every file in `dir`. Syntax errors are reported here, but
+    /// unknown variables only when a template is rendered.
+    pub fn load(dir: &Path) -> Result<TeraReports, tera::Error> {
+        let mut tera = Tera::new();
+        tera.load_from_glob(&format!("{}/**/*", dir.display()))?;
+        Ok(TeraReports { tera })
+    }
+
+    /// Renders a template by its file name, e.g. `report.html`
+    pub fn render(&self, template: &str, report: &Report) -> Result<String, tera::Error> {
+        let mut context = Context::new();
+        context.insert("report", report);
+        self.tera.render(template, &context)
+    }
+}
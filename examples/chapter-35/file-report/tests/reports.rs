@@ -0,0 +1,136 @@
+use file_report::{HtmlReport, Report, TeraReports, TextReport};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn tera() -> TeraReports {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("templates/tera");
+    TeraReports::load(&dir).unwrap()
+}
+
+fn sample_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../chapter-1/file_collector/sample_dir")
+}
+
+// Renders a report with both engines: (askama, tera)
+fn render_html(report: &Report) -> (String, String) {
+    let askama = HtmlReport { report }.to_string();
+    let tera = tera().render("report.html", report).unwrap();
+    (askama, tera)
+}
+
+fn render_text(report: &Report) -> (String, String) {
+    let askama = TextReport { report }.to_string();
+    let tera = tera().render("report.txt", report).unwrap();
+    (askama, tera)
+}
+
+fn tricky_dir() -> TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join("Tom & Jerry.txt"),
+        "<script>alert(\"hi\")</script>\nsecond line\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("empty.txt"), "").unwrap();
+    fs::write(
+        dir.path().join("logo.png"),
+        [0x89, b'P', b'N', b'G', 0xff, 0xfe],
+    )
+    .unwrap();
+    dir
+}
+
+#[test]
+fn collects_statistics() {
+    let dir = tricky_dir();
+    let report = Report::collect(dir.path()).unwrap();
+
+    let names: Vec<_> = report.files.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, ["Tom & Jerry.txt", "empty.txt"]);
+    assert_eq!(report.skipped, ["logo.png"]);
+
+    let tom = &report.files[0];
+    assert_eq!((tom.bytes, tom.lines, tom.words), (41, 2, 3));
+    assert_eq!(
+        tom.first_line.as_deref(),
+        Some("<script>alert(\"hi\")</script>")
+    );
+    assert_eq!(report.files[1].first_line, None);
+    assert_eq!(report.total_bytes, 41);
+}
+
+#[test]
+fn both_engines_render_the_same_reports() {
+    let report = Report::collect(&sample_dir()).unwrap();
+
+    let (askama, tera) = render_text(&report);
+    assert_eq!(askama.trim_end(), tera.trim_end());
+    assert!(askama.contains("2. file2.txt: 16 bytes, 1 line, 3 words\n   > Rust is awesome!"));
+    assert!(askama.ends_with("Total: 29 bytes, 2 lines, 5 words"));
+
+    let (askama, tera) = render_html(&report);
+    assert_eq!(askama.trim_end(), tera.trim_end());
+    assert!(askama.contains("<td>Hello, World!</td>"));
+    // No skipped files, so no paragraph about them
+    assert!(!askama.contains("Skipped"));
+}
+
+#[test]
+fn html_reports_escape_file_names_and_contents() {
+    let dir = tricky_dir();
+    let report = Report::collect(dir.path()).unwrap();
+
+    let (askama, tera) = render_html(&report);
+    for html in [&askama, &tera] {
+        assert!(!html.contains("<script>"), "not escaped: {}", html);
+        assert!(!html.contains("Tom & Jerry"), "not escaped: {}", html);
+        assert!(html.contains("<td><em>empty</em></td>"));
+        assert!(html.contains("Skipped files that aren't text: logo.png"));
+    }
+    // Both are valid HTML, but the engines pick different entities
+    assert!(askama.contains("<td>&#60;script&#62;alert(&#34;hi&#34;)&#60;/script&#62;</td>"));
+    assert!(tera.contains("<td>&lt;script&gt;alert(&quot;hi&quot;)&lt;/script&gt;</td>"));
+}
+
+#[test]
+fn text_reports_are_not_escaped() {
+    let dir = tricky_dir();
+    let report = Report::collect(dir.path()).unwrap();
+
+    let (askama, tera) = render_text(&report);
+    assert_eq!(askama.trim_end(), tera.trim_end());
+    assert!(askama.contains("1. Tom & Jerry.txt: 41 bytes, 2 lines, 3 words"));
+    assert!(askama.contains("   > <script>alert(\"hi\")</script>"));
+    assert!(askama.contains("2. empty.txt: 0 bytes, 0 lines, 0 words\n\nTotal"));
+    assert!(askama.ends_with("Skipped: logo.png"));
+}
+
+#[test]
+fn empty_directories_have_no_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let report = Report::collect(dir.path()).unwrap();
+
+    let (askama, tera) = render_text(&report);
+    assert_eq!(askama.trim_end(), tera.trim_end());
+    assert!(askama.contains("\nNo files\n"));
+
+    let (askama, tera) = render_html(&report);
+    assert_eq!(askama.trim_end(), tera.trim_end());
+    assert!(askama.contains("<tr><td colspan=\"6\">No files</td></tr>"));
+}
+
+#[test]
+fn tera_finds_mistakes_at_runtime() {
+    let dir = tempfile::tempdir().unwrap();
+    let report = Report::collect(dir.path()).unwrap();
+
+    // A misspelled variable parses fine and fails when rendering
+    fs::write(dir.path().join("size.txt"), "{{ report.size }}").unwrap();
+    let templates = TeraReports::load(dir.path()).unwrap();
+    assert!(templates.render("size.txt", &report).is_err());
+
+    // A syntax error fails when loading
+    fs::write(dir.path().join("for.txt"), "{% for file in %}").unwrap();
+    assert!(TeraReports::load(dir.path()).is_err());
+}
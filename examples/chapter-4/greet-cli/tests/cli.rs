@@ -0,0 +1,68 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+// Runs the compiled `greet-cli` binary, just like a user would
+fn greet_cli() -> Command {
+    Command::cargo_bin("greet-cli").unwrap()
+}
+
+#[test]
+fn greets_once_by_default() {
+    greet_cli()
+        .args(["--name", "Alice"])
+        .assert()
+        .success()
+        .stdout("Hello, Alice!\n")
+        .stderr("");
+}
+
+#[test]
+fn repeats_the_greeting() {
+    greet_cli()
+        .args(["-n", "Bob", "-c", "3"])
+        .assert()
+        .success()
+        .stdout("Hello, Bob!\n".repeat(3));
+}
+
+#[test]
+fn name_is_required() {
+    greet_cli()
+        .assert()
+        .failure()
+        .code(2)
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "the following required arguments were not provided",
+        ))
+        .stderr(predicate::str::contains("--name <NAME>"));
+}
+
+#[test]
+fn count_must_fit_in_u8() {
+    greet_cli()
+        .args(["--name", "Alice", "--count", "300"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value '300'"));
+}
+
+#[test]
+fn help_describes_the_options() {
+    greet_cli()
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("Greets a user"))
+        .stdout(predicate::str::contains("-n, --name <NAME>"))
+        .stdout(predicate::str::contains("[default: 1]"));
+}
+
+#[test]
+fn prints_version() {
+    greet_cli()
+        .arg("--version")
+        .assert()
+        .success()
+        .stdout("greet-cli 1.0\n");
+}
@@ -0,0 +1,64 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+fn my_cli() -> Command {
+    Command::cargo_bin("my-cli-uppercase").unwrap()
+}
+
+#[test]
+fn greets_by_positional_name() {
+    my_cli()
+        .arg("Alice")
+        .assert()
+        .success()
+        .stdout("Hello, Alice!\n")
+        .stderr("");
+}
+
+#[test]
+fn uppercase_flag_in_short_and_long_form() {
+    for flag in ["-u", "--uppercase"] {
+        my_cli()
+            .args(["Alice", flag])
+            .assert()
+            .success()
+            .stdout("HELLO, ALICE!\n");
+    }
+}
+
+#[test]
+fn flag_may_come_before_the_name() {
+    my_cli()
+        .args(["-u", "Bob"])
+        .assert()
+        .success()
+        .stdout("HELLO, BOB!\n");
+}
+
+#[test]
+fn name_is_required() {
+    my_cli()
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("<name>"));
+}
+
+#[test]
+fn unknown_flag_is_rejected() {
+    my_cli()
+        .args(["Alice", "--shout"])
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("unexpected argument '--shout'"));
+}
+
+#[test]
+fn prints_version() {
+    my_cli()
+        .arg("-V")
+        .assert()
+        .success()
+        .stdout("my-cli-uppercase 1.0\n");
+}
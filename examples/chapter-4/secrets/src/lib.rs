@@ -0,0 +1,151 @@
+use secrecy::{ExposeSecret, SecretString};
+use std::path::PathBuf;
+use std::{env, fs, io};
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+#[derive(Debug, Error)]
+pub enum SecretError {
+    #[error("secret {0} is not set, use {0} or {0}_FILE")]
+    Missing(String),
+    #[error("failed to read secret {name} from {}: {source}", path.display())]
+    Unreadable {
+        name: String,
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("secret {0} is empty")]
+    Empty(String),
+}
+
+// Reads a secret from the file named in NAME_FILE, or from NAME itself.
+// Docker and Kubernetes mount secrets as files, which keeps them out of
+// `docker inspect` and the process environment.
+pub fn read_secret(name: &str) -> Result<SecretString, SecretError> {
+    read_secret_with(name, |key| env::var(key).ok())
+}
+
+// The same, with a custom lookup instead of the real environment
+pub fn read_secret_with(
+    name: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<SecretString, SecretError> {
+    if let Some(path) = lookup(&format!("{}_FILE", name)) {
+        let path = PathBuf::from(path);
+        // The buffer holds the secret too, so it's wiped when dropped
+        let contents = fs::read_to_string(&path)
+            .map(Zeroizing::new)
+            .map_err(|source| SecretError::Unreadable {
+                name: name.to_string(),
+                path,
+                source,
+            })?;
+        // Editors usually add a newline at the end of the file
+        return non_empty(name, contents.trim_end());
+    }
+
+    match lookup(name).map(Zeroizing::new) {
+        Some(value) => non_empty(name, &value),
+        None => Err(SecretError::Missing(name.to_string())),
+    }
+}
+
+fn non_empty(name: &str, value: &str) -> Result<SecretString, SecretError> {
+    if value.is_empty() {
+        Err(SecretError::Empty(name.to_string()))
+    } else {
+        Ok(SecretString::from(value))
+    }
+}
+
+// `Debug` is safe to use: `SecretString` prints as [REDACTED]
+#[derive(Debug)]
+pub struct DatabaseConfig {
+    pub host: String,
+    pub user: String,
+    pub password: SecretString,
+}
+
+impl DatabaseConfig {
+    pub fn from_env() -> Result<Self, SecretError> {
+        Ok(DatabaseConfig {
+            host: env::var("DB_HOST").unwrap_or_else(|_| "localhost:5432".to_string()),
+            user: env::var("DB_USER").unwrap_or_else(|_| "app".to_string()),
+            password: read_secret("DB_PASSWORD")?,
+        })
+    }
+
+    // The only place where the password leaves its wrapper. Values built
+    // from a secret are secrets too, so the result is wiped when dropped.
+    pub fn url(&self) -> Zeroizing<String> {
+        Zeroizing::new(format!(
+            "postgres://{}:{}@{}/app",
+            self.user,
+            self.password.expose_secret(),
+            self.host
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |key| vars.get(key).cloned()
+    }
+
+    #[test]
+    fn file_takes_precedence_over_variable() {
+        let path = env::temp_dir().join("secrets-test-password");
+        fs::write(&path, "from-file\n").unwrap();
+
+        let secret = read_secret_with(
+            "DB_PASSWORD",
+            lookup(&[
+                ("DB_PASSWORD", "from-env"),
+                ("DB_PASSWORD_FILE", path.to_str().unwrap()),
+            ]),
+        )
+        .unwrap();
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(secret.expose_secret(), "from-file");
+    }
+
+    #[test]
+    fn missing_and_empty_secrets_are_errors() {
+        let err = read_secret_with("API_KEY", lookup(&[])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "secret API_KEY is not set, use API_KEY or API_KEY_FILE"
+        );
+
+        let err = read_secret_with("API_KEY", lookup(&[("API_KEY", "")])).unwrap_err();
+        assert!(matches!(err, SecretError::Empty(_)));
+
+        let err =
+            read_secret_with("API_KEY", lookup(&[("API_KEY_FILE", "/no/such/file")])).unwrap_err();
+        assert!(matches!(err, SecretError::Unreadable { .. }));
+    }
+
+    #[test]
+    fn debug_output_hides_the_password() {
+        let config = DatabaseConfig {
+            host: "db:5432".to_string(),
+            user: "app".to_string(),
+            password: SecretString::from("hunter2"),
+        };
+
+        let output = format!("{:?}", config);
+        assert!(!output.contains("hunter2"));
+        assert!(output.contains("[REDACTED]"));
+        assert_eq!(*config.url(), "postgres://app:hunter2@db:5432/app");
+    }
+}
@@ -0,0 +1,25 @@
+use secrets::DatabaseConfig;
+use tracing::{error, info};
+
+fn main() {
+    tracing_subscriber::fmt().init();
+
+    let config = match DatabaseConfig::from_env() {
+        Ok(config) => config,
+        Err(e) => {
+            error!("Invalid configuration: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // Logging the whole struct is fine, the password is redacted
+    info!(?config, "Loaded configuration");
+
+    // This would not compile: `SecretString` implements neither
+    // `Display` nor `Serialize`
+    // info!(password = %config.password);
+
+    let url = config.url();
+    info!(length = url.len(), "Connecting to the database");
+    // `url` and `config` are zeroized here, when they go out of scope
+}
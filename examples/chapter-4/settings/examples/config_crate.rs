@@ -0,0 +1,40 @@
+use clap::Parser;
+use config::{Config, ConfigError, Environment, File};
+use settings::{Cli, Settings};
+
+// The same layers with the `config` crate. It has no built-in profiles,
+// so every profile gets its own file in the config/ directory.
+fn load(cli: &Cli) -> Result<Settings, ConfigError> {
+    let profile = cli
+        .profile
+        .clone()
+        .or_else(|| std::env::var("APP_PROFILE").ok())
+        .unwrap_or_else(|| "dev".to_string());
+
+    Config::builder()
+        .add_source(Config::try_from(&Settings::default())?)
+        .add_source(File::with_name("config/default"))
+        // config/dev.toml doesn't exist, which is fine for an optional file
+        .add_source(File::with_name(&format!("config/{}", profile)).required(false))
+        .add_source(
+            Environment::with_prefix("APP")
+                .prefix_separator("_")
+                .separator("__")
+                .try_parsing(true),
+        )
+        .set_override_option("log_level", cli.log_level.clone())?
+        .set_override_option("server.port", cli.port)?
+        .build()?
+        .try_deserialize()
+}
+
+fn main() {
+    let cli = Cli::parse();
+    match load(&cli) {
+        Ok(settings) => println!("{:#?}", settings),
+        Err(e) => {
+            eprintln!("Invalid configuration: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
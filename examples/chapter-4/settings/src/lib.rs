@@ -0,0 +1,201 @@
+// figment::Error is large, and figment's own traits return it by value
+#![allow(clippy::result_large_err)]
+
+use clap::Parser;
+use figment::providers::{Env, Format, Serialized, Toml};
+use figment::value::{Dict, Map};
+use figment::{Figment, Metadata, Profile, Provider};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+// The typed result of all configuration sources
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Settings {
+    pub log_level: String,
+    pub server: Server,
+    pub database: Database,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Server {
+    pub host: String,
+    pub port: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Database {
+    pub url: String,
+    pub max_connections: u32,
+}
+
+// The lowest layer: every setting has a value even without a config file
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            log_level: "warn".to_string(),
+            server: Server {
+                host: "127.0.0.1".to_string(),
+                port: 3000,
+            },
+            database: Database {
+                url: "sqlite::memory:".to_string(),
+                max_connections: 5,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Default, Parser, Serialize)]
+#[command(about = "Shows the settings an application would run with")]
+pub struct Cli {
+    /// Configuration file
+    #[arg(short, long, default_value = "App.toml")]
+    #[serde(skip)]
+    pub config: PathBuf,
+
+    /// Profile to use: dev or prod (default: APP_PROFILE or dev)
+    #[arg(long)]
+    #[serde(skip)]
+    pub profile: Option<String>,
+
+    /// Overrides log_level
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_level: Option<String>,
+
+    /// Overrides server.port
+    #[arg(long)]
+    #[serde(skip_serializing)]
+    pub port: Option<u16>,
+}
+
+// Command-line options use the same structure as `Settings`,
+// and options that weren't given are left out entirely
+#[derive(Serialize)]
+struct CliOverrides<'a> {
+    #[serde(flatten)]
+    cli: &'a Cli,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    server: Option<PortOverride>,
+}
+
+#[derive(Serialize)]
+struct PortOverride {
+    port: u16,
+}
+
+// A custom provider gives the command line a readable name in
+// error messages and in `Figment::find_metadata`
+impl Provider for Cli {
+    fn metadata(&self) -> Metadata {
+        Metadata::named("command line")
+    }
+
+    fn data(&self) -> Result<Map<Profile, Dict>, figment::Error> {
+        let overrides = CliOverrides {
+            cli: self,
+            server: self.port.map(|port| PortOverride { port }),
+        };
+        Serialized::globals(overrides).data()
+    }
+}
+
+// Later layers override earlier ones:
+// defaults < config file < environment variables < command line
+pub fn figment(cli: &Cli) -> Figment {
+    let profile = match &cli.profile {
+        Some(profile) => Profile::new(profile),
+        None => Profile::from_env_or("APP_PROFILE", "dev"),
+    };
+    Figment::from(Serialized::defaults(Settings::default()))
+        // `nested()`: top-level tables in the file are profiles
+        .merge(Toml::file(&cli.config).nested())
+        // APP_SERVER__PORT=9000 sets server.port. `global()` makes the
+        // variables override every profile in the file.
+        .merge(
+            Env::prefixed("APP_")
+                .ignore(&["PROFILE"])
+                .split("__")
+                .global(),
+        )
+        .merge(cli)
+        .select(profile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use figment::Jail;
+
+    fn load(cli: &Cli) -> figment::Result<Settings> {
+        figment(cli).extract()
+    }
+
+    const CONFIG: &str = r#"
+        [default.server]
+        port = 8080
+
+        [prod]
+        log_level = "error"
+    "#;
+
+    // `Jail` runs the closure in a temporary directory with its own
+    // environment variables, so tests can't affect each other
+    #[test]
+    fn layers_override_each_other() {
+        Jail::expect_with(|jail| {
+            jail.create_file("App.toml", CONFIG)?;
+            let cli = Cli {
+                config: "App.toml".into(),
+                ..Cli::default()
+            };
+
+            // Defaults plus the file
+            let settings = load(&cli)?;
+            assert_eq!(settings.server.port, 8080);
+            assert_eq!(settings.log_level, "warn");
+
+            // Environment beats the file
+            jail.set_env("APP_SERVER__PORT", "9000");
+            assert_eq!(load(&cli)?.server.port, 9000);
+
+            // The command line beats the environment
+            let cli = Cli {
+                port: Some(9100),
+                ..cli
+            };
+            assert_eq!(load(&cli)?.server.port, 9100);
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn profile_selects_a_section() {
+        Jail::expect_with(|jail| {
+            jail.create_file("App.toml", CONFIG)?;
+            jail.set_env("APP_PROFILE", "prod");
+            let cli = Cli {
+                config: "App.toml".into(),
+                ..Cli::default()
+            };
+
+            let settings = load(&cli)?;
+            assert_eq!(settings.log_level, "error");
+            // Values from [default] still apply
+            assert_eq!(settings.server.port, 8080);
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn invalid_value_names_its_source() {
+        Jail::expect_with(|jail| {
+            jail.set_env("APP_SERVER__PORT", "eighty");
+            let err = load(&Cli::default()).unwrap_err();
+            let message = err.to_string().to_lowercase();
+            assert!(message.contains("server.port"), "{}", message);
+            assert!(message.contains("app_"), "{}", message);
+            Ok(())
+        });
+    }
+}
@@ -0,0 +1,25 @@
+use clap::Parser;
+use settings::{figment, Cli, Settings};
+
+fn main() {
+    let cli = Cli::parse();
+    let figment = figment(&cli);
+
+    let settings: Settings = match figment.extract() {
+        Ok(settings) => settings,
+        Err(e) => {
+            eprintln!("Invalid configuration: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    println!("Profile: {}", figment.profile());
+    println!("{:#?}", settings);
+
+    // Figment remembers where every value came from
+    for key in ["log_level", "server.host", "server.port", "database.url"] {
+        if let Some(metadata) = figment.find_metadata(key) {
+            println!("{:<12} from {}", key, metadata.name);
+        }
+    }
+}
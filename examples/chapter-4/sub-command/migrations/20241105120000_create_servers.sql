@@ -0,0 +1,5 @@
+CREATE TABLE servers (
+    id         INTEGER PRIMARY KEY NOT NULL,
+    port       INTEGER NOT NULL,
+    started_at TEXT NOT NULL
+);
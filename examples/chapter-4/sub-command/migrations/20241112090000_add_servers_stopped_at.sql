@@ -0,0 +1 @@
+ALTER TABLE servers ADD COLUMN stopped_at TEXT;
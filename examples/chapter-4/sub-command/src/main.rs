@@ -1,5 +1,7 @@
 use clap::{Parser, Subcommand};
 
+mod migrate;
+
 #[derive(Parser)]
 #[command(name = "sub-command", version = "1.0", author = "Your Name", about = "An example CLI with subcommands")]
 struct Cli {
@@ -23,6 +25,12 @@ enum Commands {
         #[arg(short, long)]
         force: bool,
     },
+    /// Applies pending database migrations
+    Migrate {
+        /// Database to migrate
+        #[arg(long, default_value = "sqlite:app.db")]
+        database_url: String,
+    },
 }
 
 fn main() {
@@ -45,5 +53,11 @@ fn main() {
             }
             // Implement restart logic here
         }
+        Commands::Migrate { database_url } => {
+            if let Err(error) = migrate::run(database_url) {
+                eprintln!("Migration failed: {}", error);
+                std::process::exit(1);
+            }
+        }
     }
 }
\ No newline at end of file
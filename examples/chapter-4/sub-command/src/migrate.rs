@@ -0,0 +1,49 @@
+use sqlx::migrate::{Migrate, Migrator};
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{ConnectOptions, SqliteConnection};
+use std::collections::HashSet;
+use std::error::Error;
+use std::str::FromStr;
+
+// Every file from `./migrations` is embedded into the binary at compile time
+static MIGRATOR: Migrator = sqlx::migrate!();
+
+// The rest of the CLI is synchronous, so this command starts
+// a Tokio runtime just for itself
+pub fn run(database_url: &str) -> Result<(), Box<dyn Error>> {
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(apply_pending(database_url))
+}
+
+async fn apply_pending(database_url: &str) -> Result<(), Box<dyn Error>> {
+    let mut conn: SqliteConnection = SqliteConnectOptions::from_str(database_url)?
+        .create_if_missing(true)
+        .connect()
+        .await?;
+
+    // Find out which migrations this database hasn't seen yet
+    conn.ensure_migrations_table().await?;
+    let applied: HashSet<i64> = conn
+        .list_applied_migrations()
+        .await?
+        .iter()
+        .map(|migration| migration.version)
+        .collect();
+    let pending: Vec<_> = MIGRATOR
+        .iter()
+        .filter(|migration| !applied.contains(&migration.version))
+        .collect();
+
+    if pending.is_empty() {
+        println!("Database is up to date");
+        return Ok(());
+    }
+    for migration in &pending {
+        println!("Applying {} {}", migration.version, migration.description);
+    }
+
+    // Each migration runs in its own transaction
+    MIGRATOR.run(&mut conn).await?;
+    println!("Applied {} migration(s)", pending.len());
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+fn sub_command() -> Command {
+    Command::cargo_bin("sub-command").unwrap()
+}
+
+#[test]
+fn start_uses_the_default_port() {
+    sub_command()
+        .arg("start")
+        .assert()
+        .success()
+        .stdout("Starting the server on port 8080\n");
+}
+
+#[test]
+fn start_with_custom_port() {
+    sub_command()
+        .args(["start", "--port", "3000"])
+        .assert()
+        .success()
+        .stdout("Starting the server on port 3000\n");
+}
+
+#[test]
+fn port_must_be_a_number() {
+    sub_command()
+        .args(["start", "-p", "http"])
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("invalid value 'http'"));
+}
+
+#[test]
+fn stop_and_restart() {
+    sub_command()
+        .arg("stop")
+        .assert()
+        .success()
+        .stdout("Stopping the server\n");
+    sub_command()
+        .arg("restart")
+        .assert()
+        .success()
+        .stdout("Restarting the server\n");
+    sub_command()
+        .args(["restart", "--force"])
+        .assert()
+        .success()
+        .stdout("Force restarting the server\n");
+}
+
+#[test]
+fn subcommand_is_required() {
+    sub_command()
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("Usage: sub-command <COMMAND>"));
+}
+
+#[test]
+fn unknown_subcommand_is_rejected() {
+    sub_command()
+        .arg("deploy")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("unrecognized subcommand 'deploy'"));
+}
+
+#[test]
+fn migrate_applies_pending_migrations_once() {
+    // A fresh database in a directory that is deleted at the end of the test
+    let dir = TempDir::new().unwrap();
+    let url = format!("sqlite:{}", dir.path().join("app.db").display());
+
+    sub_command()
+        .args(["migrate", "--database-url", &url])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Applying 20241105120000 create servers",
+        ))
+        .stdout(predicate::str::ends_with("Applied 2 migration(s)\n"));
+
+    // Running it again finds nothing to do
+    sub_command()
+        .args(["migrate", "--database-url", &url])
+        .assert()
+        .success()
+        .stdout("Database is up to date\n");
+}
+
+#[test]
+fn migrate_reports_connection_errors() {
+    sub_command()
+        .args(["migrate", "--database-url", "sqlite:/no/such/dir/app.db"])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::starts_with("Migration failed:"));
+}
@@ -0,0 +1,46 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+fn validation() -> Command {
+    Command::cargo_bin("validation").unwrap()
+}
+
+#[test]
+fn accepts_a_valid_name() {
+    validation()
+        .arg("Alice")
+        .assert()
+        .success()
+        .stdout("Hello, Alice!\n");
+}
+
+#[test]
+fn three_characters_is_the_minimum() {
+    validation()
+        .args(["Bob", "--uppercase"])
+        .assert()
+        .success()
+        .stdout("HELLO, BOB!\n");
+}
+
+#[test]
+fn short_name_fails_with_the_validator_message() {
+    validation()
+        .arg("Al")
+        .assert()
+        .failure()
+        .code(2)
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "invalid value 'Al' for '<NAME>': Name must be at least 3 characters long",
+        ));
+}
+
+#[test]
+fn empty_name_is_rejected() {
+    validation()
+        .arg("")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("at least 3 characters"));
+}
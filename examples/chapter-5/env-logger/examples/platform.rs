@@ -0,0 +1,54 @@
+use log::{error, info, warn, LevelFilter};
+
+// On Unix-like systems, send records to the local syslog daemon
+#[cfg(unix)]
+fn init_logging() -> Result<(), Box<dyn std::error::Error>> {
+    use syslog::{BasicLogger, Facility, Formatter3164};
+
+    let formatter = Formatter3164 {
+        facility: Facility::LOG_USER,
+        hostname: None,
+        process: "platform-logging".into(),
+        pid: std::process::id(),
+    };
+
+    match syslog::unix(formatter) {
+        Ok(logger) => {
+            log::set_boxed_logger(Box::new(BasicLogger::new(logger)))?;
+            log::set_max_level(LevelFilter::Info);
+        }
+        // No syslog socket (e.g. inside a minimal container): fall back to a file
+        Err(e) => {
+            eprintln!("Syslog is not available ({}), logging to a file", e);
+            init_file_logging()?;
+        }
+    }
+    Ok(())
+}
+
+// Everywhere else, write records to a local file
+#[cfg(not(unix))]
+fn init_logging() -> Result<(), Box<dyn std::error::Error>> {
+    init_file_logging()
+}
+
+fn init_file_logging() -> Result<(), Box<dyn std::error::Error>> {
+    let file = std::fs::File::create("app.log")?;
+    env_logger::Builder::new()
+        .filter_level(LevelFilter::Info)
+        .target(env_logger::Target::Pipe(Box::new(file)))
+        .try_init()?;
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    init_logging()?;
+
+    // The rest of the application uses the `log` macros and doesn't care
+    // which backend was chosen
+    info!("Application started");
+    warn!("Cache is almost full");
+    error!("Failed to reach the payment service");
+
+    Ok(())
+}
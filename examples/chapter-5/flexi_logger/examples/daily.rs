@@ -0,0 +1,176 @@
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use flexi_logger::writers::{FileLogWriter, FileLogWriterBuilder, LogWriter};
+use flexi_logger::{
+    Age, Cleanup, Criterion, DeferredNow, Duplicate, FileSpec, FlexiLoggerError, Logger, Naming,
+};
+use log::{error, info, warn, LevelFilter, Record};
+use std::fs::{self, File};
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+
+// How many days of logs are kept, today included
+const KEEP_DAYS: usize = 30;
+
+// Put each day's logs into their own directory, e.g. logs/2024-10-28
+fn directory(day: &str) -> String {
+    format!("logs/{}", day)
+}
+
+// All messages go to myapp_r<timestamp>.log
+fn all_messages(directory: &str) -> FileLogWriterBuilder {
+    FileLogWriter::builder(
+        FileSpec::default()
+            .directory(directory)
+            .basename("myapp")
+            .suffix("log"),
+    )
+    // A restart continues the newest file instead of overwriting it
+    .append()
+    .rotate(
+        // Start a new file every day
+        Criterion::Age(Age::Day),
+        // Name each file after the time it was created, e.g.
+        // myapp_r2024-10-28_09-15-02.log, so finished files are never renamed
+        Naming::TimestampsDirect,
+        // Keep 7 rotated files as plain text and 30 more compressed with gzip
+        Cleanup::KeepLogAndCompressedFiles(7, 30),
+    )
+}
+
+// A second file that only receives errors
+fn errors_only(directory: &str) -> FileLogWriterBuilder {
+    FileLogWriter::builder(
+        FileSpec::default()
+            .directory(directory)
+            .basename("myapp")
+            .discriminant("errors") // myapp_errors_r<timestamp>.log
+            .suffix("log"),
+    )
+    .append()
+    .max_level(LevelFilter::Error)
+    .rotate(
+        Criterion::Age(Age::Day),
+        Naming::TimestampsDirect,
+        Cleanup::KeepCompressedFiles(30),
+    )
+}
+
+// `Cleanup` only looks into the directory the writer currently uses, so the
+// directories of earlier days are handled here: their log files are
+// compressed, and days beyond KEEP_DAYS are deleted
+fn archive_old_days(today: &str) -> io::Result<()> {
+    let mut days = Vec::new();
+    for entry in fs::read_dir("logs")? {
+        let entry = entry?;
+        let day = entry.file_name().to_string_lossy().into_owned();
+        // Dates in the YYYY-MM-DD format sort like strings
+        if entry.file_type()?.is_dir() && day.as_str() < today {
+            days.push(day);
+        }
+    }
+    days.sort();
+
+    let expired = days.len().saturating_sub(KEEP_DAYS - 1);
+    for day in &days[..expired] {
+        fs::remove_dir_all(directory(day))?;
+    }
+    for day in &days[expired..] {
+        for entry in fs::read_dir(directory(day))? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "log") {
+                compress(&path)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// Replace myapp_r2024-10-28_09-15-02.log with myapp_r2024-10-28_09-15-02.log.gz
+fn compress(path: &Path) -> io::Result<()> {
+    let mut compressed = path.as_os_str().to_owned();
+    compressed.push(".gz");
+    let mut encoder = GzEncoder::new(File::create(compressed)?, Compression::default());
+    io::copy(&mut File::open(path)?, &mut encoder)?;
+    encoder.finish()?;
+    fs::remove_file(path)
+}
+
+// Writes to both files in the directory of the current day. The day is
+// checked for every message, not once at startup, so a program that runs
+// past midnight moves on to the next day's directory.
+struct DailyDirectory {
+    current: Mutex<(String, FileLogWriter, FileLogWriter)>,
+}
+
+impl DailyDirectory {
+    fn new() -> Result<Self, FlexiLoggerError> {
+        let day = DeferredNow::new().format("%Y-%m-%d").to_string();
+        let all = all_messages(&directory(&day)).try_build()?;
+        let errors = errors_only(&directory(&day)).try_build()?;
+        // Finish the days the program was not running at midnight
+        archive_old_days(&day)?;
+        Ok(DailyDirectory {
+            current: Mutex::new((day, all, errors)),
+        })
+    }
+}
+
+impl LogWriter for DailyDirectory {
+    fn write(&self, now: &mut DeferredNow, record: &Record) -> io::Result<()> {
+        let day = now.format("%Y-%m-%d").to_string();
+        let mut current = self.current.lock().unwrap();
+        let (current_day, all, errors) = &mut *current;
+        let new_day = *current_day != day;
+        if new_day {
+            // Yesterday's files already have their final names, so the
+            // writers simply continue with new files in the new directory
+            let next = directory(&day);
+            all.reset(&all_messages(&next)).map_err(io::Error::other)?;
+            errors
+                .reset(&errors_only(&next))
+                .map_err(io::Error::other)?;
+            *current_day = day.clone();
+        }
+
+        all.write(now, record)?;
+        if record.level() <= errors.max_log_level() {
+            errors.write(now, record)?;
+        }
+        if new_day {
+            // Yesterday's files are closed now and can be compressed
+            archive_old_days(&day)?;
+        }
+        Ok(())
+    }
+
+    fn flush(&self) -> io::Result<()> {
+        let current = self.current.lock().unwrap();
+        current.1.flush()?;
+        current.2.flush()
+    }
+
+    fn shutdown(&self) {
+        let current = self.current.lock().unwrap();
+        current.1.shutdown();
+        current.2.shutdown();
+    }
+}
+
+fn main() {
+    let writer =
+        DailyDirectory::new().unwrap_or_else(|e| panic!("Log writer initialization failed: {}", e));
+
+    let _logger = Logger::try_with_str("info")
+        .unwrap()
+        .log_to_writer(Box::new(writer))
+        .duplicate_to_stdout(Duplicate::Warn)
+        .start()
+        .unwrap_or_else(|e| panic!("Logger initialization failed: {}", e));
+
+    info!("Application started");
+    warn!("Disk usage is above 80%");
+    error!("Failed to connect to the database");
+    info!("Application finished");
+}
@@ -0,0 +1,8 @@
+use tracing::{debug, info, trace};
+
+pub fn query(sql: &str) -> usize {
+    trace!(sql, "Preparing statement");
+    debug!("Acquired connection from pool");
+    info!(rows = 3, "Query finished");
+    3
+}
@@ -0,0 +1,9 @@
+use tracing::{debug, info, warn};
+
+pub fn handle(path: &str) {
+    debug!(path, "Parsing headers");
+    info!(path, status = 200, "Request handled");
+    if path.starts_with("/admin") {
+        warn!(path, "Admin endpoint accessed");
+    }
+}
@@ -0,0 +1,37 @@
+mod db;
+mod http;
+mod worker;
+
+use tracing::Level;
+use tracing_subscriber::filter::{EnvFilter, Targets};
+use tracing_subscriber::prelude::*;
+
+fn main() {
+    // Every event has a target: the module path where it was recorded,
+    // for example `myapp::db` or `myapp::http`.
+    if std::env::var("RUST_LOG").is_ok() {
+        // RUST_LOG=myapp::db=trace,myapp::http=warn cargo run
+        tracing_subscriber::fmt()
+            .with_env_filter(EnvFilter::from_default_env())
+            .init();
+    } else {
+        // The same kind of rules, built in code
+        let filter = Targets::new()
+            .with_default(Level::INFO)
+            .with_target("myapp::db", Level::TRACE)
+            .with_target("myapp::http", Level::WARN)
+            .with_target("myapp::worker", Level::ERROR);
+
+        tracing_subscriber::registry()
+            .with(tracing_subscriber::fmt::layer())
+            .with(filter)
+            .init();
+    }
+
+    tracing::info!("Application started");
+
+    db::query("SELECT * FROM users");
+    http::handle("/index.html");
+    http::handle("/admin/settings");
+    worker::run(2);
+}
@@ -0,0 +1,9 @@
+use tracing::{debug, error, info};
+
+pub fn run(jobs: u32) {
+    for job in 0..jobs {
+        debug!(job, "Job started");
+    }
+    info!(jobs, "All jobs processed");
+    error!("Worker queue is almost full");
+}
@@ -0,0 +1,90 @@
+// Start Jaeger before running this example:
+//
+// docker run -d -p16686:16686 -p4317:4317 -e COLLECTOR_OTLP_ENABLED=true jaegertracing/all-in-one:latest
+//
+// Or with docker-compose:
+//
+// services:
+//   jaeger:
+//     image: jaegertracing/all-in-one:latest
+//     environment:
+//       - COLLECTOR_OTLP_ENABLED=true
+//     ports:
+//       - "16686:16686" # Jaeger UI
+//       - "4317:4317"   # OTLP over gRPC
+//
+// Then open http://localhost:16686 and look for the "order-service" service.
+
+use opentelemetry::trace::{TraceError, TracerProvider as _};
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::trace::{Sampler, TracerProvider};
+use opentelemetry_sdk::{runtime, trace as sdktrace, Resource};
+use std::time::Duration;
+use tracing::{info, instrument, warn};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::prelude::*;
+
+fn init_tracer_provider() -> Result<TracerProvider, TraceError> {
+    opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint("http://localhost:4317"),
+        )
+        .with_trace_config(
+            sdktrace::Config::default()
+                // Resource attributes describe the process that produced the spans
+                .with_resource(Resource::new(vec![
+                    KeyValue::new("service.name", "order-service"),
+                    KeyValue::new("service.version", env!("CARGO_PKG_VERSION")),
+                    KeyValue::new("deployment.environment", "development"),
+                ]))
+                // Keep every trace started here, or follow the caller's decision.
+                // In production, TraceIdRatioBased(0.1) would keep 10% of traces.
+                .with_sampler(Sampler::ParentBased(Box::new(
+                    Sampler::TraceIdRatioBased(1.0),
+                ))),
+        )
+        .install_batch(runtime::Tokio)
+}
+
+#[instrument]
+async fn handle_order(order_id: u64) {
+    info!("Handling order");
+    check_inventory(order_id).await;
+    charge_payment(order_id, 99.90).await;
+}
+
+#[instrument]
+async fn check_inventory(order_id: u64) {
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    info!(in_stock = true, "Inventory checked");
+}
+
+#[instrument(fields(provider = "stripe"))]
+async fn charge_payment(order_id: u64, amount: f64) {
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    warn!("Payment provider responded slowly");
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let provider = init_tracer_provider()?;
+    let tracer = provider.tracer("order-service");
+
+    // Every tracing span is also exported as an OpenTelemetry span
+    tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(tracer))
+        .with(tracing_subscriber::fmt::layer())
+        // Keep the exporter's own debug output out of the traces
+        .with(LevelFilter::INFO)
+        .init();
+
+    handle_order(42).await;
+
+    // Flush the remaining spans before the process exits
+    provider.shutdown()?;
+    Ok(())
+}
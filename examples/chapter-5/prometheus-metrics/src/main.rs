@@ -0,0 +1,55 @@
+use metrics::{
+    counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram, Unit,
+};
+use metrics_exporter_prometheus::PrometheusBuilder;
+use rand::Rng;
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::info;
+
+fn handle_request(path: &'static str) {
+    let start = Instant::now();
+
+    // Simulate work that takes between 5 and 50 ms
+    let mut rng = rand::thread_rng();
+    thread::sleep(Duration::from_millis(rng.gen_range(5..50)));
+    let status = if rng.gen_bool(0.9) { "200" } else { "500" };
+
+    // Counter: only goes up. Labels split it into separate time series.
+    counter!("http_requests_total", "path" => path, "status" => status).increment(1);
+
+    // Histogram: distribution of values, e.g. latencies
+    histogram!("http_request_duration_seconds", "path" => path)
+        .record(start.elapsed().as_secs_f64());
+}
+
+fn main() {
+    tracing_subscriber::fmt().init();
+
+    // Serve metrics on http://localhost:9000/metrics
+    PrometheusBuilder::new()
+        .with_http_listener(([127, 0, 0, 1], 9000))
+        // Without buckets, histograms are exported as summaries (quantiles)
+        .set_buckets(&[0.005, 0.01, 0.025, 0.05, 0.1])
+        .expect("Invalid buckets")
+        .install()
+        .expect("Failed to install Prometheus exporter");
+
+    describe_counter!("http_requests_total", "Total number of HTTP requests");
+    describe_histogram!(
+        "http_request_duration_seconds",
+        Unit::Seconds,
+        "HTTP request latency"
+    );
+    describe_gauge!("queue_depth", "Number of jobs waiting in the queue");
+
+    info!("Metrics available at http://localhost:9000/metrics");
+
+    let paths = ["/", "/users", "/orders"];
+    for i in 0.. {
+        handle_request(paths[i % paths.len()]);
+
+        // Gauge: a value that can go up and down
+        gauge!("queue_depth").set((i % 10) as f64);
+    }
+}
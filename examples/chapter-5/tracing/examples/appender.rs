@@ -0,0 +1,36 @@
+use tracing::{debug, info, warn};
+use tracing_appender::rolling;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{fmt, Registry};
+
+fn main() {
+    // A file appender that starts a new file every day: logs/app.log.2024-10-28
+    let file_appender = rolling::daily("logs", "app.log");
+
+    // Writes happen on a background thread, so logging never blocks on disk I/O.
+    // The guard flushes buffered lines when it is dropped, so keep it alive
+    // until the end of main. `let _ = ...` would drop it immediately!
+    let (file_writer, _guard) = tracing_appender::non_blocking(file_appender);
+
+    // Layer 1: colored, human-readable output on the console, INFO and above
+    let console_layer = fmt::layer()
+        .with_target(false)
+        .with_filter(LevelFilter::INFO);
+
+    // Layer 2: plain text without colors in the file, everything from DEBUG
+    let file_layer = fmt::layer()
+        .with_writer(file_writer)
+        .with_ansi(false)
+        .with_filter(LevelFilter::DEBUG);
+
+    // The registry stores span data; each layer decides what to do with events
+    Registry::default()
+        .with(console_layer)
+        .with(file_layer)
+        .init();
+
+    info!("Application started");
+    debug!("This line goes only to the file");
+    warn!("This line goes to both outputs");
+}
@@ -0,0 +1,73 @@
+use tracing::{info, info_span, instrument, warn};
+use tracing_subscriber::fmt::MakeWriter;
+
+// Builds a subscriber that writes one JSON object per event
+fn json_subscriber<W>(writer: W) -> impl tracing::Subscriber
+where
+    W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
+    tracing_subscriber::fmt()
+        .json()
+        .with_current_span(true) // Include the innermost span
+        .with_span_list(true) // Include every span from root to leaf
+        .with_writer(writer)
+        .finish()
+}
+
+#[instrument]
+fn handle_order(order_id: u64, amount: f64) {
+    info!(items = 3, "Order received");
+    if amount > 1000.0 {
+        warn!(limit = 1000.0, "Amount exceeds limit");
+    }
+}
+
+fn main() {
+    tracing::subscriber::set_global_default(json_subscriber(std::io::stdout))
+        .expect("Failed to set subscriber");
+
+    let request_span = info_span!("request", request_id = "req-42");
+    let _enter = request_span.enter();
+
+    handle_order(7, 1500.0);
+}
+
+#[cfg(test)]
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::common::Buffer;
+
+    #[test]
+    fn every_line_is_a_json_event() {
+        let buffer = Buffer::default();
+
+        tracing::subscriber::with_default(json_subscriber(buffer.clone()), || {
+            let _enter = info_span!("request", request_id = "req-42").entered();
+            handle_order(7, 1500.0);
+        });
+
+        let output = buffer.contents();
+        let events: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("each line must be valid JSON"))
+            .collect();
+
+        assert_eq!(events.len(), 2);
+
+        let received = &events[0];
+        assert_eq!(received["level"], "INFO");
+        assert!(received["timestamp"].is_string());
+        assert_eq!(received["fields"]["message"], "Order received");
+        assert_eq!(received["fields"]["items"], 3);
+        assert_eq!(received["span"]["name"], "handle_order");
+        assert_eq!(received["span"]["order_id"], 7);
+        assert_eq!(received["spans"][0]["request_id"], "req-42");
+
+        let warning = &events[1];
+        assert_eq!(warning["level"], "WARN");
+        assert_eq!(warning["fields"]["limit"], 1000.0);
+    }
+}
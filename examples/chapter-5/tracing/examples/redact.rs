@@ -0,0 +1,127 @@
+use std::fmt;
+use tracing::field::Field;
+use tracing::{info, warn};
+use tracing_subscriber::field::MakeExt;
+use tracing_subscriber::fmt::format::{debug_fn, Writer};
+use tracing_subscriber::fmt::MakeWriter;
+
+// Fields whose values must never be written anywhere
+const SENSITIVE_FIELDS: [&str; 4] = ["password", "token", "api_key", "secret"];
+
+// Replaces the local part of every e-mail address: alice@example.com -> ***@example.com
+fn mask_emails(text: &str) -> String {
+    text.split(' ')
+        .map(|word| match word.find('@') {
+            Some(at) if at > 0 && word[at + 1..].contains('.') => {
+                // A byte index, so leading punctuation like `“` is kept whole
+                let start = word
+                    .char_indices()
+                    .find(|(_, c)| c.is_alphanumeric())
+                    .map_or(word.len(), |(i, _)| i);
+                format!("{}***{}", &word[..start], &word[at..])
+            }
+            _ => word.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Formats a single field of an event or span, hiding secrets
+fn format_field(writer: &mut Writer<'_>, field: &Field, value: &dyn fmt::Debug) -> fmt::Result {
+    let name = field.name();
+    if SENSITIVE_FIELDS.contains(&name) {
+        return write!(writer, "{}=[REDACTED]", name);
+    }
+
+    let value = mask_emails(&format!("{:?}", value));
+    if name == "message" {
+        write!(writer, "{}", value)
+    } else {
+        write!(writer, "{}={}", name, value)
+    }
+}
+
+fn redacting_subscriber<W>(writer: W) -> impl tracing::Subscriber
+where
+    W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
+    tracing_subscriber::fmt()
+        .fmt_fields(debug_fn(format_field).delimited(" "))
+        .with_ansi(false)
+        .with_writer(writer)
+        .finish()
+}
+
+fn login(user: &str, password: &str) {
+    info!(user, password, "Login attempt");
+}
+
+fn main() {
+    tracing::subscriber::set_global_default(redacting_subscriber(std::io::stdout))
+        .expect("Failed to set subscriber");
+
+    login("alice", "hunter2");
+    info!(token = "eyJhbGciOiJIUzI1NiJ9", "Token issued");
+    warn!("Password reset requested for alice@example.com");
+    info!(email = "bob@example.org", plan = "pro", "Subscription renewed");
+}
+
+#[cfg(test)]
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::common::Buffer;
+
+    // Runs the closure with the redacting subscriber and returns everything it wrote
+    fn capture(f: impl FnOnce()) -> String {
+        let buffer = Buffer::default();
+        tracing::subscriber::with_default(redacting_subscriber(buffer.clone()), f);
+        buffer.contents()
+    }
+
+    #[test]
+    fn password_never_reaches_the_sink() {
+        let output = capture(|| login("alice", "hunter2"));
+        assert!(!output.contains("hunter2"));
+        assert!(output.contains("password=[REDACTED]"));
+        assert!(output.contains("user=\"alice\""));
+    }
+
+    #[test]
+    fn tokens_in_spans_are_redacted() {
+        let output = capture(|| {
+            let _enter = tracing::info_span!("request", token = "secret-token").entered();
+            info!("Handling request");
+        });
+        assert!(!output.contains("secret-token"));
+        assert!(output.contains("token=[REDACTED]"));
+    }
+
+    #[test]
+    fn emails_are_masked_in_messages_and_fields() {
+        let output = capture(|| {
+            warn!("Reset for alice@example.com");
+            info!(email = "bob@example.org", "Renewed");
+        });
+        assert!(!output.contains("alice@"));
+        assert!(!output.contains("bob@"));
+        assert!(output.contains("***@example.com"));
+        assert!(output.contains("email=\"***@example.org\""));
+    }
+
+    #[test]
+    fn leading_punctuation_is_kept() {
+        assert_eq!(mask_emails("(alice@example.com)"), "(***@example.com)");
+        assert_eq!(
+            mask_emails("wrote “alice@example.com”"),
+            "wrote “***@example.com”"
+        );
+    }
+
+    #[test]
+    fn words_without_domain_are_kept() {
+        assert_eq!(mask_emails("ping @here now"), "ping @here now");
+    }
+}
@@ -0,0 +1,43 @@
+use std::time::Duration;
+use tracing::{debug, info};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{fmt, reload};
+
+// How long the verbose level stays active after a signal
+const DEBUG_WINDOW: Duration = Duration::from_secs(30);
+
+#[tokio::main]
+async fn main() {
+    // Wrap the filter in a reload layer and keep the handle to change it later
+    let (filter, handle) = reload::Layer::new(LevelFilter::INFO);
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(fmt::layer())
+        .init();
+
+    info!(pid = std::process::id(), "Send SIGUSR1 to enable debug logs for 30 seconds");
+
+    #[cfg(unix)]
+    tokio::spawn(async move {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut signals = signal(SignalKind::user_defined1()).expect("Failed to listen for SIGUSR1");
+        while signals.recv().await.is_some() {
+            handle.modify(|filter| *filter = LevelFilter::DEBUG).unwrap();
+            info!("Log level switched to DEBUG");
+
+            tokio::time::sleep(DEBUG_WINDOW).await;
+
+            handle.modify(|filter| *filter = LevelFilter::INFO).unwrap();
+            info!("Log level switched back to INFO");
+        }
+    });
+
+    // Simulated work that logs at both levels
+    for tick in 0.. {
+        info!(tick, "Working");
+        debug!(tick, queue_len = tick % 7, "Internal state");
+        tokio::time::sleep(Duration::from_secs(5)).await;
+    }
+}
@@ -0,0 +1,50 @@
+use std::time::Duration;
+use tracing::{info, info_span, warn, Instrument};
+use uuid::Uuid;
+
+async fn load_user(user_id: u32) -> String {
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    info!(user_id, "User loaded");
+    format!("user-{}", user_id)
+}
+
+async fn send_email(user: String) {
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    info!(%user, "Welcome e-mail sent");
+}
+
+async fn handle_request(user_id: u32) {
+    info!("Request started");
+
+    let user = load_user(user_id).await;
+
+    // A spawned task does not inherit the current span automatically.
+    // `.in_current_span()` attaches the request span to the new task.
+    let email = tokio::spawn(send_email(user).in_current_span());
+
+    // Without it, the task's logs would lose the request id:
+    tokio::spawn(async {
+        warn!("This event has no request id");
+    })
+    .await
+    .unwrap();
+
+    email.await.unwrap();
+    info!("Request finished");
+}
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt().with_target(false).init();
+
+    let mut requests = Vec::new();
+    for user_id in [1, 2] {
+        // Every request gets its own id, recorded once in the span
+        let span = info_span!("request", request_id = %Uuid::new_v4());
+        requests.push(tokio::spawn(handle_request(user_id).instrument(span)));
+    }
+
+    for request in requests {
+        request.await.unwrap();
+    }
+}
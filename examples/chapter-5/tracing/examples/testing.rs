@@ -0,0 +1,72 @@
+use tracing::{info, warn};
+
+pub struct Account {
+    pub balance: i64,
+}
+
+// Withdraws money, logging a warning when the balance becomes low
+pub fn withdraw(account: &mut Account, amount: i64) -> Result<(), String> {
+    if amount > account.balance {
+        warn!(amount, balance = account.balance, "Withdrawal rejected");
+        return Err("insufficient funds".to_string());
+    }
+
+    account.balance -= amount;
+    info!(amount, "Withdrawal completed");
+
+    if account.balance < 100 {
+        warn!(balance = account.balance, "Balance is low");
+    }
+    Ok(())
+}
+
+fn main() {
+    tracing_subscriber::fmt().init();
+
+    let mut account = Account { balance: 500 };
+    withdraw(&mut account, 450).unwrap();
+    withdraw(&mut account, 100).unwrap_err();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+
+    #[test]
+    #[traced_test] // Installs a subscriber that records everything logged by this test
+    fn low_balance_logs_a_warning() {
+        let mut account = Account { balance: 500 };
+        withdraw(&mut account, 450).unwrap();
+
+        assert!(logs_contain("Withdrawal completed"));
+        assert!(logs_contain("Balance is low"));
+        assert!(logs_contain("balance=50"));
+    }
+
+    #[test]
+    #[traced_test]
+    fn normal_withdrawal_does_not_warn() {
+        let mut account = Account { balance: 500 };
+        withdraw(&mut account, 100).unwrap();
+
+        assert!(logs_contain("Withdrawal completed"));
+        assert!(!logs_contain("WARN"));
+    }
+
+    #[test]
+    #[traced_test]
+    fn rejected_withdrawal_logs_exactly_one_warning() {
+        let mut account = Account { balance: 50 };
+        assert!(withdraw(&mut account, 100).is_err());
+
+        // For more precise checks, inspect the captured lines directly
+        logs_assert(|lines: &[&str]| {
+            let warnings: Vec<_> = lines.iter().filter(|line| line.contains("WARN")).collect();
+            match warnings.as_slice() {
+                [line] if line.contains("Withdrawal rejected") => Ok(()),
+                _ => Err(format!("expected one rejection warning, got {:?}", warnings)),
+            }
+        });
+    }
+}
@@ -1,4 +1,5 @@
-use tracing::{info, instrument, Level};
+use std::time::{Duration, Instant};
+use tracing::{info, info_span, instrument, Level};
 use tracing_subscriber::fmt::format::FmtSpan;
 use tracing_subscriber::FmtSubscriber;
 
@@ -16,16 +17,65 @@ async fn main() {
 
     info!("Starting async application");
 
-    info!("Fetching data...");
-    fetch_data().await;
-    info!("Data fetched");
+    let mut durations = Vec::new();
+    for request in 0..5 {
+        // Manual measurement: wall-clock time from start to finish
+        let start = Instant::now();
+        fetch_data(request).await;
+        let elapsed = start.elapsed();
 
+        info!(request, elapsed = ?elapsed, "Data fetched");
+        durations.push(elapsed);
+    }
+
+    print_summary(&mut durations);
 }
 
 #[instrument]
-async fn fetch_data() {
-    // Simulate async operation
-    for _i in 0..200000000 {
+async fn fetch_data(request: u32) {
+    // Waiting for I/O: the span is idle while the future is not polled
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    // CPU-heavy work runs on a blocking thread so it doesn't stall the runtime
+    // The span is created here, so it becomes a child of `fetch_data`
+    let span = info_span!("checksum");
+    let sum = tokio::task::spawn_blocking(move || {
+        span.in_scope(|| checksum(2_000_000 + request as u64 * 500_000))
+    })
+    .await
+    .unwrap();
+
+    info!(sum, "Checksum computed");
+}
 
+fn checksum(n: u64) -> u64 {
+    (0..n).fold(0u64, |acc, x| acc.wrapping_mul(31).wrapping_add(x ^ (x >> 3)))
+}
+
+// Prints min/avg/max and a tiny text histogram of the measured durations
+fn print_summary(durations: &mut [Duration]) {
+    durations.sort();
+    let total: Duration = durations.iter().sum();
+    let avg = total / durations.len() as u32;
+
+    println!(
+        "min={:?} avg={:?} max={:?} p50={:?}",
+        durations[0],
+        avg,
+        durations[durations.len() - 1],
+        durations[durations.len() / 2],
+    );
+
+    for bucket_ms in [60, 80, 100, 150, u64::MAX] {
+        let count = durations
+            .iter()
+            .filter(|d| d.as_millis() as u64 <= bucket_ms)
+            .count();
+        let label = if bucket_ms == u64::MAX {
+            "+Inf".to_string()
+        } else {
+            format!("{}ms", bucket_ms)
+        };
+        println!("<= {:>6} {:<5} {}", label, count, "#".repeat(count));
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,54 @@
+use formats::{sample_orders, Order};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let order = &sample_orders(3)[2];
+
+    let json = serde_json::to_vec(order)?;
+    // `to_vec` writes structs as arrays: field names are left out,
+    // so the reader must use the same field order
+    let compact = rmp_serde::to_vec(order)?;
+    // `to_vec_named` writes structs as maps with field names, like JSON
+    let named = rmp_serde::to_vec_named(order)?;
+
+    println!("JSON:                 {} bytes", json.len());
+    println!("MessagePack (arrays): {} bytes", compact.len());
+    println!("MessagePack (maps):   {} bytes", named.len());
+    println!("First bytes: {:02x?}", &compact[..12]);
+
+    // Both variants can be read back with `from_slice`
+    let from_compact: Order = rmp_serde::from_slice(&compact)?;
+    let from_named: Order = rmp_serde::from_slice(&named)?;
+    assert_eq!(&from_compact, order);
+    assert_eq!(&from_named, order);
+    println!("Decoded: {:?}", from_compact);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[test]
+    fn roundtrip() {
+        for order in sample_orders(10) {
+            let bytes = rmp_serde::to_vec(&order).unwrap();
+            assert_eq!(rmp_serde::from_slice::<Order>(&bytes).unwrap(), order);
+        }
+    }
+
+    #[test]
+    fn named_fields_allow_reading_a_subset() {
+        // Like JSON, a map can be read into a struct with fewer fields
+        #[derive(Deserialize)]
+        struct Summary {
+            id: u64,
+            paid: bool,
+        }
+
+        let order = &sample_orders(1)[0];
+        let bytes = rmp_serde::to_vec_named(order).unwrap();
+        let summary: Summary = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!((summary.id, summary.paid), (order.id, order.paid));
+    }
+}
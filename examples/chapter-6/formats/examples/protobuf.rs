@@ -0,0 +1,66 @@
+use formats::{pb, sample_orders, Order};
+use prost::Message;
+
+fn main() -> Result<(), prost::DecodeError> {
+    let orders = sample_orders(3);
+
+    // Convert to the protobuf types and encode
+    let list = pb::OrderList {
+        orders: orders.iter().map(pb::Order::from).collect(),
+    };
+    let bytes = list.encode_to_vec();
+    println!("Encoded {} orders into {} bytes", orders.len(), bytes.len());
+    println!("First bytes: {:02x?}", &bytes[..12]);
+
+    // Decode and convert back
+    let decoded = pb::OrderList::decode(bytes.as_slice())?;
+    let orders_back: Vec<Order> = decoded.orders.into_iter().map(Order::from).collect();
+    println!("Decoded: {:?}", orders_back[0]);
+    assert_eq!(orders_back, orders);
+
+    // Fields with default values (0, "", false, empty lists) aren't written at all
+    let empty = pb::Order::default();
+    println!("An empty order takes {} bytes", empty.encoded_len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for order in sample_orders(10) {
+            let bytes = pb::Order::from(&order).encode_to_vec();
+            let decoded = pb::Order::decode(bytes.as_slice()).unwrap();
+            assert_eq!(Order::from(decoded), order);
+        }
+    }
+
+    #[test]
+    fn unknown_fields_are_skipped() {
+        // A newer version of the schema added field 5 to `Item`
+        #[derive(Clone, PartialEq, prost::Message)]
+        struct ItemV2 {
+            #[prost(string, tag = "1")]
+            sku: String,
+            #[prost(uint32, tag = "2")]
+            quantity: u32,
+            #[prost(uint64, tag = "3")]
+            price_cents: u64,
+            #[prost(string, tag = "5")]
+            warehouse: String,
+        }
+
+        let new = ItemV2 {
+            sku: "SKU-0001".to_string(),
+            quantity: 2,
+            price_cents: 999,
+            warehouse: "Berlin".to_string(),
+        };
+        // Old code can still read messages written by new code
+        let old = pb::Item::decode(new.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(old.sku, "SKU-0001");
+        assert_eq!(old.quantity, 2);
+    }
+}
@@ -0,0 +1,47 @@
+use formats::{pb, sample_orders, Order};
+use prost::Message;
+
+fn sizes(orders: &[Order]) -> Vec<(&'static str, usize)> {
+    let list = pb::OrderList {
+        orders: orders.iter().map(pb::Order::from).collect(),
+    };
+    vec![
+        ("JSON", serde_json::to_vec(orders).unwrap().len()),
+        (
+            "JSON (pretty)",
+            serde_json::to_vec_pretty(orders).unwrap().len(),
+        ),
+        (
+            "MessagePack (maps)",
+            rmp_serde::to_vec_named(orders).unwrap().len(),
+        ),
+        (
+            "MessagePack (arrays)",
+            rmp_serde::to_vec(orders).unwrap().len(),
+        ),
+        (
+            "bincode",
+            bincode::serde::encode_to_vec(orders, bincode::config::standard())
+                .unwrap()
+                .len(),
+        ),
+        ("Protocol Buffers", list.encode_to_vec().len()),
+    ]
+}
+
+fn main() {
+    for count in [1, 1000] {
+        let results = sizes(&sample_orders(count));
+        let json = results[0].1 as f64;
+
+        println!("{} order(s):", count);
+        for (format, size) in results {
+            println!(
+                "  {:<22} {:>7} bytes  {:>4.0}%",
+                format,
+                size,
+                size as f64 / json * 100.0
+            );
+        }
+    }
+}
@@ -0,0 +1,38 @@
+pub mod pb;
+
+use serde::{Deserialize, Serialize};
+
+// The data we'll write in every format
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Order {
+    pub id: u64,
+    pub customer: String,
+    pub items: Vec<Item>,
+    pub paid: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Item {
+    pub sku: String,
+    pub quantity: u32,
+    pub price_cents: u64,
+}
+
+// Deterministic test data, so sizes are the same on every run
+pub fn sample_orders(count: usize) -> Vec<Order> {
+    let customers = ["Alice", "Bob", "Carol", "Dave"];
+    (0..count as u64)
+        .map(|id| Order {
+            id: 1000 + id,
+            customer: customers[id as usize % customers.len()].to_string(),
+            items: (0..1 + id % 3)
+                .map(|n| Item {
+                    sku: format!("SKU-{:04}", (id * 7 + n) % 500),
+                    quantity: 1 + (id + n) as u32 % 4,
+                    price_cents: 199 + (id * 131 + n * 17) % 5000,
+                })
+                .collect(),
+            paid: id % 5 != 0,
+        })
+        .collect()
+}
@@ -0,0 +1,92 @@
+// Protocol Buffers messages, equivalent to this `orders.proto`:
+//
+// syntax = "proto3";
+// package orders;
+//
+// message Item {
+//   string sku = 1;
+//   uint32 quantity = 2;
+//   uint64 price_cents = 3;
+// }
+//
+// message Order {
+//   uint64 id = 1;
+//   string customer = 2;
+//   repeated Item items = 3;
+//   bool paid = 4;
+// }
+//
+// message OrderList {
+//   repeated Order orders = 1;
+// }
+//
+// In larger projects, `prost-build` generates these structs from the
+// `.proto` file in `build.rs`. Here, they're written out by hand.
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Item {
+    #[prost(string, tag = "1")]
+    pub sku: String,
+    #[prost(uint32, tag = "2")]
+    pub quantity: u32,
+    #[prost(uint64, tag = "3")]
+    pub price_cents: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Order {
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    #[prost(string, tag = "2")]
+    pub customer: String,
+    #[prost(message, repeated, tag = "3")]
+    pub items: Vec<Item>,
+    #[prost(bool, tag = "4")]
+    pub paid: bool,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct OrderList {
+    #[prost(message, repeated, tag = "1")]
+    pub orders: Vec<Order>,
+}
+
+// Conversions between the application types and the wire types keep
+// the protobuf details out of the rest of the code
+impl From<&crate::Order> for Order {
+    fn from(order: &crate::Order) -> Self {
+        Order {
+            id: order.id,
+            customer: order.customer.clone(),
+            items: order
+                .items
+                .iter()
+                .map(|item| Item {
+                    sku: item.sku.clone(),
+                    quantity: item.quantity,
+                    price_cents: item.price_cents,
+                })
+                .collect(),
+            paid: order.paid,
+        }
+    }
+}
+
+impl From<Order> for crate::Order {
+    fn from(order: Order) -> Self {
+        crate::Order {
+            id: order.id,
+            customer: order.customer,
+            items: order
+                .items
+                .into_iter()
+                .map(|item| crate::Item {
+                    sku: item.sku,
+                    quantity: item.quantity,
+                    price_cents: item.price_cents,
+                })
+                .collect(),
+            paid: order.paid,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use json_streaming::Event;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+// Usage: cargo run --release --example generate -- [path] [count]
+fn main() -> std::io::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let path = args.next().unwrap_or_else(|| "events.ndjson".to_string());
+    let count: u64 = args.next().map_or(1_000_000, |n| n.parse().unwrap());
+
+    let levels = ["DEBUG", "INFO", "INFO", "INFO", "WARN", "ERROR"];
+    let services = ["api", "auth", "billing", "db"];
+    let mut rng = StdRng::seed_from_u64(42);
+
+    // Writing line by line keeps the generator's memory constant, too
+    let mut out = BufWriter::new(File::create(&path)?);
+    for i in 0..count {
+        let event = Event {
+            timestamp: 1_700_000_000_000 + i * 17,
+            level: levels[rng.gen_range(0..levels.len())].to_string(),
+            service: services[rng.gen_range(0..services.len())].to_string(),
+            latency_ms: rng.gen_range(1..500),
+            message: format!(
+                "Handled request {} for user {}",
+                i,
+                rng.gen_range(1..10_000)
+            ),
+        };
+        serde_json::to_writer(&mut out, &event)?;
+        out.write_all(b"\n")?;
+    }
+    out.flush()?;
+
+    let size = std::fs::metadata(&path)?.len();
+    println!(
+        "Wrote {} events to {} ({:.1} MB)",
+        count,
+        path,
+        size as f64 / 1e6
+    );
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use json_streaming::{peak_memory_mb, stats_from_lines};
+use std::fs::File;
+use std::io::BufReader;
+use std::time::Instant;
+
+fn main() -> std::io::Result<()> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "events.ndjson".to_string());
+    let start = Instant::now();
+
+    // `BufReader` reads the file in 8 KB blocks
+    let stats = stats_from_lines(BufReader::new(File::open(&path)?))?;
+
+    stats.print();
+    println!("Time: {:.2?}", start.elapsed());
+    if let Some(mb) = peak_memory_mb() {
+        println!("Peak memory: {:.1} MB", mb);
+    }
+    Ok(())
+}
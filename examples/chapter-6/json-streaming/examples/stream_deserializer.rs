@@ -0,0 +1,21 @@
+use json_streaming::{peak_memory_mb, stats_from_stream};
+use std::fs::File;
+use std::io::BufReader;
+use std::time::Instant;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "events.ndjson".to_string());
+    let start = Instant::now();
+
+    // Without `BufReader`, every byte would be a separate system call
+    let stats = stats_from_stream(BufReader::new(File::open(&path)?))?;
+
+    stats.print();
+    println!("Time: {:.2?}", start.elapsed());
+    if let Some(mb) = peak_memory_mb() {
+        println!("Peak memory: {:.1} MB", mb);
+    }
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use json_streaming::{peak_memory_mb, Event, Stats};
+use std::time::Instant;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "events.ndjson".to_string());
+    let start = Instant::now();
+
+    // The whole file as one string, then every event as a struct:
+    // memory grows with the file size
+    let content = std::fs::read_to_string(&path)?;
+    let events: Vec<Event> = content
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<Result<_, _>>()?;
+
+    let mut stats = Stats::default();
+    for event in &events {
+        stats.add(event);
+    }
+
+    stats.print();
+    println!("Time: {:.2?}", start.elapsed());
+    if let Some(mb) = peak_memory_mb() {
+        println!("Peak memory: {:.1} MB", mb);
+    }
+    Ok(())
+}
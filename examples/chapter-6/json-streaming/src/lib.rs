@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, Read};
+
+// One line of the log file
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Event {
+    pub timestamp: u64,
+    pub level: String,
+    pub service: String,
+    pub latency_ms: u32,
+    pub message: String,
+}
+
+// Everything we keep in memory while reading: a few counters,
+// no matter how large the file is
+#[derive(Debug, Default, PartialEq)]
+pub struct Stats {
+    pub events: u64,
+    pub invalid: u64,
+    pub per_level: BTreeMap<String, u64>,
+    pub total_latency_ms: u64,
+    pub max_latency_ms: u32,
+}
+
+impl Stats {
+    pub fn add(&mut self, event: &Event) {
+        self.events += 1;
+        *self.per_level.entry(event.level.clone()).or_default() += 1;
+        self.total_latency_ms += u64::from(event.latency_ms);
+        self.max_latency_ms = self.max_latency_ms.max(event.latency_ms);
+    }
+
+    pub fn print(&self) {
+        println!("Events: {} ({} invalid)", self.events, self.invalid);
+        for (level, count) in &self.per_level {
+            println!("  {:<5} {}", level, count);
+        }
+        if self.events > 0 {
+            println!(
+                "Latency: avg {:.1} ms, max {} ms",
+                self.total_latency_ms as f64 / self.events as f64,
+                self.max_latency_ms
+            );
+        }
+    }
+}
+
+// NDJSON: one JSON document per line. Only the current line is in memory,
+// and an invalid line is skipped without affecting the others.
+pub fn stats_from_lines(reader: impl BufRead) -> io::Result<Stats> {
+    let mut stats = Stats::default();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<Event>(&line) {
+            Ok(event) => stats.add(&event),
+            Err(e) => {
+                eprintln!("Line {}: {}", index + 1, e);
+                stats.invalid += 1;
+            }
+        }
+    }
+    Ok(stats)
+}
+
+// `StreamDeserializer` reads one value after another from any reader.
+// Values may be separated by any whitespace, or by none at all.
+pub fn stats_from_stream(reader: impl Read) -> serde_json::Result<Stats> {
+    let mut stats = Stats::default();
+    let events = serde_json::Deserializer::from_reader(reader).into_iter::<Event>();
+    for event in events {
+        // After a syntax error, the stream can't find the next value,
+        // so the first error ends the whole run
+        stats.add(&event?);
+    }
+    Ok(stats)
+}
+
+// Peak memory used by this process so far, in MB (Linux only)
+pub fn peak_memory_mb() -> Option<f64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kb: f64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kb / 1024.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"{"timestamp":1,"level":"INFO","service":"api","latency_ms":10,"message":"ok"}
+{"timestamp":2,"level":"ERROR","service":"db","latency_ms":250,"message":"timeout"}
+
+{"timestamp":3,"level":"INFO","service":"api","latency_ms":30,"message":"ok"}
+"#;
+
+    #[test]
+    fn lines_and_stream_agree() {
+        let from_lines = stats_from_lines(INPUT.as_bytes()).unwrap();
+        let from_stream = stats_from_stream(INPUT.as_bytes()).unwrap();
+        assert_eq!(from_lines, from_stream);
+        assert_eq!(from_lines.events, 3);
+        assert_eq!(from_lines.per_level["INFO"], 2);
+        assert_eq!(from_lines.max_latency_ms, 250);
+    }
+
+    #[test]
+    fn invalid_lines_are_skipped_by_lines_only() {
+        let input = format!("{}not json\n", INPUT);
+        let stats = stats_from_lines(input.as_bytes()).unwrap();
+        assert_eq!((stats.events, stats.invalid), (3, 1));
+
+        assert!(stats_from_stream(input.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn stream_reads_values_without_newlines() {
+        let input = INPUT.replace('\n', "");
+        assert_eq!(stats_from_stream(input.as_bytes()).unwrap().events, 3);
+    }
+}
@@ -0,0 +1,24 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use zero_copy::{users_json, BorrowedUser, CowUser, OwnedUser};
+
+fn bench_deserialize(c: &mut Criterion) {
+    let json = users_json(10_000);
+
+    let mut group = c.benchmark_group("deserialize_10k_users");
+    group.throughput(Throughput::Bytes(json.len() as u64));
+
+    group.bench_function("owned_string", |b| {
+        b.iter(|| serde_json::from_str::<Vec<OwnedUser>>(black_box(&json)).unwrap())
+    });
+    group.bench_function("borrowed_str", |b| {
+        b.iter(|| serde_json::from_str::<Vec<BorrowedUser>>(black_box(&json)).unwrap())
+    });
+    group.bench_function("cow_str", |b| {
+        b.iter(|| serde_json::from_str::<Vec<CowUser>>(black_box(&json)).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_deserialize);
+criterion_main!(benches);
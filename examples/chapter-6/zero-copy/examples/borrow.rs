@@ -0,0 +1,65 @@
+use std::borrow::Cow;
+use zero_copy::{BorrowedUser, CowUser, OwnedUser};
+
+fn main() {
+    let plain = r#"{"name":"Alice","email":"alice@example.com","age":30}"#;
+    // `\"` is an escape sequence: the parsed string differs from the input bytes
+    let escaped = r#"{"name":"Alice \"Al\" Smith","email":"alice@example.com","age":30}"#;
+
+    let owned: OwnedUser = serde_json::from_str(plain).unwrap();
+    println!("Owned:    {:?}", owned);
+
+    let borrowed: BorrowedUser = serde_json::from_str(plain).unwrap();
+    println!("Borrowed: {:?}", borrowed);
+    // The name points into `plain`; no bytes were copied
+    let offset = borrowed.name.as_ptr() as usize - plain.as_ptr() as usize;
+    println!("name starts at byte {} of the input", offset);
+
+    // A `&str` can't hold the unescaped text, because it doesn't exist in the input
+    match serde_json::from_str::<BorrowedUser>(escaped) {
+        Ok(user) => println!("Borrowed: {:?}", user),
+        Err(e) => println!("Borrowed: error: {}", e),
+    }
+
+    // `Cow` borrows when it can and allocates only when it must
+    let describe = |value: &Cow<str>| match value {
+        Cow::Borrowed(_) => "borrowed",
+        Cow::Owned(_) => "owned",
+    };
+    for input in [plain, escaped] {
+        let user: CowUser = serde_json::from_str(input).unwrap();
+        println!(
+            "Cow:      name {:?} is {}, email is {}",
+            user.name,
+            describe(&user.name),
+            describe(&user.email)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cow_borrows_plain_strings() {
+        let input = r#"{"name":"Bob","email":"bob@example.com","age":41}"#;
+        let user: CowUser = serde_json::from_str(input).unwrap();
+        assert!(matches!(user.name, Cow::Borrowed("Bob")));
+        assert!(matches!(user.email, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn cow_owns_unescaped_strings() {
+        let input = r#"{"name":"Bob\nJr.","email":"bob@example.com","age":41}"#;
+        let user: CowUser = serde_json::from_str(input).unwrap();
+        assert!(matches!(user.name, Cow::Owned(ref name) if name == "Bob\nJr."));
+        assert!(matches!(user.email, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn str_fields_reject_escapes() {
+        let input = r#"{"name":"Bob\nJr.","email":"bob@example.com","age":41}"#;
+        assert!(serde_json::from_str::<BorrowedUser>(input).is_err());
+    }
+}
@@ -0,0 +1,43 @@
+use serde::Deserialize;
+use std::borrow::Cow;
+
+// Every string is copied into a new heap allocation
+#[derive(Debug, Deserialize)]
+pub struct OwnedUser {
+    pub name: String,
+    pub email: String,
+    pub age: u8,
+}
+
+// The strings point into the input, so the struct can't outlive it.
+// `&str` fields are borrowed automatically.
+#[derive(Debug, Deserialize)]
+pub struct BorrowedUser<'a> {
+    pub name: &'a str,
+    pub email: &'a str,
+    pub age: u8,
+}
+
+// Borrows when possible and allocates only when it has to.
+// For `Cow`, borrowing must be requested with `#[serde(borrow)]`.
+#[derive(Debug, Deserialize)]
+pub struct CowUser<'a> {
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    #[serde(borrow)]
+    pub email: Cow<'a, str>,
+    pub age: u8,
+}
+
+// A JSON array with `count` users, without escape sequences
+pub fn users_json(count: usize) -> String {
+    let users: Vec<String> = (0..count)
+        .map(|i| {
+            format!(
+                r#"{{"name":"User number {i}","email":"user{i}@example.com","age":{}}}"#,
+                18 + i % 60
+            )
+        })
+        .collect();
+    format!("[{}]", users.join(","))
+}
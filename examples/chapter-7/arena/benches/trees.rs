@@ -0,0 +1,47 @@
+use arena::{generate, index, rc, typed};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use typed_arena::Arena;
+
+// Building includes dropping the tree, which is part of its cost: the `Rc`
+// tree frees every node separately, the arenas free a few large blocks
+fn bench_build(c: &mut Criterion) {
+    let entries = generate(100_000);
+    let mut group = c.benchmark_group("build");
+    group.bench_function("rc_refcell", |b| {
+        b.iter(|| rc::build(black_box(&entries)).0.size)
+    });
+    group.bench_function("vec_index", |b| {
+        b.iter(|| index::build(black_box(&entries)).0.len())
+    });
+    group.bench_function("typed_arena", |b| {
+        b.iter(|| {
+            let arena = Arena::new();
+            let (root, _) = typed::build(&arena, black_box(&entries));
+            root.size
+        })
+    });
+    group.finish();
+}
+
+fn bench_total_size(c: &mut Criterion) {
+    let entries = generate(100_000);
+    let (rc_root, _rc_nodes) = rc::build(&entries);
+    let (tree, _) = index::build(&entries);
+    let arena = Arena::new();
+    let (typed_root, _) = typed::build(&arena, &entries);
+
+    let mut group = c.benchmark_group("total_size");
+    group.bench_function("rc_refcell", |b| {
+        b.iter(|| black_box(&rc_root).total_size())
+    });
+    group.bench_function("vec_index", |b| {
+        b.iter(|| black_box(&tree).total_size(tree.root()))
+    });
+    group.bench_function("typed_arena", |b| {
+        b.iter(|| black_box(typed_root).total_size())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_build, bench_total_size);
+criterion_main!(benches);
@@ -0,0 +1,161 @@
+//! A tree stored in a single `Vec`. Nodes refer to each other by their
+//! position in the vector, a `NodeId`, instead of by pointers.
+//!
+//! The `Tree` owns all nodes, so there's exactly one owner and no
+//! reference counting, and changing a node only needs `&mut Tree`: the
+//! borrow checker checks everything at compile time again. The price is
+//! that the borrow checker sees the whole tree as one value. While a
+//! reference to one node is alive, no node can be added or changed:
+//!
+//! ```compile_fail,E0502
+//! use arena::index::Tree;
+//!
+//! let mut tree = Tree::new("");
+//! let home = tree.add(tree.root(), "home", 0);
+//! let node = tree.get(home);
+//! // error: cannot borrow `tree` as mutable because it is also borrowed
+//! // as immutable
+//! tree.add(home, "alice", 0);
+//! println!("{}", node.name);
+//! ```
+//!
+//! The fix is to keep the `NodeId`, which is `Copy` and borrows nothing,
+//! and to look the node up again when it's needed.
+
+use crate::Entry;
+
+/// The position of a node in its `Tree`. It's only meaningful for the tree
+/// that created it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(u32);
+
+pub struct Node {
+    pub name: String,
+    pub size: u64,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+pub struct Tree {
+    nodes: Vec<Node>,
+}
+
+impl Tree {
+    pub fn new(root_name: &str) -> Self {
+        Self::with_capacity(root_name, 0)
+    }
+
+    pub fn with_capacity(root_name: &str, capacity: usize) -> Self {
+        let mut nodes = Vec::with_capacity(capacity + 1);
+        nodes.push(Node {
+            name: root_name.to_string(),
+            size: 0,
+            parent: None,
+            children: Vec::new(),
+        });
+        Tree { nodes }
+    }
+
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    pub fn add(&mut self, parent: NodeId, name: &str, size: u64) -> NodeId {
+        let id = NodeId(self.nodes.len() as u32);
+        self.nodes.push(Node {
+            name: name.to_string(),
+            size,
+            parent: Some(parent),
+            children: Vec::new(),
+        });
+        self.nodes[parent.0 as usize].children.push(id);
+        id
+    }
+
+    /// Panics if `id` belongs to another, larger tree
+    pub fn get(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0 as usize]
+    }
+
+    pub fn get_mut(&mut self, id: NodeId) -> &mut Node {
+        &mut self.nodes[id.0 as usize]
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.get(id).parent
+    }
+
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        &self.get(id).children
+    }
+
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = vec![self.get(id).name.as_str()];
+        let mut current = self.parent(id);
+        while let Some(parent) = current {
+            names.push(&self.get(parent).name);
+            current = self.parent(parent);
+        }
+        names.reverse();
+        names.join("/")
+    }
+
+    pub fn total_size(&self, id: NodeId) -> u64 {
+        let node = self.get(id);
+        node.size
+            + node
+                .children
+                .iter()
+                .map(|&child| self.total_size(child))
+                .sum::<u64>()
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+pub fn build(entries: &[Entry]) -> (Tree, Vec<NodeId>) {
+    let mut tree = Tree::with_capacity("", entries.len());
+    let mut ids = Vec::with_capacity(entries.len() + 1);
+    ids.push(tree.root());
+    for entry in entries {
+        let id = tree.add(ids[entry.parent], &entry.name, entry.size);
+        ids.push(id);
+    }
+    (tree, ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nodes_link_to_parents_and_children() {
+        let mut tree = Tree::new("");
+        let home = tree.add(tree.root(), "home", 0);
+        let alice = tree.add(home, "alice", 10);
+        tree.add(alice, "notes.txt", 5);
+
+        assert_eq!(tree.path(alice), "/home/alice");
+        assert_eq!(tree.children(tree.root()), [home]);
+        assert_eq!(tree.total_size(home), 15);
+
+        // Changing a node needs `&mut Tree`, and nothing else
+        tree.get_mut(alice).name = "alicia".to_string();
+        assert_eq!(tree.path(alice), "/home/alicia");
+    }
+
+    #[test]
+    #[should_panic]
+    fn ids_from_another_tree_are_not_checked_at_compile_time() {
+        let mut big = Tree::new("");
+        let id = big.add(big.root(), "only in big", 0);
+        let small = Tree::new("");
+        small.get(id);
+    }
+}
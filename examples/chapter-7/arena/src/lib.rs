@@ -0,0 +1,63 @@
+//! The same directory tree, with parent links, built three ways: with
+//! `Rc`, `RefCell`, and `Weak` as in the `rc_tree` example, in a `Vec`
+//! with indices as links, and in a `typed_arena::Arena` with references
+//! as links.
+
+pub mod index;
+pub mod rc;
+pub mod typed;
+
+/// One entry of a generated tree: the index of the parent entry, a name,
+/// and a size
+pub struct Entry {
+    pub parent: usize,
+    pub name: String,
+    pub size: u64,
+}
+
+/// Generates the entries of a tree with `count` nodes below the root.
+/// Each entry's parent is an earlier entry, or the root at index 0, so the
+/// entries can be added in order. The same `count` always gives the same
+/// tree.
+pub fn generate(count: usize) -> Vec<Entry> {
+    let mut seed: u64 = 7;
+    (1..=count)
+        .map(|i| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            Entry {
+                parent: (seed >> 33) as usize % i,
+                name: format!("node{}", i),
+                size: seed % 10_000,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_trees_agree() {
+        let entries = generate(1_000);
+        let expected: u64 = entries.iter().map(|e| e.size).sum();
+
+        let (rc_root, rc_nodes) = rc::build(&entries);
+        assert_eq!(rc_root.total_size(), expected);
+
+        let (tree, ids) = index::build(&entries);
+        assert_eq!(tree.total_size(tree.root()), expected);
+
+        let arena = typed_arena::Arena::new();
+        let (typed_root, typed_nodes) = typed::build(&arena, &entries);
+        assert_eq!(typed_root.total_size(), expected);
+
+        for i in [1, 500, 1000] {
+            let path = rc_nodes[i].path();
+            assert_eq!(tree.path(ids[i]), path);
+            assert_eq!(typed_nodes[i].path(), path);
+        }
+    }
+}
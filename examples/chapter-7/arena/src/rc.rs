@@ -0,0 +1,69 @@
+//! The tree from the `rc_tree` example: every node is a separate
+//! allocation with a reference count, and children are added through a
+//! `RefCell`.
+
+use crate::Entry;
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+pub struct Node {
+    pub name: String,
+    pub size: u64,
+    parent: RefCell<Weak<Node>>,
+    children: RefCell<Vec<Rc<Node>>>,
+}
+
+impl Node {
+    pub fn new_root(name: &str) -> Rc<Node> {
+        Rc::new(Node {
+            name: name.to_string(),
+            size: 0,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(Vec::new()),
+        })
+    }
+
+    pub fn add_child(parent: &Rc<Node>, name: &str, size: u64) -> Rc<Node> {
+        let child = Rc::new(Node {
+            name: name.to_string(),
+            size,
+            parent: RefCell::new(Rc::downgrade(parent)),
+            children: RefCell::new(Vec::new()),
+        });
+        parent.children.borrow_mut().push(Rc::clone(&child));
+        child
+    }
+
+    pub fn path(&self) -> String {
+        let mut names = vec![self.name.clone()];
+        let mut current = self.parent.borrow().upgrade();
+        while let Some(node) = current {
+            names.push(node.name.clone());
+            current = node.parent.borrow().upgrade();
+        }
+        names.reverse();
+        names.join("/")
+    }
+
+    pub fn total_size(&self) -> u64 {
+        self.size
+            + self
+                .children
+                .borrow()
+                .iter()
+                .map(|child| child.total_size())
+                .sum::<u64>()
+    }
+}
+
+/// Builds the tree and returns the root and every node, in the order of
+/// the entries, with the root first
+pub fn build(entries: &[Entry]) -> (Rc<Node>, Vec<Rc<Node>>) {
+    let root = Node::new_root("");
+    let mut nodes = vec![Rc::clone(&root)];
+    for entry in entries {
+        let node = Node::add_child(&nodes[entry.parent], &entry.name, entry.size);
+        nodes.push(node);
+    }
+    (root, nodes)
+}
@@ -0,0 +1,82 @@
+//! A tree in a `typed_arena::Arena`. The arena hands out `&'a Node`
+//! references that live as long as the arena, and frees all nodes at once
+//! when the arena is dropped.
+//!
+//! Because no node is freed before the others, nodes can point at each
+//! other with plain references, even in cycles like parent and child.
+//! There's no reference counting and no `Weak`. A node's parent exists
+//! before the node, so it's a plain reference set once by the constructor.
+//! The list of children grows after the node is shared, so it still needs
+//! a `RefCell`.
+
+use crate::Entry;
+use std::cell::RefCell;
+use typed_arena::Arena;
+
+pub struct Node<'a> {
+    pub name: String,
+    pub size: u64,
+    parent: Option<&'a Node<'a>>,
+    children: RefCell<Vec<&'a Node<'a>>>,
+}
+
+impl<'a> Node<'a> {
+    pub fn new_root(arena: &'a Arena<Node<'a>>, name: &str) -> &'a Node<'a> {
+        arena.alloc(Node {
+            name: name.to_string(),
+            size: 0,
+            parent: None,
+            children: RefCell::new(Vec::new()),
+        })
+    }
+
+    pub fn add_child(&'a self, arena: &'a Arena<Node<'a>>, name: &str, size: u64) -> &'a Node<'a> {
+        let child = arena.alloc(Node {
+            name: name.to_string(),
+            size,
+            parent: Some(self),
+            children: RefCell::new(Vec::new()),
+        });
+        self.children.borrow_mut().push(child);
+        child
+    }
+
+    pub fn parent(&self) -> Option<&'a Node<'a>> {
+        self.parent
+    }
+
+    pub fn path(&self) -> String {
+        let mut names = vec![self.name.as_str()];
+        let mut current = self.parent();
+        while let Some(node) = current {
+            names.push(&node.name);
+            current = node.parent();
+        }
+        names.reverse();
+        names.join("/")
+    }
+
+    pub fn total_size(&self) -> u64 {
+        self.size
+            + self
+                .children
+                .borrow()
+                .iter()
+                .map(|child| child.total_size())
+                .sum::<u64>()
+    }
+}
+
+pub fn build<'a>(
+    arena: &'a Arena<Node<'a>>,
+    entries: &[Entry],
+) -> (&'a Node<'a>, Vec<&'a Node<'a>>) {
+    let root = Node::new_root(arena, "");
+    let mut nodes = Vec::with_capacity(entries.len() + 1);
+    nodes.push(root);
+    for entry in entries {
+        let node = nodes[entry.parent].add_child(arena, &entry.name, entry.size);
+        nodes.push(node);
+    }
+    (root, nodes)
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// Counts words in several documents, one thread per document.
+//
+// `Rc` can't be used here: its reference count isn't updated atomically,
+// so it doesn't implement `Send`, and the compiler refuses to move it into
+// another thread. `Arc` does the same job with atomic operations.
+//
+// `Arc` only gives shared, read-only access. To modify the counts, they're
+// wrapped in a `Mutex`, the thread-safe counterpart of `RefCell`: instead of
+// panicking on a conflicting borrow, `lock` waits until the other thread is done.
+fn count_words(documents: Vec<String>) -> HashMap<String, usize> {
+    let documents = Arc::new(documents);
+    let counts = Arc::new(Mutex::new(HashMap::new()));
+
+    let handles: Vec<_> = (0..documents.len())
+        .map(|i| {
+            // Each thread gets its own handle. Cloning an `Arc` only
+            // increments the count; the data isn't copied.
+            let documents = Arc::clone(&documents);
+            let counts = Arc::clone(&counts);
+            thread::spawn(move || {
+                // Count locally first, so the lock is held only briefly
+                let mut local: HashMap<String, usize> = HashMap::new();
+                for word in documents[i].split_whitespace() {
+                    *local.entry(word.to_lowercase()).or_default() += 1;
+                }
+                let mut counts = counts.lock().unwrap();
+                for (word, n) in local {
+                    *counts.entry(word).or_default() += n;
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    // All threads are finished, so this is the last reference and the
+    // counts can be moved out of the `Arc` and the `Mutex`
+    Arc::try_unwrap(counts)
+        .expect("all threads have finished")
+        .into_inner()
+        .unwrap()
+}
+
+fn main() {
+    let documents = vec![
+        "the quick brown fox".to_string(),
+        "The lazy dog".to_string(),
+        "the fox and the dog".to_string(),
+    ];
+    let counts = count_words(documents);
+
+    let mut sorted: Vec<_> = counts.into_iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    for (word, n) in sorted.iter().take(3) {
+        println!("{}: {}", word, n);
+    }
+
+    let config = Arc::new(String::from("shared settings"));
+    let copies: Vec<_> = (0..3).map(|_| Arc::clone(&config)).collect();
+    println!("References to config: {}", Arc::strong_count(&config));
+    drop(copies);
+    println!("After dropping the copies: {}", Arc::strong_count(&config));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_words_from_all_threads() {
+        let counts = count_words(vec![
+            "a b a".to_string(),
+            "B c".to_string(),
+            "a".to_string(),
+        ]);
+        assert_eq!(counts["a"], 3);
+        assert_eq!(counts["b"], 2);
+        assert_eq!(counts["c"], 1);
+    }
+
+    #[test]
+    fn no_documents_means_no_words() {
+        assert!(count_words(Vec::new()).is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt;
+
+// Without the `Box`, `List` would contain a `List`, which contains a `List`,
+// and so on: the compiler couldn't compute its size. A `Box` is a pointer
+// with a fixed size, and the next node lives on the heap.
+enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+use List::{Cons, Nil};
+
+impl List {
+    fn new() -> Self {
+        Nil
+    }
+
+    // Takes ownership of the list and returns a longer one. Only the
+    // pointer to the old list is moved, not its nodes.
+    fn prepend(self, value: i32) -> Self {
+        Cons(value, Box::new(self))
+    }
+
+    fn iter(&self) -> Iter<'_> {
+        Iter { next: self }
+    }
+}
+
+struct Iter<'a> {
+    next: &'a List,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        match self.next {
+            Cons(value, rest) => {
+                self.next = rest;
+                Some(*value)
+            }
+            Nil => None,
+        }
+    }
+}
+
+impl fmt::Display for List {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for value in self.iter() {
+            write!(f, "{} -> ", value)?;
+        }
+        write!(f, "Nil")
+    }
+}
+
+// The automatically generated drop is recursive: dropping a node drops its
+// box, which drops the next node, and so on. A list with a million nodes
+// would overflow the stack, so we unlink the nodes one by one in a loop.
+impl Drop for List {
+    fn drop(&mut self) {
+        if let Cons(_, next) = self {
+            // Detach the rest of the list, then free it node by node. Each
+            // detached node has `Nil` as its tail, so its own drop stops here.
+            let mut rest = std::mem::replace(&mut **next, Nil);
+            while let Cons(_, next) = &mut rest {
+                rest = std::mem::replace(&mut **next, Nil);
+            }
+        }
+    }
+}
+
+fn main() {
+    let list = List::new().prepend(3).prepend(2).prepend(1);
+    println!("{}", list);
+    println!("Sum: {}", list.iter().sum::<i32>());
+
+    // The `Box` itself is just a pointer, whatever the size of the list
+    println!("Size of List: {} bytes", std::mem::size_of::<List>());
+    println!(
+        "Size of Box<List>: {} bytes",
+        std::mem::size_of::<Box<List>>()
+    );
+
+    let long = (0..1_000_000).fold(List::new(), List::prepend);
+    println!("Long list has {} nodes", long.iter().count());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_and_iterates() {
+        let list = List::new().prepend(3).prepend(2).prepend(1);
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> Nil");
+    }
+
+    #[test]
+    fn dropping_a_long_list_does_not_overflow_the_stack() {
+        let list = (0..1_000_000).fold(List::new(), List::prepend);
+        assert_eq!(list.iter().count(), 1_000_000);
+        drop(list);
+    }
+}
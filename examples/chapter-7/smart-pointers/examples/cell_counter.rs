@@ -0,0 +1,100 @@
+use std::cell::{Cell, RefCell};
+
+// Counts how often a lookup is called. `lookup` only takes `&self`, so the
+// compiler would reject `self.calls += 1`. A `Cell` allows changing a value
+// behind a shared reference.
+//
+// `Cell` never hands out references to its contents: `get` copies the value
+// out and `set` replaces it. With no references, there's nothing to check,
+// so unlike `RefCell`, a `Cell` has no runtime cost and can never panic.
+// The price is that it only works well with `Copy` types like numbers.
+struct Dictionary {
+    words: Vec<(&'static str, &'static str)>,
+    calls: Cell<u32>,
+    misses: Cell<u32>,
+    // For non-`Copy` data, `RefCell` is needed. It checks the borrowing
+    // rules at runtime and panics if they're broken.
+    history: RefCell<Vec<String>>,
+}
+
+impl Dictionary {
+    fn new(words: Vec<(&'static str, &'static str)>) -> Self {
+        Dictionary {
+            words,
+            calls: Cell::new(0),
+            misses: Cell::new(0),
+            history: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn lookup(&self, word: &str) -> Option<&'static str> {
+        self.calls.set(self.calls.get() + 1);
+        self.history.borrow_mut().push(word.to_string());
+
+        let found = self.words.iter().find(|(w, _)| *w == word).map(|(_, t)| *t);
+        if found.is_none() {
+            self.misses.set(self.misses.get() + 1);
+        }
+        found
+    }
+
+    fn stats(&self) -> String {
+        format!(
+            "{} lookups, {} misses, last: {:?}",
+            self.calls.get(),
+            self.misses.get(),
+            self.history.borrow().last()
+        )
+    }
+}
+
+fn main() {
+    let dictionary = Dictionary::new(vec![("hello", "hola"), ("cat", "gato")]);
+
+    // Several shared references to the same dictionary, all able to count
+    let a = &dictionary;
+    let b = &dictionary;
+    println!("hello -> {:?}", a.lookup("hello"));
+    println!("dog -> {:?}", b.lookup("dog"));
+    println!("cat -> {:?}", a.lookup("cat"));
+    println!("{}", dictionary.stats());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_calls_through_shared_references() {
+        let dictionary = Dictionary::new(vec![("one", "uno")]);
+        let shared = &dictionary;
+        shared.lookup("one");
+        shared.lookup("two");
+        dictionary.lookup("three");
+        assert_eq!(dictionary.calls.get(), 3);
+        assert_eq!(dictionary.misses.get(), 2);
+        assert_eq!(
+            dictionary.stats(),
+            "3 lookups, 2 misses, last: Some(\"three\")"
+        );
+    }
+
+    #[test]
+    fn cell_values_can_be_updated_while_read() {
+        // There are no references into a `Cell`, so this can't conflict
+        let counter = Cell::new(1);
+        let before = counter.get();
+        counter.set(before + 1);
+        assert_eq!((before, counter.get()), (1, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "already borrowed")]
+    fn double_borrow_of_refcell_panics() {
+        let dictionary = Dictionary::new(vec![("one", "uno")]);
+        let _history = dictionary.history.borrow();
+        // `lookup` needs to borrow the history mutably while we still hold
+        // a shared borrow. The compiler can't see it; the `RefCell` can.
+        dictionary.lookup("one");
+    }
+}
@@ -0,0 +1,136 @@
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+// A directory tree. Every directory is shared by its parent and by anyone
+// who holds a handle to it, so nodes are `Rc`. Children are added after
+// a node is created and shared, so the lists are in a `RefCell`.
+//
+// Parents own their children with strong references. Children point back
+// with `Weak` references: two strong references pointing at each other
+// would keep both nodes alive forever, leaking memory.
+struct Dir {
+    name: String,
+    parent: RefCell<Weak<Dir>>,
+    children: RefCell<Vec<Rc<Dir>>>,
+}
+
+impl Dir {
+    fn new(name: &str) -> Rc<Dir> {
+        Rc::new(Dir {
+            name: name.to_string(),
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(Vec::new()),
+        })
+    }
+
+    // Takes `&Rc<Dir>` rather than `&self`, because the child needs
+    // a `Weak` pointer to the `Rc`, not a reference to the `Dir`
+    fn add_child(parent: &Rc<Dir>, name: &str) -> Rc<Dir> {
+        let child = Dir::new(name);
+        *child.parent.borrow_mut() = Rc::downgrade(parent);
+        parent.children.borrow_mut().push(Rc::clone(&child));
+        child
+    }
+
+    // Walks up the parent links. `upgrade` returns `None` when the parent
+    // has already been dropped.
+    fn path(&self) -> String {
+        match self.parent.borrow().upgrade() {
+            Some(parent) => {
+                let parent_path = parent.path();
+                if parent_path.ends_with('/') {
+                    format!("{}{}", parent_path, self.name)
+                } else {
+                    format!("{}/{}", parent_path, self.name)
+                }
+            }
+            None => self.name.clone(),
+        }
+    }
+
+    fn print(&self, depth: usize) {
+        println!("{}{}", "  ".repeat(depth), self.name);
+        for child in self.children.borrow().iter() {
+            child.print(depth + 1);
+        }
+    }
+}
+
+fn main() {
+    let root = Dir::new("/");
+    let home = Dir::add_child(&root, "home");
+    let alice = Dir::add_child(&home, "alice");
+    Dir::add_child(&alice, "projects");
+    Dir::add_child(&root, "tmp");
+
+    root.print(0);
+    println!("Path of alice: {}", alice.path());
+
+    // `home` is owned by `root` and by our local variable
+    println!(
+        "home: strong = {}, weak = {}",
+        Rc::strong_count(&home),
+        Rc::weak_count(&home)
+    );
+
+    // The borrow checker can't see inside a `RefCell`. The rules are checked
+    // at runtime instead, and breaking them is reported as an error here.
+    let children = root.children.borrow();
+    match root.children.try_borrow_mut() {
+        Ok(_) => println!("Borrowed mutably"),
+        Err(e) => println!("Can't modify children while they're being read: {}", e),
+    }
+    drop(children);
+
+    // Dropping the last strong reference to the root frees it, even though
+    // its children still point at it
+    drop(root);
+    println!("Path of home after dropping the root: {}", home.path());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_paths_from_parent_links() {
+        let root = Dir::new("/");
+        let usr = Dir::add_child(&root, "usr");
+        let bin = Dir::add_child(&usr, "bin");
+        assert_eq!(root.path(), "/");
+        assert_eq!(usr.path(), "/usr");
+        assert_eq!(bin.path(), "/usr/bin");
+        assert_eq!(root.children.borrow().len(), 1);
+    }
+
+    #[test]
+    fn weak_parent_links_do_not_keep_the_parent_alive() {
+        let root = Dir::new("/");
+        let etc = Dir::add_child(&root, "etc");
+        assert_eq!(Rc::strong_count(&root), 1);
+        assert_eq!(Rc::weak_count(&root), 1);
+
+        drop(root);
+        assert!(etc.parent.borrow().upgrade().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "already borrowed")]
+    fn mutable_borrow_while_reading_panics() {
+        let root = Dir::new("/");
+        Dir::add_child(&root, "var");
+
+        // Compiles fine, but breaks the borrowing rules at runtime
+        for _child in root.children.borrow().iter() {
+            Dir::add_child(&root, "oops");
+        }
+    }
+
+    #[test]
+    fn try_borrow_mut_reports_the_conflict() {
+        let root = Dir::new("/");
+        let _reading = root.children.borrow();
+        assert!(root.children.try_borrow_mut().is_err());
+        assert!(root.children.try_borrow().is_ok());
+    }
+}
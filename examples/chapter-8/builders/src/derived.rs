@@ -0,0 +1,35 @@
+//! The builder generated by `#[derive(Builder)]` on `HttpRequest`.
+//!
+//! The macro writes the struct with an `Option` for every field, a setter
+//! for every field, and `build`, which returns an error for fields without
+//! a value or a default. We only add what it can't know: a setter that
+//! adds one header at a time, and the validation.
+
+use crate::{check_body, check_headers, check_url, BuildError};
+
+pub use crate::DerivedRequestBuilder;
+
+impl DerivedRequestBuilder {
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers
+            .get_or_insert_with(Vec::new)
+            .push((name.into(), value.into()));
+        self
+    }
+
+    // Called by the generated `build` before it creates the request. The
+    // fields are still `Option`s here, because any of them may be unset.
+    pub(crate) fn validate(&self) -> Result<(), BuildError> {
+        if let Some(url) = &self.url {
+            check_url(url)?;
+        }
+        if let Some(headers) = &self.headers {
+            check_headers(headers)?;
+        }
+        // `body` is an `Option<Option<String>>`: unset, or set to a value
+        check_body(
+            self.method.unwrap_or_default(),
+            self.body.as_ref().and_then(Option::as_ref),
+        )
+    }
+}
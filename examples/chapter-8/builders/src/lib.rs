@@ -0,0 +1,112 @@
+//! The same `HttpRequest` builder written three ways: by hand, generated
+//! with `derive_builder`, and as a typestate builder that turns some
+//! mistakes into compile errors.
+
+pub mod derived;
+pub mod manual;
+pub mod typestate;
+
+use derive_builder::{Builder, UninitializedFieldError};
+use std::fmt;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Method {
+    #[default]
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+impl Method {
+    /// GET and DELETE requests have no body
+    pub fn allows_body(self) -> bool {
+        matches!(self, Method::Post | Method::Put)
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Delete => "DELETE",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The request all three builders produce.
+///
+/// `#[derive(Builder)]` generates `DerivedRequestBuilder`, see the
+/// `derived` module. The attributes only affect the generated builder;
+/// `HttpRequest` itself is an ordinary struct.
+#[derive(Debug, Clone, PartialEq, Builder)]
+#[builder(
+    name = "DerivedRequestBuilder",
+    pattern = "owned",
+    build_fn(validate = "Self::validate", error = "BuildError")
+)]
+pub struct HttpRequest {
+    #[builder(default)]
+    pub method: Method,
+    #[builder(setter(into))]
+    pub url: String,
+    // Set one at a time with the custom `header` setter
+    #[builder(setter(custom), default)]
+    pub headers: Vec<(String, String)>,
+    #[builder(setter(into, strip_option), default)]
+    pub body: Option<String>,
+    #[builder(default = "Duration::from_secs(30)")]
+    pub timeout: Duration,
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum BuildError {
+    #[error("{0} is required")]
+    MissingField(String),
+    #[error("'{0}' is not an http:// or https:// URL")]
+    InvalidUrl(String),
+    #[error("'{0}' is not a valid header name")]
+    InvalidHeader(String),
+    #[error("{0} requests can't have a body")]
+    BodyNotAllowed(Method),
+}
+
+// Lets the generated `build` report a field that was never set
+impl From<UninitializedFieldError> for BuildError {
+    fn from(error: UninitializedFieldError) -> Self {
+        BuildError::MissingField(error.field_name().to_string())
+    }
+}
+
+// The rules every builder checks at runtime
+fn check_url(url: &str) -> Result<(), BuildError> {
+    match url.split_once("://") {
+        Some(("http" | "https", rest)) if !rest.is_empty() => Ok(()),
+        _ => Err(BuildError::InvalidUrl(url.to_string())),
+    }
+}
+
+fn check_headers(headers: &[(String, String)]) -> Result<(), BuildError> {
+    for (name, _) in headers {
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(BuildError::InvalidHeader(name.clone()));
+        }
+    }
+    Ok(())
+}
+
+fn check_body(method: Method, body: Option<&String>) -> Result<(), BuildError> {
+    if body.is_some() && !method.allows_body() {
+        return Err(BuildError::BodyNotAllowed(method));
+    }
+    Ok(())
+}
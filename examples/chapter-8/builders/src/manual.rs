@@ -0,0 +1,64 @@
+use crate::{check_body, check_headers, check_url, BuildError, HttpRequest, Method};
+use std::time::Duration;
+
+/// A hand-written builder.
+///
+/// Every field is optional until `build`, which fills in the defaults and
+/// checks everything at once. The setters take `self` by value and return
+/// it, so calls can be chained.
+#[derive(Debug, Clone, Default)]
+pub struct RequestBuilder {
+    method: Method,
+    url: Option<String>,
+    headers: Vec<(String, String)>,
+    body: Option<String>,
+    timeout: Option<Duration>,
+}
+
+impl RequestBuilder {
+    pub fn new() -> Self {
+        RequestBuilder::default()
+    }
+
+    pub fn method(mut self, method: Method) -> Self {
+        self.method = method;
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Can be called several times; each call adds a header
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn build(self) -> Result<HttpRequest, BuildError> {
+        let url = self
+            .url
+            .ok_or_else(|| BuildError::MissingField("url".to_string()))?;
+        check_url(&url)?;
+        check_headers(&self.headers)?;
+        check_body(self.method, self.body.as_ref())?;
+        Ok(HttpRequest {
+            method: self.method,
+            url,
+            headers: self.headers,
+            body: self.body,
+            timeout: self.timeout.unwrap_or(Duration::from_secs(30)),
+        })
+    }
+}
@@ -0,0 +1,150 @@
+//! A typestate builder: the builder's type records which methods have
+//! been called, and each state only has the methods that are allowed in
+//! it.
+//!
+//! A request without a URL has no `build` method:
+//!
+//! ```compile_fail,E0599
+//! use builders::typestate::RequestBuilder;
+//!
+//! let request = RequestBuilder::new().header("Accept", "text/html").build();
+//! ```
+//!
+//! A GET request has no `body` method:
+//!
+//! ```compile_fail,E0599
+//! use builders::typestate::RequestBuilder;
+//!
+//! let request = RequestBuilder::new()
+//!     .get("https://example.com")
+//!     .body("hello")
+//!     .build();
+//! ```
+//!
+//! And the method and URL can only be set once:
+//!
+//! ```compile_fail,E0599
+//! use builders::typestate::RequestBuilder;
+//!
+//! let request = RequestBuilder::new()
+//!     .get("https://example.com")
+//!     .post("https://example.org")
+//!     .build();
+//! ```
+
+use crate::{check_headers, check_url, BuildError, HttpRequest, Method};
+use std::marker::PhantomData;
+use std::time::Duration;
+
+// The states. They are never created, only used as type parameters.
+
+/// No method and URL yet
+pub struct NoUrl;
+/// A GET or DELETE request
+pub struct WithoutBody;
+/// A POST or PUT request, which may have a body
+pub struct WithBody;
+
+// Only the states with a URL implement `HasUrl`. The trait is public in a
+// private module, so other crates can't implement it for their own types.
+mod sealed {
+    pub trait HasUrl {}
+    impl HasUrl for super::WithoutBody {}
+    impl HasUrl for super::WithBody {}
+}
+
+pub struct RequestBuilder<S> {
+    method: Method,
+    url: String,
+    headers: Vec<(String, String)>,
+    // Not called `body`, so the compiler's error for `.body()` on a GET
+    // request isn't confused by a private field of the same name
+    request_body: Option<String>,
+    timeout: Duration,
+    // Uses `S` without storing a value of it, so the state costs nothing
+    state: PhantomData<S>,
+}
+
+impl RequestBuilder<NoUrl> {
+    pub fn new() -> Self {
+        RequestBuilder {
+            method: Method::Get,
+            url: String::new(),
+            headers: Vec::new(),
+            request_body: None,
+            timeout: Duration::from_secs(30),
+            state: PhantomData,
+        }
+    }
+
+    pub fn get(self, url: impl Into<String>) -> RequestBuilder<WithoutBody> {
+        self.with_url(Method::Get, url.into())
+    }
+
+    pub fn delete(self, url: impl Into<String>) -> RequestBuilder<WithoutBody> {
+        self.with_url(Method::Delete, url.into())
+    }
+
+    pub fn post(self, url: impl Into<String>) -> RequestBuilder<WithBody> {
+        self.with_url(Method::Post, url.into())
+    }
+
+    pub fn put(self, url: impl Into<String>) -> RequestBuilder<WithBody> {
+        self.with_url(Method::Put, url.into())
+    }
+
+    // A transition: the same data in a builder of another type
+    fn with_url<T>(self, method: Method, url: String) -> RequestBuilder<T> {
+        RequestBuilder {
+            method,
+            url,
+            headers: self.headers,
+            request_body: self.request_body,
+            timeout: self.timeout,
+            state: PhantomData,
+        }
+    }
+}
+
+impl Default for RequestBuilder<NoUrl> {
+    fn default() -> Self {
+        RequestBuilder::new()
+    }
+}
+
+// Allowed in every state
+impl<S> RequestBuilder<S> {
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl RequestBuilder<WithBody> {
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.request_body = Some(body.into());
+        self
+    }
+}
+
+impl<S: sealed::HasUrl> RequestBuilder<S> {
+    /// The types guarantee a URL and no body on a GET request, but they
+    /// can't check what the strings contain, so `build` still returns a
+    /// `Result`
+    pub fn build(self) -> Result<HttpRequest, BuildError> {
+        check_url(&self.url)?;
+        check_headers(&self.headers)?;
+        Ok(HttpRequest {
+            method: self.method,
+            url: self.url,
+            headers: self.headers,
+            body: self.request_body,
+            timeout: self.timeout,
+        })
+    }
+}
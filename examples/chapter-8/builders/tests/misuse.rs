@@ -0,0 +1,145 @@
+//! The same mistakes made with each builder. The typestate builder's
+//! compile errors are checked by the doc tests in `src/typestate.rs`.
+
+use builders::derived::DerivedRequestBuilder;
+use builders::manual::RequestBuilder as ManualBuilder;
+use builders::typestate::RequestBuilder as TypedBuilder;
+use builders::{BuildError, HttpRequest, Method};
+use std::time::Duration;
+
+fn expected() -> HttpRequest {
+    HttpRequest {
+        method: Method::Post,
+        url: "https://example.com/users".to_string(),
+        headers: vec![
+            ("Content-Type".to_string(), "application/json".to_string()),
+            ("Accept".to_string(), "application/json".to_string()),
+        ],
+        body: Some(r#"{"name":"Alice"}"#.to_string()),
+        timeout: Duration::from_secs(5),
+    }
+}
+
+#[test]
+fn all_builders_build_the_same_request() {
+    let manual = ManualBuilder::new()
+        .method(Method::Post)
+        .url("https://example.com/users")
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .body(r#"{"name":"Alice"}"#)
+        .timeout(Duration::from_secs(5))
+        .build();
+    let derived = DerivedRequestBuilder::default()
+        .method(Method::Post)
+        .url("https://example.com/users")
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .body(r#"{"name":"Alice"}"#)
+        .timeout(Duration::from_secs(5))
+        .build();
+    let typed = TypedBuilder::new()
+        .post("https://example.com/users")
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .body(r#"{"name":"Alice"}"#)
+        .timeout(Duration::from_secs(5))
+        .build();
+
+    assert_eq!(manual, Ok(expected()));
+    assert_eq!(derived, Ok(expected()));
+    assert_eq!(typed, Ok(expected()));
+}
+
+#[test]
+fn defaults_are_the_same() {
+    let manual = ManualBuilder::new()
+        .url("http://localhost")
+        .build()
+        .unwrap();
+    let derived = DerivedRequestBuilder::default()
+        .url("http://localhost")
+        .build()
+        .unwrap();
+    let typed = TypedBuilder::new().get("http://localhost").build().unwrap();
+    assert_eq!(manual, derived);
+    assert_eq!(manual, typed);
+    assert_eq!(
+        (manual.method, manual.timeout),
+        (Method::Get, Duration::from_secs(30))
+    );
+}
+
+// Caught at runtime by the manual and derived builders; the typestate
+// builder has no `build` method without a URL
+#[test]
+fn missing_url() {
+    let missing = Err(BuildError::MissingField("url".to_string()));
+    assert_eq!(ManualBuilder::new().build(), missing);
+    assert_eq!(DerivedRequestBuilder::default().build(), missing);
+}
+
+// Caught at runtime by the manual and derived builders; the typestate
+// builder has no `body` method for GET requests
+#[test]
+fn body_on_a_get_request() {
+    let not_allowed = Err(BuildError::BodyNotAllowed(Method::Get));
+    let manual = ManualBuilder::new()
+        .url("https://example.com")
+        .body("hello")
+        .build();
+    let derived = DerivedRequestBuilder::default()
+        .url("https://example.com")
+        .body("hello")
+        .build();
+    assert_eq!(manual, not_allowed);
+    assert_eq!(derived, not_allowed);
+}
+
+// Not caught by the manual and derived builders: the second call silently
+// wins. The typestate builder only has `get` and `post` before a URL is set.
+#[test]
+fn url_set_twice() {
+    let manual = ManualBuilder::new()
+        .url("https://example.com")
+        .url("https://example.org")
+        .build()
+        .unwrap();
+    let derived = DerivedRequestBuilder::default()
+        .url("https://example.com")
+        .url("https://example.org")
+        .build()
+        .unwrap();
+    assert_eq!(manual.url, "https://example.org");
+    assert_eq!(derived.url, "https://example.org");
+}
+
+// The contents of strings can only be checked at runtime, by all three
+#[test]
+fn invalid_values() {
+    let invalid_url = Err(BuildError::InvalidUrl("example.com".to_string()));
+    assert_eq!(ManualBuilder::new().url("example.com").build(), invalid_url);
+    assert_eq!(
+        DerivedRequestBuilder::default().url("example.com").build(),
+        invalid_url
+    );
+    assert_eq!(TypedBuilder::new().get("example.com").build(), invalid_url);
+
+    let invalid_header = Err(BuildError::InvalidHeader("X Trace".to_string()));
+    let url = "https://example.com";
+    assert_eq!(
+        ManualBuilder::new().url(url).header("X Trace", "1").build(),
+        invalid_header
+    );
+    assert_eq!(
+        DerivedRequestBuilder::default()
+            .url(url)
+            .header("X Trace", "1")
+            .build(),
+        invalid_header
+    );
+    assert_eq!(
+        TypedBuilder::new().get(url).header("X Trace", "1").build(),
+        invalid_header
+    );
+}
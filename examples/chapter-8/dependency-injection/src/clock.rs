@@ -0,0 +1,42 @@
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Mutex;
+
+/// The current time. Code that calls `Utc::now()` directly can only be
+/// tested at the time the test happens to run.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The real time
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock for tests that only moves when it is told to
+pub struct FixedClock {
+    // A `Mutex`, because `advance` changes the time through `&self`,
+    // while the service holds another reference to the clock
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl FixedClock {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        FixedClock {
+            now: Mutex::new(now),
+        }
+    }
+
+    pub fn advance(&self, by: Duration) {
+        *self.now.lock().unwrap() += by;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
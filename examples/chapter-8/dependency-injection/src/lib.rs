@@ -0,0 +1,15 @@
+//! A user service whose dependencies, the clock, the mailer, and the user
+//! storage, are traits. The program passes in the real implementations,
+//! and the tests pass in simple in-memory ones.
+
+mod clock;
+mod mailer;
+mod repo;
+mod service;
+mod sqlite;
+
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use mailer::{MailError, Mailer, OutboxMailer, RecordingMailer, SentMail};
+pub use repo::{InMemoryUserRepo, RepoError, User, UserRepo};
+pub use service::{ServiceError, UserService};
+pub use sqlite::SqliteUserRepo;
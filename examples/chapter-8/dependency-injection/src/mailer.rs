@@ -0,0 +1,185 @@
+use newtypes::Email;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+#[error("failed to send email to {to}: {reason}")]
+pub struct MailError {
+    pub to: Email,
+    pub reason: String,
+}
+
+pub trait Mailer: Send + Sync {
+    fn send(&self, to: &Email, subject: &str, body: &str) -> Result<(), MailError>;
+}
+
+/// Writes every message to a file in a directory, where another process
+/// or a person can pick it up. A production mailer would talk to an SMTP
+/// server or an email API instead, behind the same trait.
+pub struct OutboxMailer {
+    dir: PathBuf,
+    count: AtomicUsize,
+}
+
+impl OutboxMailer {
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        // Continues after the highest number in the directory, so a file
+        // that was picked up and deleted doesn't free its number
+        let mut count = 0;
+        for entry in fs::read_dir(&dir)? {
+            let name = entry?.file_name();
+            let number = name.to_str().and_then(|name| name.split_once('-'));
+            if let Some(Ok(n)) = number.map(|(n, _)| n.parse::<usize>()) {
+                count = count.max(n);
+            }
+        }
+        Ok(OutboxMailer {
+            dir,
+            count: AtomicUsize::new(count),
+        })
+    }
+}
+
+impl Mailer for OutboxMailer {
+    fn send(&self, to: &Email, subject: &str, body: &str) -> Result<(), MailError> {
+        let message = format!("To: {}\nSubject: {}\n\n{}\n", to, subject, body);
+        let error = |e: io::Error| MailError {
+            to: to.clone(),
+            reason: e.to_string(),
+        };
+        // `create_new` fails instead of overwriting, so a number that
+        // another process took in the meantime is skipped
+        let mut file = loop {
+            let n = self.count.fetch_add(1, Ordering::SeqCst) + 1;
+            let path = self
+                .dir
+                .join(format!("{:04}-{}.txt", n, file_name_part(to)));
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(file) => break file,
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(error(e)),
+            }
+        };
+        file.write_all(message.as_bytes()).map_err(error)
+    }
+}
+
+// A valid address may still contain `/` or other characters that don't
+// belong in a file name, so everything except letters, digits, and
+// `@._+-` becomes `_`
+fn file_name_part(to: &Email) -> String {
+    to.to_string()
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '@' | '.' | '_' | '+' | '-' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentMail {
+    pub to: Email,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Keeps the messages in memory, so tests can check what was sent, and
+/// fails on request, so tests can check what happens then
+#[derive(Default)]
+pub struct RecordingMailer {
+    sent: Mutex<Vec<SentMail>>,
+    failing: Mutex<bool>,
+}
+
+impl RecordingMailer {
+    pub fn new() -> Self {
+        RecordingMailer::default()
+    }
+
+    pub fn sent(&self) -> Vec<SentMail> {
+        self.sent.lock().unwrap().clone()
+    }
+
+    pub fn set_failing(&self, failing: bool) {
+        *self.failing.lock().unwrap() = failing;
+    }
+}
+
+impl Mailer for RecordingMailer {
+    fn send(&self, to: &Email, subject: &str, body: &str) -> Result<(), MailError> {
+        if *self.failing.lock().unwrap() {
+            return Err(MailError {
+                to: to.clone(),
+                reason: "the mail server is down".to_string(),
+            });
+        }
+        self.sent.lock().unwrap().push(SentMail {
+            to: to.clone(),
+            subject: subject.to_string(),
+            body: body.to_string(),
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outbox_mailer_writes_one_file_per_message() {
+        let dir = tempfile::tempdir().unwrap();
+        let mailer = OutboxMailer::new(dir.path().join("outbox")).unwrap();
+        let to: Email = "alice@example.com".parse().unwrap();
+        mailer.send(&to, "Hello", "First").unwrap();
+        mailer.send(&to, "Hello", "Second").unwrap();
+
+        let second = dir.path().join("outbox/0002-alice@example.com.txt");
+        assert_eq!(
+            fs::read_to_string(second).unwrap(),
+            "To: alice@example.com\nSubject: Hello\n\nSecond\n"
+        );
+    }
+
+    #[test]
+    fn outbox_mailer_never_overwrites_a_message() {
+        let dir = tempfile::tempdir().unwrap();
+        let outbox = dir.path().join("outbox");
+        let to: Email = "bob@example.com".parse().unwrap();
+        let first = OutboxMailer::new(&outbox).unwrap();
+        first.send(&to, "Hello", "First").unwrap();
+        first.send(&to, "Hello", "Second").unwrap();
+        // The first file was picked up, so only 0002 is left
+        fs::remove_file(outbox.join("0001-bob@example.com.txt")).unwrap();
+
+        let second = OutboxMailer::new(&outbox).unwrap();
+        // Another process takes the next number in the meantime
+        fs::write(outbox.join("0003-bob@example.com.txt"), "Elsewhere").unwrap();
+        second.send(&to, "Hello", "Third").unwrap();
+
+        let read = |name: &str| fs::read_to_string(outbox.join(name)).unwrap();
+        assert!(read("0002-bob@example.com.txt").ends_with("Second\n"));
+        assert_eq!(read("0003-bob@example.com.txt"), "Elsewhere");
+        assert!(read("0004-bob@example.com.txt").ends_with("Third\n"));
+    }
+
+    #[test]
+    fn outbox_file_names_stay_inside_the_outbox() {
+        let dir = tempfile::tempdir().unwrap();
+        let mailer = OutboxMailer::new(dir.path().join("outbox")).unwrap();
+        let to: Email = "a/b@example.com".parse().unwrap();
+        mailer.send(&to, "Hello", "Slash").unwrap();
+
+        let file = dir.path().join("outbox/0001-a_b@example.com.txt");
+        assert!(fs::read_to_string(file)
+            .unwrap()
+            .starts_with("To: a/b@example.com\n"));
+    }
+}
@@ -0,0 +1,37 @@
+use dependency_injection::{OutboxMailer, SqliteUserRepo, SystemClock, UserService};
+use newtypes::Email;
+use std::sync::Arc;
+
+// The only place that knows the real implementations. Everything they
+// need, such as file names, is decided here, too.
+fn build_service() -> Result<UserService, Box<dyn std::error::Error>> {
+    let clock = Arc::new(SystemClock);
+    let mailer = Arc::new(OutboxMailer::new("outbox")?);
+    let users = Arc::new(SqliteUserRepo::open("users.db")?);
+    Ok(UserService::new(clock, mailer, users))
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service = build_service()?;
+
+    // `cargo run -- alice@example.com bob@example.com`
+    for arg in std::env::args().skip(1) {
+        let result = arg
+            .parse::<Email>()
+            .map_err(|e| e.to_string())
+            .and_then(|email| service.register(email).map_err(|e| e.to_string()));
+        match result {
+            Ok(user) => println!(
+                "Registered {} as user {}, trial ends {}",
+                user.email,
+                user.id,
+                user.trial_ends_at.format("%Y-%m-%d")
+            ),
+            Err(e) => println!("Error: {}", e),
+        }
+    }
+
+    let reminders = service.send_trial_reminders()?;
+    println!("Sent {} trial reminders", reminders);
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use newtypes::{Email, UserId};
+use std::sync::Mutex;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct User {
+    pub id: UserId,
+    pub email: Email,
+    pub registered_at: DateTime<Utc>,
+    pub trial_ends_at: DateTime<Utc>,
+    pub reminded: bool,
+}
+
+/// The error doesn't name a database, so the trait doesn't depend on one
+#[derive(Debug, Error)]
+#[error("storage error: {0}")]
+pub struct RepoError(pub String);
+
+pub trait UserRepo: Send + Sync {
+    fn find_by_email(&self, email: &Email) -> Result<Option<User>, RepoError>;
+
+    /// Stores a new user and assigns its id
+    fn insert(
+        &self,
+        email: &Email,
+        registered_at: DateTime<Utc>,
+        trial_ends_at: DateTime<Utc>,
+    ) -> Result<User, RepoError>;
+
+    fn update(&self, user: &User) -> Result<(), RepoError>;
+
+    fn all(&self) -> Result<Vec<User>, RepoError>;
+}
+
+/// Users in a `Vec`, for tests and prototypes
+#[derive(Default)]
+pub struct InMemoryUserRepo {
+    users: Mutex<Vec<User>>,
+}
+
+impl InMemoryUserRepo {
+    pub fn new() -> Self {
+        InMemoryUserRepo::default()
+    }
+}
+
+impl UserRepo for InMemoryUserRepo {
+    fn find_by_email(&self, email: &Email) -> Result<Option<User>, RepoError> {
+        let users = self.users.lock().unwrap();
+        Ok(users.iter().find(|user| user.email == *email).cloned())
+    }
+
+    fn insert(
+        &self,
+        email: &Email,
+        registered_at: DateTime<Utc>,
+        trial_ends_at: DateTime<Utc>,
+    ) -> Result<User, RepoError> {
+        let mut users = self.users.lock().unwrap();
+        let id = UserId::try_from(users.len() as u64 + 1).unwrap();
+        let user = User {
+            id,
+            email: email.clone(),
+            registered_at,
+            trial_ends_at,
+            reminded: false,
+        };
+        users.push(user.clone());
+        Ok(user)
+    }
+
+    fn update(&self, user: &User) -> Result<(), RepoError> {
+        let mut users = self.users.lock().unwrap();
+        let stored = users
+            .iter_mut()
+            .find(|stored| stored.id == user.id)
+            .ok_or_else(|| RepoError(format!("user {} not found", user.id)))?;
+        *stored = user.clone();
+        Ok(())
+    }
+
+    fn all(&self) -> Result<Vec<User>, RepoError> {
+        Ok(self.users.lock().unwrap().clone())
+    }
+}
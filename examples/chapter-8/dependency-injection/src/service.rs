@@ -0,0 +1,194 @@
+use crate::{Clock, Mailer, RepoError, User, UserRepo};
+use chrono::Duration;
+use newtypes::Email;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ServiceError {
+    #[error("{0} is already registered")]
+    AlreadyRegistered(Email),
+    #[error(transparent)]
+    Repo(#[from] RepoError),
+}
+
+/// The business logic: registering users for a trial and reminding them
+/// before it ends.
+///
+/// The service doesn't create its dependencies, it receives them. It only
+/// knows the traits, so it works with any clock, mailer, and storage.
+pub struct UserService {
+    clock: Arc<dyn Clock>,
+    mailer: Arc<dyn Mailer>,
+    users: Arc<dyn UserRepo>,
+}
+
+impl UserService {
+    pub const TRIAL_DAYS: i64 = 14;
+    pub const REMIND_DAYS_BEFORE: i64 = 3;
+
+    pub fn new(clock: Arc<dyn Clock>, mailer: Arc<dyn Mailer>, users: Arc<dyn UserRepo>) -> Self {
+        UserService {
+            clock,
+            mailer,
+            users,
+        }
+    }
+
+    /// Registers a user for a trial and sends a welcome email. The user is
+    /// registered even if the email fails: the account matters more.
+    pub fn register(&self, email: Email) -> Result<User, ServiceError> {
+        if self.users.find_by_email(&email)?.is_some() {
+            return Err(ServiceError::AlreadyRegistered(email));
+        }
+        let now = self.clock.now();
+        let trial_ends_at = now + Duration::days(Self::TRIAL_DAYS);
+        let user = self.users.insert(&email, now, trial_ends_at)?;
+
+        let body = format!("Your trial ends on {}.", trial_ends_at.format("%B %-d, %Y"));
+        if let Err(e) = self.mailer.send(&email, "Welcome!", &body) {
+            eprintln!("Warning: {}", e);
+        }
+        Ok(user)
+    }
+
+    /// Reminds every user whose trial ends within the next few days, once.
+    /// Returns the number of reminders sent. A user whose reminder fails is
+    /// tried again on the next call.
+    pub fn send_trial_reminders(&self) -> Result<usize, ServiceError> {
+        let now = self.clock.now();
+        let remind_from = now + Duration::days(Self::REMIND_DAYS_BEFORE);
+        let mut sent = 0;
+        for mut user in self.users.all()? {
+            let due =
+                !user.reminded && user.trial_ends_at > now && user.trial_ends_at <= remind_from;
+            if !due {
+                continue;
+            }
+            let days_left = (user.trial_ends_at - now).num_days();
+            let subject = match days_left {
+                0 => "Your trial ends today".to_string(),
+                1 => "Your trial ends tomorrow".to_string(),
+                n => format!("Your trial ends in {} days", n),
+            };
+            match self
+                .mailer
+                .send(&user.email, &subject, "Upgrade now to keep your data.")
+            {
+                Ok(()) => {
+                    user.reminded = true;
+                    self.users.update(&user)?;
+                    sent += 1;
+                }
+                Err(e) => eprintln!("Warning: {}", e),
+            }
+        }
+        Ok(sent)
+    }
+}
+
+// Fast, deterministic tests of the business logic: no database, no mail
+// server, and a clock that only moves when the test moves it
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FixedClock, InMemoryUserRepo, RecordingMailer};
+    use chrono::{DateTime, Utc};
+
+    struct Fixture {
+        clock: Arc<FixedClock>,
+        mailer: Arc<RecordingMailer>,
+        users: Arc<InMemoryUserRepo>,
+        service: UserService,
+    }
+
+    // The test keeps its own `Arc`s to the fakes, to move the clock and
+    // look at what the service did
+    fn fixture() -> Fixture {
+        let start = "2024-05-01T09:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let clock = Arc::new(FixedClock::new(start));
+        let mailer = Arc::new(RecordingMailer::new());
+        let users = Arc::new(InMemoryUserRepo::new());
+        let service = UserService::new(clock.clone(), mailer.clone(), users.clone());
+        Fixture {
+            clock,
+            mailer,
+            users,
+            service,
+        }
+    }
+
+    fn email(s: &str) -> Email {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn registers_users_for_a_trial() {
+        let f = fixture();
+        let user = f.service.register(email("alice@example.com")).unwrap();
+
+        assert_eq!(user.registered_at, f.clock.now());
+        assert_eq!(user.trial_ends_at.to_rfc3339(), "2024-05-15T09:00:00+00:00");
+        let sent = f.mailer.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].subject, "Welcome!");
+        assert_eq!(sent[0].body, "Your trial ends on May 15, 2024.");
+    }
+
+    #[test]
+    fn rejects_duplicate_registrations() {
+        let f = fixture();
+        f.service.register(email("alice@example.com")).unwrap();
+        let result = f.service.register(email("alice@example.com"));
+        assert!(matches!(result, Err(ServiceError::AlreadyRegistered(_))));
+        assert_eq!(f.users.all().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn keeps_the_user_when_the_welcome_email_fails() {
+        let f = fixture();
+        f.mailer.set_failing(true);
+        f.service.register(email("alice@example.com")).unwrap();
+        assert_eq!(f.users.all().unwrap().len(), 1);
+        assert!(f.mailer.sent().is_empty());
+    }
+
+    #[test]
+    fn reminds_once_three_days_before_the_trial_ends() {
+        let f = fixture();
+        f.service.register(email("alice@example.com")).unwrap();
+
+        // Ten days later, the trial ends in four days: too early
+        f.clock.advance(Duration::days(10));
+        assert_eq!(f.service.send_trial_reminders().unwrap(), 0);
+
+        f.clock.advance(Duration::days(1));
+        assert_eq!(f.service.send_trial_reminders().unwrap(), 1);
+        assert_eq!(f.mailer.sent()[1].subject, "Your trial ends in 3 days");
+
+        // Not twice
+        f.clock.advance(Duration::days(1));
+        assert_eq!(f.service.send_trial_reminders().unwrap(), 0);
+    }
+
+    #[test]
+    fn retries_failed_reminders() {
+        let f = fixture();
+        f.service.register(email("alice@example.com")).unwrap();
+        f.clock.advance(Duration::days(13));
+
+        f.mailer.set_failing(true);
+        assert_eq!(f.service.send_trial_reminders().unwrap(), 0);
+        f.mailer.set_failing(false);
+        assert_eq!(f.service.send_trial_reminders().unwrap(), 1);
+        assert_eq!(f.mailer.sent()[1].subject, "Your trial ends tomorrow");
+    }
+
+    #[test]
+    fn expired_trials_get_no_reminder() {
+        let f = fixture();
+        f.service.register(email("alice@example.com")).unwrap();
+        f.clock.advance(Duration::days(15));
+        assert_eq!(f.service.send_trial_reminders().unwrap(), 0);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{RepoError, User, UserRepo};
+use chrono::{DateTime, Utc};
+use newtypes::{Email, UserId};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use std::path::Path;
+use std::sync::Mutex;
+
+/// The real storage: a SQLite database, as in Chapter 10
+pub struct SqliteUserRepo {
+    // `Connection` can't be shared between threads, and the trait requires
+    // `Sync`, so every call takes the connection from a `Mutex`
+    conn: Mutex<Connection>,
+}
+
+impl From<rusqlite::Error> for RepoError {
+    fn from(error: rusqlite::Error) -> Self {
+        RepoError(error.to_string())
+    }
+}
+
+impl SqliteUserRepo {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, RepoError> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self, RepoError> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self, RepoError> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS users (
+                id            INTEGER PRIMARY KEY AUTOINCREMENT,
+                email         TEXT NOT NULL UNIQUE,
+                registered_at TEXT NOT NULL,
+                trial_ends_at TEXT NOT NULL,
+                reminded      INTEGER NOT NULL DEFAULT 0
+            );",
+        )?;
+        Ok(SqliteUserRepo {
+            conn: Mutex::new(conn),
+        })
+    }
+}
+
+const COLUMNS: &str = "id, email, registered_at, trial_ends_at, reminded";
+
+// The database only contains values that were valid when they were
+// stored, so an invalid one is reported as a conversion error
+fn row_to_user(row: &Row) -> rusqlite::Result<User> {
+    let invalid = |column, e: Box<dyn std::error::Error + Send + Sync>| {
+        rusqlite::Error::FromSqlConversionFailure(column, rusqlite::types::Type::Text, e)
+    };
+    let id: u64 = row.get(0)?;
+    let email: String = row.get(1)?;
+    Ok(User {
+        id: UserId::try_from(id).map_err(|e| invalid(0, e.into()))?,
+        email: Email::try_from(email).map_err(|e| invalid(1, e.into()))?,
+        registered_at: row.get(2)?,
+        trial_ends_at: row.get(3)?,
+        reminded: row.get(4)?,
+    })
+}
+
+impl UserRepo for SqliteUserRepo {
+    fn find_by_email(&self, email: &Email) -> Result<Option<User>, RepoError> {
+        let conn = self.conn.lock().unwrap();
+        let sql = format!("SELECT {} FROM users WHERE email = ?1", COLUMNS);
+        let user = conn
+            .query_row(&sql, [email.as_str()], row_to_user)
+            .optional()?;
+        Ok(user)
+    }
+
+    fn insert(
+        &self,
+        email: &Email,
+        registered_at: DateTime<Utc>,
+        trial_ends_at: DateTime<Utc>,
+    ) -> Result<User, RepoError> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO users (email, registered_at, trial_ends_at) VALUES (?1, ?2, ?3)",
+            params![email.as_str(), registered_at, trial_ends_at],
+        )?;
+        let id = UserId::try_from(conn.last_insert_rowid() as u64)
+            .map_err(|e| RepoError(e.to_string()))?;
+        Ok(User {
+            id,
+            email: email.clone(),
+            registered_at,
+            trial_ends_at,
+            reminded: false,
+        })
+    }
+
+    fn update(&self, user: &User) -> Result<(), RepoError> {
+        let conn = self.conn.lock().unwrap();
+        let changed = conn.execute(
+            "UPDATE users SET email = ?2, trial_ends_at = ?3, reminded = ?4 WHERE id = ?1",
+            params![
+                user.id.get(),
+                user.email.as_str(),
+                user.trial_ends_at,
+                user.reminded
+            ],
+        )?;
+        if changed == 0 {
+            return Err(RepoError(format!("user {} not found", user.id)));
+        }
+        Ok(())
+    }
+
+    fn all(&self) -> Result<Vec<User>, RepoError> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn.prepare(&format!("SELECT {} FROM users ORDER BY id", COLUMNS))?;
+        let users = statement
+            .query_map([], row_to_user)?
+            .collect::<Result<_, _>>()?;
+        Ok(users)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    // The in-memory and the SQLite repository must behave the same, so
+    // the service tests with one are meaningful for the other
+    #[test]
+    fn stores_and_updates_users() {
+        let repo = SqliteUserRepo::open_in_memory().unwrap();
+        let email: Email = "alice@example.com".parse().unwrap();
+        let now = "2024-05-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        let mut user = repo.insert(&email, now, now + Duration::days(14)).unwrap();
+        assert_eq!(user.id.get(), 1);
+        assert!(repo.insert(&email, now, now).is_err(), "emails are unique");
+
+        user.reminded = true;
+        repo.update(&user).unwrap();
+        assert_eq!(repo.find_by_email(&email).unwrap(), Some(user.clone()));
+        assert_eq!(repo.all().unwrap(), vec![user]);
+    }
+}
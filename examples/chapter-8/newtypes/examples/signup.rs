@@ -0,0 +1,106 @@
+use newtypes::{Email, NonEmptyVec, UserId};
+use serde::{Deserialize, Serialize};
+
+/// A sign-up request as it arrives from a web form or API.
+///
+/// There is no `validate` method: if deserializing succeeds, every field
+/// is valid, because each field's type checks itself.
+#[derive(Debug, Deserialize)]
+struct SignupRequest {
+    email: Email,
+    interests: NonEmptyVec<String>,
+    referred_by: Option<UserId>,
+}
+
+#[derive(Debug, Serialize)]
+struct User {
+    id: UserId,
+    email: Email,
+    interests: NonEmptyVec<String>,
+}
+
+// Takes validated types, so it needs no checks and returns no errors
+fn register(id: UserId, request: SignupRequest) -> User {
+    if let Some(referrer) = request.referred_by {
+        println!("  {} was referred by user {}", request.email, referrer);
+    }
+    println!(
+        "  Welcome email to {} about {}",
+        request.email.local_part(),
+        request.interests.first()
+    );
+    User {
+        id,
+        email: request.email,
+        interests: request.interests,
+    }
+}
+
+fn main() {
+    let inputs = [
+        r#"{"email": "Alice@Example.com", "interests": ["rust", "web"], "referred_by": 7}"#,
+        r#"{"email": "bob@example", "interests": ["rust"]}"#,
+        r#"{"email": "carol@example.com", "interests": []}"#,
+        r#"{"email": "dave@example.com", "interests": ["cli"], "referred_by": 0}"#,
+    ];
+    for (n, json) in (1..).zip(inputs) {
+        println!("{}", json);
+        match serde_json::from_str::<SignupRequest>(json) {
+            Ok(request) => {
+                let id = UserId::try_from(n).expect("ids start at 1");
+                let user = register(id, request);
+                println!("  Stored: {}", serde_json::to_string(&user).unwrap());
+            }
+            Err(e) => println!("  Rejected: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> Result<SignupRequest, String> {
+        serde_json::from_str(json).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn valid_requests_are_normalized() {
+        let request = parse(r#"{"email": " a@EXAMPLE.com", "interests": ["x"]}"#).unwrap();
+        assert_eq!(request.email.as_str(), "a@example.com");
+        assert_eq!(request.referred_by, None);
+    }
+
+    #[test]
+    fn invalid_fields_are_rejected_with_our_messages() {
+        let error = parse(r#"{"email": "bob", "interests": ["x"]}"#).unwrap_err();
+        assert!(
+            error.starts_with("'bob' must contain exactly one '@'"),
+            "{}",
+            error
+        );
+        let error = parse(r#"{"email": "b@example.com", "interests": []}"#).unwrap_err();
+        assert!(
+            error.starts_with("the list must contain at least one element"),
+            "{}",
+            error
+        );
+        let error = parse(r#"{"email": "b@example.com", "interests": ["x"], "referred_by": 0}"#)
+            .unwrap_err();
+        assert!(
+            error.starts_with("UserId must be a positive number, got 0"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn newtypes_serialize_as_their_inner_value() {
+        let request = parse(r#"{"email": "a@example.com", "interests": ["x", "y"]}"#).unwrap();
+        let user = register(UserId::try_from(3).unwrap(), request);
+        assert_eq!(
+            serde_json::to_string(&user).unwrap(),
+            r#"{"id":3,"email":"a@example.com","interests":["x","y"]}"#
+        );
+    }
+}
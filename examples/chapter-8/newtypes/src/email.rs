@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// An email address that has passed validation.
+///
+/// The field is private, so the only way to get an `Email` is through
+/// `TryFrom` or `parse`, and every function that takes an `Email` can rely
+/// on it being valid without checking again.
+///
+/// Serde uses the same check: `#[serde(try_from = "String")]` deserializes
+/// a string and converts it with `TryFrom<String>`, so invalid JSON input
+/// is rejected with our error message. Serializing a newtype struct
+/// writes just the inner string.
+///
+/// ```
+/// use newtypes::Email;
+///
+/// let email: Email = " Alice@Example.COM ".parse().unwrap();
+/// assert_eq!(email.as_str(), "Alice@example.com");
+/// assert_eq!(email.domain(), "example.com");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Email(String);
+
+#[derive(Debug, Error, PartialEq)]
+pub enum EmailError {
+    #[error("email address is empty")]
+    Empty,
+    #[error("email address is longer than {max} bytes")]
+    TooLong { max: usize },
+    #[error("'{0}' must contain exactly one '@'")]
+    At(String),
+    #[error("'{0}' has no name before the '@'")]
+    NoLocalPart(String),
+    #[error("'{0}' has no valid domain after the '@'")]
+    InvalidDomain(String),
+    #[error("'{0}' contains whitespace")]
+    Whitespace(String),
+}
+
+impl Email {
+    // The limit from RFC 5321, which counts bytes, not characters
+    const MAX_LEN: usize = 254;
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The part before the '@'
+    pub fn local_part(&self) -> &str {
+        self.0.split_once('@').unwrap().0
+    }
+
+    /// The part after the '@', in lowercase
+    pub fn domain(&self) -> &str {
+        // Can't fail: the constructor checked that there is an '@'
+        self.0.split_once('@').unwrap().1
+    }
+}
+
+impl TryFrom<String> for Email {
+    type Error = EmailError;
+
+    /// Trims the address and lowercases the domain, which is case-insensitive.
+    /// The local part is kept as it is, because mail servers may treat
+    /// `Alice` and `alice` as different mailboxes.
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(EmailError::Empty);
+        }
+        if value.len() > Self::MAX_LEN {
+            return Err(EmailError::TooLong { max: Self::MAX_LEN });
+        }
+        if value.chars().any(char::is_whitespace) {
+            return Err(EmailError::Whitespace(value.to_string()));
+        }
+        let (local, domain) = match value.split_once('@') {
+            Some((local, domain)) if !domain.contains('@') => (local, domain),
+            _ => return Err(EmailError::At(value.to_string())),
+        };
+        if local.is_empty() {
+            return Err(EmailError::NoLocalPart(value.to_string()));
+        }
+        // At least two non-empty labels, such as "example.com"
+        if domain.split('.').count() < 2 || domain.split('.').any(str::is_empty) {
+            return Err(EmailError::InvalidDomain(value.to_string()));
+        }
+        Ok(Email(format!("{}@{}", local, domain.to_lowercase())))
+    }
+}
+
+impl TryFrom<&str> for Email {
+    type Error = EmailError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Email::try_from(value.to_string())
+    }
+}
+
+impl FromStr for Email {
+    type Err = EmailError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Email::try_from(s)
+    }
+}
+
+impl AsRef<str> for Email {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Email {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// Unwrapping is always fine: every `Email` is a valid `String`, but not
+// the other way around
+impl From<Email> for String {
+    fn from(email: Email) -> Self {
+        email.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_and_normalizes_valid_addresses() {
+        let email = Email::try_from("  Bob.Smith@Mail.Example.ORG\n").unwrap();
+        assert_eq!(email.as_str(), "Bob.Smith@mail.example.org");
+        assert_eq!(email.local_part(), "Bob.Smith");
+        assert_eq!(String::from(email), "Bob.Smith@mail.example.org");
+    }
+
+    #[test]
+    fn rejects_invalid_addresses() {
+        let error = |s: &str| Email::try_from(s).unwrap_err();
+        assert_eq!(error("   "), EmailError::Empty);
+        assert_eq!(error("alice"), EmailError::At("alice".into()));
+        assert_eq!(error("a@b@c.com"), EmailError::At("a@b@c.com".into()));
+        assert_eq!(
+            error("@example.com"),
+            EmailError::NoLocalPart("@example.com".into())
+        );
+        assert_eq!(
+            error("alice@localhost"),
+            EmailError::InvalidDomain("alice@localhost".into())
+        );
+        assert_eq!(
+            error("alice@example."),
+            EmailError::InvalidDomain("alice@example.".into())
+        );
+        assert_eq!(
+            error("al ice@example.com"),
+            EmailError::Whitespace("al ice@example.com".into())
+        );
+        let long = format!("{}@example.com", "a".repeat(250));
+        assert_eq!(error(&long), EmailError::TooLong { max: 254 });
+        // 137 characters, but 262 bytes
+        let long = format!("{}@example.com", "ü".repeat(125));
+        assert_eq!(error(&long), EmailError::TooLong { max: 254 });
+    }
+}
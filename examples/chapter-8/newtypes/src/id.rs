@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::num::NonZeroU64;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+#[error("{kind} must be a positive number, got {value}")]
+pub struct InvalidId {
+    pub kind: &'static str,
+    pub value: u64,
+}
+
+// `UserId` and `OrderId` need the same code, so a macro from Chapter 18
+// writes it for both
+macro_rules! id_type {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[serde(try_from = "u64", into = "u64")]
+        pub struct $name(NonZeroU64);
+
+        impl $name {
+            pub fn get(self) -> u64 {
+                self.0.get()
+            }
+        }
+
+        impl TryFrom<u64> for $name {
+            type Error = InvalidId;
+
+            fn try_from(value: u64) -> Result<Self, Self::Error> {
+                NonZeroU64::new(value).map($name).ok_or(InvalidId {
+                    kind: stringify!($name),
+                    value,
+                })
+            }
+        }
+
+        impl From<$name> for u64 {
+            fn from(id: $name) -> u64 {
+                id.get()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+id_type!(
+    /// The id of a user. Ids start at 1, as in most databases.
+    ///
+    /// `UserId` and `OrderId` are both numbers, but different types, so
+    /// the compiler catches a user id passed where an order id belongs:
+    ///
+    /// ```compile_fail,E0308
+    /// use newtypes::{OrderId, UserId};
+    ///
+    /// fn cancel_order(user: UserId, order: OrderId) {}
+    ///
+    /// let user = UserId::try_from(7).unwrap();
+    /// let order = OrderId::try_from(42).unwrap();
+    /// cancel_order(order, user); // error[E0308]: arguments to this function are incorrect
+    /// ```
+    ///
+    /// `NonZeroU64` inside also makes `Option<UserId>` as small as a `u64`,
+    /// because `None` can be stored as 0.
+    UserId
+);
+
+id_type!(
+    /// The id of an order
+    OrderId
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_must_be_positive() {
+        assert_eq!(UserId::try_from(7).unwrap().get(), 7);
+        assert_eq!(
+            OrderId::try_from(0),
+            Err(InvalidId {
+                kind: "OrderId",
+                value: 0
+            })
+        );
+    }
+
+    #[test]
+    fn optional_ids_need_no_extra_space() {
+        assert_eq!(std::mem::size_of::<Option<UserId>>(), 8);
+    }
+}
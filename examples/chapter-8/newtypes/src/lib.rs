@@ -0,0 +1,11 @@
+//! Newtypes that make invalid values unrepresentable: an `Email` that has
+//! been validated, ids that can't be mixed up, and a vector that is never
+//! empty.
+
+mod email;
+mod id;
+mod non_empty;
+
+pub use email::{Email, EmailError};
+pub use id::{InvalidId, OrderId, UserId};
+pub use non_empty::{EmptyVecError, NonEmptyVec};
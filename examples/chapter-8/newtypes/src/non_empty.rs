@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use std::ops::Deref;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+#[error("the list must contain at least one element")]
+pub struct EmptyVecError;
+
+/// A `Vec` with at least one element.
+///
+/// `first` and `last` return a `&T`, not an `Option<&T>`: the check for
+/// emptiness happened once, when the value was created. There is no
+/// method that removes elements, so the invariant can't be broken later.
+///
+/// ```
+/// use newtypes::NonEmptyVec;
+///
+/// let mut tags = NonEmptyVec::new("rust");
+/// tags.push("beginners");
+/// assert_eq!(*tags.first(), "rust");
+/// // Slice methods work through `Deref`
+/// assert!(tags.contains(&"beginners"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<T>")]
+pub struct NonEmptyVec<T>(Vec<T>);
+
+impl<T> NonEmptyVec<T> {
+    pub fn new(first: T) -> Self {
+        NonEmptyVec(vec![first])
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.0.push(value);
+    }
+
+    pub fn first(&self) -> &T {
+        &self.0[0]
+    }
+
+    pub fn last(&self) -> &T {
+        &self.0[self.0.len() - 1]
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
+    type Error = EmptyVecError;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        if vec.is_empty() {
+            Err(EmptyVecError)
+        } else {
+            Ok(NonEmptyVec(vec))
+        }
+    }
+}
+
+// Read-only access to the elements as a slice: `len`, `iter`, indexing.
+// There is no `DerefMut` to `Vec`, which would allow `clear`.
+impl<T> Deref for NonEmptyVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> IntoIterator for NonEmptyVec<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyVec<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_only_be_created_with_elements() {
+        assert_eq!(NonEmptyVec::<i32>::try_from(vec![]), Err(EmptyVecError));
+        let numbers = NonEmptyVec::try_from(vec![3, 1, 2]).unwrap();
+        assert_eq!((*numbers.first(), *numbers.last()), (3, 2));
+        assert_eq!(numbers.iter().max(), Some(&3));
+        assert_eq!(numbers.into_vec(), [3, 1, 2]);
+    }
+}
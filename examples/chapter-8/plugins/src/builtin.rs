@@ -0,0 +1,144 @@
+//! The plugins that come with the crate. Each one registers itself next
+//! to its definition; there is no list of them anywhere else.
+
+use crate::{register_plugin, Plugin, PluginError};
+use std::collections::HashSet;
+
+/// Joins lines with a newline after each, like the input had
+fn join_lines<'a>(lines: impl Iterator<Item = &'a str>) -> String {
+    lines.map(|line| format!("{}\n", line)).collect()
+}
+
+#[derive(Default)]
+pub struct Upper;
+
+impl Plugin for Upper {
+    fn name(&self) -> &'static str {
+        "upper"
+    }
+
+    fn description(&self) -> &'static str {
+        "Converts the text to uppercase"
+    }
+
+    fn process(&self, input: &str) -> Result<String, PluginError> {
+        Ok(input.to_uppercase())
+    }
+}
+
+register_plugin!(Upper);
+
+#[derive(Default)]
+pub struct Sort;
+
+impl Plugin for Sort {
+    fn name(&self) -> &'static str {
+        "sort"
+    }
+
+    fn description(&self) -> &'static str {
+        "Sorts the lines"
+    }
+
+    fn process(&self, input: &str) -> Result<String, PluginError> {
+        let mut lines: Vec<&str> = input.lines().collect();
+        lines.sort_unstable();
+        Ok(join_lines(lines.into_iter()))
+    }
+}
+
+register_plugin!(Sort);
+
+#[derive(Default)]
+pub struct Dedup;
+
+impl Plugin for Dedup {
+    fn name(&self) -> &'static str {
+        "dedup"
+    }
+
+    fn description(&self) -> &'static str {
+        "Removes repeated lines, keeping the first one"
+    }
+
+    fn process(&self, input: &str) -> Result<String, PluginError> {
+        let mut seen = HashSet::new();
+        Ok(join_lines(input.lines().filter(|line| seen.insert(*line))))
+    }
+}
+
+register_plugin!(Dedup);
+
+#[derive(Default)]
+pub struct Count;
+
+impl Plugin for Count {
+    fn name(&self) -> &'static str {
+        "count"
+    }
+
+    fn description(&self) -> &'static str {
+        "Counts lines, words, and characters"
+    }
+
+    fn process(&self, input: &str) -> Result<String, PluginError> {
+        Ok(format!(
+            "{} lines, {} words, {} characters\n",
+            input.lines().count(),
+            input.split_whitespace().count(),
+            input.chars().count()
+        ))
+    }
+}
+
+register_plugin!(Count);
+
+#[derive(Default)]
+pub struct Json;
+
+impl Plugin for Json {
+    fn name(&self) -> &'static str {
+        "json"
+    }
+
+    fn description(&self) -> &'static str {
+        "Pretty-prints JSON, fails on invalid JSON"
+    }
+
+    fn process(&self, input: &str) -> Result<String, PluginError> {
+        let value: serde_json::Value = serde_json::from_str(input).map_err(|e| self.error(e))?;
+        let pretty = serde_json::to_string_pretty(&value).map_err(|e| self.error(e))?;
+        Ok(pretty + "\n")
+    }
+}
+
+register_plugin!(Json);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_and_dedup_work_on_lines() {
+        assert_eq!(Sort.process("b\na\nc").unwrap(), "a\nb\nc\n");
+        assert_eq!(Dedup.process("a\nb\na\nb\nc\n").unwrap(), "a\nb\nc\n");
+    }
+
+    #[test]
+    fn count_counts_unicode_characters() {
+        assert_eq!(
+            Count.process("héllo wörld\n").unwrap(),
+            "1 lines, 2 words, 12 characters\n"
+        );
+    }
+
+    #[test]
+    fn json_reports_invalid_input() {
+        assert_eq!(
+            Json.process(r#"{"a":[1,2]}"#).unwrap(),
+            "{\n  \"a\": [\n    1,\n    2\n  ]\n}\n"
+        );
+        let error = Json.process("{oops").unwrap_err();
+        assert_eq!(error.plugin, "json");
+    }
+}
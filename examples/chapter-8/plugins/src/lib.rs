@@ -0,0 +1,70 @@
+//! Text processors that implement a common `Plugin` trait and are found
+//! and run by name at runtime.
+//!
+//! A plugin registers itself with `register_plugin!`, wherever it is
+//! defined. `Registry::discover` collects every registered plugin without
+//! a central list that has to be updated for each new one.
+
+pub mod builtin;
+mod registry;
+
+pub use registry::{Registry, RegistryError};
+
+// The macro below expands in other crates, which may not depend on
+// `inventory` themselves
+#[doc(hidden)]
+pub use inventory;
+
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+#[error("{plugin}: {reason}")]
+pub struct PluginError {
+    pub plugin: &'static str,
+    pub reason: String,
+}
+
+/// A processor that turns text into other text.
+///
+/// `Send + Sync` allows the registry to be shared between threads.
+pub trait Plugin: Send + Sync {
+    /// The name used to select the plugin on the command line
+    fn name(&self) -> &'static str;
+
+    /// A one-line description for the list of plugins
+    fn description(&self) -> &'static str;
+
+    fn process(&self, input: &str) -> Result<String, PluginError>;
+
+    /// A helper for implementations to create an error with their name
+    fn error(&self, reason: impl ToString) -> PluginError
+    where
+        Self: Sized,
+    {
+        PluginError {
+            plugin: self.name(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// An entry collected by `inventory`: a function that creates the plugin.
+///
+/// `inventory` only accepts values that can be built at compile time, and a
+/// `Box<dyn Plugin>` can't, but a pointer to a function that builds one can.
+pub struct PluginFactory(pub fn() -> Box<dyn Plugin>);
+
+inventory::collect!(PluginFactory);
+
+/// Registers a plugin type so that `Registry::discover` finds it. The type
+/// must implement `Plugin` and `Default`.
+#[macro_export]
+macro_rules! register_plugin {
+    ($plugin:ty) => {
+        $crate::inventory::submit! {
+            $crate::PluginFactory(|| {
+                ::std::boxed::Box::new(<$plugin as ::std::default::Default>::default())
+            })
+        }
+    };
+}
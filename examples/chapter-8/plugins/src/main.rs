@@ -0,0 +1,59 @@
+use plugins::{register_plugin, Plugin, PluginError, Registry};
+use std::io::Read;
+use std::process::ExitCode;
+
+// A plugin defined by the program, not by the library. It registers
+// itself the same way as the built-in ones.
+#[derive(Default)]
+struct Number;
+
+impl Plugin for Number {
+    fn name(&self) -> &'static str {
+        "number"
+    }
+
+    fn description(&self) -> &'static str {
+        "Numbers the lines"
+    }
+
+    fn process(&self, input: &str) -> Result<String, PluginError> {
+        Ok(input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| format!("{:>3} {}\n", i + 1, line))
+            .collect())
+    }
+}
+
+register_plugin!(Number);
+
+fn main() -> ExitCode {
+    let registry = Registry::discover();
+    let names: Vec<String> = std::env::args().skip(1).collect();
+
+    if names.is_empty() || names[0] == "list" {
+        println!("Usage: plugins <plugin>... < input");
+        println!("Available plugins:");
+        for plugin in registry.plugins() {
+            println!("  {:<8} {}", plugin.name(), plugin.description());
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let mut input = String::new();
+    if let Err(e) = std::io::stdin().read_to_string(&mut input) {
+        eprintln!("Error: failed to read the input: {}", e);
+        return ExitCode::FAILURE;
+    }
+
+    match registry.run(&names, &input) {
+        Ok(output) => {
+            print!("{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
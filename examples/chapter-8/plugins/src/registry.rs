@@ -0,0 +1,136 @@
+use crate::{Plugin, PluginError, PluginFactory};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum RegistryError {
+    #[error("a plugin named '{0}' is already registered")]
+    Duplicate(&'static str),
+    #[error("unknown plugin '{name}', available: {available}")]
+    Unknown { name: String, available: String },
+    #[error(transparent)]
+    Plugin(#[from] PluginError),
+}
+
+/// The plugins known to the program, by name.
+///
+/// The plugins are stored as `Box<dyn Plugin>`: different types behind one
+/// interface, chosen at runtime. A `BTreeMap` keeps them sorted by name.
+#[derive(Default)]
+pub struct Registry {
+    plugins: BTreeMap<&'static str, Box<dyn Plugin>>,
+}
+
+impl Registry {
+    /// An empty registry, for registering plugins by hand
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A registry with every plugin registered with `register_plugin!`
+    /// in any crate linked into the program.
+    ///
+    /// Panics if two plugins have the same name: that's a bug in the
+    /// program, not something a user can fix.
+    pub fn discover() -> Self {
+        let mut registry = Self::new();
+        for factory in inventory::iter::<PluginFactory> {
+            let plugin = (factory.0)();
+            // The order in which `inventory` returns the entries is not
+            // specified, so there is no "first" one to keep
+            if let Err(e) = registry.register(plugin) {
+                panic!("{}", e);
+            }
+        }
+        registry
+    }
+
+    pub fn register(&mut self, plugin: Box<dyn Plugin>) -> Result<(), RegistryError> {
+        let name = plugin.name();
+        if self.plugins.contains_key(name) {
+            return Err(RegistryError::Duplicate(name));
+        }
+        self.plugins.insert(name, plugin);
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Result<&dyn Plugin, RegistryError> {
+        self.plugins
+            .get(name)
+            .map(|plugin| plugin.as_ref())
+            .ok_or_else(|| RegistryError::Unknown {
+                name: name.to_string(),
+                available: self.names().collect::<Vec<_>>().join(", "),
+            })
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.plugins.keys().copied()
+    }
+
+    pub fn plugins(&self) -> impl Iterator<Item = &dyn Plugin> {
+        self.plugins.values().map(|plugin| plugin.as_ref())
+    }
+
+    /// Runs the named plugins one after another, each on the output of the
+    /// previous one. All names are checked before any plugin runs.
+    pub fn run<S: AsRef<str>>(&self, names: &[S], input: &str) -> Result<String, RegistryError> {
+        let pipeline = names
+            .iter()
+            .map(|name| self.get(name.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut text = input.to_string();
+        for plugin in pipeline {
+            text = plugin.process(&text)?;
+        }
+        Ok(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtin::{Sort, Upper};
+
+    #[test]
+    fn discovers_the_builtin_plugins() {
+        let registry = Registry::discover();
+        let names: Vec<_> = registry.names().collect();
+        assert_eq!(names, ["count", "dedup", "json", "sort", "upper"]);
+    }
+
+    #[test]
+    fn rejects_duplicate_names() {
+        let mut registry = Registry::new();
+        registry.register(Box::new(Upper)).unwrap();
+        let result = registry.register(Box::new(Upper));
+        assert_eq!(result, Err(RegistryError::Duplicate("upper")));
+    }
+
+    #[test]
+    fn runs_plugins_in_order() {
+        let mut registry = Registry::new();
+        registry.register(Box::new(Upper)).unwrap();
+        registry.register(Box::new(Sort)).unwrap();
+        let output = registry.run(&["sort", "upper"], "pear\napple\n").unwrap();
+        assert_eq!(output, "APPLE\nPEAR\n");
+    }
+
+    #[test]
+    fn checks_all_names_before_running() {
+        let registry = Registry::discover();
+        let error = registry.run(&["upper", "nope"], "text").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unknown plugin 'nope', available: count, dedup, json, sort, upper"
+        );
+    }
+
+    #[test]
+    fn reports_which_plugin_failed() {
+        let registry = Registry::discover();
+        let error = registry.run(&["upper", "json"], "{oops").unwrap_err();
+        assert!(error.to_string().starts_with("json: "), "{}", error);
+    }
+}
@@ -0,0 +1,32 @@
+use networking::tcp::{self, Request, Response};
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    let mut conn = tcp::connect("127.0.0.1:4000").await?;
+
+    let requests = [
+        Request::Ping,
+        Request::Set {
+            key: "language".into(),
+            value: "Rust".into(),
+        },
+        Request::Get {
+            key: "language".into(),
+        },
+        Request::Get {
+            key: "missing".into(),
+        },
+    ];
+
+    for request in requests {
+        tcp::send(&mut conn, &request).await?;
+        match tcp::receive::<Response>(&mut conn).await? {
+            Some(response) => println!("{:?} -> {:?}", request, response),
+            None => {
+                println!("Server closed the connection");
+                break;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use networking::udp::{self, Metrics};
+use tokio::net::UdpSocket;
+use tracing::info;
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    tracing_subscriber::fmt().with_target(false).init();
+
+    let socket = UdpSocket::bind("127.0.0.1:5000").await?;
+    info!("Receiving on udp://{}", socket.local_addr()?);
+
+    // Lose every third reply to see the sender's re-send logic at work
+    udp::receive(socket, Metrics::default(), Some(3)).await
+}
@@ -0,0 +1,20 @@
+use networking::udp::Sender;
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    tracing_subscriber::fmt().with_target(false).init();
+
+    let mut sender = Sender::connect("127.0.0.1:5000").await?;
+
+    for _ in 0..3 {
+        let rtt = sender.ping().await?;
+        println!("Pong after {:.1?}", rtt);
+    }
+
+    let metrics = [("cpu", 0.42), ("memory", 0.71), ("disk", 0.15)];
+    for (name, value) in metrics {
+        let attempts = sender.send_metric(name, value).await?;
+        println!("Metric {} acknowledged after {} attempt(s)", name, attempts);
+    }
+    Ok(())
+}
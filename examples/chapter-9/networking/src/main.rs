@@ -0,0 +1,14 @@
+use networking::tcp;
+use shutdown::shutdown_signal;
+use tokio::net::TcpListener;
+use tracing::info;
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    tracing_subscriber::fmt().with_target(false).init();
+
+    let listener = TcpListener::bind("127.0.0.1:4000").await?;
+    info!("Listening on {}", listener.local_addr()?);
+    // Ctrl-C or SIGTERM stops the accept loop, and `main` returns
+    tcp::serve(listener, shutdown_signal()).await
+}
@@ -0,0 +1,121 @@
+use bytes::Bytes;
+use futures::{SinkExt, StreamExt};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::io;
+use std::sync::{Arc, Mutex};
+use tokio::net::{TcpListener, TcpStream, ToSocketAddrs};
+use tokio_util::codec::{Framed, LengthDelimitedCodec};
+use tracing::{info, warn};
+
+// Larger frames are rejected before their payload is read into memory
+pub const MAX_FRAME_LENGTH: usize = 64 * 1024;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum Request {
+    Ping,
+    Get { key: String },
+    Set { key: String, value: String },
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum Response {
+    Pong,
+    Value { value: Option<String> },
+    Stored,
+    Error { message: String },
+}
+
+// A TCP stream that reads and writes whole frames instead of bytes.
+// Every frame is a 4-byte big-endian length followed by the payload.
+pub type Connection = Framed<TcpStream, LengthDelimitedCodec>;
+
+pub fn framed(stream: TcpStream) -> Connection {
+    let codec = LengthDelimitedCodec::builder()
+        .length_field_type::<u32>()
+        .max_frame_length(MAX_FRAME_LENGTH)
+        .new_codec();
+    Framed::new(stream, codec)
+}
+
+pub async fn connect(addr: impl ToSocketAddrs) -> io::Result<Connection> {
+    let stream = TcpStream::connect(addr).await?;
+    Ok(framed(stream))
+}
+
+pub async fn send<T: Serialize>(conn: &mut Connection, message: &T) -> io::Result<()> {
+    let payload = serde_json::to_vec(message)?;
+    conn.send(Bytes::from(payload)).await
+}
+
+// Returns `None` when the other side closed the connection
+pub async fn receive<T: DeserializeOwned>(conn: &mut Connection) -> io::Result<Option<T>> {
+    match conn.next().await {
+        Some(frame) => Ok(Some(serde_json::from_slice(&frame?)?)),
+        None => Ok(None),
+    }
+}
+
+type Db = Arc<Mutex<HashMap<String, String>>>;
+
+// Accepts connections until `shutdown` completes, handling each one in
+// its own task. Connections that are still open end with the runtime.
+pub async fn serve(listener: TcpListener, shutdown: impl Future<Output = ()>) -> io::Result<()> {
+    let db = Db::default();
+    tokio::pin!(shutdown);
+    loop {
+        let (stream, peer) = tokio::select! {
+            accepted = listener.accept() => accepted?,
+            () = &mut shutdown => {
+                info!("Stopped accepting connections");
+                return Ok(());
+            }
+        };
+        let db = db.clone();
+        tokio::spawn(async move {
+            info!(%peer, "Client connected");
+            // An error only ends this connection, never the server
+            match handle_connection(stream, db).await {
+                Ok(()) => info!(%peer, "Client disconnected"),
+                Err(e) => warn!(%peer, error = %e, "Connection closed with error"),
+            }
+        });
+    }
+}
+
+async fn handle_connection(stream: TcpStream, db: Db) -> io::Result<()> {
+    let mut conn = framed(stream);
+
+    // `next()` waits until a complete frame has arrived, no matter
+    // how many reads it takes
+    while let Some(frame) = conn.next().await {
+        let frame = frame?;
+        let response = match serde_json::from_slice::<Request>(&frame) {
+            Ok(request) => execute(request, &db),
+            // A valid frame with an invalid payload: answer and keep going
+            Err(e) => Response::Error {
+                message: format!("Invalid request: {}", e),
+            },
+        };
+        send(&mut conn, &response).await?;
+    }
+    Ok(())
+}
+
+fn execute(request: Request, db: &Db) -> Response {
+    let mut db = db.lock().unwrap();
+    match request {
+        Request::Ping => Response::Pong,
+        Request::Get { key } => Response::Value {
+            value: db.get(&key).cloned(),
+        },
+        Request::Set { key, value } => {
+            db.insert(key, value);
+            Response::Stored
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::net::{ToSocketAddrs, UdpSocket};
+use tokio::time::timeout;
+use tracing::{info, warn};
+
+// Small enough to never be split into several IP packets
+const MAX_DATAGRAM: usize = 1024;
+
+// Every datagram carries exactly one packet, so no framing is needed
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum Packet {
+    Ping { seq: u64 },
+    Pong { seq: u64 },
+    Metric { seq: u64, name: String, value: f64 },
+    Ack { seq: u64 },
+}
+
+impl Packet {
+    fn seq(&self) -> u64 {
+        match self {
+            Packet::Ping { seq }
+            | Packet::Pong { seq }
+            | Packet::Metric { seq, .. }
+            | Packet::Ack { seq } => *seq,
+        }
+    }
+}
+
+pub type Metrics = Arc<Mutex<Vec<(String, f64)>>>;
+
+// Answers pings and records metrics. To simulate an unreliable network,
+// every `lose_every`-th reply is "lost" and never sent.
+pub async fn receive(
+    socket: UdpSocket,
+    metrics: Metrics,
+    lose_every: Option<u64>,
+) -> io::Result<()> {
+    let mut buf = [0; MAX_DATAGRAM];
+    // Re-sent packets arrive twice; remember which ones we've seen
+    let mut seen: HashSet<(SocketAddr, u64)> = HashSet::new();
+    let mut replies = 0u64;
+
+    loop {
+        // There are no connections: each datagram tells us who sent it
+        let (len, peer) = socket.recv_from(&mut buf).await?;
+        let packet: Packet = match serde_json::from_slice(&buf[..len]) {
+            Ok(packet) => packet,
+            Err(e) => {
+                warn!(%peer, error = %e, "Ignoring invalid packet");
+                continue;
+            }
+        };
+
+        let reply = match packet {
+            Packet::Ping { seq } => Packet::Pong { seq },
+            Packet::Metric { seq, name, value } => {
+                if seen.insert((peer, seq)) {
+                    info!(%peer, seq, %name, value, "Metric recorded");
+                    metrics.lock().unwrap().push((name, value));
+                } else {
+                    info!(%peer, seq, "Duplicate metric ignored");
+                }
+                Packet::Ack { seq }
+            }
+            other => {
+                warn!(%peer, ?other, "Unexpected packet");
+                continue;
+            }
+        };
+
+        replies += 1;
+        if lose_every.is_some_and(|n| replies.is_multiple_of(n)) {
+            warn!(%peer, ?reply, "Simulating a lost reply");
+            continue;
+        }
+        socket.send_to(&serde_json::to_vec(&reply)?, peer).await?;
+    }
+}
+
+pub struct Sender {
+    socket: UdpSocket,
+    next_seq: u64,
+    timeout: Duration,
+    max_attempts: u32,
+}
+
+impl Sender {
+    pub async fn connect(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        // Port 0: let the operating system pick a free local port
+        let socket = UdpSocket::bind("127.0.0.1:0").await?;
+        // "Connecting" a UDP socket sends nothing; it only sets the default
+        // destination and filters out datagrams from other addresses
+        socket.connect(addr).await?;
+        Ok(Sender {
+            socket,
+            next_seq: 0,
+            timeout: Duration::from_millis(200),
+            max_attempts: 5,
+        })
+    }
+
+    pub fn with_retries(mut self, timeout: Duration, max_attempts: u32) -> Self {
+        self.timeout = timeout;
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    // Returns the round-trip time, including any re-sends
+    pub async fn ping(&mut self) -> io::Result<Duration> {
+        let start = Instant::now();
+        let seq = self.next_seq();
+        self.request(Packet::Ping { seq }).await?;
+        Ok(start.elapsed())
+    }
+
+    // Returns how many attempts it took until the metric was acknowledged
+    pub async fn send_metric(&mut self, name: &str, value: f64) -> io::Result<u32> {
+        let seq = self.next_seq();
+        let name = name.to_string();
+        self.request(Packet::Metric { seq, name, value }).await
+    }
+
+    fn next_seq(&mut self) -> u64 {
+        self.next_seq += 1;
+        self.next_seq
+    }
+
+    // UDP may lose any datagram, so we wait for a reply for a while
+    // and send the same packet again if none arrives
+    async fn request(&mut self, packet: Packet) -> io::Result<u32> {
+        let seq = packet.seq();
+        let bytes = serde_json::to_vec(&packet)?;
+
+        for attempt in 1..=self.max_attempts {
+            self.socket.send(&bytes).await?;
+            match timeout(self.timeout, self.wait_for_reply(seq)).await {
+                Ok(Ok(())) => return Ok(attempt),
+                // Nobody is listening (yet); the receiver may be restarting
+                Ok(Err(e)) if e.kind() == io::ErrorKind::ConnectionRefused => {
+                    warn!(seq, attempt, "Connection refused, re-sending");
+                    tokio::time::sleep(self.timeout).await;
+                }
+                Ok(Err(e)) => return Err(e),
+                Err(_elapsed) => warn!(seq, attempt, "No reply, re-sending"),
+            }
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!(
+                "no reply to packet {} after {} attempts",
+                seq, self.max_attempts
+            ),
+        ))
+    }
+
+    async fn wait_for_reply(&self, seq: u64) -> io::Result<()> {
+        let mut buf = [0; MAX_DATAGRAM];
+        loop {
+            let len = self.socket.recv(&mut buf).await?;
+            // Late replies to earlier packets are skipped
+            match serde_json::from_slice::<Packet>(&buf[..len]) {
+                Ok(reply) if reply.seq() == seq => return Ok(()),
+                _ => continue,
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use networking::tcp::{self, Request, Response, MAX_FRAME_LENGTH};
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::oneshot;
+
+async fn start_server() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(tcp::serve(listener, std::future::pending()));
+    addr
+}
+
+async fn call(conn: &mut tcp::Connection, request: Request) -> Response {
+    tcp::send(conn, &request).await.unwrap();
+    tcp::receive(conn).await.unwrap().unwrap()
+}
+
+#[tokio::test]
+async fn clients_share_the_store() {
+    let addr = start_server().await;
+    let mut first = tcp::connect(addr).await.unwrap();
+    let mut second = tcp::connect(addr).await.unwrap();
+
+    let set = Request::Set {
+        key: "color".into(),
+        value: "green".into(),
+    };
+    assert_eq!(call(&mut first, set).await, Response::Stored);
+
+    let get = Request::Get {
+        key: "color".into(),
+    };
+    assert_eq!(
+        call(&mut second, get).await,
+        Response::Value {
+            value: Some("green".into())
+        }
+    );
+}
+
+#[tokio::test]
+async fn frames_arriving_in_pieces_are_reassembled() {
+    let addr = start_server().await;
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+
+    // Build a frame by hand: 4-byte length, then the JSON payload
+    let payload = br#"{"type":"Ping"}"#;
+    let mut frame = (payload.len() as u32).to_be_bytes().to_vec();
+    frame.extend_from_slice(payload);
+
+    // Send it three bytes at a time, so the server sees many partial reads
+    for chunk in frame.chunks(3) {
+        stream.write_all(chunk).await.unwrap();
+        stream.flush().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+
+    let len = stream.read_u32().await.unwrap() as usize;
+    let mut response = vec![0; len];
+    stream.read_exact(&mut response).await.unwrap();
+    assert_eq!(response, br#"{"type":"Pong"}"#);
+}
+
+#[tokio::test]
+async fn invalid_payload_gets_an_error_response() {
+    let addr = start_server().await;
+    let mut conn = tcp::connect(addr).await.unwrap();
+
+    tcp::send(&mut conn, &"not a request").await.unwrap();
+    let response: Response = tcp::receive(&mut conn).await.unwrap().unwrap();
+    assert!(matches!(response, Response::Error { .. }));
+
+    // The connection is still usable afterwards
+    assert_eq!(call(&mut conn, Request::Ping).await, Response::Pong);
+}
+
+#[tokio::test]
+async fn oversized_frame_closes_the_connection() {
+    let addr = start_server().await;
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+
+    // Announce a frame that's too large; the server gives up right away
+    let too_large = (MAX_FRAME_LENGTH + 1) as u32;
+    stream.write_all(&too_large.to_be_bytes()).await.unwrap();
+
+    let mut buf = [0; 1];
+    assert_eq!(stream.read(&mut buf).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn stops_accepting_on_shutdown() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (stop, stopped) = oneshot::channel::<()>();
+    let server = tokio::spawn(tcp::serve(listener, async {
+        let _ = stopped.await;
+    }));
+
+    let mut conn = tcp::connect(addr).await.unwrap();
+    assert_eq!(call(&mut conn, Request::Ping).await, Response::Pong);
+
+    stop.send(()).unwrap();
+    server.await.unwrap().unwrap();
+    // The listener is closed, so new clients are refused
+    assert!(TcpStream::connect(addr).await.is_err());
+}
@@ -0,0 +1,60 @@
+use networking::udp::{self, Metrics, Sender};
+use std::io::ErrorKind;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::net::UdpSocket;
+
+async fn start_receiver(lose_every: Option<u64>) -> (SocketAddr, Metrics) {
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let addr = socket.local_addr().unwrap();
+    let metrics = Metrics::default();
+    tokio::spawn(udp::receive(socket, metrics.clone(), lose_every));
+    (addr, metrics)
+}
+
+#[tokio::test]
+async fn ping_gets_a_pong() {
+    let (addr, _) = start_receiver(None).await;
+    let mut sender = Sender::connect(addr).await.unwrap();
+    assert!(sender.ping().await.is_ok());
+}
+
+#[tokio::test]
+async fn lost_replies_are_resent_without_duplicates() {
+    // Every second reply is lost, so every second metric needs two attempts
+    let (addr, metrics) = start_receiver(Some(2)).await;
+    let mut sender = Sender::connect(addr)
+        .await
+        .unwrap()
+        .with_retries(Duration::from_millis(50), 3);
+
+    assert_eq!(sender.send_metric("cpu", 0.5).await.unwrap(), 1);
+    assert_eq!(sender.send_metric("memory", 0.7).await.unwrap(), 2);
+
+    // The re-sent metric arrived twice but was recorded once
+    let recorded = metrics.lock().unwrap().clone();
+    assert_eq!(
+        recorded,
+        [("cpu".to_string(), 0.5), ("memory".to_string(), 0.7)]
+    );
+}
+
+#[tokio::test]
+async fn gives_up_when_nobody_answers() {
+    // A socket that receives packets but never replies
+    let silent = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let mut sender = Sender::connect(silent.local_addr().unwrap())
+        .await
+        .unwrap()
+        .with_retries(Duration::from_millis(20), 3);
+
+    let err = sender.ping().await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TimedOut);
+
+    // All three attempts reached the socket
+    let mut buf = [0; 1024];
+    for _ in 0..3 {
+        let len = silent.recv(&mut buf).await.unwrap();
+        assert_eq!(&buf[..len], br#"{"type":"Ping","seq":1}"#);
+    }
+}
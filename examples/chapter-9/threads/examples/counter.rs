@@ -0,0 +1,29 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+fn main() {
+    // `Arc` (atomically reference counted) lets several threads own the same
+    // value; `Mutex` makes sure only one of them changes it at a time
+    let counter = Arc::new(Mutex::new(0));
+    let mut handles = Vec::new();
+
+    for _ in 0..8 {
+        // Every thread gets its own handle to the same counter
+        let counter = Arc::clone(&counter);
+        handles.push(thread::spawn(move || {
+            for _ in 0..1_000 {
+                // `lock` blocks until no other thread holds the lock.
+                // The guard unlocks automatically when it goes out of scope.
+                let mut value = counter.lock().unwrap();
+                *value += 1;
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    println!("Counter: {}", *counter.lock().unwrap());
+    println!("Owners left: {}", Arc::strong_count(&counter));
+}
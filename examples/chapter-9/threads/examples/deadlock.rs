@@ -0,0 +1,104 @@
+use std::sync::{Arc, Barrier, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+struct Account {
+    id: u32,
+    balance: Mutex<i64>,
+}
+
+// A real program would call `lock()` here and, in a deadlock, wait forever.
+// To keep the demonstration from hanging, we retry `try_lock` for a while
+// and give up instead.
+fn lock_or_give_up<'a>(account: &'a Account, who: &str) -> Option<MutexGuard<'a, i64>> {
+    let deadline = Instant::now() + Duration::from_millis(300);
+    while Instant::now() < deadline {
+        if let Ok(guard) = account.balance.try_lock() {
+            return Some(guard);
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    println!(
+        "{}: waited 300 ms for account {}, giving up (deadlock!)",
+        who, account.id
+    );
+    None
+}
+
+// WRONG: locks `from` first and `to` second. Two opposite transfers each
+// hold one lock and wait for the other: neither can ever continue.
+fn transfer_unordered(from: &Account, to: &Account, amount: i64, who: &str, barrier: &Barrier) {
+    let mut from_balance = from.balance.lock().unwrap();
+    // Make sure both threads hold their first lock before going on
+    barrier.wait();
+    if let Some(mut to_balance) = lock_or_give_up(to, who) {
+        *from_balance -= amount;
+        *to_balance += amount;
+    }
+}
+
+// RIGHT: always lock the account with the smaller id first. Every thread
+// acquires the locks in the same order, so a cycle can't form.
+fn transfer_ordered(from: &Account, to: &Account, amount: i64) {
+    let (first, second) = if from.id < to.id {
+        (from, to)
+    } else {
+        (to, from)
+    };
+    let mut first_balance = first.balance.lock().unwrap();
+    let mut second_balance = second.balance.lock().unwrap();
+
+    if from.id < to.id {
+        *first_balance -= amount;
+        *second_balance += amount;
+    } else {
+        *second_balance -= amount;
+        *first_balance += amount;
+    }
+}
+
+fn main() {
+    let alice = Arc::new(Account {
+        id: 1,
+        balance: Mutex::new(100),
+    });
+    let bob = Arc::new(Account {
+        id: 2,
+        balance: Mutex::new(100),
+    });
+
+    println!("Unordered locking:");
+    let barrier = Arc::new(Barrier::new(2));
+    let t1 = {
+        let (alice, bob, barrier) = (alice.clone(), bob.clone(), barrier.clone());
+        thread::spawn(move || transfer_unordered(&alice, &bob, 10, "alice->bob", &barrier))
+    };
+    let t2 = {
+        let (alice, bob, barrier) = (alice.clone(), bob.clone(), barrier.clone());
+        thread::spawn(move || transfer_unordered(&bob, &alice, 20, "bob->alice", &barrier))
+    };
+    t1.join().unwrap();
+    t2.join().unwrap();
+
+    println!("Ordered locking:");
+    let handles: Vec<_> = (0..100)
+        .map(|i| {
+            let (alice, bob) = (alice.clone(), bob.clone());
+            thread::spawn(move || {
+                if i % 2 == 0 {
+                    transfer_ordered(&alice, &bob, 10);
+                } else {
+                    transfer_ordered(&bob, &alice, 10);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    println!(
+        "Balances: alice={} bob={}",
+        alice.balance.lock().unwrap(),
+        bob.balance.lock().unwrap()
+    );
+}
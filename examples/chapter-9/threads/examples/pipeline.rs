@@ -0,0 +1,111 @@
+use crossbeam_channel::{bounded, select, tick, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const FIRST: u64 = 1_000_000_000;
+const COUNT: u64 = 200_000;
+
+// The CPU-heavy step of the pipeline: trial division
+fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut divisor = 2;
+    while divisor * divisor <= n {
+        if n.is_multiple_of(divisor) {
+            return false;
+        }
+        divisor += 1;
+    }
+    true
+}
+
+// Stage 1: a single producer generates the candidates
+fn produce(numbers: Sender<u64>) {
+    for n in FIRST..FIRST + COUNT {
+        // `send` blocks while the bounded channel is full
+        numbers.send(n).unwrap();
+    }
+    // Dropping `numbers` disconnects the channel for the transformers
+}
+
+// Stage 2: several transformers share one input channel (fan-out)
+// and all write to one output channel (fan-in)
+fn transform(numbers: Receiver<u64>, primes: Sender<u64>) {
+    // Crossbeam channels can have many receivers; each number
+    // is delivered to exactly one of them
+    for n in numbers {
+        if is_prime(n) {
+            primes.send(n).unwrap();
+        }
+    }
+}
+
+// Stage 3: the consumer collects results and reports progress
+fn consume(primes: Receiver<u64>) -> Vec<u64> {
+    let ticker = tick(Duration::from_millis(250));
+    let mut found = Vec::new();
+    loop {
+        // `select!` waits on several channels and handles whichever is ready
+        select! {
+            recv(primes) -> prime => match prime {
+                Ok(prime) => found.push(prime),
+                // All transformers are done and the channel is empty
+                Err(_) => break,
+            },
+            recv(ticker) -> _ => println!("  ... {} primes so far", found.len()),
+        }
+    }
+    found
+}
+
+fn run_pipeline(transformers: usize) -> Vec<u64> {
+    let (numbers_tx, numbers_rx) = bounded(1_000);
+    let (primes_tx, primes_rx) = bounded(1_000);
+
+    let producer = thread::spawn(move || produce(numbers_tx));
+    let workers: Vec<_> = (0..transformers)
+        .map(|_| {
+            let (numbers, primes) = (numbers_rx.clone(), primes_tx.clone());
+            thread::spawn(move || transform(numbers, primes))
+        })
+        .collect();
+    // Drop our own copies, otherwise the channels would never disconnect
+    drop(numbers_rx);
+    drop(primes_tx);
+
+    let mut primes = consume(primes_rx);
+
+    producer.join().unwrap();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    // Transformers finish in any order, so restore the natural order
+    primes.sort_unstable();
+    primes
+}
+
+fn run_single_threaded() -> Vec<u64> {
+    (FIRST..FIRST + COUNT).filter(|&n| is_prime(n)).collect()
+}
+
+fn main() {
+    let transformers = thread::available_parallelism().map_or(4, |n| n.get());
+
+    let start = Instant::now();
+    let expected = run_single_threaded();
+    let single = start.elapsed();
+    println!("Single-threaded: {} primes in {:?}", expected.len(), single);
+
+    println!("Pipeline (transformers: {}):", transformers);
+    let start = Instant::now();
+    let primes = run_pipeline(transformers);
+    let pipeline = start.elapsed();
+    println!("Pipeline: {} primes in {:?}", primes.len(), pipeline);
+
+    assert_eq!(primes, expected);
+    println!(
+        "Speedup: {:.2}x",
+        single.as_secs_f64() / pipeline.as_secs_f64()
+    );
+}
@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    // Configuration is read constantly and changed rarely: a good fit for
+    // `RwLock`, which allows many readers at once or a single writer
+    let config = Arc::new(RwLock::new(HashMap::from([
+        ("log_level", "info".to_string()),
+        ("timeout", "30".to_string()),
+    ])));
+
+    let readers: Vec<_> = (1..=3)
+        .map(|id| {
+            let config = Arc::clone(&config);
+            thread::spawn(move || {
+                for _ in 0..3 {
+                    // Several threads can hold a read lock at the same time
+                    let level = config.read().unwrap()["log_level"].clone();
+                    println!("Reader {} sees log_level={}", id, level);
+                    thread::sleep(Duration::from_millis(20));
+                }
+            })
+        })
+        .collect();
+
+    let writer = {
+        let config = Arc::clone(&config);
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(30));
+            // A write lock waits until all readers are gone
+            // and blocks new readers while it's held
+            let mut config = config.write().unwrap();
+            config.insert("log_level", "debug".to_string());
+            println!("Writer changed log_level to debug");
+        })
+    };
+
+    for reader in readers {
+        reader.join().unwrap();
+    }
+    writer.join().unwrap();
+}
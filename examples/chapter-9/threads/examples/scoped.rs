@@ -0,0 +1,24 @@
+use std::thread;
+
+fn main() {
+    let numbers: Vec<u64> = (1..=1_000).collect();
+    let mut results = [0u64; 4];
+
+    // Threads started inside `thread::scope` are joined automatically at the
+    // end of the scope. Because they can't outlive it, they may borrow local
+    // data: no `Arc`, no `move`, no cloning.
+    thread::scope(|scope| {
+        for (chunk, result) in numbers.chunks(250).zip(results.iter_mut()) {
+            scope.spawn(move || {
+                // Each thread reads its own chunk and writes its own result slot
+                *result = chunk.iter().map(|n| n * n).sum();
+            });
+        }
+    });
+
+    println!("Partial sums: {:?}", results);
+    println!("Sum of squares: {}", results.iter().sum::<u64>());
+
+    // `numbers` is still ours: the threads only borrowed it
+    println!("Numbers still available: {} items", numbers.len());
+}
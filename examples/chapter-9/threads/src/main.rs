@@ -0,0 +1,20 @@
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    // `thread::spawn` starts an operating system thread.
+    // The closure must own its data, hence `move`.
+    let handles: Vec<_> = (1..=3)
+        .map(|id| {
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(10 * id));
+                println!("Thread {} finished", id);
+                id * 10
+            })
+        })
+        .collect();
+
+    // `join` waits for the thread and returns the closure's result
+    let total: u64 = handles.into_iter().map(|h| h.join().unwrap()).sum();
+    println!("Total: {}", total);
+}
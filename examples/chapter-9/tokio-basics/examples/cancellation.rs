@@ -0,0 +1,47 @@
+use std::time::Duration;
+use tokio::time::sleep;
+use tokio_util::sync::CancellationToken;
+
+async fn worker(id: u32, token: CancellationToken) {
+    let mut processed = 0;
+    loop {
+        tokio::select! {
+            // `cancelled()` completes as soon as the token is cancelled
+            _ = token.cancelled() => {
+                println!("Worker {}: cancelled after {} items, cleaning up", id, processed);
+                return;
+            }
+            _ = sleep(Duration::from_millis(100)) => {
+                processed += 1;
+            }
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let token = CancellationToken::new();
+
+    // Child tokens are cancelled together with their parent,
+    // but can also be cancelled on their own
+    let workers: Vec<_> = (1..=3)
+        .map(|id| tokio::spawn(worker(id, token.child_token())))
+        .collect();
+
+    let background = token.child_token();
+    let job = tokio::spawn(worker(99, background.clone()));
+
+    sleep(Duration::from_millis(250)).await;
+    println!("Stopping the background job only");
+    background.cancel();
+    job.await.unwrap();
+
+    sleep(Duration::from_millis(250)).await;
+    println!("Shutting down");
+    token.cancel();
+
+    for handle in workers {
+        handle.await.unwrap();
+    }
+    println!("All workers stopped");
+}
@@ -0,0 +1,53 @@
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+
+async fn load_user() -> String {
+    sleep(Duration::from_millis(200)).await;
+    "Alice".to_string()
+}
+
+async fn load_orders() -> Vec<u32> {
+    sleep(Duration::from_millis(300)).await;
+    vec![101, 102]
+}
+
+async fn load_balance(valid: bool) -> Result<f64, String> {
+    sleep(Duration::from_millis(100)).await;
+    if valid {
+        Ok(42.5)
+    } else {
+        Err("balance service unavailable".to_string())
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    // One after another: 200 ms + 300 ms
+    let start = Instant::now();
+    let user = load_user().await;
+    let orders = load_orders().await;
+    println!(
+        "Sequential: {} has {} orders ({} ms)",
+        user,
+        orders.len(),
+        start.elapsed().as_millis()
+    );
+
+    // `join!` polls both futures at the same time and waits for all of them:
+    // the total time is that of the slowest one
+    let start = Instant::now();
+    let (user, orders) = tokio::join!(load_user(), load_orders());
+    println!(
+        "Concurrent: {} has {} orders ({} ms)",
+        user,
+        orders.len(),
+        start.elapsed().as_millis()
+    );
+
+    // `try_join!` returns the first error and drops the remaining futures
+    let result = tokio::try_join!(load_balance(true), load_balance(false));
+    match result {
+        Ok((first, second)) => println!("Balances: {} and {}", first, second),
+        Err(error) => println!("try_join! failed: {}", error),
+    }
+}
@@ -0,0 +1,41 @@
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::{interval, sleep};
+
+async fn query_server(name: &str, millis: u64) -> String {
+    sleep(Duration::from_millis(millis)).await;
+    format!("response from {}", name)
+}
+
+#[tokio::main]
+async fn main() {
+    // `select!` waits for the first branch to complete and drops the others
+    tokio::select! {
+        response = query_server("primary", 300) => println!("Got {}", response),
+        response = query_server("replica", 100) => println!("Got {}", response),
+    }
+
+    // In a loop, `select!` handles several event sources at once
+    let (tx, mut rx) = mpsc::channel(8);
+    tokio::spawn(async move {
+        for message in ["first", "second", "third"] {
+            sleep(Duration::from_millis(120)).await;
+            tx.send(message).await.unwrap();
+        }
+        // Dropping `tx` here closes the channel
+    });
+
+    let mut ticker = interval(Duration::from_millis(100));
+    loop {
+        tokio::select! {
+            message = rx.recv() => match message {
+                Some(message) => println!("Message: {}", message),
+                None => {
+                    println!("Channel closed, stopping");
+                    break;
+                }
+            },
+            _ = ticker.tick() => println!("Tick"),
+        }
+    }
+}
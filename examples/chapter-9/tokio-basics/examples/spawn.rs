@@ -0,0 +1,45 @@
+use std::time::Duration;
+use tokio::task::JoinSet;
+use tokio::time::{sleep, Instant};
+
+async fn download(file: &str, millis: u64) -> usize {
+    sleep(Duration::from_millis(millis)).await;
+    println!("Downloaded {}", file);
+    file.len() * 1024
+}
+
+#[tokio::main]
+async fn main() {
+    let start = Instant::now();
+
+    // `spawn` starts a task that runs in the background, independently of
+    // the current one. The task must own its data, hence `async move`.
+    let handle = tokio::spawn(async move { download("report.pdf", 300).await });
+
+    println!("Spawned a task, main keeps running");
+
+    // Awaiting the `JoinHandle` returns the task's result, or an error
+    // if the task panicked or was aborted
+    let size = handle.await.unwrap();
+    println!("report.pdf: {} bytes", size);
+
+    // `JoinSet` manages many tasks and yields results as they finish
+    let mut set = JoinSet::new();
+    for (file, millis) in [("a.txt", 300), ("b.txt", 100), ("c.txt", 200)] {
+        set.spawn(download(file, millis));
+    }
+
+    let mut total = 0;
+    while let Some(result) = set.join_next().await {
+        total += result.unwrap();
+    }
+    println!("Downloaded {} bytes in {:?}", total, start.elapsed());
+
+    // `abort` stops a task at its next `.await`
+    let slow = tokio::spawn(download("huge.iso", 10_000));
+    slow.abort();
+    match slow.await {
+        Err(error) if error.is_cancelled() => println!("huge.iso download was aborted"),
+        _ => println!("huge.iso finished unexpectedly"),
+    }
+}
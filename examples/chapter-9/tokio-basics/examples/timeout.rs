@@ -0,0 +1,34 @@
+use std::time::Duration;
+use tokio::time::{sleep, timeout};
+
+async fn slow_operation(millis: u64) -> &'static str {
+    sleep(Duration::from_millis(millis)).await;
+    "done"
+}
+
+#[tokio::main]
+async fn main() {
+    // `timeout` wraps any future and gives up after the given duration
+    match timeout(Duration::from_millis(200), slow_operation(100)).await {
+        Ok(result) => println!("Fast operation: {}", result),
+        Err(_) => println!("Fast operation timed out"),
+    }
+
+    // When the time is up, the inner future is dropped, which cancels it
+    match timeout(Duration::from_millis(200), slow_operation(1_000)).await {
+        Ok(result) => println!("Slow operation: {}", result),
+        Err(elapsed) => println!("Slow operation: {}", elapsed),
+    }
+
+    // Retrying with a timeout per attempt
+    for attempt in 1..=3 {
+        let millis = 400 / attempt;
+        match timeout(Duration::from_millis(250), slow_operation(millis)).await {
+            Ok(result) => {
+                println!("Attempt {}: {}", attempt, result);
+                break;
+            }
+            Err(_) => println!("Attempt {}: timed out after 250 ms", attempt),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinSet;
+use tokio::time::sleep;
+use tracing::{info, info_span, Instrument};
+
+#[derive(Debug)]
+struct Job {
+    id: u32,
+    input: u64,
+}
+
+#[derive(Debug, PartialEq)]
+struct JobResult {
+    job_id: u32,
+    output: u64,
+}
+
+async fn process(job: &Job) -> u64 {
+    // Simulates an I/O-bound operation, such as calling another service
+    sleep(Duration::from_millis(50)).await;
+    job.input * job.input
+}
+
+// A worker takes jobs from the shared queue until the queue is closed
+async fn worker(queue: Arc<Mutex<mpsc::Receiver<Job>>>, results: mpsc::Sender<JobResult>) {
+    loop {
+        // The lock is held only while waiting for the next job,
+        // so other workers can take jobs while this one is processing
+        let job = queue.lock().await.recv().await;
+        let Some(job) = job else {
+            // `None` means every sender was dropped and the queue is empty
+            info!("queue closed, shutting down");
+            break;
+        };
+
+        let output = process(&job).await;
+        info!(job_id = job.id, output, "job done");
+        results
+            .send(JobResult {
+                job_id: job.id,
+                output,
+            })
+            .await
+            .expect("result receiver dropped");
+    }
+}
+
+async fn run_pool(jobs: Vec<Job>, worker_count: usize, queue_size: usize) -> Vec<JobResult> {
+    // A bounded channel: `send` waits while `queue_size` jobs are already queued.
+    // This backpressure keeps a fast producer from using unlimited memory.
+    let (job_tx, job_rx) = mpsc::channel(queue_size);
+    let (result_tx, mut result_rx) = mpsc::channel(queue_size);
+
+    // `mpsc` has a single receiver, so the workers share it behind a mutex
+    let queue = Arc::new(Mutex::new(job_rx));
+    let mut workers = JoinSet::new();
+    for id in 1..=worker_count {
+        let task = worker(Arc::clone(&queue), result_tx.clone());
+        // Every log line written inside the worker carries `worker{id=...}`
+        workers.spawn(task.instrument(info_span!("worker", id)));
+    }
+    // Only the workers hold result senders now, so the result channel
+    // closes as soon as the last worker exits
+    drop(result_tx);
+
+    let producer = tokio::spawn(
+        async move {
+            for job in jobs {
+                info!(job_id = job.id, free_slots = job_tx.capacity(), "submitting");
+                job_tx.send(job).await.expect("all workers stopped");
+            }
+            // `job_tx` is dropped here, which tells the workers to finish
+            info!("all jobs submitted");
+        }
+        .instrument(info_span!("producer")),
+    );
+
+    let mut results = Vec::new();
+    while let Some(result) = result_rx.recv().await {
+        results.push(result);
+    }
+
+    producer.await.unwrap();
+    while let Some(worker) = workers.join_next().await {
+        worker.unwrap();
+    }
+    results
+}
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt().with_target(false).init();
+
+    let jobs = (1..=8).map(|id| Job { id, input: id as u64 }).collect();
+    let mut results = run_pool(jobs, 3, 2).await;
+
+    results.sort_by_key(|result| result.job_id);
+    let outputs: Vec<u64> = results.iter().map(|result| result.output).collect();
+    println!("Results: {:?}", outputs);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn all_submitted_jobs_complete() {
+        let jobs = (1..=50).map(|id| Job { id, input: 2 }).collect();
+
+        let mut results = run_pool(jobs, 4, 3).await;
+
+        results.sort_by_key(|result| result.job_id);
+        let ids: Vec<u32> = results.iter().map(|result| result.job_id).collect();
+        assert_eq!(ids, (1..=50).collect::<Vec<_>>());
+        assert!(results.iter().all(|result| result.output == 4));
+    }
+
+    #[tokio::test]
+    async fn empty_job_list_shuts_down_cleanly() {
+        let results = run_pool(Vec::new(), 2, 1).await;
+        assert!(results.is_empty());
+    }
+}
@@ -0,0 +1,19 @@
+use std::time::Duration;
+use tokio::time::sleep;
+
+// An `async fn` returns a future. Nothing runs until the future is awaited.
+async fn fetch_greeting(name: &str) -> String {
+    // Simulates waiting for a network response without blocking the thread
+    sleep(Duration::from_millis(100)).await;
+    format!("Hello, {}!", name)
+}
+
+// `#[tokio::main]` starts the Tokio runtime and runs `main` on it
+#[tokio::main]
+async fn main() {
+    let future = fetch_greeting("Tokio");
+    println!("The future is created, but nothing has happened yet");
+
+    let greeting = future.await;
+    println!("{}", greeting);
+}